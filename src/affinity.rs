@@ -0,0 +1,349 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pinning benchmark threads to CPU cores.
+//!
+//! On hybrid CPUs such as Alder Lake, an unpinned benchmark thread
+//! migrates between performance and efficiency cores, and its latencies
+//! fall into two clusters depending on where it happened to run.
+//! [`CpuSelection`] names the cores to run on, either explicitly or as
+//! "the performance cores", and resolves to a [`CpuPinning`] that pins
+//! threads with `sched_setaffinity` on Linux and `SetThreadAffinityMask`
+//! on Windows. Recording the pinning in
+//! [`Metadata::cpu_pinning`](crate::Metadata::cpu_pinning) keeps pinned
+//! and unpinned reports apart.
+//!
+//! Performance cores are found in sysfs on Linux: the `cpu_core` PMU of
+//! Intel hybrid CPUs, or else the largest `cpu_capacity` of ARM
+//! big.LITTLE systems. On CPUs with only one kind of core they are all
+//! cores the process may run on.
+
+use std::fmt;
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+/// Why threads could not be pinned.
+#[derive(Debug)]
+pub enum AffinityError {
+    /// The platform has no thread affinity, e.g. macOS, or cannot tell
+    /// performance cores apart.
+    Unsupported,
+    /// A selected core does not exist or is not available to the process.
+    UnavailableCore(usize),
+    /// The selection resolved to no cores.
+    NoCores,
+    /// The OS refused the affinity.
+    Io(io::Error),
+}
+
+impl fmt::Display for AffinityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported => write!(f, "CPU affinity is not supported on this platform"),
+            Self::UnavailableCore(core) => write!(f, "core {} is not available", core),
+            Self::NoCores => write!(f, "no cores selected"),
+            Self::Io(source) => write!(f, "setting CPU affinity: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for AffinityError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Which cores to run benchmark threads on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CpuSelection {
+    /// These core indices, as the OS numbers them.
+    Cores(Vec<usize>),
+    /// All performance cores, avoiding the efficiency cores of hybrid
+    /// CPUs.
+    PerformanceCores,
+}
+
+impl CpuSelection {
+    /// Resolves the selection to cores available to the process, without
+    /// pinning anything.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use zkbench::{CpuSelection, Metadata};
+    ///
+    /// let pinning = CpuSelection::PerformanceCores.resolve()?;
+    /// pinning.apply()?;
+    /// let mut metadata = Metadata::create("my-impl", "0.1.0");
+    /// metadata.cpu_pinning = Some(pinning);
+    /// # Ok::<(), zkbench::AffinityError>(())
+    /// ```
+    pub fn resolve(&self) -> Result<CpuPinning, AffinityError> {
+        let allowed = allowed_cores()?;
+        let mut cores = match self {
+            Self::Cores(cores) => {
+                if let Some(&core) = cores.iter().find(|core| !allowed.contains(core)) {
+                    return Err(AffinityError::UnavailableCore(core));
+                }
+                cores.clone()
+            }
+            Self::PerformanceCores => performance_cores(&allowed)?,
+        };
+        cores.sort_unstable();
+        cores.dedup();
+        if cores.is_empty() {
+            return Err(AffinityError::NoCores);
+        }
+        Ok(CpuPinning {
+            cores,
+            performance_cores_only: *self == Self::PerformanceCores,
+        })
+    }
+}
+
+/// Cores benchmark threads were pinned to, as recorded in
+/// [`Metadata::cpu_pinning`](crate::Metadata::cpu_pinning).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CpuPinning {
+    /// Core indices, sorted.
+    pub cores: Vec<usize>,
+    /// Whether the cores were chosen as the performance cores.
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub performance_cores_only: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+impl CpuPinning {
+    /// Restricts the calling thread to the cores. Threads it spawns
+    /// afterwards inherit the restriction on Linux.
+    pub fn apply(&self) -> Result<(), AffinityError> {
+        set_affinity(&self.cores)
+    }
+}
+
+/// Pins the calling thread to the selected cores and returns the pinning
+/// to record.
+pub fn pin_current_thread(selection: &CpuSelection) -> Result<CpuPinning, AffinityError> {
+    let pinning = selection.resolve()?;
+    pinning.apply()?;
+    Ok(pinning)
+}
+
+/// Cores the calling thread may run on.
+#[cfg(target_os = "linux")]
+pub(crate) fn allowed_cores() -> Result<Vec<usize>, AffinityError> {
+    // SAFETY: `cpu_set_t` is plain data, for which all zeroes is valid.
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    // SAFETY: `set` is a writable `cpu_set_t` of the given size.
+    let status =
+        unsafe { libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) };
+    if status != 0 {
+        return Err(AffinityError::Io(io::Error::last_os_error()));
+    }
+    Ok((0..libc::CPU_SETSIZE as usize)
+        // SAFETY: `core` is below `CPU_SETSIZE`.
+        .filter(|&core| unsafe { libc::CPU_ISSET(core, &set) })
+        .collect())
+}
+
+#[cfg(target_os = "linux")]
+fn set_affinity(cores: &[usize]) -> Result<(), AffinityError> {
+    // SAFETY: as above.
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for &core in cores {
+        if core >= libc::CPU_SETSIZE as usize {
+            return Err(AffinityError::UnavailableCore(core));
+        }
+        // SAFETY: `core` is below `CPU_SETSIZE`.
+        unsafe { libc::CPU_SET(core, &mut set) };
+    }
+    // SAFETY: `set` is a valid `cpu_set_t` of the given size; 0 is the
+    // calling thread.
+    let status =
+        unsafe { libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) };
+    if status != 0 {
+        return Err(AffinityError::Io(io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn performance_cores(allowed: &[usize]) -> Result<Vec<usize>, AffinityError> {
+    Ok(performance_cores_linux(std::path::Path::new("/"), allowed))
+}
+
+/// Reads sysfs below `root`.
+#[cfg(target_os = "linux")]
+fn performance_cores_linux(root: &std::path::Path, allowed: &[usize]) -> Vec<usize> {
+    let cpus = root.join("sys/devices/system/cpu");
+    if let Some(core_pmu) = std::fs::read_to_string(root.join("sys/devices/cpu_core/cpus"))
+        .ok()
+        .and_then(|list| parse_cpu_list(list.trim()))
+    {
+        let cores: Vec<usize> = allowed
+            .iter()
+            .copied()
+            .filter(|core| core_pmu.contains(core))
+            .collect();
+        if !cores.is_empty() {
+            return cores;
+        }
+    }
+    let capacities: Option<Vec<u64>> = allowed
+        .iter()
+        .map(|core| {
+            let path = cpus.join(format!("cpu{core}/cpu_capacity"));
+            std::fs::read_to_string(path).ok()?.trim().parse().ok()
+        })
+        .collect();
+    if let Some(capacities) = capacities
+        && let Some(&max) = capacities.iter().max()
+    {
+        return allowed
+            .iter()
+            .zip(&capacities)
+            .filter(|&(_, &capacity)| capacity == max)
+            .map(|(&core, _)| core)
+            .collect();
+    }
+    allowed.to_vec()
+}
+
+/// Parses a sysfs CPU list such as `0-7,16,18-19`.
+#[cfg(target_os = "linux")]
+fn parse_cpu_list(list: &str) -> Option<Vec<usize>> {
+    let mut cores = Vec::new();
+    for range in list.split(',').filter(|r| !r.is_empty()) {
+        match range.split_once('-') {
+            Some((first, last)) => cores.extend(first.parse::<usize>().ok()?..=last.parse().ok()?),
+            None => cores.push(range.parse().ok()?),
+        }
+    }
+    Some(cores)
+}
+
+#[cfg(windows)]
+pub(crate) fn allowed_cores() -> Result<Vec<usize>, AffinityError> {
+    let cpus = std::thread::available_parallelism()
+        .map_err(AffinityError::Io)?
+        .get();
+    Ok((0..cpus.min(usize::BITS as usize)).collect())
+}
+
+#[cfg(windows)]
+fn set_affinity(cores: &[usize]) -> Result<(), AffinityError> {
+    use windows_sys::Win32::System::Threading::{GetCurrentThread, SetThreadAffinityMask};
+
+    let mut mask = 0usize;
+    for &core in cores {
+        // Beyond the first processor group, which needs the group APIs.
+        if core >= usize::BITS as usize {
+            return Err(AffinityError::UnavailableCore(core));
+        }
+        mask |= 1 << core;
+    }
+    // SAFETY: the pseudo handle of the current thread needs no closing.
+    if unsafe { SetThreadAffinityMask(GetCurrentThread(), mask) } == 0 {
+        return Err(AffinityError::Io(io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn performance_cores(_allowed: &[usize]) -> Result<Vec<usize>, AffinityError> {
+    Err(AffinityError::Unsupported)
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+pub(crate) fn allowed_cores() -> Result<Vec<usize>, AffinityError> {
+    Err(AffinityError::Unsupported)
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+fn set_affinity(_cores: &[usize]) -> Result<(), AffinityError> {
+    Err(AffinityError::Unsupported)
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+fn performance_cores(_allowed: &[usize]) -> Result<Vec<usize>, AffinityError> {
+    Err(AffinityError::Unsupported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pins_to_available_cores() {
+        if cfg!(not(any(target_os = "linux", windows))) {
+            assert!(matches!(
+                CpuSelection::Cores(vec![0]).resolve(),
+                Err(AffinityError::Unsupported)
+            ));
+            return;
+        }
+        let allowed = allowed_cores().unwrap();
+        let core = allowed[0];
+        let pinning = std::thread::spawn(move || {
+            let pinning = pin_current_thread(&CpuSelection::Cores(vec![core, core])).unwrap();
+            if cfg!(target_os = "linux") {
+                assert_eq!(allowed_cores().unwrap(), [core]);
+            }
+            pinning
+        })
+        .join()
+        .unwrap();
+        assert_eq!(pinning.cores, [core]);
+        assert!(!pinning.performance_cores_only);
+        assert_eq!(
+            serde_json::to_value(&pinning).unwrap(),
+            serde_json::json!({ "cores": [core] })
+        );
+
+        assert!(matches!(
+            CpuSelection::Cores(vec![1 << 20]).resolve(),
+            Err(AffinityError::UnavailableCore(_))
+        ));
+        assert!(matches!(
+            CpuSelection::Cores(Vec::new()).resolve(),
+            Err(AffinityError::NoCores)
+        ));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn finds_performance_cores_in_sysfs() {
+        let root = std::env::temp_dir().join(format!("zkbench-affinity-{}", std::process::id()));
+        let cpus = root.join("sys/devices/system/cpu");
+        // No hybrid information: all allowed cores.
+        std::fs::create_dir_all(&cpus).unwrap();
+        assert_eq!(performance_cores_linux(&root, &[0, 1, 2]), [0, 1, 2]);
+
+        // big.LITTLE capacities.
+        for (core, capacity) in [(0, 446), (1, 446), (2, 1024), (3, 1024)] {
+            let dir = cpus.join(format!("cpu{core}"));
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("cpu_capacity"), format!("{capacity}\n")).unwrap();
+        }
+        assert_eq!(performance_cores_linux(&root, &[0, 1, 2, 3]), [2, 3]);
+
+        // Intel hybrid: P-cores with hyperthreads first, then E-cores.
+        std::fs::create_dir_all(root.join("sys/devices/cpu_core")).unwrap();
+        std::fs::write(root.join("sys/devices/cpu_core/cpus"), "0-1,3\n").unwrap();
+        assert_eq!(performance_cores_linux(&root, &[1, 2, 3]), [1, 3]);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            parse_cpu_list("0-3,8,10-11"),
+            Some(vec![0, 1, 2, 3, 8, 10, 11])
+        );
+        assert_eq!(parse_cpu_list("0-x"), None);
+    }
+}
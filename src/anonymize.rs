@@ -0,0 +1,433 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Removing identifying details from reports before sharing them.
+//!
+//! Reports carry more than numbers: CI run links name the organization,
+//! branches often contain usernames, and free-form benchmark metadata and
+//! artifact paths can hold hostnames, home directories, signed URLs or
+//! cloud account ids. [`anonymize`] removes or masks these while leaving
+//! the platform untouched, so an anonymized report keeps its
+//! [fingerprint](crate::Platform::fingerprint) and is still compared
+//! against reports from the same hardware.
+//!
+//! | Field | Treatment |
+//! |-------|-----------|
+//! | `run_id`, `run_url`, `branch` | removed |
+//! | `commit_sha`, `git_tag` | removed with [`AnonymizeOptions::strip_git`] |
+//! | benchmark `metadata` | identifying keys (`host`, `user`, `serial`, `instance_id`, ...) removed; text masked |
+//! | artifact `location` | text masked |
+//!
+//! Masking replaces home directory names with `<user>`, e-mail addresses
+//! with `<email>`, IPv4 addresses with `<ip>`, AWS ARNs with `<arn>` and
+//! EC2 instance ids with `<instance>`, strips credentials and query
+//! strings from URLs, and replaces [`AnonymizeOptions::redact`] strings
+//! with `<redacted>`.
+
+use serde_json::Value;
+
+use crate::migrate::pointer_escape;
+use crate::schema::BenchmarkReport;
+
+/// What [`anonymize`] removes beyond the defaults.
+#[derive(Debug, Clone, Default)]
+pub struct AnonymizeOptions {
+    /// Also remove the commit and tag, e.g. for reports of a private
+    /// repository.
+    pub strip_git: bool,
+    /// Strings masked wherever they appear in free-form text, such as the
+    /// hostname or username of the machine that produced the report.
+    pub redact: Vec<String>,
+}
+
+/// An anonymized report.
+#[derive(Debug, Clone)]
+pub struct Anonymized {
+    pub report: BenchmarkReport,
+    /// JSON paths of the fields that were removed or masked.
+    pub changed: Vec<String>,
+}
+
+/// Returns a copy of `report` without identifying details.
+///
+/// The run id, run URL and branch are removed, as are the commit and tag
+/// with [`AnonymizeOptions::strip_git`]. Identifying benchmark metadata
+/// keys are dropped, and the remaining metadata text and artifact
+/// locations are masked. The platform is kept.
+pub fn anonymize(report: &BenchmarkReport, opts: &AnonymizeOptions) -> Anonymized {
+    let mut report = report.clone();
+    let mut changed = Vec::new();
+    let metadata = &mut report.metadata;
+    for (field, value) in [
+        ("run_id", &mut metadata.run_id),
+        ("run_url", &mut metadata.run_url),
+        ("branch", &mut metadata.branch),
+    ] {
+        if value.take().is_some() {
+            changed.push(format!("/metadata/{field}"));
+        }
+    }
+    if opts.strip_git {
+        if metadata.commit_sha != "unknown" {
+            metadata.commit_sha = "unknown".to_string();
+            changed.push("/metadata/commit_sha".to_string());
+        }
+        if metadata.git_tag.take().is_some() {
+            changed.push("/metadata/git_tag".to_string());
+        }
+    }
+
+    let mut names: Vec<String> = report.benchmarks.keys().cloned().collect();
+    names.sort();
+    for name in names {
+        let result = report.benchmarks.get_mut(&name).expect("listed above");
+        let path = format!("/benchmarks/{}", pointer_escape(&name));
+        let mut keys: Vec<String> = result.metadata.keys().cloned().collect();
+        keys.sort();
+        for key in keys {
+            let path = format!("{path}/metadata/{}", pointer_escape(&key));
+            if is_identifying_key(&key) {
+                result.metadata.remove(&key);
+                changed.push(path);
+            } else {
+                let value = result.metadata.get_mut(&key).expect("listed above");
+                scrub_value(value, opts, &path, &mut changed);
+            }
+        }
+        for (i, artifact) in result.artifacts.iter_mut().enumerate() {
+            let masked = scrub_text(&artifact.location, opts);
+            if masked != artifact.location {
+                artifact.location = masked;
+                changed.push(format!("{path}/artifacts/{i}/location"));
+            }
+        }
+    }
+    Anonymized { report, changed }
+}
+
+fn scrub_value(value: &mut Value, opts: &AnonymizeOptions, path: &str, changed: &mut Vec<String>) {
+    match value {
+        Value::String(text) => {
+            let masked = scrub_text(text, opts);
+            if masked != *text {
+                *text = masked;
+                changed.push(path.to_string());
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                scrub_value(item, opts, &format!("{path}/{i}"), changed);
+            }
+        }
+        Value::Object(map) => {
+            let keys: Vec<String> = map.keys().cloned().collect();
+            for key in keys {
+                let path = format!("{path}/{}", pointer_escape(&key));
+                if is_identifying_key(&key) {
+                    map.remove(&key);
+                    changed.push(path);
+                } else if let Some(item) = map.get_mut(&key) {
+                    scrub_value(item, opts, &path, changed);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Words marking a metadata key as identifying.
+const IDENTIFYING_WORDS: &[&str] = &[
+    "host", "hostname", "user", "username", "login", "email", "account", "serial", "uuid", "ip",
+    "mac", "token", "secret", "password",
+];
+
+/// Words naming something whose id is identifying, e.g. `instance_id`.
+const IDENTIFIED_WORDS: &[&str] = &["instance", "machine", "node", "project", "subscription"];
+
+/// Whether a metadata key names an identifying detail, judged by its
+/// words: `gpu_serial` and `hostName` are, `instance_type` is not.
+fn is_identifying_key(key: &str) -> bool {
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+    let mut previous_lower = false;
+    for c in key.chars() {
+        if !c.is_ascii_alphanumeric() || (c.is_ascii_uppercase() && previous_lower) {
+            words.extend((!word.is_empty()).then(|| std::mem::take(&mut word)));
+        }
+        if c.is_ascii_alphanumeric() {
+            word.push(c.to_ascii_lowercase());
+        }
+        previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+    }
+    words.extend((!word.is_empty()).then_some(word));
+    words
+        .iter()
+        .any(|w| IDENTIFYING_WORDS.contains(&w.as_str()))
+        || words
+            .windows(2)
+            .any(|pair| IDENTIFIED_WORDS.contains(&pair[0].as_str()) && pair[1] == "id")
+        || words.iter().any(|w| {
+            IDENTIFIED_WORDS
+                .iter()
+                .any(|n| w.strip_prefix(n) == Some("id"))
+        })
+}
+
+/// Directory prefixes followed by a username.
+const HOME_PREFIXES: &[&str] = &["/home/", "/Users/", "\\Users\\"];
+
+fn scrub_text(text: &str, opts: &AnonymizeOptions) -> String {
+    let mut text = text.to_string();
+    for literal in opts.redact.iter().filter(|l| !l.is_empty()) {
+        text = text.replace(literal.as_str(), "<redacted>");
+    }
+    for prefix in HOME_PREFIXES {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find(prefix) {
+            let (before, after) = rest.split_at(start + prefix.len());
+            out.push_str(before);
+            let end = after
+                .find(|c: char| c == '/' || c == '\\' || c.is_whitespace())
+                .unwrap_or(after.len());
+            out.push_str(if end == 0 { "" } else { "<user>" });
+            rest = &after[end..];
+        }
+        out.push_str(rest);
+        text = out;
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        out.push_str(&scrub_token(&rest[..end]));
+        let space_end = rest[end..]
+            .find(|c: char| !c.is_whitespace())
+            .map_or(rest.len(), |i| end + i);
+        out.push_str(&rest[end..space_end]);
+        rest = &rest[space_end..];
+    }
+    out
+}
+
+/// Masks one whitespace-delimited token, ignoring surrounding quotes and
+/// punctuation.
+fn scrub_token(token: &str) -> String {
+    let core = token.trim_matches(|c: char| "\"'()<>[]{},;".contains(c));
+    if core.is_empty() {
+        return token.to_string();
+    }
+    let masked = if let Some(url) = scrub_url(core) {
+        url
+    } else if is_email(core) {
+        "<email>".to_string()
+    } else if is_ipv4(core) {
+        "<ip>".to_string()
+    } else if core.starts_with("arn:") {
+        "<arn>".to_string()
+    } else if is_instance_id(core) {
+        "<instance>".to_string()
+    } else {
+        return token.to_string();
+    };
+    token.replacen(core, &masked, 1)
+}
+
+/// Strips credentials, query string and fragment from a URL; `None` if
+/// `text` is not a URL or has none of them.
+fn scrub_url(text: &str) -> Option<String> {
+    let (scheme, rest) = text.split_once("://")?;
+    let end = rest.find(['?', '#']).unwrap_or(rest.len());
+    let rest = &rest[..end];
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let url = format!("{scheme}://{host}{path}");
+    (url != text).then_some(url)
+}
+
+fn is_email(text: &str) -> bool {
+    let Some((local, domain)) = text.split_once('@') else {
+        return false;
+    };
+    let valid = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "._%+-".contains(c))
+    };
+    valid(local) && valid(domain) && domain.contains('.') && !domain.ends_with('.')
+}
+
+fn is_ipv4(text: &str) -> bool {
+    let address = text.rsplit_once(':').map_or(text, |(address, _)| address);
+    let octets: Vec<&str> = address.split('.').collect();
+    octets.len() == 4
+        && octets.iter().all(|octet| {
+            !octet.is_empty()
+                && octet.bytes().all(|b| b.is_ascii_digit())
+                && octet.parse::<u8>().is_ok()
+        })
+}
+
+fn is_instance_id(text: &str) -> bool {
+    text.strip_prefix("i-")
+        .is_some_and(|id| matches!(id.len(), 8 | 17) && id.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::artifact::{Artifact, ArtifactKind};
+    use crate::schema::{BenchmarkResult, MetricValue};
+    use crate::testing::ReportBuilder;
+
+    fn prove() -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::new(10.0, "ms")),
+            artifacts: vec![Artifact {
+                kind: ArtifactKind::Flamegraph,
+                location: "https://bucket.example.com/flame.svg?X-Amz-Signature=abc".to_string(),
+                content_hash: None,
+                size_bytes: None,
+            }],
+            metadata: [
+                ("hostname".to_string(), json!("bench-07.acme.internal")),
+                ("gpuSerial".to_string(), json!("1323620043212")),
+                ("instance_type".to_string(), json!("c7i.8xlarge")),
+                (
+                    "cloud".to_string(),
+                    json!({
+                        "instance_id": "i-0123456789abcdef0",
+                        "role": "arn:aws:iam::123456789012:role/bench",
+                    }),
+                ),
+                (
+                    "command".to_string(),
+                    json!("/home/alice/prover/run.sh --notify alice@acme.com --rpc 10.0.3.7:8545"),
+                ),
+            ]
+            .into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn removes_identifying_details() {
+        let original = ReportBuilder::new()
+            .commit("abc123def456")
+            .metadata(|metadata| {
+                metadata.branch = Some("alice/faster-msm".to_string());
+                metadata.git_tag = Some("v1.0.0".to_string());
+                metadata.run_id = Some("42".to_string());
+                metadata.run_url =
+                    Some("https://github.com/acme/prover/actions/runs/42".to_string());
+            })
+            .result("prove", prove())
+            .build();
+        let anonymized = anonymize(&original, &AnonymizeOptions::default());
+        let report = &anonymized.report;
+        assert_eq!(
+            anonymized.changed,
+            [
+                "/metadata/run_id",
+                "/metadata/run_url",
+                "/metadata/branch",
+                "/benchmarks/prove/metadata/cloud/instance_id",
+                "/benchmarks/prove/metadata/cloud/role",
+                "/benchmarks/prove/metadata/command",
+                "/benchmarks/prove/metadata/gpuSerial",
+                "/benchmarks/prove/metadata/hostname",
+                "/benchmarks/prove/artifacts/0/location",
+            ]
+        );
+        assert_eq!(report.metadata.commit_sha, "abc123def456");
+        assert_eq!(report.metadata.git_tag.as_deref(), Some("v1.0.0"));
+        assert_eq!(
+            report.metadata.platform.fingerprint(),
+            original.metadata.platform.fingerprint()
+        );
+        let result = &report.benchmarks["prove"];
+        assert_eq!(
+            result.metadata["command"],
+            "/home/<user>/prover/run.sh --notify <email> --rpc <ip>"
+        );
+        assert_eq!(result.metadata["cloud"], json!({ "role": "<arn>" }));
+        assert_eq!(result.metadata["instance_type"], "c7i.8xlarge");
+        assert_eq!(
+            result.artifacts[0].location,
+            "https://bucket.example.com/flame.svg"
+        );
+    }
+
+    #[test]
+    fn strips_git_and_redacts_strings() {
+        let opts = AnonymizeOptions {
+            strip_git: true,
+            redact: vec!["prover".to_string()],
+        };
+        let original = ReportBuilder::new()
+            .commit("abc123def456")
+            .metadata(|metadata| {
+                metadata.branch = Some("alice/faster-msm".to_string());
+                metadata.git_tag = Some("v1.0.0".to_string());
+                metadata.run_id = Some("42".to_string());
+                metadata.run_url =
+                    Some("https://github.com/acme/prover/actions/runs/42".to_string());
+            })
+            .result("prove", prove())
+            .build();
+        let anonymized = anonymize(&original, &opts);
+        let report = &anonymized.report;
+        assert_eq!(report.metadata.commit_sha, "unknown");
+        assert_eq!(report.metadata.git_tag, None);
+        assert!(
+            report.benchmarks["prove"].metadata["command"]
+                .as_str()
+                .unwrap()
+                .starts_with("/home/<user>/<redacted>/run.sh")
+        );
+    }
+
+    #[test]
+    fn judges_keys_by_words() {
+        for key in [
+            "host",
+            "user_name",
+            "gpuSerial",
+            "instanceId",
+            "node-id",
+            "machine_id",
+        ] {
+            assert!(is_identifying_key(key), "{key}");
+        }
+        for key in [
+            "instance_type",
+            "hosting_cost",
+            "usage",
+            "node_count",
+            "curve",
+        ] {
+            assert!(!is_identifying_key(key), "{key}");
+        }
+    }
+
+    #[test]
+    fn masks_tokens() {
+        let opts = AnonymizeOptions::default();
+        assert_eq!(
+            scrub_text("C:\\Users\\bob\\x", &opts),
+            "C:\\Users\\<user>\\x"
+        );
+        assert_eq!(
+            scrub_text("\"https://u:p@h.io/a#b\", v1.2.3", &opts),
+            "\"https://h.io/a\", v1.2.3"
+        );
+        assert_eq!(scrub_text("host i-0abc1234", &opts), "host <instance>");
+        assert_eq!(scrub_text("256.1.1.1 1.2.3", &opts), "256.1.1.1 1.2.3");
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Diagnostic artifacts attached to benchmark results.
+//!
+//! Profiles, flamegraphs and GPU traces collected during a run are
+//! referenced from the result that produced them, so a regression in the
+//! report links straight to the data needed to diagnose it.
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::schema::BenchmarkResult;
+use crate::store::BLOB_SCHEME;
+
+/// What an artifact contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactKind {
+    /// Rendered flamegraph (usually SVG).
+    Flamegraph,
+    /// Linux `perf record` output.
+    PerfData,
+    /// Raw sampling profile, e.g. pprof protobuf.
+    Profile,
+    /// GPU trace from Nsight, rocprof or similar.
+    GpuTrace,
+    /// Serialized proof bytes.
+    Proof,
+    /// Witness file the prover consumed.
+    Witness,
+    /// Anything else; unknown kinds deserialize to this variant.
+    #[serde(other)]
+    Other,
+}
+
+/// A file or URL attached to a benchmark result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Artifact {
+    pub kind: ArtifactKind,
+    /// Local path or URL of the artifact.
+    pub location: String,
+    /// Content hash in `sha256:<hex>` form, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
+}
+
+impl Artifact {
+    /// Creates an artifact for a local file, hashing its contents.
+    ///
+    /// The file is streamed through the hasher, so multi-gigabyte
+    /// `perf.data` files are not loaded into memory.
+    pub fn from_file(kind: ArtifactKind, path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let mut file = File::open(path)?;
+        let mut hasher = Sha256::new();
+        let size = io::copy(&mut file, &mut hasher)?;
+        Ok(Self {
+            kind,
+            location: path.display().to_string(),
+            content_hash: Some(format!("sha256:{:x}", hasher.finalize())),
+            size_bytes: Some(size),
+        })
+    }
+
+    /// Creates an artifact referencing a remote URL. The content hash is
+    /// optional since the caller may not have the bytes at hand.
+    pub fn from_url(kind: ArtifactKind, url: &str, content_hash: Option<&str>) -> Self {
+        Self {
+            kind,
+            location: url.to_string(),
+            content_hash: content_hash.map(str::to_string),
+            size_bytes: None,
+        }
+    }
+
+    /// Creates an artifact referencing a blob in a
+    /// [`BlobStore`](crate::store::BlobStore) by its hash.
+    pub fn from_blob(kind: ArtifactKind, hash: &str, size_bytes: u64) -> Self {
+        Self {
+            kind,
+            location: format!("{BLOB_SCHEME}{hash}"),
+            content_hash: Some(hash.to_string()),
+            size_bytes: Some(size_bytes),
+        }
+    }
+
+    /// Hash of the blob the artifact references, when it lives in a
+    /// [`BlobStore`](crate::store::BlobStore).
+    pub fn blob_hash(&self) -> Option<&str> {
+        self.location.strip_prefix(BLOB_SCHEME)
+    }
+}
+
+impl BenchmarkResult {
+    /// Attaches an artifact to this result.
+    pub fn attach_artifact(&mut self, artifact: Artifact) {
+        self.artifacts.push(artifact);
+    }
+
+    /// Hashes a local file and attaches it as an artifact.
+    pub fn attach_file(&mut self, kind: ArtifactKind, path: impl AsRef<Path>) -> io::Result<()> {
+        self.attach_artifact(Artifact::from_file(kind, path)?);
+        Ok(())
+    }
+
+    /// Returns the attached artifacts of the given kind.
+    pub fn artifacts_of(&self, kind: ArtifactKind) -> impl Iterator<Item = &Artifact> {
+        self.artifacts.iter().filter(move |a| a.kind == kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn from_file_hashes_contents() {
+        let path =
+            std::env::temp_dir().join(format!("zkbench-artifact-{}.svg", std::process::id()));
+        File::create(&path).unwrap().write_all(b"abc").unwrap();
+        let artifact = Artifact::from_file(ArtifactKind::Flamegraph, &path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            artifact.content_hash.as_deref(),
+            Some("sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(artifact.size_bytes, Some(3));
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let mut result = BenchmarkResult::default();
+        assert!(
+            result
+                .attach_file(ArtifactKind::PerfData, "/nonexistent/perf.data")
+                .is_err()
+        );
+        assert!(result.artifacts.is_empty());
+    }
+
+    #[test]
+    fn attach_and_filter() {
+        let mut result = BenchmarkResult::default();
+        result.attach_artifact(Artifact::from_url(
+            ArtifactKind::GpuTrace,
+            "https://example.com/trace.nsys-rep",
+            None,
+        ));
+        result.attach_artifact(Artifact::from_url(
+            ArtifactKind::Flamegraph,
+            "https://example.com/flame.svg",
+            Some("sha256:00"),
+        ));
+        assert_eq!(result.artifacts_of(ArtifactKind::GpuTrace).count(), 1);
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"kind\":\"gpu_trace\""));
+        let parsed: BenchmarkResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.artifacts, result.artifacts);
+    }
+
+    #[test]
+    fn unknown_kind_deserializes_as_other() {
+        let artifact: Artifact =
+            serde_json::from_str(r#"{"kind": "heap_snapshot", "location": "heap.bin"}"#).unwrap();
+        assert_eq!(artifact.kind, ArtifactKind::Other);
+    }
+}
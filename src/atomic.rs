@@ -0,0 +1,90 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Atomic, durable file writes.
+//!
+//! A benchmark process killed mid-write must not leave a half-written
+//! report behind for the next trend query to trip over. Every file the
+//! crate writes goes through [`write_atomic`]: the contents are written
+//! to a temporary file next to the target, synced to disk and renamed
+//! over the target.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Writes `contents` to `path` atomically and durably.
+///
+/// Readers see either the previous file or the complete new one, never a
+/// partial write, and the new contents (and the rename) are on disk when
+/// the call returns. The parent directory must exist. On failure the
+/// target is left untouched and the temporary file is removed.
+pub fn write_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let path = path.as_ref();
+    let name = path.file_name().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{}: not a file path", path.display()),
+        )
+    })?;
+    let dir = path
+        .parent()
+        .filter(|d| !d.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let tmp = dir.join(format!(
+        ".{}.tmp-{}-{}",
+        name.to_string_lossy(),
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    ));
+    let written = (|| {
+        let mut file = File::create(&tmp)?;
+        file.write_all(contents.as_ref())?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)
+    })();
+    if written.is_err() {
+        let _ = fs::remove_file(&tmp);
+        return written;
+    }
+    sync_dir(dir)
+}
+
+/// Syncs a directory so a rename into it survives a crash. Directories
+/// cannot be opened for syncing on every platform; there the rename is
+/// as durable as the OS makes it.
+fn sync_dir(dir: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        File::open(dir)?.sync_all()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = dir;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replaces_file_without_leftovers() {
+        let dir = std::env::temp_dir().join(format!("zkbench-atomic-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("report.json");
+        write_atomic(&path, "old").unwrap();
+        write_atomic(&path, "new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        let missing = dir.join("missing/report.json");
+        assert!(write_atomic(&missing, "x").is_err());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Named baselines.
+//!
+//! Like Criterion's `--save-baseline` / `--baseline`, a report can be saved
+//! under a name such as `main` or `v0.3.0` and compared against later.
+//! Baselines are stored as `<dir>/<name>.json`, where `dir` defaults to
+//! `<target dir>/zkbench/baselines`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use zkbench::baseline::BaselineStore;
+//! # let report: zkbench::BenchmarkReport = unimplemented!();
+//!
+//! let store = BaselineStore::default();
+//! store.save("main", &report)?;
+//! let main = store.load("main")?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::BenchmarkReport;
+
+/// The baseline a project compares against, the `[baseline]` table of
+/// [`ComparisonConfig`](crate::ComparisonConfig).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BaselineConfig {
+    /// Name of the baseline; `main` by default.
+    pub name: String,
+    /// Directory of the baselines; a relative one is resolved against the
+    /// directory of the configuration file it was loaded from. The
+    /// [`BaselineStore::default`] directory when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<PathBuf>,
+}
+
+impl Default for BaselineConfig {
+    fn default() -> Self {
+        Self {
+            name: "main".to_string(),
+            dir: None,
+        }
+    }
+}
+
+impl BaselineConfig {
+    /// Returns the store the baseline is kept in.
+    pub fn store(&self) -> BaselineStore {
+        self.dir
+            .clone()
+            .map_or_else(BaselineStore::default, BaselineStore::new)
+    }
+}
+
+/// A directory of named baseline reports.
+#[derive(Debug, Clone)]
+pub struct BaselineStore {
+    dir: PathBuf,
+}
+
+impl Default for BaselineStore {
+    /// Uses `$CARGO_TARGET_DIR/zkbench/baselines`, falling back to
+    /// `target/zkbench/baselines` relative to the working directory.
+    fn default() -> Self {
+        let target = std::env::var_os("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("target"));
+        Self::new(target.join("zkbench").join("baselines"))
+    }
+}
+
+impl BaselineStore {
+    /// Stores baselines in `dir`, which is created on first save.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the directory baselines are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the file a baseline is stored in.
+    pub fn path(&self, name: &str) -> io::Result<PathBuf> {
+        validate(name)?;
+        Ok(self.dir.join(format!("{}.json", name)))
+    }
+
+    /// Saves a report under `name`, replacing any existing baseline of that
+    /// name, and returns the file it was written to.
+    pub fn save(&self, name: &str, report: &BenchmarkReport) -> io::Result<PathBuf> {
+        let path = self.path(name)?;
+        fs::create_dir_all(&self.dir)?;
+        report.save(&path)?;
+        Ok(path)
+    }
+
+    /// Loads the baseline saved under `name`.
+    pub fn load(&self, name: &str) -> io::Result<BenchmarkReport> {
+        let path = self.path(name)?;
+        BenchmarkReport::load(&path).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no baseline named '{}' in {}", name, self.dir.display()),
+            ),
+            _ => e,
+        })
+    }
+
+    /// Whether a baseline named `name` exists.
+    pub fn exists(&self, name: &str) -> bool {
+        self.path(name).is_ok_and(|path| path.is_file())
+    }
+
+    /// Lists the saved baselines, sorted by name. A missing directory has
+    /// no baselines.
+    pub fn list(&self) -> io::Result<Vec<String>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut names = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json")
+                && let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+            {
+                names.push(name.to_string());
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    /// Deletes the baseline saved under `name`.
+    pub fn delete(&self, name: &str) -> io::Result<()> {
+        fs::remove_file(self.path(name)?)
+    }
+}
+
+/// Baseline names become file names, so they must not be able to escape
+/// the baseline directory.
+fn validate(name: &str) -> io::Result<()> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\', '\0']) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid baseline name '{}'", name),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    fn store(name: &str) -> BaselineStore {
+        let dir =
+            std::env::temp_dir().join(format!("zkbench-baseline-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        BaselineStore::new(dir)
+    }
+
+    #[test]
+    fn save_load_list_delete() {
+        let store = store("roundtrip");
+        assert!(store.list().unwrap().is_empty());
+
+        store
+            .save("main", &ReportBuilder::new().version("1.0").build())
+            .unwrap();
+        store
+            .save("v0.3.0", &ReportBuilder::new().version("0.3.0").build())
+            .unwrap();
+        store
+            .save("main", &ReportBuilder::new().version("1.1").build())
+            .unwrap();
+        assert_eq!(store.list().unwrap(), ["main", "v0.3.0"]);
+        assert_eq!(store.load("main").unwrap().metadata.version, "1.1");
+        assert!(store.exists("v0.3.0"));
+
+        store.delete("v0.3.0").unwrap();
+        assert!(!store.exists("v0.3.0"));
+        let err = store.load("v0.3.0").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("v0.3.0"));
+        fs::remove_dir_all(store.dir()).unwrap();
+    }
+
+    #[test]
+    fn rejects_path_like_names() {
+        let store = store("names");
+        for name in ["", "../main", "a/b", ".hidden", "a\\b"] {
+            let err = store
+                .save(name, &ReportBuilder::new().version("1.0").build())
+                .unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{name}");
+        }
+        assert!(!store.exists("../main"));
+    }
+}
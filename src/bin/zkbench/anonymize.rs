@@ -0,0 +1,83 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench anonymize`: strips identifying details from a report.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+use zkbench::{AnonymizeOptions, anonymize};
+
+use crate::{Result, load_report, write_output};
+
+/// Strip identifying details from a report before sharing it publicly.
+///
+/// Removes the CI run id and link and the branch, drops benchmark
+/// metadata such as hostnames, usernames, serials and cloud instance ids,
+/// and masks home directories, e-mail and IP addresses, ARNs and signed
+/// URL parameters in the remaining text. The platform is kept, so the
+/// report keeps its fingerprint. Each removed or masked field is listed
+/// on standard error.
+#[derive(Debug, Args)]
+pub(crate) struct AnonymizeArgs {
+    /// Report to anonymize (`-` reads standard input).
+    report: PathBuf,
+    /// Output file; standard output if omitted.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Also remove the commit and tag.
+    #[arg(long)]
+    strip_git: bool,
+    /// Mask this string wherever it appears; may be repeated.
+    #[arg(long, value_name = "TEXT")]
+    redact: Vec<String>,
+    /// Do not mask this machine's hostname and username, which are
+    /// masked by default.
+    #[arg(long)]
+    keep_local_names: bool,
+}
+
+pub(crate) fn run(args: AnonymizeArgs) -> Result<ExitCode> {
+    let report = load_report(&args.report)?;
+    let mut opts = AnonymizeOptions {
+        strip_git: args.strip_git,
+        redact: args.redact,
+    };
+    if !args.keep_local_names {
+        opts.redact.extend(local_names());
+    }
+    let anonymized = anonymize(&report, &opts);
+    for path in &anonymized.changed {
+        eprintln!("anonymized {path}");
+    }
+    write_output(
+        args.output.as_ref(),
+        &(anonymized.report.to_json(true)? + "\n"),
+    )?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// This machine's hostname (full and short) and the current username.
+/// Names shorter than three characters are skipped, as masking them
+/// would mangle unrelated text.
+fn local_names() -> Vec<String> {
+    let hostname = std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|h| h.trim().to_string());
+    let short = hostname
+        .as_deref()
+        .and_then(|h| h.split_once('.'))
+        .map(|(short, _)| short.to_string());
+    let username = std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok();
+    // The full hostname comes first, so it is masked as a whole.
+    [hostname, short, username]
+        .into_iter()
+        .flatten()
+        .filter(|name| name.len() >= 3)
+        .collect()
+}
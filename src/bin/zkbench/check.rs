@@ -0,0 +1,128 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench check`: the CI gate.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use zkbench::baseline::BaselineStore;
+use zkbench::{
+    CiProvider, ComparisonConfig, RunEnvironment, gate, gate_with_budget, record_budget,
+    render_comparison_markdown, render_comparison_terminal, render_github_annotations,
+};
+
+use crate::{EXIT_FAILURE, Result, load_report, use_color};
+
+/// Environment variable naming the file GitHub Actions renders as the
+/// job summary.
+const STEP_SUMMARY_ENV: &str = "GITHUB_STEP_SUMMARY";
+
+/// Gate a report against the configured baseline.
+///
+/// Compares the report against the `[baseline]` of the nearest
+/// zkbench.toml (`main` by default) with its `[compare]` thresholds,
+/// applies the `[gate]` policy and `[budget]`, and exits with 1 when the
+/// gate fails. On GitHub Actions, violations are reported as annotations
+/// and the comparison is added to the job summary.
+#[derive(Debug, Args)]
+pub(crate) struct CheckArgs {
+    /// Report of this run (`-` reads standard input).
+    report: PathBuf,
+    /// Baseline name, overriding the configured one.
+    #[arg(long, value_name = "NAME")]
+    baseline: Option<String>,
+    /// Directory of named baselines, overriding the configured one.
+    #[arg(long, value_name = "DIR")]
+    baseline_dir: Option<PathBuf>,
+    /// Configuration file; defaults to the nearest zkbench.toml.
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+    /// Overrides the configured default threshold.
+    #[arg(long)]
+    threshold: Option<f64>,
+    /// Pass when the baseline does not exist yet, e.g. on the first run.
+    #[arg(long)]
+    allow_missing_baseline: bool,
+    /// Record the report's debt in the `[budget]` ledger, e.g. on the
+    /// main branch; without it the ledger is only read.
+    #[arg(long)]
+    record_budget: bool,
+    /// When to print GitHub Actions annotations.
+    #[arg(long, value_enum, default_value_t = Annotations::Auto)]
+    annotations: Annotations,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Annotations {
+    /// On GitHub Actions only.
+    Auto,
+    Always,
+    Never,
+}
+
+pub(crate) fn run(args: CheckArgs) -> Result<ExitCode> {
+    let config = match &args.config {
+        Some(path) => ComparisonConfig::load(path)?,
+        None => ComparisonConfig::discover(std::env::current_dir()?)?,
+    };
+    let name = args.baseline.as_deref().unwrap_or(&config.baseline.name);
+    let store = match &args.baseline_dir {
+        Some(dir) => BaselineStore::new(dir),
+        None => config.baseline.store(),
+    };
+    let candidate = load_report(&args.report)?;
+    if !store.exists(name) && args.allow_missing_baseline {
+        println!(
+            "no baseline named '{name}' in {}; skipping the gate",
+            store.dir().display()
+        );
+        return Ok(ExitCode::SUCCESS);
+    }
+    let baseline = store.load(name)?;
+
+    let mut opts = config.compare;
+    if let Some(threshold) = args.threshold {
+        opts.threshold = threshold;
+    }
+    let result = match &config.budget {
+        Some(budget) => {
+            gate_with_budget(&baseline, &candidate, &opts, &config.gate, budget, &store)?
+        }
+        None => gate(&baseline, &candidate, &opts, &config.gate),
+    };
+    if args.record_budget
+        && let Some(status) = &result.budget
+    {
+        record_budget(&store, &candidate, status)?;
+    }
+
+    let github = RunEnvironment::from_env().ci == Some(CiProvider::GithubActions);
+    print!(
+        "{}",
+        render_comparison_terminal(&result.comparison, use_color())
+    );
+    println!();
+    match args.annotations {
+        Annotations::Always => print!("{}", render_github_annotations(&result)),
+        Annotations::Auto if github => print!("{}", render_github_annotations(&result)),
+        _ => print!("{result}"),
+    }
+    if github && let Some(path) = std::env::var_os(STEP_SUMMARY_ENV) {
+        let mut summary = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(
+            summary,
+            "{}\n{}",
+            render_comparison_markdown(&result.comparison, &[]),
+            result
+        )?;
+    }
+    Ok(if result.passed() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_FAILURE)
+    })
+}
@@ -0,0 +1,86 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench compare`: compares a report against a baseline report.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use zkbench::baseline::BaselineStore;
+use zkbench::{
+    ComparisonConfig, Verdict, compare, render_comparison_markdown, render_comparison_terminal,
+};
+
+use crate::{EXIT_FAILURE, Result, load_report, use_color};
+
+/// Compare a report against a baseline and fail on regressions.
+///
+/// Thresholds and matching rules come from the `[compare]` table of the
+/// nearest zkbench.toml, unless `--config` names another file.
+#[derive(Debug, Args)]
+pub(crate) struct CompareArgs {
+    /// BASELINE CANDIDATE report files, or only CANDIDATE with
+    /// `--baseline` (`-` reads standard input).
+    #[arg(required = true, num_args = 1..=2, value_name = "REPORT")]
+    reports: Vec<PathBuf>,
+    /// Compare against this named baseline instead of a file.
+    #[arg(long, value_name = "NAME")]
+    baseline: Option<String>,
+    /// Directory of named baselines, overriding the configured one.
+    #[arg(long, value_name = "DIR", requires = "baseline")]
+    baseline_dir: Option<PathBuf>,
+    /// Configuration file; defaults to the nearest zkbench.toml.
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+    /// Overrides the configured default threshold.
+    #[arg(long)]
+    threshold: Option<f64>,
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = Format::Terminal)]
+    format: Format,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Format {
+    /// Diff table, colored on a terminal.
+    Terminal,
+    /// Markdown diff table, e.g. for a job summary.
+    Markdown,
+    /// The full comparison as JSON.
+    Json,
+}
+
+pub(crate) fn run(args: CompareArgs) -> Result<ExitCode> {
+    let config = match &args.config {
+        Some(path) => ComparisonConfig::load(path)?,
+        None => ComparisonConfig::discover(std::env::current_dir()?)?,
+    };
+    let (baseline, candidate) = match (&args.baseline, args.reports.as_slice()) {
+        (Some(name), [candidate]) => {
+            let store = match &args.baseline_dir {
+                Some(dir) => BaselineStore::new(dir),
+                None => config.baseline.store(),
+            };
+            (store.load(name)?, load_report(candidate)?)
+        }
+        (None, [baseline, candidate]) => (load_report(baseline)?, load_report(candidate)?),
+        (Some(_), _) => return Err("with --baseline, give only the candidate report".into()),
+        (None, _) => return Err("give a baseline and a candidate report, or --baseline".into()),
+    };
+    let mut opts = config.compare;
+    if let Some(threshold) = args.threshold {
+        opts.threshold = threshold;
+    }
+
+    let comparison = compare(&baseline, &candidate, &opts);
+    match args.format {
+        Format::Terminal => print!("{}", render_comparison_terminal(&comparison, use_color())),
+        Format::Markdown => print!("{}", render_comparison_markdown(&comparison, &[])),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&comparison)?),
+    }
+    Ok(match comparison.verdict {
+        Verdict::Pass => ExitCode::SUCCESS,
+        Verdict::Fail | Verdict::Refused => ExitCode::from(EXIT_FAILURE),
+    })
+}
@@ -0,0 +1,35 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench completions`: shell completion scripts.
+
+use std::io;
+use std::process::ExitCode;
+
+use clap::{Args, CommandFactory};
+use clap_complete::Shell;
+
+use crate::{Cli, Result};
+
+/// Print a completion script for a shell.
+///
+/// Install it where the shell looks for completions, e.g.
+/// `zkbench completions bash > /etc/bash_completion.d/zkbench`,
+/// `zkbench completions zsh > "${fpath[1]}/_zkbench"` or
+/// `zkbench completions fish > ~/.config/fish/completions/zkbench.fish`.
+#[derive(Debug, Args)]
+pub(crate) struct CompletionsArgs {
+    /// Shell to complete in.
+    #[arg(value_enum)]
+    shell: Shell,
+}
+
+pub(crate) fn run(args: CompletionsArgs) -> Result<ExitCode> {
+    clap_complete::generate(
+        args.shell,
+        &mut Cli::command(),
+        "zkbench",
+        &mut io::stdout(),
+    );
+    Ok(ExitCode::SUCCESS)
+}
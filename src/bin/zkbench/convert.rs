@@ -0,0 +1,163 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench convert`: converts between reports and other formats.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use zkbench::{
+    BenchmarkReport, BenchmarkResult, DigestFormat, Metadata, export_bmf, export_csv,
+    export_github_action_benchmark, export_prometheus, import_bmf, import_criterion, import_csv,
+    import_github_action_benchmark, render_html_with, render_markdown_with,
+};
+
+use crate::{Result, read_input, write_output};
+
+/// Convert a report to another format, or another format to a report.
+///
+/// Formats without report metadata (BMF, github-action-benchmark and
+/// Criterion output) are converted into a report of `--implementation`,
+/// with the commit and platform of the current checkout and machine.
+#[derive(Debug, Args)]
+pub(crate) struct ConvertArgs {
+    /// Input file (`-` for standard input), or directory for
+    /// `--from criterion-dir`.
+    input: PathBuf,
+    /// Input format.
+    #[arg(long, value_enum, default_value_t = InputFormat::Json)]
+    from: InputFormat,
+    /// Output format.
+    #[arg(long, value_enum)]
+    to: Format,
+    /// Output file; standard output if omitted.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Implementation name of reports built from formats without
+    /// metadata.
+    #[arg(long, default_value = "unknown")]
+    implementation: String,
+    /// Implementation version of reports built from formats without
+    /// metadata.
+    #[arg(long, value_name = "VERSION", default_value = "unknown")]
+    impl_version: String,
+    /// How markdown and HTML output write test-vector hashes.
+    #[arg(long, value_enum, default_value_t = DigestStyle::Short)]
+    digest_format: DigestStyle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum DigestStyle {
+    /// Full lowercase hex.
+    Hex,
+    /// First 12 hex digits.
+    Short,
+    /// Base58, as Solana and IPFS tooling write hashes.
+    Base58,
+    /// Full hex with a `0x` prefix, as Ethereum tooling writes hashes.
+    #[value(name = "0x")]
+    Prefixed,
+}
+
+impl From<DigestStyle> for DigestFormat {
+    fn from(style: DigestStyle) -> Self {
+        match style {
+            DigestStyle::Hex => Self::Hex,
+            DigestStyle::Short => Self::Short,
+            DigestStyle::Base58 => Self::Base58,
+            DigestStyle::Prefixed => Self::Prefixed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum InputFormat {
+    /// Report JSON.
+    Json,
+    /// CSV as written by `--to csv`, holding exactly one report.
+    Csv,
+    /// Bencher Metric Format JSON.
+    Bmf,
+    /// github-action-benchmark `customSmallerIsBetter` JSON.
+    GithubActionBenchmark,
+    /// A `target/criterion` directory.
+    CriterionDir,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Format {
+    /// Pretty-printed report JSON.
+    Json,
+    /// One row per metric, with report and platform columns.
+    Csv,
+    /// GitHub-flavored markdown table.
+    Markdown,
+    /// Self-contained HTML page.
+    Html,
+    /// Bencher Metric Format, for `bencher run --adapter json`.
+    Bmf,
+    /// Input of github-action-benchmark's `customSmallerIsBetter` tool.
+    GithubActionBenchmark,
+    /// Prometheus text exposition format.
+    Prometheus,
+}
+
+pub(crate) fn run(args: ConvertArgs) -> Result<ExitCode> {
+    let report = read(&args)?;
+    let output = convert(&report, args.to, args.digest_format.into())?;
+    write_output(args.output.as_ref(), &output)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn read(args: &ConvertArgs) -> Result<BenchmarkReport> {
+    let benchmarks = match args.from {
+        InputFormat::Json => return Ok(BenchmarkReport::from_json(&read_input(&args.input)?)?),
+        InputFormat::Csv => {
+            let mut reports = import_csv(&read_input(&args.input)?)?;
+            if reports.len() != 1 {
+                return Err(format!(
+                    "the CSV holds {} reports; convert needs exactly one",
+                    reports.len()
+                )
+                .into());
+            }
+            return Ok(reports.remove(0));
+        }
+        InputFormat::Bmf => import_bmf(&read_input(&args.input)?)?,
+        InputFormat::GithubActionBenchmark => {
+            import_github_action_benchmark(&read_input(&args.input)?)?
+        }
+        InputFormat::CriterionDir => {
+            if !args.input.is_dir() {
+                return Err(format!("{} is not a directory", args.input.display()).into());
+            }
+            import_criterion(&args.input)?
+        }
+    };
+    Ok(new_report(args, benchmarks))
+}
+
+fn new_report(args: &ConvertArgs, benchmarks: HashMap<String, BenchmarkResult>) -> BenchmarkReport {
+    BenchmarkReport {
+        metadata: Metadata::create(&args.implementation, &args.impl_version),
+        benchmarks,
+    }
+}
+
+fn convert(
+    report: &BenchmarkReport,
+    format: Format,
+    digest_format: DigestFormat,
+) -> Result<String> {
+    Ok(match format {
+        Format::Json => report.to_json(true)? + "\n",
+        Format::Csv => export_csv(std::slice::from_ref(report)),
+        Format::Markdown => render_markdown_with(report, digest_format),
+        Format::Html => render_html_with(std::slice::from_ref(report), digest_format),
+        Format::Bmf => export_bmf(report, true)? + "\n",
+        Format::GithubActionBenchmark => export_github_action_benchmark(report, true)? + "\n",
+        Format::Prometheus => export_prometheus(report),
+    })
+}
@@ -0,0 +1,232 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The `zkbench` command-line tool (feature `cli`).
+//!
+//! Exposes the library to CI scripts and non-Rust pipelines: validating,
+//! comparing and gating, converting, merging, summarizing, plotting,
+//! anonymizing and uploading report files, following trends in report
+//! stores and migrating them to the current schema version, whose JSON
+//! Schema it prints, re-running benchmarks on changes and checking the
+//! benchmarking host. Shell completions and manual pages are generated
+//! from the same definitions.
+//!
+//! Exit codes: 0 on success, 1 when a check fails (an invalid report, a
+//! regression), 2 on usage and I/O errors.
+
+mod anonymize;
+mod check;
+mod compare;
+mod completions;
+mod convert;
+mod man;
+mod merge;
+mod migrate;
+mod platform;
+#[cfg(feature = "plot")]
+mod plot;
+mod schema;
+mod summarize;
+mod trend;
+#[cfg(any(feature = "publish", feature = "object-store"))]
+mod upload;
+mod validate;
+mod watch;
+
+use std::error::Error;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use zkbench::store::{DirStore, ReportStore};
+use zkbench::{BenchmarkReport, load_reports, write_atomic};
+
+pub(crate) type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Exit code of a check that failed.
+pub(crate) const EXIT_FAILURE: u8 = 1;
+
+/// Exit code of usage and I/O errors.
+const EXIT_ERROR: u8 = 2;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "zkbench",
+    version,
+    about = "Work with zkbench benchmark reports"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    Validate(validate::ValidateArgs),
+    Compare(compare::CompareArgs),
+    Check(check::CheckArgs),
+    Convert(convert::ConvertArgs),
+    Merge(merge::MergeArgs),
+    Summarize(summarize::SummarizeArgs),
+    Migrate(migrate::MigrateArgs),
+    Anonymize(anonymize::AnonymizeArgs),
+    Trend(trend::TrendArgs),
+    Watch(watch::WatchArgs),
+    #[cfg(feature = "plot")]
+    Plot(plot::PlotArgs),
+    #[cfg(any(feature = "publish", feature = "object-store"))]
+    Upload(upload::UploadArgs),
+    Schema(schema::SchemaArgs),
+    Platform(platform::PlatformArgs),
+    Completions(completions::CompletionsArgs),
+    Man(man::ManArgs),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Validate(args) => validate::run(args),
+        Command::Compare(args) => compare::run(args),
+        Command::Check(args) => check::run(args),
+        Command::Convert(args) => convert::run(args),
+        Command::Merge(args) => merge::run(args),
+        Command::Summarize(args) => summarize::run(args),
+        Command::Migrate(args) => migrate::run(args),
+        Command::Anonymize(args) => anonymize::run(args),
+        Command::Trend(args) => trend::run(args),
+        Command::Watch(args) => watch::run(args),
+        #[cfg(feature = "plot")]
+        Command::Plot(args) => plot::run(args),
+        #[cfg(any(feature = "publish", feature = "object-store"))]
+        Command::Upload(args) => upload::run(args),
+        Command::Schema(args) => schema::run(args),
+        Command::Platform(args) => platform::run(args),
+        Command::Completions(args) => completions::run(args),
+        Command::Man(args) => man::run(args),
+    };
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("zkbench: error: {e}");
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+/// Loads a report file; `-` reads standard input.
+pub(crate) fn load_report(path: &Path) -> Result<BenchmarkReport> {
+    if path == Path::new("-") {
+        let json = io::read_to_string(io::stdin())?;
+        return Ok(BenchmarkReport::from_json(&json).map_err(|e| format!("<stdin>: {e}"))?);
+    }
+    Ok(BenchmarkReport::load(path)?)
+}
+
+/// Loads the reports below a directory, warning about files that are
+/// not reports instead of failing.
+pub(crate) fn load_dir(dir: &Path) -> Result<Vec<BenchmarkReport>> {
+    let loaded = load_reports(dir)?;
+    for (_, e) in &loaded.skipped {
+        eprintln!("warning: skipped {e}");
+    }
+    Ok(loaded.reports)
+}
+
+/// Reads a file, or standard input for `-`.
+pub(crate) fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        io::read_to_string(io::stdin())
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+/// Opens a report store: a directory store, or a SQLite database file
+/// with the `sqlite` feature.
+pub(crate) fn open_store(path: &Path) -> Result<Box<dyn ReportStore>> {
+    if !path.exists() {
+        return Err(format!("{}: no such store", path.display()).into());
+    }
+    if path.is_dir() {
+        return Ok(Box::new(DirStore::new(path)));
+    }
+    #[cfg(feature = "sqlite")]
+    return Ok(Box::new(zkbench::store::SqliteStore::open(path)?));
+    #[cfg(not(feature = "sqlite"))]
+    Err(format!(
+        "{} is not a directory; SQLite stores need the `sqlite` feature",
+        path.display()
+    )
+    .into())
+}
+
+/// Writes output to a file, atomically, or to standard output.
+pub(crate) fn write_output(output: Option<&PathBuf>, contents: &str) -> Result<()> {
+    match output {
+        Some(path) => write_atomic(path, contents)?,
+        None => io::stdout().lock().write_all(contents.as_bytes())?,
+    }
+    Ok(())
+}
+
+/// Turns a benchmark or series name into a file name stem, replacing
+/// everything but ASCII alphanumerics and `-` with `_`.
+#[cfg(feature = "plot")]
+pub(crate) fn file_stem(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Whether to color standard output: only on a terminal, and never when
+/// `NO_COLOR` is set.
+pub(crate) fn use_color() -> bool {
+    io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_is_well_formed() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parses_subcommands() {
+        let cli =
+            Cli::try_parse_from(["zkbench", "convert", "r.json", "--to", "markdown"]).unwrap();
+        assert!(matches!(cli.command, Command::Convert(_)));
+        assert!(Cli::try_parse_from(["zkbench", "convert", "r.json", "--to", "pdf"]).is_err());
+        assert!(
+            Cli::try_parse_from(["zkbench", "compare", "r.json", "--baseline", "main"]).is_ok()
+        );
+        assert!(Cli::try_parse_from(["zkbench", "compare", "a.json", "b.json", "c.json"]).is_err());
+    }
+
+    #[test]
+    fn generates_completions_and_man_pages() {
+        for shell in [clap_complete::Shell::Bash, clap_complete::Shell::Zsh] {
+            let mut out = Vec::new();
+            clap_complete::generate(shell, &mut Cli::command(), "zkbench", &mut out);
+            let script = String::from_utf8(out).unwrap();
+            assert!(script.contains("summarize"), "{shell}");
+        }
+        let mut out = Vec::new();
+        clap_mangen::Man::new(Cli::command())
+            .render(&mut out)
+            .unwrap();
+        let page = String::from_utf8(out).unwrap();
+        assert!(page.contains(".TH zkbench 1"));
+        assert!(page.contains("Work with zkbench benchmark reports"));
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench man`: manual pages.
+
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, CommandFactory};
+use clap_mangen::Man;
+
+use crate::{Cli, Result};
+
+/// Print the zkbench(1) manual page, or write all pages to a directory.
+///
+/// `zkbench man | man -l -` shows the page without installing it;
+/// `zkbench man -o /usr/local/share/man/man1` installs `zkbench.1` and a
+/// `zkbench-<command>.1` page per subcommand.
+#[derive(Debug, Args)]
+pub(crate) struct ManArgs {
+    /// Directory to write every page into, instead of printing the
+    /// top-level page.
+    #[arg(short, long, value_name = "DIR")]
+    output: Option<PathBuf>,
+}
+
+pub(crate) fn run(args: ManArgs) -> Result<ExitCode> {
+    match &args.output {
+        Some(dir) => {
+            std::fs::create_dir_all(dir)?;
+            clap_mangen::generate_to(Cli::command(), dir)?;
+        }
+        None => Man::new(Cli::command()).render(&mut io::stdout().lock())?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
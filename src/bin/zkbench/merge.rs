@@ -0,0 +1,71 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench merge`: joins the reports of sharded runs.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use zkbench::{DuplicatePolicy, MergePolicy, merge_reports_with};
+
+use crate::{Result, load_report, write_output};
+
+/// Merge the reports of sharded runs into one.
+///
+/// The merged report is written atomically, so a reader never sees a
+/// partially written file even when `--output` is one of the inputs.
+#[derive(Debug, Args)]
+pub(crate) struct MergeArgs {
+    /// Report files of one implementation and commit (`-` for standard
+    /// input).
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+    /// Output file; standard output if omitted.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// What to do with a benchmark found in more than one report.
+    #[arg(long, value_enum, default_value_t = OnDuplicate::Error)]
+    on_duplicate: OnDuplicate,
+    /// Merge reports of different commits, keeping the first report's.
+    #[arg(long)]
+    allow_mixed_commits: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum OnDuplicate {
+    /// Fail.
+    Error,
+    /// Keep the result from the file given first.
+    First,
+    /// Keep the result from the file given last.
+    Last,
+    /// Keep the result with the lowest latency.
+    Fastest,
+}
+
+impl From<OnDuplicate> for DuplicatePolicy {
+    fn from(value: OnDuplicate) -> Self {
+        match value {
+            OnDuplicate::Error => Self::Error,
+            OnDuplicate::First => Self::KeepFirst,
+            OnDuplicate::Last => Self::KeepLast,
+            OnDuplicate::Fastest => Self::KeepFastest,
+        }
+    }
+}
+
+pub(crate) fn run(args: MergeArgs) -> Result<ExitCode> {
+    let reports = args
+        .files
+        .iter()
+        .map(|path| load_report(path))
+        .collect::<Result<Vec<_>>>()?;
+    let policy = MergePolicy {
+        on_duplicate: args.on_duplicate.into(),
+        allow_mixed_commits: args.allow_mixed_commits,
+    };
+    let merged = merge_reports_with(&reports, &policy)?;
+    write_output(args.output.as_ref(), &(merged.to_json(true)? + "\n"))?;
+    Ok(ExitCode::SUCCESS)
+}
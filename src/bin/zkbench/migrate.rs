@@ -0,0 +1,55 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench migrate`: upgrades a report store to the current schema
+//! version.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+use zkbench::store::DirStore;
+
+use crate::{EXIT_FAILURE, Result};
+
+/// Upgrade the reports of a store to the current schema version.
+#[derive(Debug, Args)]
+pub(crate) struct MigrateArgs {
+    /// Directory store, or SQLite database (feature `sqlite`).
+    store: PathBuf,
+    /// Only report what would be migrated.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+pub(crate) fn run(args: MigrateArgs) -> Result<ExitCode> {
+    if !args.store.exists() {
+        return Err(format!("{}: no such store", args.store.display()).into());
+    }
+    let report = if args.store.is_file() {
+        migrate_sqlite(&args)?
+    } else {
+        DirStore::new(&args.store).migrate(args.dry_run)?
+    };
+    print!("{report}");
+    Ok(if report.failed.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_FAILURE)
+    })
+}
+
+#[cfg(feature = "sqlite")]
+fn migrate_sqlite(args: &MigrateArgs) -> Result<zkbench::store::MigrationReport> {
+    let mut store = zkbench::store::SqliteStore::open(&args.store)?;
+    Ok(store.migrate(args.dry_run)?)
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn migrate_sqlite(args: &MigrateArgs) -> Result<zkbench::store::MigrationReport> {
+    Err(format!(
+        "{} is not a directory; SQLite stores need the `sqlite` feature",
+        args.store.display()
+    )
+    .into())
+}
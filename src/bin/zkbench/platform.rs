@@ -0,0 +1,44 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench platform`: platform detection and host checks.
+
+use std::process::ExitCode;
+
+use clap::Args;
+use zkbench::{Platform, check_environment};
+
+use crate::{EXIT_FAILURE, Result};
+
+/// Print the detected platform as JSON, as it would appear in a report.
+///
+/// The output includes the platform fingerprint reports are grouped by,
+/// which helps find out why two runners' results are not compared.
+/// `--check` also inspects the host for conditions that make results
+/// noisy, such as a `powersave` frequency governor or battery power.
+#[derive(Debug, Args)]
+pub(crate) struct PlatformArgs {
+    /// Warn about unsuitable benchmarking conditions on standard error,
+    /// exiting with 1 if there are any.
+    #[arg(long)]
+    check: bool,
+}
+
+pub(crate) fn run(args: PlatformArgs) -> Result<ExitCode> {
+    let platform = Platform::current();
+    let mut json = serde_json::to_value(&platform)?;
+    json["fingerprint"] = platform.fingerprint().into();
+    println!("{}", serde_json::to_string_pretty(&json)?);
+    if !args.check {
+        return Ok(ExitCode::SUCCESS);
+    }
+    let warnings = check_environment();
+    for warning in &warnings {
+        eprintln!("warning: {warning}");
+    }
+    Ok(if warnings.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_FAILURE)
+    })
+}
@@ -0,0 +1,195 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench plot`: charts from reports (feature `plot`).
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use zkbench::plot::{DistributionStyle, plot_distribution, plot_sweep, plot_trend};
+use zkbench::store::Query;
+use zkbench::{BenchmarkReport, MetricKind, extract_sweeps};
+
+use crate::{Result, file_stem, load_dir, load_report};
+
+/// Render charts from one or more reports into a directory.
+///
+/// Writes a latency distribution per benchmark
+/// (`distribution-<benchmark>`), a scaling curve per parameter sweep
+/// (`sweep-<name>`) and, for implementations with at least two reports,
+/// one trend line per benchmark and metric in chronological order
+/// (`trend-<implementation>-<benchmark>-<metric>`). The directory can be
+/// uploaded as a CI artifact as is.
+#[derive(Debug, Args)]
+pub(crate) struct PlotArgs {
+    /// Report files, or directories searched for `*.json` reports.
+    #[arg(required = true)]
+    inputs: Vec<PathBuf>,
+    /// Directory the charts are written into; created if missing.
+    #[arg(short, long, value_name = "DIR")]
+    output: PathBuf,
+    /// Kind of chart to draw; may be repeated. All kinds by default.
+    #[arg(short, long = "kind", value_enum, value_name = "KIND")]
+    kinds: Vec<Kind>,
+    /// Image format.
+    #[arg(long, value_enum, default_value_t = ImageFormat::Svg)]
+    format: ImageFormat,
+    /// Only benchmarks matching this glob; may be repeated.
+    #[arg(short, long = "benchmark", value_name = "GLOB")]
+    benchmarks: Vec<String>,
+    /// Metric of the trend lines; may be repeated. All by default.
+    #[arg(short, long = "metric", value_name = "METRIC")]
+    metrics: Vec<MetricKind>,
+    /// Draw distributions as violins instead of box plots.
+    #[arg(long)]
+    violin: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Kind {
+    /// Latency distribution of each benchmark across reports.
+    Distribution,
+    /// Log-log scaling curve of each parameter sweep.
+    Sweep,
+    /// Each metric of each benchmark over an implementation's reports.
+    Trend,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum ImageFormat {
+    Svg,
+    Png,
+}
+
+impl ImageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Svg => "svg",
+            Self::Png => "png",
+        }
+    }
+}
+
+pub(crate) fn run(args: PlotArgs) -> Result<ExitCode> {
+    let mut reports = Vec::new();
+    for input in &args.inputs {
+        if input.is_dir() {
+            reports.extend(load_dir(input)?);
+        } else {
+            reports.push(load_report(input)?);
+        }
+    }
+    let mut query = Query::new();
+    query.benchmarks = args.benchmarks.clone();
+    let reports = query.apply(&reports);
+    if reports.is_empty() {
+        return Err("no matching benchmarks in the given reports".into());
+    }
+
+    std::fs::create_dir_all(&args.output)?;
+    let wants = |kind| args.kinds.is_empty() || args.kinds.contains(&kind);
+    let mut charts = Vec::new();
+    if wants(Kind::Distribution) {
+        charts.extend(distributions(&reports, &args)?);
+    }
+    if wants(Kind::Sweep) {
+        charts.extend(sweeps(&reports, &args)?);
+    }
+    if wants(Kind::Trend) {
+        charts.extend(trends(&reports, &args)?);
+    }
+    if charts.is_empty() {
+        return Err("nothing to plot for the requested chart kinds".into());
+    }
+    for chart in &charts {
+        println!("{}", chart.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn chart_path(args: &PlotArgs, name: &str) -> PathBuf {
+    args.output
+        .join(format!("{}.{}", file_stem(name), args.format.extension()))
+}
+
+fn distributions(reports: &[BenchmarkReport], args: &PlotArgs) -> Result<Vec<PathBuf>> {
+    let style = if args.violin {
+        DistributionStyle::Violin
+    } else {
+        DistributionStyle::Box
+    };
+    let names: BTreeSet<&str> = reports
+        .iter()
+        .flat_map(|r| &r.benchmarks)
+        .filter(|(_, result)| result.latency.is_some())
+        .map(|(name, _)| name.as_str())
+        .collect();
+    let mut charts = Vec::new();
+    for name in names {
+        let path = chart_path(args, &format!("distribution-{name}"));
+        plot_distribution(reports, name, style, &path)?;
+        charts.push(path);
+    }
+    Ok(charts)
+}
+
+fn sweeps(reports: &[BenchmarkReport], args: &PlotArgs) -> Result<Vec<PathBuf>> {
+    let names: BTreeSet<String> = reports
+        .iter()
+        .flat_map(extract_sweeps)
+        .map(|sweep| sweep.name)
+        .collect();
+    let mut charts = Vec::new();
+    for name in names {
+        let path = chart_path(args, &format!("sweep-{name}"));
+        plot_sweep(reports, &name, &path)?;
+        charts.push(path);
+    }
+    Ok(charts)
+}
+
+fn trends(reports: &[BenchmarkReport], args: &PlotArgs) -> Result<Vec<PathBuf>> {
+    let implementations: BTreeSet<&str> = reports
+        .iter()
+        .map(|r| r.metadata.implementation.as_str())
+        .collect();
+    let metrics = if args.metrics.is_empty() {
+        &MetricKind::ALL[..]
+    } else {
+        &args.metrics[..]
+    };
+    let mut charts = Vec::new();
+    for implementation in implementations {
+        let history: Vec<BenchmarkReport> = reports
+            .iter()
+            .filter(|r| r.metadata.implementation == implementation)
+            .cloned()
+            .collect();
+        for name in benchmark_names(&history) {
+            for &metric in metrics {
+                let points = history
+                    .iter()
+                    .filter_map(|r| r.benchmarks.get(name))
+                    .filter(|result| metric.get(result).is_some())
+                    .count();
+                if points < 2 {
+                    continue;
+                }
+                let path = chart_path(args, &format!("trend-{implementation}-{name}-{metric}"));
+                plot_trend(&history, name, metric, &path)?;
+                charts.push(path);
+            }
+        }
+    }
+    Ok(charts)
+}
+
+fn benchmark_names(reports: &[BenchmarkReport]) -> BTreeSet<&str> {
+    reports
+        .iter()
+        .flat_map(|r| r.benchmarks.keys())
+        .map(String::as_str)
+        .collect()
+}
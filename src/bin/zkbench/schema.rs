@@ -0,0 +1,37 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench schema`: the JSON Schema of the report format.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+use zkbench::{SCHEMA_VERSION, report_json_schema};
+
+use crate::{Result, write_output};
+
+/// Print the JSON Schema of reports at the current schema version.
+///
+/// The schema is generated from the same definitions the library
+/// serializes, so validators and code generators for other languages
+/// stay in sync with the reports zkbench writes.
+#[derive(Debug, Args)]
+pub(crate) struct SchemaArgs {
+    /// Output file; standard output if omitted.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Print only the schema version number.
+    #[arg(long)]
+    schema_version: bool,
+}
+
+pub(crate) fn run(args: SchemaArgs) -> Result<ExitCode> {
+    let contents = if args.schema_version {
+        format!("{SCHEMA_VERSION}\n")
+    } else {
+        serde_json::to_string_pretty(&report_json_schema())? + "\n"
+    };
+    write_output(args.output.as_ref(), &contents)?;
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,52 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench summarize`: prints a report as a terminal table.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use zkbench::{MetricKind, render_terminal, render_terminal_sorted};
+
+use crate::{Result, load_report, use_color};
+
+/// Print a report as a table.
+///
+/// Values are scaled to readable units (`1.5 s`, not `1500000000 ns`);
+/// rows are sorted by benchmark name unless `--sort` picks a metric.
+#[derive(Debug, Args)]
+pub(crate) struct SummarizeArgs {
+    /// Report file (`-` for standard input).
+    file: PathBuf,
+    /// Sort rows by this metric, smallest first.
+    #[arg(long, value_enum, default_value_t = SortBy::Name)]
+    sort: SortBy,
+    /// Reverse the order of a metric sort.
+    #[arg(long, short)]
+    reverse: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum SortBy {
+    Name,
+    Latency,
+    Memory,
+    Throughput,
+}
+
+pub(crate) fn run(args: SummarizeArgs) -> Result<ExitCode> {
+    let report = load_report(&args.file)?;
+    let kind = match args.sort {
+        SortBy::Name => None,
+        SortBy::Latency => Some(MetricKind::Latency),
+        SortBy::Memory => Some(MetricKind::Memory),
+        SortBy::Throughput => Some(MetricKind::Throughput),
+    };
+    let table = match kind {
+        Some(kind) => render_terminal_sorted(&report, None, 0.0, kind, args.reverse, use_color()),
+        None => render_terminal(&report, None, 0.0, use_color()),
+    };
+    print!("{table}");
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,122 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench trend`: trend analysis over a report store.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use zkbench::store::{DIR_STORE_INDEX, Query};
+use zkbench::{TrendOptions, analyze_trends, render_trends_terminal};
+
+use crate::{Result, load_dir, open_store, use_color};
+
+/// Show how benchmarks evolved over the reports of a store.
+///
+/// Prints one sparkline per benchmark and metric, with the change
+/// points found in the series and the commit at which each happened.
+#[derive(Debug, Args)]
+pub(crate) struct TrendArgs {
+    /// Directory store, directory of report files, or SQLite database
+    /// (feature `sqlite`).
+    store: PathBuf,
+    /// Only reports of this implementation.
+    #[arg(long)]
+    implementation: Option<String>,
+    /// Only benchmarks matching this glob; may be repeated.
+    #[arg(short, long = "benchmark", value_name = "GLOB")]
+    benchmarks: Vec<String>,
+    /// Only this metric (`latency`, `memory`, `throughput` or a counter);
+    /// may be repeated.
+    #[arg(short, long = "metric", value_name = "METRIC")]
+    metrics: Vec<String>,
+    /// Minimum relative shift reported as a change point (0.05 is 5%).
+    #[arg(long, default_value_t = TrendOptions::default().min_change)]
+    min_change: f64,
+    /// Also write an SVG chart per series into this directory (feature
+    /// `plot`; latency, memory and throughput only).
+    #[arg(long, value_name = "DIR")]
+    svg: Option<PathBuf>,
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = Format::Terminal)]
+    format: Format,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Format {
+    /// Sparklines, colored on a terminal.
+    Terminal,
+    /// The trends, with all points, as JSON.
+    Json,
+}
+
+pub(crate) fn run(args: TrendArgs) -> Result<ExitCode> {
+    let mut query = Query::new();
+    query.implementation = args.implementation.clone();
+    query.benchmarks = args.benchmarks.clone();
+    let reports = if args.store.is_dir() && !args.store.join(DIR_STORE_INDEX).exists() {
+        query.apply(&load_dir(&args.store)?)
+    } else {
+        open_store(&args.store)?.query(&query)?
+    };
+    let opts = TrendOptions {
+        min_change: args.min_change,
+        ..Default::default()
+    };
+    let trends: Vec<_> = analyze_trends(&reports, &opts)
+        .into_iter()
+        .filter(|t| args.metrics.is_empty() || args.metrics.contains(&t.metric))
+        .collect();
+    if trends.is_empty() {
+        return Err(format!("no matching series in {}", args.store.display()).into());
+    }
+    if let Some(dir) = &args.svg {
+        write_charts(&reports, &trends, dir)?;
+    }
+    match args.format {
+        Format::Terminal => print!("{}", render_trends_terminal(&trends, use_color())),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&trends)?),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Writes `<implementation>-<benchmark>-<metric>.svg` per series.
+#[cfg(feature = "plot")]
+fn write_charts(
+    reports: &[zkbench::BenchmarkReport],
+    trends: &[zkbench::Trend],
+    dir: &std::path::Path,
+) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for trend in trends {
+        let Ok(kind) = trend.metric.parse::<zkbench::MetricKind>() else {
+            continue;
+        };
+        let history: Vec<_> = reports
+            .iter()
+            .filter(|r| r.metadata.implementation == trend.implementation)
+            .cloned()
+            .collect();
+        let name = format!(
+            "{}-{}-{}",
+            trend.implementation, trend.benchmark, trend.metric
+        );
+        zkbench::plot::plot_trend(
+            &history,
+            &trend.benchmark,
+            kind,
+            dir.join(crate::file_stem(&name) + ".svg"),
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "plot"))]
+fn write_charts(
+    _: &[zkbench::BenchmarkReport],
+    _: &[zkbench::Trend],
+    _: &std::path::Path,
+) -> Result<()> {
+    Err("SVG charts need the `plot` feature".into())
+}
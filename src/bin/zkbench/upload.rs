@@ -0,0 +1,122 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench upload`: publishes reports to an ingestion endpoint or a
+//! bucket (feature `publish` or `object-store`).
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+use zkbench::BenchmarkReport;
+#[cfg(feature = "publish")]
+use zkbench::publish::UPLOAD_TOKEN_ENV;
+use zkbench::publish::UPLOAD_URL_ENV;
+
+use crate::{Result, load_report};
+
+/// Publish reports to an HTTP ingestion endpoint or an S3/GCS bucket.
+///
+/// The destination defaults to `$ZKBENCH_UPLOAD_URL`. HTTP endpoints
+/// (feature `publish`) get `$ZKBENCH_UPLOAD_TOKEN` as a bearer token;
+/// buckets (feature `object-store`) use the `AWS_*` or `GOOGLE_*`
+/// credentials of the environment.
+#[derive(Debug, Args)]
+pub(crate) struct UploadArgs {
+    /// Report files (`-` for standard input).
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+    /// `http(s)://` endpoint or `s3://`/`gs://` bucket URL.
+    #[arg(long, value_name = "URL")]
+    to: Option<String>,
+    /// Retries of a failed HTTP request.
+    #[arg(long, default_value_t = 3)]
+    retries: u32,
+    /// Object key layout in buckets, e.g. `{implementation}/{id}.json`.
+    #[arg(long, value_name = "TEMPLATE")]
+    key_template: Option<String>,
+    /// Print where each report would go without uploading it.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+pub(crate) fn run(args: UploadArgs) -> Result<ExitCode> {
+    let url = match &args.to {
+        Some(url) => url.clone(),
+        None => std::env::var(UPLOAD_URL_ENV)
+            .map_err(|_| format!("no destination; pass --to or set {UPLOAD_URL_ENV}"))?,
+    };
+    let reports = args
+        .files
+        .iter()
+        .map(|path| load_report(path))
+        .collect::<Result<Vec<_>>>()?;
+    if url.starts_with("http://") || url.starts_with("https://") {
+        upload_http(&args, &url, &reports)?;
+    } else {
+        upload_bucket(&args, &url, &reports)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(feature = "publish")]
+fn upload_http(args: &UploadArgs, url: &str, reports: &[BenchmarkReport]) -> Result<()> {
+    let mut publisher = zkbench::publish::HttpPublisher::new(url).with_max_retries(args.retries);
+    let token = std::env::var(UPLOAD_TOKEN_ENV).ok();
+    if let Some(token) = &token {
+        publisher = publisher.with_token(token);
+    }
+    for report in reports {
+        if args.dry_run {
+            println!(
+                "would POST {} to {url} ({}, Idempotency-Key {})",
+                report.metadata.implementation,
+                if token.is_some() {
+                    format!("token from {UPLOAD_TOKEN_ENV}")
+                } else {
+                    "no token".to_string()
+                },
+                zkbench::report_id(report)
+            );
+            continue;
+        }
+        let receipt = publisher.publish(report)?;
+        println!(
+            "published {} to {url}: HTTP {} after {} attempt(s)",
+            report.metadata.implementation, receipt.status, receipt.attempts
+        );
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "publish"))]
+fn upload_http(_: &UploadArgs, _: &str, _: &[BenchmarkReport]) -> Result<()> {
+    Err("HTTP uploads need the `publish` feature".into())
+}
+
+#[cfg(feature = "object-store")]
+fn upload_bucket(args: &UploadArgs, url: &str, reports: &[BenchmarkReport]) -> Result<()> {
+    let mut publisher = zkbench::publish::BucketPublisher::from_url(url)?;
+    if let Some(template) = &args.key_template {
+        publisher = publisher.with_key_template(template);
+    }
+    for report in reports {
+        let destination = publisher.url_for(report).unwrap_or_default();
+        if args.dry_run {
+            println!("would upload to {destination}");
+            continue;
+        }
+        publisher.upload_blocking(report)?;
+        println!("uploaded to {destination}");
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "object-store"))]
+fn upload_bucket(_: &UploadArgs, url: &str, _: &[BenchmarkReport]) -> Result<()> {
+    if url.starts_with("s3://") || url.starts_with("s3a://") || url.starts_with("gs://") {
+        Err("bucket uploads need the `object-store` feature".into())
+    } else {
+        Err(format!("unsupported destination {url}; use http(s)://, s3:// or gs://").into())
+    }
+}
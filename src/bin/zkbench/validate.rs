@@ -0,0 +1,61 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench validate`: checks report files against the schema and the
+//! semantic validation rules.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+use zkbench::{Severity, validate_json};
+
+use crate::{EXIT_FAILURE, Result, read_input};
+
+/// Check report files against the current schema and validation rules.
+///
+/// Reports every problem found, with the JSON path of the offending
+/// value: values outside their bounds, units of the wrong kind,
+/// duplicate or unknown keys, unparseable timestamps and more.
+#[derive(Debug, Args)]
+pub(crate) struct ValidateArgs {
+    /// Report files (`-` for standard input).
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+    /// Fail on warnings as well as errors.
+    #[arg(long)]
+    deny_warnings: bool,
+}
+
+pub(crate) fn run(args: ValidateArgs) -> Result<ExitCode> {
+    let mut valid = true;
+    for path in &args.files {
+        let name = path.display();
+        let issues = match read_input(path) {
+            Ok(json) => validate_json(&json),
+            Err(e) => {
+                println!("{name}: cannot read: {e}");
+                valid = false;
+                continue;
+            }
+        };
+        let errors = issues
+            .iter()
+            .filter(|i| i.severity == Severity::Error)
+            .count();
+        if errors > 0 || (args.deny_warnings && !issues.is_empty()) {
+            valid = false;
+        }
+        if issues.is_empty() {
+            println!("{name}: ok");
+        }
+        for issue in &issues {
+            println!("{name}: {issue}");
+        }
+    }
+    Ok(if valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_FAILURE)
+    })
+}
@@ -0,0 +1,204 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! `zkbench watch`: re-runs benchmarks on changes.
+
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, SystemTime};
+
+use clap::Args;
+use zkbench::{
+    BenchmarkReport, ChangeDetector, ComparisonConfig, DuplicatePolicy, MergePolicy,
+    OUTPUT_DIR_ENV, merge_reports_with, render_terminal,
+};
+
+use crate::{Result, load_dir, use_color};
+
+/// Re-run a bench command whenever the sources change, printing how each
+/// run compares with the previous one.
+///
+/// The command and watched paths come from the `[watch]` table of the
+/// nearest zkbench.toml unless given here. Press Enter to re-run without
+/// a change, and `q` Enter to quit. Deltas use the `[compare]` threshold.
+#[derive(Debug, Args)]
+pub(crate) struct WatchArgs {
+    /// Shell command running the benchmarks, e.g.
+    /// `cargo bench --bench prover`.
+    #[arg(short, long)]
+    command: Option<String>,
+    /// Report the command writes; by default the reports it writes into
+    /// $ZKBENCH_OUTPUT_DIR are used.
+    #[arg(long, value_name = "FILE")]
+    report: Option<PathBuf>,
+    /// File or directory to watch; may be repeated.
+    #[arg(short = 'w', long = "watch", value_name = "PATH")]
+    paths: Vec<PathBuf>,
+    /// Configuration file; defaults to the nearest zkbench.toml.
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+}
+
+enum Input {
+    Rerun,
+    Quit,
+}
+
+pub(crate) fn run(args: WatchArgs) -> Result<ExitCode> {
+    let config = match &args.config {
+        Some(path) => ComparisonConfig::load(path)?,
+        None => ComparisonConfig::discover(std::env::current_dir()?)?,
+    };
+    let mut watch = config.watch;
+    if args.command.is_some() {
+        watch.command = args.command;
+    }
+    if args.report.is_some() {
+        watch.report = args.report;
+    }
+    if !args.paths.is_empty() {
+        watch.paths = args.paths;
+    }
+    let Some(command) = watch.command else {
+        return Err("no bench command; pass --command or set `command` in [watch]".into());
+    };
+    let output_dir = std::env::temp_dir().join(format!("zkbench-watch-{}", std::process::id()));
+    let interval = Duration::from_millis(watch.poll_interval_ms.max(10));
+    let mut detector = ChangeDetector::new(watch.paths.clone(), watch.ignore);
+    let input = read_input();
+    let mut stdin_open = true;
+
+    let paths: Vec<_> = watch
+        .paths
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect();
+    println!(
+        "watching {}; press Enter to re-run, q Enter to quit",
+        paths.join(", ")
+    );
+    let mut previous: Option<BenchmarkReport> = None;
+    let mut pending = true;
+    loop {
+        if pending {
+            pending = false;
+            println!("\n$ {command}");
+            match run_once(&command, watch.report.as_deref(), &output_dir) {
+                Ok(report) => {
+                    let threshold = config.compare.threshold;
+                    print!(
+                        "{}",
+                        render_terminal(&report, previous.as_ref(), threshold, use_color())
+                    );
+                    previous = Some(report);
+                }
+                Err(e) => eprintln!("zkbench: error: {e}"),
+            }
+            // The run may touch watched files itself.
+            detector.poll();
+        }
+        let received = if stdin_open {
+            input.recv_timeout(interval)
+        } else {
+            std::thread::sleep(interval);
+            Err(RecvTimeoutError::Timeout)
+        };
+        match received {
+            Ok(Input::Rerun) => pending = true,
+            Ok(Input::Quit) => break,
+            Err(RecvTimeoutError::Disconnected) => stdin_open = false,
+            Err(RecvTimeoutError::Timeout) => {
+                let mut changed = detector.poll();
+                if changed.is_empty() {
+                    continue;
+                }
+                // Wait for editors and formatters to finish writing.
+                loop {
+                    std::thread::sleep(interval);
+                    let more = detector.poll();
+                    if more.is_empty() {
+                        break;
+                    }
+                    changed.extend(more);
+                }
+                changed.sort();
+                changed.dedup();
+                let more = match changed.len() {
+                    1 => String::new(),
+                    n => format!(" and {} more", n - 1),
+                };
+                println!("\nchanged: {}{more}", changed[0].display());
+                pending = true;
+            }
+        }
+    }
+    let _ = std::fs::remove_dir_all(&output_dir);
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Forwards lines typed on standard input: `q` quits, anything else
+/// re-runs.
+fn read_input() -> Receiver<Input> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            let input = if line.trim() == "q" {
+                Input::Quit
+            } else {
+                Input::Rerun
+            };
+            if sender.send(input).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// Runs the bench command and loads the report it wrote.
+fn run_once(command: &str, report: Option<&Path>, output_dir: &Path) -> Result<BenchmarkReport> {
+    let _ = std::fs::remove_dir_all(output_dir);
+    let started = SystemTime::now();
+    let status = shell(command).env(OUTPUT_DIR_ENV, output_dir).status()?;
+    if !status.success() {
+        return Err(format!("bench command failed ({status})").into());
+    }
+    if let Some(path) = report {
+        if std::fs::metadata(path)?.modified()? < started {
+            return Err(format!("{} was not updated by the command", path.display()).into());
+        }
+        return Ok(BenchmarkReport::load(path)?);
+    }
+    let reports = if output_dir.is_dir() {
+        load_dir(output_dir)?
+    } else {
+        Vec::new()
+    };
+    if reports.is_empty() {
+        return Err(format!(
+            "the command wrote no report into ${OUTPUT_DIR_ENV}; pass --report if it writes elsewhere"
+        )
+        .into());
+    }
+    // `cargo bench` runs one binary per bench target, each writing a report.
+    let policy = MergePolicy {
+        on_duplicate: DuplicatePolicy::KeepLast,
+        allow_mixed_commits: true,
+    };
+    Ok(merge_reports_with(&reports, &policy)?)
+}
+
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]);
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]);
+        shell
+    }
+}
@@ -0,0 +1,378 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Locating the commit that introduced a regression.
+//!
+//! Nightly runs rarely cover every commit. [`Bisect`] binary-searches the
+//! commit history using stored reports where they exist and, if given a
+//! runner, benchmarks the missing commits on demand.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::compare::higher_is_better;
+use crate::schema::BenchmarkReport;
+use crate::trend::parse_timestamp;
+use crate::units::to_base;
+
+/// Error returned by [`Bisect::run`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum BisectError {
+    /// Fewer than two commits were given.
+    EmptyHistory,
+    /// The commit has no report, or the report lacks the metric, and no
+    /// runner produced one.
+    NoMeasurement { commit: String },
+    /// The newest commit is not regressed against the oldest.
+    NotRegressed { commit: String, change: f64 },
+}
+
+impl fmt::Display for BisectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyHistory => write!(f, "bisecting needs at least two commits"),
+            Self::NoMeasurement { commit } => write!(f, "no measurement for commit {}", commit),
+            Self::NotRegressed { commit, change } => write!(
+                f,
+                "commit {} is not regressed ({:+.1}% against the first commit)",
+                commit,
+                change * 100.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BisectError {}
+
+/// A commit whose measurement was consulted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BisectStep {
+    pub commit: String,
+    /// Metric value in its base unit.
+    pub value: f64,
+    /// Change against the first (good) commit, `value / reference - 1`.
+    pub change: f64,
+    pub regressed: bool,
+    /// Whether the value came from the runner rather than a stored report.
+    pub ran: bool,
+}
+
+/// Result of a successful bisection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BisectOutcome {
+    /// Newest commit known not to be regressed.
+    pub last_good: String,
+    /// Oldest commit known to be regressed.
+    pub first_bad: String,
+    /// Commits between the two that could not be measured. When empty,
+    /// `first_bad` is the culprit.
+    pub untested: Vec<String>,
+    /// Measurements consulted, in the order they were taken.
+    pub steps: Vec<BisectStep>,
+}
+
+type Runner<'a> = Box<dyn FnMut(&str) -> Option<BenchmarkReport> + 'a>;
+
+/// Metric value at a commit, in base units.
+#[derive(Clone, Copy)]
+struct Measurement {
+    value: f64,
+    higher_is_better: bool,
+    /// Whether the runner produced it.
+    ran: bool,
+}
+
+/// Binary search for the first commit at which a benchmark regressed.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use zkbench::{Bisect, BenchmarkReport, BenchmarkResult, Metadata, MetricValue};
+///
+/// let report = |ms: f64| BenchmarkReport {
+///     metadata: Metadata::create("impl", "1.0.0"),
+///     benchmarks: [(
+///         "prove".to_string(),
+///         BenchmarkResult {
+///             latency: Some(MetricValue::new(ms, "ms")),
+///             ..Default::default()
+///         },
+///     )]
+///     .into_iter()
+///     .collect(),
+/// };
+/// let commits: Vec<String> = ["a", "b", "c", "d"].map(String::from).to_vec();
+/// let stored = HashMap::from([
+///     ("a".to_string(), report(100.0)),
+///     ("d".to_string(), report(130.0)),
+/// ]);
+/// let outcome = Bisect::new("prove", "latency", 0.1)
+///     .with_runner(|commit| Some(report(if commit == "b" { 101.0 } else { 129.0 })))
+///     .run(&commits, &stored)
+///     .unwrap();
+/// assert_eq!(outcome.first_bad, "c");
+/// assert!(outcome.untested.is_empty());
+/// ```
+pub struct Bisect<'a> {
+    benchmark: String,
+    metric: String,
+    threshold: f64,
+    runner: Option<Runner<'a>>,
+}
+
+impl<'a> Bisect<'a> {
+    /// Searches for the first commit at which `metric` of `benchmark`
+    /// moved in the worse direction by more than `threshold` (a fraction:
+    /// `0.05` is 5%) against the oldest commit.
+    pub fn new(benchmark: &str, metric: &str, threshold: f64) -> Self {
+        Self {
+            benchmark: benchmark.to_string(),
+            metric: metric.to_string(),
+            threshold,
+            runner: None,
+        }
+    }
+
+    /// Benchmarks commits without a stored report by calling `runner`,
+    /// e.g. to check out the commit and run the suite. Returning `None`
+    /// leaves the commit untested.
+    pub fn with_runner(mut self, runner: impl FnMut(&str) -> Option<BenchmarkReport> + 'a) -> Self {
+        self.runner = Some(Box::new(runner));
+        self
+    }
+
+    /// Bisects `commits`, ordered oldest to newest, using the stored
+    /// reports keyed by commit SHA (see [`reports_by_commit`]).
+    ///
+    /// The oldest commit is the good reference and the newest must be
+    /// regressed against it. When a midpoint cannot be measured, the
+    /// nearest measurable commit inside the range is used instead; if
+    /// none remains, the outcome lists the unmeasured commits.
+    pub fn run(
+        &mut self,
+        commits: &[String],
+        reports: &HashMap<String, BenchmarkReport>,
+    ) -> Result<BisectOutcome, BisectError> {
+        if commits.len() < 2 {
+            return Err(BisectError::EmptyHistory);
+        }
+        let mut steps = Vec::new();
+        let reference = self.measure_required(&commits[0], reports)?;
+        steps.push(self.step(&commits[0], reference, reference));
+
+        let last = commits.len() - 1;
+        let bad = self.measure_required(&commits[last], reports)?;
+        let step = self.step(&commits[last], bad, reference);
+        if !step.regressed {
+            return Err(BisectError::NotRegressed {
+                commit: step.commit,
+                change: step.change,
+            });
+        }
+        steps.push(step);
+
+        let (mut lo, mut hi) = (0, last);
+        let mut unmeasured = vec![false; commits.len()];
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            // Try the midpoint first, then alternate outwards.
+            let candidates = (0..hi - lo - 1).map(|k| {
+                let offset = k.div_ceil(2);
+                if k % 2 == 1 {
+                    mid - offset
+                } else {
+                    mid + offset
+                }
+            });
+            let mut probe = None;
+            for i in candidates {
+                if !(lo + 1..hi).contains(&i) || unmeasured[i] {
+                    continue;
+                }
+                match self.measure(&commits[i], reports) {
+                    Some(value) => {
+                        probe = Some((i, value));
+                        break;
+                    }
+                    None => unmeasured[i] = true,
+                }
+            }
+            let Some((i, value)) = probe else {
+                break;
+            };
+            let step = self.step(&commits[i], value, reference);
+            if step.regressed {
+                hi = i;
+            } else {
+                lo = i;
+            }
+            steps.push(step);
+        }
+
+        Ok(BisectOutcome {
+            last_good: commits[lo].clone(),
+            first_bad: commits[hi].clone(),
+            untested: commits[lo + 1..hi].to_vec(),
+            steps,
+        })
+    }
+
+    fn measure_required(
+        &mut self,
+        commit: &str,
+        reports: &HashMap<String, BenchmarkReport>,
+    ) -> Result<Measurement, BisectError> {
+        self.measure(commit, reports)
+            .ok_or_else(|| BisectError::NoMeasurement {
+                commit: commit.to_string(),
+            })
+    }
+
+    fn measure(
+        &mut self,
+        commit: &str,
+        reports: &HashMap<String, BenchmarkReport>,
+    ) -> Option<Measurement> {
+        if let Some(measurement) = reports.get(commit).and_then(|r| self.value(r, false)) {
+            return Some(measurement);
+        }
+        let report = self.runner.as_mut()?(commit)?;
+        self.value(&report, true)
+    }
+
+    fn value(&self, report: &BenchmarkReport, ran: bool) -> Option<Measurement> {
+        let result = report.benchmarks.get(&self.benchmark)?;
+        let (_, metric) = result
+            .metrics()
+            .into_iter()
+            .find(|(name, _)| *name == self.metric)?;
+        let value = to_base(metric.value, &metric.unit);
+        value.is_finite().then(|| Measurement {
+            value,
+            higher_is_better: higher_is_better(&self.metric, &metric.unit),
+            ran,
+        })
+    }
+
+    fn step(&self, commit: &str, at: Measurement, reference: Measurement) -> BisectStep {
+        let change = at.value / reference.value - 1.0;
+        let worse = if reference.higher_is_better {
+            -change
+        } else {
+            change
+        };
+        BisectStep {
+            commit: commit.to_string(),
+            value: at.value,
+            change,
+            regressed: worse > self.threshold,
+            ran: at.ran,
+        }
+    }
+}
+
+/// Indexes reports by commit SHA, keeping the most recent report when a
+/// commit was benchmarked more than once.
+pub fn reports_by_commit(
+    reports: impl IntoIterator<Item = BenchmarkReport>,
+) -> HashMap<String, BenchmarkReport> {
+    let mut by_commit: HashMap<String, BenchmarkReport> = HashMap::new();
+    for report in reports {
+        let newer = by_commit
+            .get(&report.metadata.commit_sha)
+            .is_none_or(|current| {
+                parse_timestamp(&report.metadata.timestamp)
+                    >= parse_timestamp(&current.metadata.timestamp)
+            });
+        if newer {
+            by_commit.insert(report.metadata.commit_sha.clone(), report);
+        }
+    }
+    by_commit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    fn history(values: &[f64]) -> (Vec<String>, HashMap<String, BenchmarkReport>) {
+        let commits: Vec<String> = (0..values.len()).map(|i| format!("c{i}")).collect();
+        let reports = reports_by_commit(commits.iter().zip(values).map(|(commit, ms)| {
+            ReportBuilder::new()
+                .commit(commit)
+                .latencies_ms(&[("prove", *ms)])
+                .build()
+        }));
+        (commits, reports)
+    }
+
+    #[test]
+    fn finds_first_bad_commit() {
+        let (commits, reports) = history(&[100.0, 101.0, 99.0, 102.0, 120.0, 121.0, 119.0, 122.0]);
+        let outcome = Bisect::new("prove", "latency", 0.1)
+            .run(&commits, &reports)
+            .unwrap();
+        assert_eq!(
+            (outcome.last_good.as_str(), outcome.first_bad.as_str()),
+            ("c3", "c4")
+        );
+        assert!(outcome.untested.is_empty());
+        // Reference, newest, then log2(8) = 3 probes.
+        assert_eq!(outcome.steps.len(), 5);
+    }
+
+    #[test]
+    fn gaps_are_filled_by_runner_or_reported() {
+        let (commits, mut reports) = history(&[100.0, 100.0, 100.0, 130.0, 130.0, 130.0]);
+        for gap in ["c2", "c3"] {
+            reports.remove(gap);
+        }
+        let outcome = Bisect::new("prove", "latency", 0.1)
+            .run(&commits, &reports)
+            .unwrap();
+        assert_eq!(outcome.last_good, "c1");
+        assert_eq!(outcome.first_bad, "c4");
+        assert_eq!(outcome.untested, ["c2", "c3"]);
+
+        let mut runs = Vec::new();
+        let outcome = Bisect::new("prove", "latency", 0.1)
+            .with_runner(|commit| {
+                runs.push(commit.to_string());
+                Some(
+                    ReportBuilder::new()
+                        .commit(commit)
+                        .latencies_ms(&[("prove", if commit == "c2" { 100.0 } else { 130.0 })])
+                        .build(),
+                )
+            })
+            .run(&commits, &reports)
+            .unwrap();
+        assert_eq!(outcome.first_bad, "c3");
+        assert!(outcome.untested.is_empty());
+        assert!(outcome.steps.iter().any(|s| s.ran));
+        assert_eq!(runs.len(), 2);
+    }
+
+    #[test]
+    fn errors() {
+        let (commits, reports) = history(&[100.0, 101.0]);
+        let mut bisect = Bisect::new("prove", "latency", 0.1);
+        assert!(matches!(
+            bisect.run(&commits, &reports),
+            Err(BisectError::NotRegressed { .. })
+        ));
+        assert_eq!(
+            bisect.run(&commits[..1], &reports).unwrap_err(),
+            BisectError::EmptyHistory
+        );
+        let mut missing = Bisect::new("verify", "latency", 0.1);
+        assert_eq!(
+            missing.run(&commits, &reports).unwrap_err().to_string(),
+            "no measurement for commit c0"
+        );
+    }
+}
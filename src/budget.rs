@@ -0,0 +1,402 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Regression budget accounting.
+//!
+//! Over a release cycle, small regressions are usually acceptable as long
+//! as the suite as a whole does not drift too far from the last release.
+//! A [`BudgetPolicy`] pins a reference baseline and a budget (e.g. 3%);
+//! the suite-level slowdown since the reference is the accumulated
+//! *debt*. [`gate_with_budget`] tolerates regressions while the debt
+//! stays within the budget, and [`record_budget`] records the debt of
+//! the gated commit in a [`BudgetLedger`] next to the baselines.
+//!
+//! ```toml
+//! [budget]
+//! reference = "v0.3.0"
+//! budget = 0.03
+//! max_regression = 0.10
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::atomic::write_atomic;
+use crate::baseline::BaselineStore;
+use crate::compare::CompareOptions;
+use crate::gate::{GatePolicy, GateResult, Violation, ViolationKind, gate};
+use crate::schema::{BenchmarkReport, MetricKind};
+use crate::score::suite_score;
+
+/// `exempted_by` of the violations tolerated by the budget.
+pub const BUDGET_EXEMPTION: &str = "budget";
+
+/// Regression budget against a pinned reference, the `[budget]` table of
+/// [`ComparisonConfig`](crate::ComparisonConfig).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BudgetPolicy {
+    /// Name of the reference baseline in the [`BaselineStore`].
+    pub reference: String,
+    /// Suite-level slowdown (a fraction: `0.03` is 3%) tolerated since the
+    /// reference.
+    #[serde(default = "default_budget")]
+    pub budget: f64,
+    /// Metric the suite slowdown is measured on.
+    #[serde(default = "default_metric")]
+    pub metric: MetricKind,
+    /// Regressions larger than this fail the gate regardless of the
+    /// budget. `None` tolerates any regression within budget.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_regression: Option<f64>,
+}
+
+fn default_budget() -> f64 {
+    0.03
+}
+
+fn default_metric() -> MetricKind {
+    MetricKind::Latency
+}
+
+impl BudgetPolicy {
+    /// Creates a policy with a 3% latency budget against `reference`.
+    pub fn new(reference: &str) -> Self {
+        Self {
+            reference: reference.to_string(),
+            budget: default_budget(),
+            metric: default_metric(),
+            max_regression: None,
+        }
+    }
+
+    /// Replaces the budget.
+    pub fn with_budget(mut self, budget: f64) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Caps the size of a single tolerated regression.
+    pub fn with_max_regression(mut self, max_regression: f64) -> Self {
+        self.max_regression = Some(max_regression);
+        self
+    }
+}
+
+/// Suite-level slowdown of `candidate` since `reference` on `metric`: the
+/// inverse of the [`suite_score`] minus one, so `0.02` means 2% slower on
+/// the geometric average and negative values are speedups. Returns `None`
+/// if the reports share no benchmark.
+pub fn suite_debt(
+    candidate: &BenchmarkReport,
+    reference: &BenchmarkReport,
+    metric: MetricKind,
+) -> Option<f64> {
+    suite_score(candidate, reference, metric).map(|s| 1.0 / s.score.value - 1.0)
+}
+
+/// Debt of one gated commit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BudgetEntry {
+    pub commit_sha: String,
+    pub timestamp: String,
+    pub debt: f64,
+}
+
+/// History of the debt accumulated against one reference, stored as
+/// `<baselines>/budgets/<reference>.json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BudgetLedger {
+    pub reference: String,
+    /// Entries in the order they were recorded.
+    pub entries: Vec<BudgetEntry>,
+}
+
+impl BudgetLedger {
+    /// File the ledger of `reference` is stored in.
+    pub fn path(store: &BaselineStore, reference: &str) -> io::Result<PathBuf> {
+        store.path(reference)?;
+        Ok(store
+            .dir()
+            .join("budgets")
+            .join(format!("{}.json", reference)))
+    }
+
+    /// Loads the ledger of `reference`; a missing ledger is empty.
+    pub fn load(store: &BaselineStore, reference: &str) -> io::Result<Self> {
+        match fs::read_to_string(Self::path(store, reference)?) {
+            Ok(text) => serde_json::from_str(&text).map_err(io::Error::other),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self {
+                reference: reference.to_string(),
+                entries: Vec::new(),
+            }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Writes the ledger back to the store.
+    pub fn save(&self, store: &BaselineStore) -> io::Result<PathBuf> {
+        let path = Self::path(store, &self.reference)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        write_atomic(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+
+    /// Debt of the latest entry.
+    pub fn debt(&self) -> Option<f64> {
+        self.entries.last().map(|e| e.debt)
+    }
+
+    /// Appends an entry, replacing an earlier one of the same commit.
+    pub fn record(&mut self, entry: BudgetEntry) {
+        self.entries.retain(|e| e.commit_sha != entry.commit_sha);
+        self.entries.push(entry);
+    }
+}
+
+/// Budget standing of a gated candidate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BudgetStatus {
+    pub reference: String,
+    pub budget: f64,
+    /// Suite slowdown of the candidate since the reference.
+    pub debt: f64,
+    /// Debt of the previous ledger entry, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub previous_debt: Option<f64>,
+}
+
+impl BudgetStatus {
+    /// Whether the debt is within the budget.
+    pub fn within_budget(&self) -> bool {
+        self.debt <= self.budget
+    }
+
+    /// Budget left; negative when it is overdrawn.
+    pub fn remaining(&self) -> f64 {
+        self.budget - self.debt
+    }
+}
+
+impl fmt::Display for BudgetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "regression budget: {:+.1}% of {:.1}% used since {}",
+            self.debt * 100.0,
+            self.budget * 100.0,
+            self.reference
+        )?;
+        if let Some(previous) = self.previous_debt {
+            write!(
+                f,
+                " ({:+.1}% since last run)",
+                (self.debt - previous) * 100.0
+            )?;
+        }
+        if !self.within_budget() {
+            f.write_str(", over budget")?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs [`gate`] with regressions paid for out of a regression budget.
+///
+/// The candidate's debt against the `budget.reference` baseline in
+/// `store` is measured; its [`BudgetLedger`] is only read, so evaluating
+/// a candidate, e.g. of a pull request, leaves no trace until the status
+/// is passed to [`record_budget`]. While the debt stays
+/// within the budget, regressions no larger than
+/// [`BudgetPolicy::max_regression`] are moved to the exempted violations
+/// with `exempted_by` set to [`BUDGET_EXEMPTION`]; removed benchmarks
+/// still count. The status is attached to the result. Fails if the
+/// reference cannot be loaded or shares no benchmark with the candidate.
+pub fn gate_with_budget(
+    baseline: &BenchmarkReport,
+    candidate: &BenchmarkReport,
+    opts: &CompareOptions,
+    policy: &GatePolicy,
+    budget: &BudgetPolicy,
+    store: &BaselineStore,
+) -> io::Result<GateResult> {
+    let reference = store.load(&budget.reference)?;
+    let debt = suite_debt(candidate, &reference, budget.metric).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "candidate shares no {} benchmark with reference '{}'",
+                budget.metric, budget.reference
+            ),
+        )
+    })?;
+    let previous_debt = BudgetLedger::load(store, &budget.reference)?
+        .entries
+        .iter()
+        .rev()
+        .find(|e| e.commit_sha != candidate.metadata.commit_sha)
+        .map(|e| e.debt);
+    let status = BudgetStatus {
+        reference: budget.reference.clone(),
+        budget: budget.budget,
+        debt,
+        previous_debt,
+    };
+
+    let mut result = gate(baseline, candidate, opts, policy);
+    if status.within_budget() {
+        let (tolerated, violations) = result.violations.into_iter().partition(|v| {
+            matches!(v.kind, ViolationKind::Regression { change, .. }
+                if budget.max_regression.is_none_or(|max| change.abs() <= max))
+        });
+        result.violations = violations;
+        result
+            .exempted
+            .extend(tolerated.into_iter().map(|mut v: Violation| {
+                v.exempted_by = Some(BUDGET_EXEMPTION.to_string());
+                v
+            }));
+        result.verdict = policy.verdict(&result.comparison, result.violations.len());
+    }
+    result.budget = Some(status);
+    Ok(result)
+}
+
+/// Adds the candidate's debt from a [`gate_with_budget`] status to the
+/// [`BudgetLedger`] of its reference, e.g. on the main branch, and
+/// returns the ledger path.
+pub fn record_budget(
+    store: &BaselineStore,
+    candidate: &BenchmarkReport,
+    status: &BudgetStatus,
+) -> io::Result<PathBuf> {
+    let mut ledger = BudgetLedger::load(store, &status.reference)?;
+    ledger.record(BudgetEntry {
+        commit_sha: candidate.metadata.commit_sha.clone(),
+        timestamp: candidate.metadata.timestamp.clone(),
+        debt: status.debt,
+    });
+    ledger.save(store)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    fn store(name: &str) -> BaselineStore {
+        let dir =
+            std::env::temp_dir().join(format!("zkbench-budget-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let store = BaselineStore::new(dir);
+        store
+            .save(
+                "v1",
+                &ReportBuilder::new()
+                    .commit("ref")
+                    .latencies_ms(&[("a", 100.0), ("b", 100.0)])
+                    .build(),
+            )
+            .unwrap();
+        store
+    }
+
+    #[test]
+    fn debt_is_inverse_score() {
+        let reference = ReportBuilder::new()
+            .commit("ref")
+            .latencies_ms(&[("a", 100.0), ("b", 100.0)])
+            .build();
+        let candidate = ReportBuilder::new()
+            .commit("c1")
+            .latencies_ms(&[("a", 104.0), ("b", 100.0)])
+            .build();
+        let debt = suite_debt(&candidate, &reference, MetricKind::Latency).unwrap();
+        assert!((debt - (1.04f64.sqrt() - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn tolerates_regressions_within_budget() {
+        let store = store("within");
+        let baseline = ReportBuilder::new()
+            .commit("c0")
+            .latencies_ms(&[("a", 100.0), ("b", 100.0)])
+            .build();
+        // A 6% regression on one of two benchmarks is ~3% on the suite.
+        let candidate = ReportBuilder::new()
+            .commit("c1")
+            .latencies_ms(&[("a", 106.0), ("b", 100.0)])
+            .build();
+        let opts = CompareOptions::default();
+        let policy = GatePolicy::default();
+        assert!(!gate(&baseline, &candidate, &opts, &policy).passed());
+
+        let budget = BudgetPolicy::new("v1").with_budget(0.05);
+        let result =
+            gate_with_budget(&baseline, &candidate, &opts, &policy, &budget, &store).unwrap();
+        assert!(result.passed());
+        assert_eq!(
+            result.exempted[0].exempted_by.as_deref(),
+            Some(BUDGET_EXEMPTION)
+        );
+        let status = result.budget.as_ref().unwrap();
+        assert!((status.debt - (1.06f64.sqrt() - 1.0)).abs() < 1e-12);
+        assert!(status.previous_debt.is_none());
+
+        let capped = budget.clone().with_max_regression(0.05);
+        assert!(
+            !gate_with_budget(&baseline, &candidate, &opts, &policy, &capped, &store)
+                .unwrap()
+                .passed()
+        );
+        // Evaluating without recording leaves the ledger alone.
+        assert!(BudgetLedger::load(&store, "v1").unwrap().entries.is_empty());
+        fs::remove_dir_all(store.dir()).unwrap();
+    }
+
+    #[test]
+    fn ledger_tracks_debt_and_overdraft_fails() {
+        let store = store("ledger");
+        let opts = CompareOptions::default();
+        let policy = GatePolicy::default();
+        let budget = BudgetPolicy::new("v1");
+        let c1 = ReportBuilder::new()
+            .commit("c1")
+            .latencies_ms(&[("a", 104.0), ("b", 100.0)])
+            .build();
+        let c2 = ReportBuilder::new()
+            .commit("c2")
+            .latencies_ms(&[("a", 110.0), ("b", 100.0)])
+            .build();
+        let first = gate_with_budget(&c1, &c1, &opts, &policy, &budget, &store).unwrap();
+        assert!(first.passed());
+        record_budget(&store, &c1, first.budget.as_ref().unwrap()).unwrap();
+        let result = gate_with_budget(&c1, &c2, &opts, &policy, &budget, &store).unwrap();
+        record_budget(&store, &c2, result.budget.as_ref().unwrap()).unwrap();
+        assert!(!result.passed());
+        let status = result.budget.unwrap();
+        assert!(!status.within_budget());
+        assert!(
+            status
+                .to_string()
+                .contains("since v1 (+2.9% since last run), over budget")
+        );
+
+        let ledger = BudgetLedger::load(&store, "v1").unwrap();
+        let commits: Vec<_> = ledger
+            .entries
+            .iter()
+            .map(|e| e.commit_sha.as_str())
+            .collect();
+        assert_eq!(commits, ["c1", "c2"]);
+        assert_eq!(ledger.debt(), Some(status.debt));
+        assert_eq!(store.list().unwrap(), ["v1"]);
+        assert!(BudgetLedger::path(&store, "../x").is_err());
+        fs::remove_dir_all(store.dir()).unwrap();
+    }
+}
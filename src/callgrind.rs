@@ -0,0 +1,399 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Instruction counts and simulated cache behavior under Callgrind.
+//!
+//! Wall-clock times on shared CI runners vary by several percent between
+//! runs, while the instructions a prover executes do not. Like
+//! iai-callgrind, [`Callgrind::run`] re-runs the bench binary under
+//! Valgrind's Callgrind tool, counts only the benchmarked closure, and
+//! returns the counts as exact counters with the same names
+//! [`import_iai_callgrind`](crate::import_iai_callgrind) uses, so either
+//! source can be compared with the other.
+
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus};
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Environment variable naming the benchmark a re-run binary measures.
+pub const CALLGRIND_BENCH_ENV: &str = "ZKBENCH_CALLGRIND_BENCH";
+
+/// Function Callgrind collects inside; see `--toggle-collect`.
+const REGION: &str = "zkbench_callgrind_region";
+
+/// Why a benchmark could not be measured under Callgrind.
+#[derive(Debug)]
+pub enum CallgrindError {
+    /// Valgrind is not installed.
+    Unavailable,
+    /// The bench binary or the output file could not be accessed.
+    Io { path: PathBuf, source: io::Error },
+    /// Valgrind or the bench binary failed.
+    Failed { status: ExitStatus, stderr: String },
+    /// The Callgrind output file has no event totals.
+    Parse { path: PathBuf, message: String },
+}
+
+impl fmt::Display for CallgrindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "valgrind is not installed"),
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Self::Failed { status, stderr } => {
+                write!(f, "callgrind run failed ({}): {}", status, stderr.trim())
+            }
+            Self::Parse { path, message } => {
+                write!(
+                    f,
+                    "invalid callgrind output {}: {}",
+                    path.display(),
+                    message
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CallgrindError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Runs benchmarks under Callgrind.
+///
+/// [`run`](Self::run) starts the current executable again, with its
+/// arguments, under `valgrind --tool=callgrind` and with
+/// [`CALLGRIND_BENCH_ENV`] set to the benchmark name. In that process,
+/// the same `run` call executes the closure once with collection on and
+/// exits, and `run` calls for other benchmarks return `Ok(None)`. The
+/// closure should therefore not depend on state built by earlier
+/// benchmarks.
+///
+/// With cache simulation, the default, the counters are `instructions`,
+/// `l1_hits`, `ll_hits`, `ram_hits`, `total_read_write` and
+/// `estimated_cycles` (`l1_hits + 5 * ll_hits + 35 * ram_hits`), all in
+/// unit `count`; without it, only `instructions`.
+///
+/// # Example
+///
+/// ```no_run
+/// use zkbench::Callgrind;
+/// # fn prove() {}
+///
+/// let callgrind = Callgrind::new();
+/// if callgrind.is_available() {
+///     if let Some(result) = callgrind.run("prove", prove)? {
+///         println!("{} instructions", result.counters["instructions"].value);
+///     }
+/// }
+/// # Ok::<(), zkbench::CallgrindError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Callgrind {
+    valgrind: PathBuf,
+    cache_sim: bool,
+    out_dir: PathBuf,
+}
+
+impl Default for Callgrind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Callgrind {
+    /// Uses `valgrind` from `PATH`, with cache simulation, writing its
+    /// output files to the temporary directory.
+    pub fn new() -> Self {
+        Self {
+            valgrind: PathBuf::from("valgrind"),
+            cache_sim: true,
+            out_dir: std::env::temp_dir(),
+        }
+    }
+
+    /// Uses the `valgrind` executable at `path`.
+    pub fn with_valgrind(mut self, path: impl Into<PathBuf>) -> Self {
+        self.valgrind = path.into();
+        self
+    }
+
+    /// Turns cache simulation on or off. Without it, runs are faster but
+    /// only instructions are counted.
+    pub fn with_cache_sim(mut self, cache_sim: bool) -> Self {
+        self.cache_sim = cache_sim;
+        self
+    }
+
+    /// Writes Callgrind output files to `dir` instead of the temporary
+    /// directory. They are removed once read.
+    pub fn with_out_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.out_dir = dir.into();
+        self
+    }
+
+    /// Whether Valgrind can be run, so callers can skip Callgrind
+    /// benchmarks elsewhere, e.g. on macOS runners.
+    pub fn is_available(&self) -> bool {
+        Command::new(&self.valgrind)
+            .arg("--version")
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    /// Measures `f` under Callgrind; see the type docs. Returns `Ok(None)`
+    /// in a Callgrind run of another benchmark and
+    /// [`CallgrindError::Unavailable`] without Valgrind.
+    pub fn run(
+        &self,
+        name: &str,
+        f: impl FnOnce(),
+    ) -> Result<Option<BenchmarkResult>, CallgrindError> {
+        if let Some(target) = std::env::var_os(CALLGRIND_BENCH_ENV) {
+            if target != name {
+                return Ok(None);
+            }
+            let mut f = Some(f);
+            zkbench_callgrind_region(&mut || (f.take().expect("runs once"))());
+            std::process::exit(0);
+        }
+        let exe = std::env::current_exe().map_err(|source| CallgrindError::Io {
+            path: PathBuf::from("current executable"),
+            source,
+        })?;
+        let out_file = self.out_dir.join(format!(
+            "zkbench-callgrind-{}-{}.out",
+            std::process::id(),
+            name.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+        ));
+        let mut out_arg = OsString::from("--callgrind-out-file=");
+        out_arg.push(&out_file);
+        let output = Command::new(&self.valgrind)
+            .args(["--tool=callgrind", "--collect-atstart=no"])
+            .arg(format!("--toggle-collect={REGION}"))
+            .arg(format!(
+                "--cache-sim={}",
+                if self.cache_sim { "yes" } else { "no" }
+            ))
+            .arg(out_arg)
+            .arg(&exe)
+            .args(std::env::args_os().skip(1))
+            .env(CALLGRIND_BENCH_ENV, name)
+            .output();
+        let output = match output {
+            Ok(output) => output,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(CallgrindError::Unavailable);
+            }
+            Err(source) => {
+                return Err(CallgrindError::Io {
+                    path: self.valgrind.clone(),
+                    source,
+                });
+            }
+        };
+        if !output.status.success() {
+            let _ = fs::remove_file(&out_file);
+            return Err(CallgrindError::Failed {
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
+        }
+        let contents = fs::read_to_string(&out_file).map_err(|source| CallgrindError::Io {
+            path: out_file.clone(),
+            source,
+        })?;
+        let _ = fs::remove_file(&out_file);
+        let events = parse_totals(&contents).map_err(|message| CallgrindError::Parse {
+            path: out_file,
+            message,
+        })?;
+        Ok(Some(BenchmarkResult {
+            iterations: 1,
+            counters: counters(&events),
+            ..Default::default()
+        }))
+    }
+}
+
+/// The only code Callgrind collects events in; it looks the function up
+/// by this unmangled name.
+#[unsafe(no_mangle)]
+#[inline(never)]
+fn zkbench_callgrind_region(f: &mut dyn FnMut()) {
+    f();
+}
+
+/// Reads the `events:` names and their `totals:` (or, from older
+/// Valgrind releases, `summary:`) from a Callgrind output file. Events
+/// without a value are zero.
+fn parse_totals(contents: &str) -> Result<BTreeMap<String, u64>, String> {
+    let mut names = None;
+    let mut totals = None;
+    for line in contents.lines() {
+        if let Some(events) = line.strip_prefix("events:") {
+            names = Some(events.split_whitespace().collect::<Vec<_>>());
+        } else if let Some(values) = line
+            .strip_prefix("totals:")
+            .or_else(|| line.strip_prefix("summary:"))
+        {
+            totals = Some(
+                values
+                    .split_whitespace()
+                    .map(|value| {
+                        value
+                            .parse::<u64>()
+                            .map_err(|_| format!("invalid total '{}'", value))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+        }
+    }
+    let names = names.ok_or("no events line")?;
+    let totals = totals.ok_or("no totals line")?;
+    Ok(names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.to_string(), totals.get(i).copied().unwrap_or(0)))
+        .collect())
+}
+
+/// Turns Callgrind events into counters, deriving cache hits the way
+/// iai-callgrind does when the cache events are present.
+fn counters(events: &BTreeMap<String, u64>) -> HashMap<String, MetricValue> {
+    let event = |name: &str| events.get(name).copied();
+    let mut counters = HashMap::new();
+    let mut insert = |name: &str, value: u64| {
+        counters.insert(name.to_string(), MetricValue::new(value as f64, "count"));
+    };
+    if let Some(instructions) = event("Ir") {
+        insert("instructions", instructions);
+    }
+    if let [
+        Some(ir),
+        Some(dr),
+        Some(dw),
+        Some(i1mr),
+        Some(d1mr),
+        Some(d1mw),
+        Some(ilmr),
+        Some(dlmr),
+        Some(dlmw),
+    ] = [
+        "Ir", "Dr", "Dw", "I1mr", "D1mr", "D1mw", "ILmr", "DLmr", "DLmw",
+    ]
+    .map(event)
+    {
+        let total = ir + dr + dw;
+        let l1_misses = i1mr + d1mr + d1mw;
+        let ram_hits = ilmr + dlmr + dlmw;
+        let l1_hits = total - l1_misses;
+        let ll_hits = l1_misses - ram_hits;
+        insert("l1_hits", l1_hits);
+        insert("ll_hits", ll_hits);
+        insert("ram_hits", ram_hits);
+        insert("total_read_write", total);
+        insert("estimated_cycles", l1_hits + 5 * ll_hits + 35 * ram_hits);
+    }
+    counters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "\
+# callgrind format
+version: 1
+creator: callgrind-3.22.0
+pid: 4242
+cmd:  target/release/deps/prover-1234
+part: 1
+
+desc: I1 cache: 32768 B, 64 B, 8-way associative
+positions: line
+events: Ir Dr Dw I1mr D1mr D1mw ILmr DLmr DLmw
+summary: 17341 4200 2062 5 10 3 2 1 0
+
+fl=(1) src/lib.rs
+fn=(1) zkbench_callgrind_region
+0 17341 4200 2062 5 10 3 2 1
+totals: 17341 4200 2062 5 10 3 2 1
+";
+
+    #[test]
+    fn derives_cache_counters() {
+        let events = parse_totals(OUTPUT).unwrap();
+        assert_eq!(events["DLmw"], 0);
+        let counters = counters(&events);
+        let count = |name: &str| {
+            assert_eq!(counters[name].unit, "count");
+            counters[name].value
+        };
+        assert_eq!(count("instructions"), 17341.0);
+        assert_eq!(count("total_read_write"), 23603.0);
+        assert_eq!(count("l1_hits"), 23585.0);
+        assert_eq!(count("ll_hits"), 15.0);
+        assert_eq!(count("ram_hits"), 3.0);
+        assert_eq!(count("estimated_cycles"), 23585.0 + 75.0 + 105.0);
+
+        let counters = super::counters(&parse_totals("events: Ir\ntotals: 42\n").unwrap());
+        assert_eq!(counters.keys().collect::<Vec<_>>(), ["instructions"]);
+        assert!(parse_totals("events: Ir\n").is_err());
+        assert!(parse_totals("events: Ir\ntotals: x\n").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn runs_under_valgrind() {
+        use std::os::unix::fs::PermissionsExt;
+
+        assert!(
+            !Callgrind::new()
+                .with_valgrind("/nonexistent/valgrind")
+                .is_available()
+        );
+        assert!(matches!(
+            Callgrind::new()
+                .with_valgrind("/nonexistent/valgrind")
+                .run("prove", || {}),
+            Err(CallgrindError::Unavailable)
+        ));
+
+        // Stands in for Valgrind by writing the output file it would.
+        let dir = std::env::temp_dir().join(format!("zkbench-callgrind-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let valgrind = dir.join("valgrind");
+        fs::write(
+            &valgrind,
+            "#!/bin/sh\n\
+             [ \"$1\" = --version ] && { echo valgrind-3.22.0; exit 0; }\n\
+             [ \"$ZKBENCH_CALLGRIND_BENCH\" = prove/small ] || exit 3\n\
+             for arg; do case $arg in --callgrind-out-file=*) out=${arg#*=};; esac; done\n\
+             printf 'events: Ir\\ntotals: 42\\n' > \"$out\"\n",
+        )
+        .unwrap();
+        fs::set_permissions(&valgrind, fs::Permissions::from_mode(0o755)).unwrap();
+        let callgrind = Callgrind::new().with_valgrind(&valgrind).with_out_dir(&dir);
+        assert!(callgrind.is_available());
+        let result = callgrind.run("prove/small", || {}).unwrap().unwrap();
+        assert_eq!(result.iterations, 1);
+        assert_eq!(result.counters["instructions"].value, 42.0);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        assert!(matches!(
+            callgrind.run("other", || {}),
+            Err(CallgrindError::Failed { .. })
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
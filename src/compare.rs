@@ -0,0 +1,1097 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Comparison of a candidate report against a baseline.
+//!
+//! [`compare`] pairs benchmarks by name, computes the relative change of
+//! every metric recorded on both sides, classifies each change as a
+//! regression, improvement or neither, and derives an overall verdict.
+//! Renderers, exporters and CI gates build on the resulting
+//! [`Comparison`].
+//!
+//! Benchmark timings are noisy, so a percentage threshold alone flags
+//! regressions that are nothing but run-to-run variance. Whenever the
+//! reports carry enough data, a change is instead only classified when it
+//! is statistically significant; the [`DecisionRule`] that was applied is
+//! recorded with every delta.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::guardrail::{PlatformMismatch, PlatformPolicy};
+use crate::matching::pair_names;
+use crate::noise::{NoiseProfile, relative_spread};
+use crate::normalize::{Normalization, NormalizationNote, normalize_report};
+use crate::scaling::{ScalingComparison, compare_scaling};
+use crate::schema::{BenchmarkReport, BenchmarkResult, MetricKind, MetricValue};
+use crate::speedup::SPEEDUP_UNIT;
+use crate::statistics::welch_t_test;
+use crate::units::to_base;
+
+/// Options controlling [`compare`].
+///
+/// Missing fields deserialize to their defaults, so this is also the
+/// shape of the `[compare]` table of
+/// [`ComparisonConfig`](crate::ComparisonConfig).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompareOptions {
+    /// Relative change (a fraction: `0.05` is 5%) a metric must move in
+    /// the worse direction to count as a regression, and in the better
+    /// direction to count as an improvement. With samples or confidence
+    /// bounds the change must also be statistically significant.
+    pub threshold: f64,
+    /// Per-metric thresholds replacing `threshold`, keyed by
+    /// [`MetricKind`] or counter name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub metrics: BTreeMap<String, f64>,
+    /// Per-benchmark overrides, taking precedence over `metrics`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub benchmarks: Vec<BenchmarkThreshold>,
+    /// Significance level of the t-test applied to latency samples.
+    pub alpha: f64,
+    /// Whether benchmarks missing from the candidate fail the verdict.
+    pub fail_on_removed: bool,
+    /// Hardware normalization for reports from different machines.
+    pub normalization: Normalization,
+    /// Coefficient of variation above which a metric is too noisy to
+    /// judge: its changes are classified
+    /// [`Inconclusive`](Classification::Inconclusive). `None` never gives
+    /// up.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cv: Option<f64>,
+    /// Multiple of the coefficient of variation a threshold is widened
+    /// to: a benchmark with 4% noise and a factor of 2 needs an 8% change
+    /// to be flagged, even with a 5% threshold. `0` disables widening.
+    pub noise_factor: f64,
+    /// Run-to-run noise from past reports. Preferred over the noise seen
+    /// within the two compared reports, which cannot reveal drift between
+    /// runs.
+    #[serde(skip)]
+    pub noise: NoiseProfile,
+    /// Renamed benchmarks, from baseline name to candidate name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+    /// Pair benchmarks whose
+    /// [`normalize_benchmark_name`](crate::normalize_benchmark_name)s are
+    /// equal.
+    pub normalize_names: bool,
+    /// Pair remaining benchmarks whose normalized names have at least this
+    /// similarity (`0.0..=1.0`, by edit distance). `None` disables fuzzy
+    /// matching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuzzy_match: Option<f64>,
+    /// Change of a sweep's fitted exponent beyond which
+    /// [`compare_scaling`](crate::compare_scaling) reports a complexity
+    /// change rather than a constant-factor one.
+    pub scaling_tolerance: f64,
+    /// Platform fields the two reports must share.
+    pub platform: PlatformPolicy,
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 0.05,
+            metrics: BTreeMap::new(),
+            benchmarks: Vec::new(),
+            alpha: 0.05,
+            fail_on_removed: false,
+            normalization: Normalization::None,
+            max_cv: None,
+            noise_factor: 2.0,
+            noise: NoiseProfile::default(),
+            aliases: BTreeMap::new(),
+            normalize_names: false,
+            fuzzy_match: None,
+            scaling_tolerance: 0.1,
+            platform: PlatformPolicy::default(),
+        }
+    }
+}
+
+impl CompareOptions {
+    /// Threshold applying to a metric of a benchmark.
+    ///
+    /// The most specific setting wins: a metric threshold of a matching
+    /// benchmark override, then the override's own threshold, then the
+    /// per-metric threshold, then the global one. When several overrides
+    /// match, the last one listed wins.
+    pub fn threshold_for(&self, benchmark: &str, metric: &str) -> f64 {
+        let matching: Vec<&BenchmarkThreshold> = self
+            .benchmarks
+            .iter()
+            .filter(|o| glob_match(&o.pattern, benchmark))
+            .collect();
+        matching
+            .iter()
+            .rev()
+            .find_map(|o| o.metrics.get(metric).copied())
+            .or_else(|| matching.iter().rev().find_map(|o| o.threshold))
+            .or_else(|| self.metrics.get(metric).copied())
+            .unwrap_or(self.threshold)
+    }
+}
+
+/// Thresholds for the benchmarks whose names match a glob pattern.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkThreshold {
+    /// Glob over benchmark names: `*` matches any run of characters
+    /// (including `/`), `?` a single character.
+    pub pattern: String,
+    /// Threshold for every metric of the matching benchmarks.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub threshold: Option<f64>,
+    /// Per-metric thresholds of the matching benchmarks.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub metrics: BTreeMap<String, f64>,
+}
+
+impl BenchmarkThreshold {
+    /// Creates an override setting one threshold for every metric.
+    pub fn new(pattern: &str, threshold: f64) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            threshold: Some(threshold),
+            metrics: BTreeMap::new(),
+        }
+    }
+}
+
+/// Matches `text` against a glob of `*` and `?` wildcards.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let (pattern, text): (Vec<char>, Vec<char>) =
+        (pattern.chars().collect(), text.chars().collect());
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text position it was tried at.
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, tried)) => {
+                    backtrack = Some((star, tried + 1));
+                    p = star + 1;
+                    t = tried + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// How a metric moved between the two reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Classification {
+    Regression,
+    Improvement,
+    Unchanged,
+    /// The metric is too noisy for a verdict (see
+    /// [`CompareOptions::max_cv`]).
+    Inconclusive,
+}
+
+/// Rule used to decide whether a change is significant, with the
+/// evidence it was based on.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum DecisionRule {
+    /// Welch's t-test on the raw samples of both sides; significant when
+    /// `p_value < alpha`.
+    WelchTTest { p_value: f64, alpha: f64 },
+    /// Confidence intervals of both sides; significant when they do not
+    /// overlap.
+    ConfidenceIntervals { overlap: bool },
+    /// Plain percentage threshold, used when no statistics are available;
+    /// significant when `|change| > threshold`.
+    Threshold { threshold: f64 },
+}
+
+impl DecisionRule {
+    /// Whether the rule judged the change significant.
+    pub fn is_significant(&self) -> bool {
+        match *self {
+            Self::WelchTTest { p_value, alpha } => p_value < alpha,
+            Self::ConfidenceIntervals { overlap } => !overlap,
+            // The threshold is applied to the change by `metric_delta`.
+            Self::Threshold { .. } => true,
+        }
+    }
+}
+
+impl std::fmt::Display for DecisionRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WelchTTest { p_value, alpha } => {
+                write!(f, "Welch's t-test p = {:.3} (alpha {})", p_value, alpha)
+            }
+            Self::ConfidenceIntervals { overlap: true } => {
+                write!(f, "confidence intervals overlap")
+            }
+            Self::ConfidenceIntervals { overlap: false } => {
+                write!(f, "confidence intervals disjoint")
+            }
+            Self::Threshold { threshold } => write!(f, "threshold {:.1}%", threshold * 100.0),
+        }
+    }
+}
+
+/// Change of one metric of one benchmark.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricDelta {
+    /// Metric name: a [`MetricKind`] name or a counter name.
+    pub metric: String,
+    pub baseline: MetricValue,
+    pub candidate: MetricValue,
+    /// Relative change, `candidate / baseline - 1`, computed after
+    /// converting both sides to a common unit.
+    pub change: f64,
+    /// Whether a larger value is an improvement for this metric.
+    pub higher_is_better: bool,
+    /// Coefficient of variation the decision accounted for: historical
+    /// when known, otherwise the larger within-run spread of both sides.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cv: Option<f64>,
+    /// How significance was decided.
+    pub rule: DecisionRule,
+    pub classification: Classification,
+}
+
+impl MetricDelta {
+    /// Relative change oriented so that positive is always worse.
+    pub fn regression_amount(&self) -> f64 {
+        if self.higher_is_better {
+            -self.change
+        } else {
+            self.change
+        }
+    }
+}
+
+/// All metric changes of a benchmark present in both reports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkComparison {
+    /// Name in the candidate.
+    pub name: String,
+    /// Name in the baseline, when the benchmark was paired across a
+    /// rename.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub renamed_from: Option<String>,
+    pub deltas: Vec<MetricDelta>,
+}
+
+impl BenchmarkComparison {
+    /// Returns the delta of a metric, if it was recorded on both sides.
+    pub fn delta(&self, metric: &str) -> Option<&MetricDelta> {
+        self.deltas.iter().find(|d| d.metric == metric)
+    }
+}
+
+/// Overall outcome of a comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// The platforms differ in a field [`CompareOptions::platform`]
+    /// requires to match and its action is to
+    /// [refuse](crate::PlatformAction::Refuse), so no change was classified.
+    /// Not a pass.
+    Refused,
+}
+
+/// Result of [`compare`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comparison {
+    /// Benchmarks present in both reports, sorted by name.
+    pub benchmarks: Vec<BenchmarkComparison>,
+    /// Benchmarks only in the candidate, sorted.
+    pub added: Vec<String>,
+    /// Benchmarks only in the baseline, sorted.
+    pub removed: Vec<String>,
+    pub verdict: Verdict,
+    /// Normalization requested by [`CompareOptions::normalization`]; when
+    /// present, the deltas are not raw measurements.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub normalization: Option<NormalizationNote>,
+    /// Platform differences violating [`CompareOptions::platform`].
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub platform_mismatch: Option<PlatformMismatch>,
+    /// Fitted scaling curves of the sweeps in both reports.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub scaling: Vec<ScalingComparison>,
+}
+
+impl Comparison {
+    /// Iterates over all metric deltas as `(benchmark, delta)` pairs.
+    pub fn deltas(&self) -> impl Iterator<Item = (&str, &MetricDelta)> {
+        self.benchmarks
+            .iter()
+            .flat_map(|b| b.deltas.iter().map(move |d| (b.name.as_str(), d)))
+    }
+
+    /// Iterates over the deltas classified as regressions.
+    pub fn regressions(&self) -> impl Iterator<Item = (&str, &MetricDelta)> {
+        self.deltas()
+            .filter(|(_, d)| d.classification == Classification::Regression)
+    }
+
+    /// Iterates over the deltas classified as improvements.
+    pub fn improvements(&self) -> impl Iterator<Item = (&str, &MetricDelta)> {
+        self.deltas()
+            .filter(|(_, d)| d.classification == Classification::Improvement)
+    }
+}
+
+/// Compares a candidate report against a baseline.
+///
+/// Benchmarks are paired by name, and across renames through
+/// [`CompareOptions::aliases`], [`CompareOptions::normalize_names`] and
+/// [`CompareOptions::fuzzy_match`]. Every metric recorded for a benchmark
+/// in both reports, including
+/// counters, yields a [`MetricDelta`]. Standard metrics use
+/// [`MetricKind::higher_is_better`]; counters are treated as rates
+/// (higher is better) when their unit ends in `/s` or is the speedup unit
+/// `x`, and as costs otherwise.
+///
+/// Significance is decided by the first applicable [`DecisionRule`]:
+/// Welch's t-test when both latencies come with at least two samples,
+/// non-overlapping confidence intervals when both sides have bounds, and
+/// otherwise the threshold alone. A significant change beyond
+/// [`CompareOptions::threshold_for`] the benchmark and metric, widened to
+/// [`CompareOptions::noise_factor`] times the metric's coefficient of
+/// variation, is a regression in the worse direction and an improvement
+/// in the better one; metrics noisier than [`CompareOptions::max_cv`] are
+/// inconclusive, and so is every change when the platforms differ in a
+/// field [`CompareOptions::platform`] requires to match and its action is
+/// to refuse, in which case the verdict is [`Verdict::Refused`]. Sweeps
+/// in both reports additionally get their scaling curves compared by
+/// [`compare_scaling`]. Otherwise the verdict fails when there is at
+/// least one regression, or a removed benchmark with
+/// [`CompareOptions::fail_on_removed`].
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{
+///     BenchmarkReport, BenchmarkResult, CompareOptions, Metadata, MetricValue, Verdict, compare,
+/// };
+///
+/// let report = |ms: f64| BenchmarkReport {
+///     metadata: Metadata::create("my-impl", "0.1.0"),
+///     benchmarks: [(
+///         "prove".to_string(),
+///         BenchmarkResult {
+///             latency: Some(MetricValue::new(ms, "ms")),
+///             ..Default::default()
+///         },
+///     )]
+///     .into_iter()
+///     .collect(),
+/// };
+/// let comparison = compare(&report(100.0), &report(120.0), &CompareOptions::default());
+/// assert_eq!(comparison.verdict, Verdict::Fail);
+/// assert_eq!(comparison.regressions().count(), 1);
+/// ```
+pub fn compare(
+    baseline: &BenchmarkReport,
+    candidate: &BenchmarkReport,
+    opts: &CompareOptions,
+) -> Comparison {
+    let note = (opts.normalization != Normalization::None)
+        .then(|| NormalizationNote::new(opts.normalization, baseline, candidate));
+    let (baseline, candidate) = match &note {
+        Some(NormalizationNote {
+            baseline_factor: Some(b),
+            candidate_factor: Some(c),
+            ..
+        }) => (
+            Cow::Owned(normalize_report(baseline, *b)),
+            Cow::Owned(normalize_report(candidate, *c)),
+        ),
+        _ => (Cow::Borrowed(baseline), Cow::Borrowed(candidate)),
+    };
+    let mismatch = opts.platform.check(
+        &baseline.metadata.platform,
+        &candidate.metadata.platform,
+        note.as_ref().filter(|n| n.applied).map(|n| n.mode),
+    );
+    let refused = mismatch.as_ref().is_some_and(|m| m.refused);
+    let judge = note
+        .as_ref()
+        .is_none_or(|n| n.applied || n.mode != Normalization::Fingerprint);
+
+    let baseline_names: Vec<&str> = baseline.benchmarks.keys().map(String::as_str).collect();
+    let candidate_names: Vec<&str> = candidate.benchmarks.keys().map(String::as_str).collect();
+    let pairs = pair_names(
+        &baseline_names,
+        &candidate_names,
+        &opts.aliases,
+        opts.normalize_names,
+        opts.fuzzy_match,
+    );
+
+    let mut benchmarks = Vec::new();
+    let mut added = Vec::new();
+    for (name, result) in candidate.sorted_benchmarks() {
+        let Some(&baseline_name) = pairs.get(name.as_str()) else {
+            added.push(name.clone());
+            continue;
+        };
+        let before = &baseline.benchmarks[baseline_name];
+        let deltas = result
+            .metrics()
+            .into_iter()
+            .filter_map(|(metric, after)| {
+                let (_, prior) = before.metrics().into_iter().find(|(m, _)| *m == metric)?;
+                let cv = opts.noise.get(name, metric).or_else(|| {
+                    [
+                        relative_spread(before, metric, prior),
+                        relative_spread(result, metric, after),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .reduce(f64::max)
+                });
+                let threshold = opts
+                    .threshold_for(name, metric)
+                    .max(opts.noise_factor * cv.unwrap_or(0.0));
+                let mut delta = metric_delta(
+                    metric,
+                    (before, prior),
+                    (result, after),
+                    threshold,
+                    opts.alpha,
+                )?;
+                delta.cv = cv;
+                if opts.max_cv.zip(cv).is_some_and(|(max, cv)| cv > max) {
+                    delta.classification = Classification::Inconclusive;
+                }
+                if refused {
+                    delta.classification = Classification::Inconclusive;
+                }
+                if !judge {
+                    delta.classification = Classification::Unchanged;
+                }
+                Some(delta)
+            })
+            .collect();
+        benchmarks.push(BenchmarkComparison {
+            name: name.clone(),
+            renamed_from: (baseline_name != name).then(|| baseline_name.to_string()),
+            deltas,
+        });
+    }
+    let paired: Vec<&str> = pairs.values().copied().collect();
+    let mut removed: Vec<String> = baseline_names
+        .iter()
+        .filter(|name| !paired.contains(name))
+        .map(|name| name.to_string())
+        .collect();
+    removed.sort();
+
+    let mut comparison = Comparison {
+        benchmarks,
+        added,
+        removed,
+        verdict: Verdict::Pass,
+        scaling: compare_scaling(&baseline, &candidate, opts),
+        normalization: note,
+        platform_mismatch: mismatch,
+    };
+    if refused {
+        comparison.verdict = Verdict::Refused;
+    } else if comparison.regressions().next().is_some()
+        || (opts.fail_on_removed && !comparison.removed.is_empty())
+    {
+        comparison.verdict = Verdict::Fail;
+    }
+    comparison
+}
+
+/// Whether a larger value of the named metric is an improvement: rates
+/// (`/s`), efficiencies (`/J`), speedups (`x`) and clock frequencies
+/// (`Hz`) are, other counters are costs.
+pub(crate) fn higher_is_better(metric: &str, unit: &str) -> bool {
+    match metric.parse::<MetricKind>() {
+        Ok(kind) => kind.higher_is_better(),
+        Err(_) => {
+            unit.ends_with("/s")
+                || unit.ends_with("/J")
+                || unit == SPEEDUP_UNIT
+                || unit.ends_with("Hz")
+        }
+    }
+}
+
+fn metric_delta(
+    metric: &str,
+    (baseline_result, baseline): (&BenchmarkResult, &MetricValue),
+    (candidate_result, candidate): (&BenchmarkResult, &MetricValue),
+    threshold: f64,
+    alpha: f64,
+) -> Option<MetricDelta> {
+    let before = to_base(baseline.value, &baseline.unit);
+    let after = to_base(candidate.value, &candidate.unit);
+    if before == 0.0 || !before.is_finite() || !after.is_finite() {
+        return None;
+    }
+    let samples = |result: &BenchmarkResult, metric: &MetricValue| -> Vec<f64> {
+        result
+            .samples
+            .iter()
+            .map(|s| to_base(*s, &metric.unit))
+            .collect()
+    };
+    let t_test = (metric == MetricKind::Latency.as_str())
+        .then(|| {
+            welch_t_test(
+                &samples(baseline_result, baseline),
+                &samples(candidate_result, candidate),
+            )
+        })
+        .flatten();
+    let rule = match (t_test, intervals(baseline), intervals(candidate)) {
+        (Some(p_value), _, _) => DecisionRule::WelchTTest { p_value, alpha },
+        (None, Some((b_lo, b_hi)), Some((c_lo, c_hi))) => DecisionRule::ConfidenceIntervals {
+            overlap: c_lo <= b_hi && b_lo <= c_hi,
+        },
+        _ => DecisionRule::Threshold { threshold },
+    };
+
+    let change = after / before - 1.0;
+    let higher_is_better = higher_is_better(metric, &candidate.unit);
+    let worse = if higher_is_better { -change } else { change };
+    // Significance alone is not enough: with many samples or tight
+    // intervals even a 0.1% change is significant.
+    let classification = if !rule.is_significant() {
+        Classification::Unchanged
+    } else if worse > threshold {
+        Classification::Regression
+    } else if worse < -threshold {
+        Classification::Improvement
+    } else {
+        Classification::Unchanged
+    };
+    Some(MetricDelta {
+        metric: metric.to_string(),
+        baseline: baseline.clone(),
+        candidate: candidate.clone(),
+        change,
+        higher_is_better,
+        cv: None,
+        rule,
+        classification,
+    })
+}
+
+/// Confidence bounds of a metric in its base unit.
+fn intervals(metric: &MetricValue) -> Option<(f64, f64)> {
+    Some((
+        to_base(metric.lower_value?, &metric.unit),
+        to_base(metric.upper_value?, &metric.unit),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::guardrail::{PlatformAction, PlatformField};
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+    use std::collections::HashMap;
+
+    fn latency(value: f64, unit: &str) -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::new(value, unit)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn classifies_by_threshold_and_direction() {
+        let baseline = ReportBuilder::new()
+            .results([
+                ("slower", latency(100.0, "ms")),
+                ("faster", latency(100.0, "ms")),
+                ("noise", latency(100.0, "ms")),
+                (
+                    "rate",
+                    BenchmarkResult {
+                        throughput: Some(MetricValue::new(1000.0, "ops/s")),
+                        ..Default::default()
+                    },
+                ),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .results([
+                ("slower", latency(0.12, "s")),
+                ("faster", latency(80.0, "ms")),
+                ("noise", latency(103.0, "ms")),
+                (
+                    "rate",
+                    BenchmarkResult {
+                        throughput: Some(MetricValue::new(800.0, "ops/s")),
+                        ..Default::default()
+                    },
+                ),
+            ])
+            .build();
+        let comparison = compare(&baseline, &candidate, &CompareOptions::default());
+        let class = |name: &str, metric: &str| {
+            comparison
+                .benchmarks
+                .iter()
+                .find(|b| b.name == name)
+                .and_then(|b| b.delta(metric))
+                .map(|d| d.classification)
+        };
+        assert_eq!(class("slower", "latency"), Some(Classification::Regression));
+        assert_eq!(
+            class("faster", "latency"),
+            Some(Classification::Improvement)
+        );
+        assert_eq!(class("noise", "latency"), Some(Classification::Unchanged));
+        assert_eq!(
+            class("rate", "throughput"),
+            Some(Classification::Regression)
+        );
+        let slower = comparison
+            .benchmarks
+            .iter()
+            .find(|b| b.name == "slower")
+            .unwrap();
+        assert!((slower.deltas[0].change - 0.2).abs() < 1e-9);
+        assert_eq!(comparison.verdict, Verdict::Fail);
+        assert_eq!(comparison.regressions().count(), 2);
+        assert_eq!(comparison.improvements().count(), 1);
+    }
+
+    #[test]
+    fn counters_use_unit_direction() {
+        let with_counters =
+            |instructions: f64, bytes_per_s: f64, proofs_per_j: f64, mhz: f64| BenchmarkResult {
+                counters: HashMap::from([
+                    (
+                        "instructions".to_string(),
+                        MetricValue::new(instructions, "count"),
+                    ),
+                    (
+                        "bytes_throughput".to_string(),
+                        MetricValue::new(bytes_per_s, "B/s"),
+                    ),
+                    (
+                        "gpu_proofs_per_joule".to_string(),
+                        MetricValue::new(proofs_per_j, "proofs/J"),
+                    ),
+                    ("cpu_frequency".to_string(), MetricValue::new(mhz, "MHz")),
+                ]),
+                ..Default::default()
+            };
+        let comparison = compare(
+            &ReportBuilder::new()
+                .results([("hash", with_counters(1000.0, 100.0, 2.0, 3000.0))])
+                .build(),
+            &ReportBuilder::new()
+                .results([("hash", with_counters(900.0, 150.0, 3.0, 3600.0))])
+                .build(),
+            &CompareOptions::default(),
+        );
+        let hash = &comparison.benchmarks[0];
+        assert_eq!(
+            hash.delta("instructions").unwrap().classification,
+            Classification::Improvement
+        );
+        assert_eq!(
+            hash.delta("bytes_throughput").unwrap().classification,
+            Classification::Improvement
+        );
+        assert_eq!(
+            hash.delta("gpu_proofs_per_joule").unwrap().classification,
+            Classification::Improvement
+        );
+        assert_eq!(
+            hash.delta("cpu_frequency").unwrap().classification,
+            Classification::Improvement
+        );
+        assert_eq!(comparison.verdict, Verdict::Pass);
+    }
+
+    #[test]
+    fn added_and_removed() {
+        let baseline = ReportBuilder::new()
+            .results([("kept", latency(1.0, "ms")), ("gone", latency(1.0, "ms"))])
+            .build();
+        let candidate = ReportBuilder::new()
+            .results([("kept", latency(1.0, "ms")), ("new", latency(1.0, "ms"))])
+            .build();
+        let comparison = compare(&baseline, &candidate, &CompareOptions::default());
+        assert_eq!(comparison.added, ["new"]);
+        assert_eq!(comparison.removed, ["gone"]);
+        assert_eq!(comparison.verdict, Verdict::Pass);
+
+        let strict = CompareOptions {
+            fail_on_removed: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            compare(&baseline, &candidate, &strict).verdict,
+            Verdict::Fail
+        );
+    }
+
+    #[test]
+    fn comparison_serializes() {
+        let comparison = compare(
+            &ReportBuilder::new()
+                .results([("a", latency(1.0, "ms"))])
+                .build(),
+            &ReportBuilder::new()
+                .results([("a", latency(2.0, "ms"))])
+                .build(),
+            &CompareOptions::default(),
+        );
+        let json = serde_json::to_value(&comparison).unwrap();
+        assert_eq!(json["verdict"], "fail");
+        assert_eq!(
+            json["benchmarks"][0]["deltas"][0]["classification"],
+            "regression"
+        );
+        assert_eq!(json["benchmarks"][0]["deltas"][0]["change"], 1.0);
+    }
+
+    #[test]
+    fn confidence_intervals_override_threshold() {
+        let with_bounds = |value: f64, lower: f64, upper: f64| BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(value, "ms", lower, upper)),
+            ..Default::default()
+        };
+        let baseline = ReportBuilder::new()
+            .results([
+                ("noisy", with_bounds(100.0, 80.0, 120.0)),
+                ("shifted", with_bounds(100.0, 99.0, 101.0)),
+                ("tight", with_bounds(100.0, 99.0, 101.0)),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .results([
+                ("noisy", with_bounds(115.0, 95.0, 135.0)),
+                ("shifted", with_bounds(110.0, 109.0, 111.0)),
+                ("tight", with_bounds(102.0, 101.5, 102.5)),
+            ])
+            .build();
+        let comparison = compare(&baseline, &candidate, &CompareOptions::default());
+        let noisy = comparison.benchmarks[0].delta("latency").unwrap();
+        assert_eq!(
+            noisy.rule,
+            DecisionRule::ConfidenceIntervals { overlap: true }
+        );
+        assert_eq!(noisy.classification, Classification::Unchanged);
+        let shifted = comparison.benchmarks[1].delta("latency").unwrap();
+        assert_eq!(
+            shifted.rule,
+            DecisionRule::ConfidenceIntervals { overlap: false }
+        );
+        assert_eq!(shifted.classification, Classification::Regression);
+        // Disjoint intervals do not make a 2% change below the threshold
+        // a regression.
+        let tight = comparison.benchmarks[2].delta("latency").unwrap();
+        assert_eq!(
+            tight.rule,
+            DecisionRule::ConfidenceIntervals { overlap: false }
+        );
+        assert_eq!(tight.classification, Classification::Unchanged);
+    }
+
+    #[test]
+    fn significant_changes_below_threshold_are_unchanged() {
+        let sampled = |base: f64| BenchmarkResult {
+            latency: Some(MetricValue::new(base, "ms")),
+            samples: (0..50).map(|i| base + (i % 5) as f64 * 0.01).collect(),
+            ..Default::default()
+        };
+        let comparison = compare(
+            &ReportBuilder::new()
+                .results([("prove", sampled(100.0))])
+                .build(),
+            &ReportBuilder::new()
+                .results([("prove", sampled(100.5))])
+                .build(),
+            &CompareOptions {
+                threshold: 0.05,
+                ..Default::default()
+            },
+        );
+        let delta = comparison.benchmarks[0].delta("latency").unwrap();
+        assert!(matches!(delta.rule, DecisionRule::WelchTTest { p_value, .. } if p_value < 1e-6));
+        assert_eq!(delta.classification, Classification::Unchanged);
+    }
+
+    #[test]
+    fn samples_use_welch_t_test() {
+        let sampled = |samples: Vec<f64>| BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(
+                samples.iter().sum::<f64>() / samples.len() as f64,
+                "us",
+                0.0,
+                1e9,
+            )),
+            samples,
+            ..Default::default()
+        };
+        let baseline = ReportBuilder::new()
+            .results([
+                ("shifted", sampled(vec![10.0, 10.2, 9.9, 10.1, 10.0, 9.8])),
+                (
+                    "overlapping",
+                    sampled(vec![10.0, 12.0, 8.0, 11.0, 9.0, 10.0]),
+                ),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .results([
+                ("shifted", sampled(vec![11.0, 11.1, 10.9, 11.2, 11.0, 10.8])),
+                (
+                    "overlapping",
+                    sampled(vec![10.5, 12.5, 8.5, 11.5, 9.5, 10.5]),
+                ),
+            ])
+            .build();
+        let comparison = compare(&baseline, &candidate, &CompareOptions::default());
+        let overlapping = comparison.benchmarks[0].delta("latency").unwrap();
+        assert!(
+            matches!(overlapping.rule, DecisionRule::WelchTTest { p_value, .. } if p_value > 0.05)
+        );
+        assert_eq!(overlapping.classification, Classification::Unchanged);
+        let shifted = comparison.benchmarks[1].delta("latency").unwrap();
+        assert!(matches!(shifted.rule, DecisionRule::WelchTTest { p_value, .. } if p_value < 0.05));
+        assert_eq!(shifted.classification, Classification::Regression);
+
+        let json = serde_json::to_value(shifted).unwrap();
+        assert_eq!(json["rule"]["rule"], "welch_t_test");
+    }
+
+    #[test]
+    fn thresholds_per_metric_and_benchmark() {
+        let opts = CompareOptions {
+            metrics: [("latency".to_string(), 0.02), ("memory".to_string(), 0.1)].into(),
+            benchmarks: vec![
+                BenchmarkThreshold::new("msm/*", 0.15),
+                BenchmarkThreshold {
+                    pattern: "msm/gpu?".to_string(),
+                    threshold: None,
+                    metrics: [("memory".to_string(), 0.3)].into(),
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(opts.threshold_for("fft", "latency"), 0.02);
+        assert_eq!(opts.threshold_for("fft", "memory"), 0.1);
+        assert_eq!(opts.threshold_for("fft", "throughput"), 0.05);
+        assert_eq!(opts.threshold_for("msm/cpu", "latency"), 0.15);
+        assert_eq!(opts.threshold_for("msm/gpu1", "memory"), 0.3);
+        assert_eq!(opts.threshold_for("msm/gpu1", "latency"), 0.15);
+
+        let baseline = ReportBuilder::new()
+            .results([
+                ("fft", latency(100.0, "ms")),
+                ("msm/cpu", latency(100.0, "ms")),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .results([
+                ("fft", latency(103.0, "ms")),
+                ("msm/cpu", latency(110.0, "ms")),
+            ])
+            .build();
+        let comparison = compare(&baseline, &candidate, &opts);
+        let fft = comparison.benchmarks[0].delta("latency").unwrap();
+        assert_eq!(fft.rule, DecisionRule::Threshold { threshold: 0.02 });
+        assert_eq!(fft.classification, Classification::Regression);
+        let msm = comparison.benchmarks[1].delta("latency").unwrap();
+        assert_eq!(msm.classification, Classification::Unchanged);
+    }
+
+    #[test]
+    fn glob_matching() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("msm/*", "msm/bn254/2^20"));
+        assert!(glob_match("*/prove", "keccak/prove"));
+        assert!(glob_match("a*b*c", "aXbYbZc"));
+        assert!(glob_match("fft-??", "fft-16"));
+        assert!(!glob_match("fft-??", "fft-1"));
+        assert!(!glob_match("msm/*", "fft/msm/x"));
+        assert!(!glob_match("prove", "prove2"));
+    }
+
+    #[test]
+    fn normalizes_across_machines() {
+        let on = |cores: usize, ms: f64| {
+            let mut report = ReportBuilder::new()
+                .results([("prove", latency(ms, "ms"))])
+                .build();
+            report.metadata.platform.cpu_count = cores;
+            report
+        };
+        // Twice the cores, but only 1.5x faster: worse per core.
+        let (baseline, candidate) = (on(8, 100.0), on(16, 66.7));
+        assert_eq!(
+            compare(&baseline, &candidate, &CompareOptions::default()).verdict,
+            Verdict::Pass
+        );
+        let opts = CompareOptions {
+            normalization: Normalization::PerCore,
+            ..Default::default()
+        };
+        let comparison = compare(&baseline, &candidate, &opts);
+        assert_eq!(comparison.verdict, Verdict::Fail);
+        let delta = comparison.benchmarks[0].delta("latency").unwrap();
+        assert_eq!(delta.baseline.value, 800.0);
+        assert!((delta.change - 0.334).abs() < 1e-9);
+        assert!(comparison.normalization.as_ref().unwrap().applied);
+
+        let bucketed = CompareOptions {
+            normalization: Normalization::Fingerprint,
+            ..Default::default()
+        };
+        let comparison = compare(&on(8, 100.0), &on(16, 200.0), &bucketed);
+        assert_eq!(comparison.verdict, Verdict::Pass);
+        assert_eq!(comparison.regressions().count(), 0);
+        assert!(!comparison.normalization.unwrap().applied);
+        let same = compare(&on(8, 100.0), &on(8, 200.0), &bucketed);
+        assert_eq!(same.verdict, Verdict::Fail);
+    }
+
+    #[test]
+    fn noisy_benchmarks_widen_or_give_up() {
+        let baseline = ReportBuilder::new()
+            .results([
+                ("steady", latency(100.0, "ms")),
+                ("noisy", latency(100.0, "ms")),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .results([
+                ("steady", latency(108.0, "ms")),
+                ("noisy", latency(108.0, "ms")),
+            ])
+            .build();
+        let history = [(95.0, 100.0), (100.0, 100.0), (105.0, 100.0)].map(|(noisy, steady)| {
+            ReportBuilder::new()
+                .results([
+                    ("noisy", latency(noisy, "ms")),
+                    ("steady", latency(steady, "ms")),
+                ])
+                .build()
+        });
+        let opts = CompareOptions {
+            noise: NoiseProfile::from_history(&history, 3),
+            ..Default::default()
+        };
+        let comparison = compare(&baseline, &candidate, &opts);
+        let noisy = comparison.benchmarks[0].delta("latency").unwrap();
+        assert!((noisy.cv.unwrap() - 0.05).abs() < 1e-12);
+        assert_eq!(noisy.rule, DecisionRule::Threshold { threshold: 0.1 });
+        assert_eq!(noisy.classification, Classification::Unchanged);
+        let steady = comparison.benchmarks[1].delta("latency").unwrap();
+        assert_eq!(steady.classification, Classification::Regression);
+
+        let strict = CompareOptions {
+            max_cv: Some(0.03),
+            ..opts
+        };
+        let comparison = compare(&baseline, &candidate, &strict);
+        let noisy = comparison.benchmarks[0].delta("latency").unwrap();
+        assert_eq!(noisy.classification, Classification::Inconclusive);
+        assert_eq!(comparison.regressions().count(), 1);
+    }
+
+    #[test]
+    fn pairs_renamed_benchmarks() {
+        let baseline = ReportBuilder::new()
+            .results([
+                ("msm_g1_16", latency(100.0, "ms")),
+                ("old", latency(1.0, "ms")),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .results([
+                ("msm/g1/2^16", latency(100.0, "ms")),
+                ("new", latency(1.0, "ms")),
+            ])
+            .build();
+        let comparison = compare(&baseline, &candidate, &CompareOptions::default());
+        assert_eq!(comparison.removed.len(), 2);
+
+        let opts = CompareOptions {
+            aliases: BTreeMap::from([("old".to_string(), "new".to_string())]),
+            normalize_names: true,
+            ..Default::default()
+        };
+        let comparison = compare(&baseline, &candidate, &opts);
+        assert!(comparison.added.is_empty() && comparison.removed.is_empty());
+        let msm = &comparison.benchmarks[0];
+        assert_eq!(msm.name, "msm/g1/2^16");
+        assert_eq!(msm.renamed_from.as_deref(), Some("msm_g1_16"));
+        assert_eq!(
+            comparison.benchmarks[1].renamed_from.as_deref(),
+            Some("old")
+        );
+    }
+
+    #[test]
+    fn guards_against_platform_mismatch() {
+        let on = |cores: usize, ms: f64| {
+            let mut report = ReportBuilder::new()
+                .results([("prove", latency(ms, "ms"))])
+                .build();
+            report.metadata.platform.cpu_count = cores;
+            report
+        };
+        let comparison = compare(&on(64, 100.0), &on(8, 200.0), &CompareOptions::default());
+        assert_eq!(comparison.verdict, Verdict::Fail);
+        let mismatch = comparison.platform_mismatch.unwrap();
+        assert_eq!(mismatch.differences[0].field, PlatformField::CpuCount);
+        assert!(!mismatch.refused);
+
+        let refuse = CompareOptions {
+            platform: PlatformPolicy {
+                action: PlatformAction::Refuse,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let comparison = compare(&on(64, 100.0), &on(8, 200.0), &refuse);
+        assert_eq!(comparison.verdict, Verdict::Refused);
+        assert_eq!(
+            comparison.benchmarks[0].deltas[0].classification,
+            Classification::Inconclusive
+        );
+        let per_core = CompareOptions {
+            normalization: Normalization::PerCore,
+            ..refuse.clone()
+        };
+        assert!(
+            compare(&on(64, 100.0), &on(8, 200.0), &per_core)
+                .platform_mismatch
+                .is_none()
+        );
+        assert!(
+            compare(&on(8, 100.0), &on(8, 200.0), &refuse)
+                .platform_mismatch
+                .is_none()
+        );
+    }
+}
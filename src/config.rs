@@ -0,0 +1,288 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Project configuration read from `zkbench.toml`.
+//!
+//! ```toml
+//! [compare]
+//! threshold = 0.05
+//! normalize_names = true
+//!
+//! [compare.aliases]
+//! "prove_keccak" = "keccak/prove"
+//!
+//! [compare.metrics]
+//! latency = 0.02
+//! memory = 0.10
+//!
+//! [[compare.benchmarks]]
+//! pattern = "msm/*"
+//! threshold = 0.15
+//! metrics = { memory = 0.25 }
+//!
+//! [gate]
+//! exempt_tags = ["flaky"]
+//!
+//! [baseline]
+//! name = "main"
+//! dir = "bench/baselines"
+//!
+//! [budget]
+//! reference = "v0.3.0"
+//! budget = 0.03
+//!
+//! [retention]
+//! keep_last = 50
+//!
+//! [watch]
+//! command = "cargo bench --bench prover"
+//!
+//! [scores.recursion]
+//! default_weight = 0.0
+//! weights = [{ pattern = "recursion/*", weight = 3.0 }]
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::baseline::BaselineConfig;
+use crate::budget::BudgetPolicy;
+use crate::compare::CompareOptions;
+use crate::gate::GatePolicy;
+use crate::score::ScoreProfile;
+use crate::store::RetentionPolicy;
+use crate::watch::WatchConfig;
+
+/// Name of the configuration file looked up by [`ComparisonConfig::discover`].
+pub const CONFIG_FILE_NAME: &str = "zkbench.toml";
+
+/// Error returned when loading a [`ComparisonConfig`].
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The file is not valid TOML or does not match the schema.
+    Parse {
+        path: Option<PathBuf>,
+        source: toml::de::Error,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "failed to read {}: {}", path.display(), source),
+            Self::Parse {
+                path: Some(path),
+                source,
+            } => write!(f, "invalid {}: {}", path.display(), source),
+            Self::Parse { path: None, source } => write!(f, "invalid configuration: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Parse { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Comparison settings of a project, so that one global threshold does
+/// not have to fit every metric and benchmark.
+///
+/// Every table is optional; missing settings take the defaults of
+/// [`CompareOptions`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComparisonConfig {
+    /// The `[compare]` table.
+    pub compare: CompareOptions,
+    /// The `[gate]` table.
+    pub gate: GatePolicy,
+    /// The `[baseline]` table: the baseline `zkbench check` compares
+    /// against.
+    pub baseline: BaselineConfig,
+    /// Named score profiles, the `[scores.<name>]` tables.
+    pub scores: BTreeMap<String, ScoreProfile>,
+    /// The `[budget]` table; absent disables budget accounting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetPolicy>,
+    /// The `[retention]` table applied when pruning report stores.
+    pub retention: RetentionPolicy,
+    /// The `[watch]` table used by `zkbench watch`.
+    pub watch: WatchConfig,
+}
+
+impl ComparisonConfig {
+    /// Parses a configuration from TOML.
+    pub fn from_toml_str(text: &str) -> Result<Self, ConfigError> {
+        toml::from_str(text).map_err(|source| ConfigError::Parse { path: None, source })
+    }
+
+    /// Reads a configuration file. A relative baseline directory is
+    /// resolved against the directory of the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let mut config: Self = toml::from_str(&text).map_err(|source| ConfigError::Parse {
+            path: Some(path.to_path_buf()),
+            source,
+        })?;
+        if let (Some(dir), Some(parent)) = (&mut config.baseline.dir, path.parent())
+            && dir.is_relative()
+        {
+            *dir = parent.join(&*dir);
+        }
+        Ok(config)
+    }
+
+    /// Loads the nearest [`CONFIG_FILE_NAME`] in `start` or one of its
+    /// ancestors, or the default configuration if there is none.
+    pub fn discover(start: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        match start
+            .as_ref()
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE_NAME))
+            .find(|path| path.is_file())
+        {
+            Some(path) => Self::load(path),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+[compare]
+threshold = 0.04
+fail_on_removed = true
+fuzzy_match = 0.8
+
+[compare.aliases]
+"prove_keccak" = "keccak/prove"
+
+[compare.metrics]
+latency = 0.02
+memory = 0.10
+
+[compare.platform]
+require = ["cpu_vendor", "arch"]
+action = "refuse"
+
+[[compare.benchmarks]]
+pattern = "msm/*"
+threshold = 0.15
+metrics = { memory = 0.25 }
+
+[gate]
+exempt_tags = ["flaky", "experimental"]
+
+[baseline]
+name = "release"
+
+[watch]
+command = "cargo bench --bench prover"
+
+[budget]
+reference = "v0.3.0"
+max_regression = 0.1
+
+[scores.recursion]
+default_weight = 0.0
+
+[[scores.recursion.weights]]
+pattern = "recursion/*"
+weight = 3.0
+"#;
+
+    #[test]
+    fn parses_thresholds() {
+        let config = ComparisonConfig::from_toml_str(EXAMPLE).unwrap();
+        let opts = &config.compare;
+        assert!(opts.fail_on_removed);
+        assert_eq!(opts.alpha, 0.05);
+        assert_eq!(opts.threshold_for("fft", "throughput"), 0.04);
+        assert_eq!(opts.threshold_for("fft", "latency"), 0.02);
+        assert_eq!(opts.threshold_for("msm/g1", "latency"), 0.15);
+        assert_eq!(opts.threshold_for("msm/g1", "memory"), 0.25);
+        assert_eq!(opts.aliases["prove_keccak"], "keccak/prove");
+        assert_eq!(opts.fuzzy_match, Some(0.8));
+        assert_eq!(opts.platform.action, crate::PlatformAction::Refuse);
+        assert_eq!(opts.platform.require.len(), 2);
+        assert_eq!(config.gate.exempt_tags, ["flaky", "experimental"]);
+        assert_eq!(config.gate.max_violations, 0);
+        assert_eq!(config.baseline.name, "release");
+        assert!(config.baseline.dir.is_none());
+        assert_eq!(
+            config.watch.command.as_deref(),
+            Some("cargo bench --bench prover")
+        );
+        let budget = config.budget.as_ref().unwrap();
+        assert_eq!(budget.reference, "v0.3.0");
+        assert_eq!(budget.budget, 0.03);
+        assert_eq!(budget.max_regression, Some(0.1));
+        let recursion = &config.scores["recursion"];
+        assert_eq!(recursion.weight_for("recursion/verify"), 3.0);
+        assert_eq!(recursion.weight_for("fft"), 0.0);
+    }
+
+    #[test]
+    fn empty_file_is_default() {
+        let config = ComparisonConfig::from_toml_str("").unwrap();
+        assert_eq!(config.compare.threshold, 0.05);
+        assert!(config.compare.benchmarks.is_empty());
+        assert!(config.budget.is_none());
+        assert_eq!(config.baseline.name, "main");
+    }
+
+    #[test]
+    fn rejects_malformed_values() {
+        let err = ComparisonConfig::from_toml_str("[compare]\nthreshold = \"5%\"").unwrap_err();
+        assert!(matches!(err, ConfigError::Parse { path: None, .. }));
+    }
+
+    #[test]
+    fn discovers_file_in_ancestors() {
+        let dir = std::env::temp_dir().join(format!("zkbench-config-{}", std::process::id()));
+        let nested = dir.join("crates/prover");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(
+            ComparisonConfig::discover(&nested)
+                .unwrap()
+                .compare
+                .threshold,
+            0.05
+        );
+        fs::write(dir.join(CONFIG_FILE_NAME), EXAMPLE).unwrap();
+        assert_eq!(
+            ComparisonConfig::discover(&nested)
+                .unwrap()
+                .compare
+                .threshold,
+            0.04
+        );
+
+        let example = EXAMPLE.replace(
+            "name = \"release\"",
+            "name = \"release\"\ndir = \"bench/baselines\"",
+        );
+        fs::write(dir.join(CONFIG_FILE_NAME), example).unwrap();
+        let config = ComparisonConfig::discover(&nested).unwrap();
+        assert_eq!(config.baseline.store().dir(), dir.join("bench/baselines"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
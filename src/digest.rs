@@ -0,0 +1,297 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validated hash digests.
+//!
+//! [`Digest`] holds the 32 bytes every [`HashAlgorithm`] produces.
+//! Parsing accepts hex in either case with an optional `0x` prefix, as
+//! Ethereum tooling writes it, so a truncated or mistyped hash in a
+//! report fails when the report is read rather than as a spurious
+//! mismatch later. Digests are written as 64 lowercase hex digits and
+//! compared in constant time; renderings for people and other ecosystems
+//! use a [`DigestFormat`].
+//!
+//! [`HashAlgorithm`]: crate::HashAlgorithm
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use subtle::ConstantTimeEq;
+
+/// Error returned when parsing a [`Digest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DigestError {
+    /// The digest does not have 64 hex digits; holds the number found.
+    InvalidLength(usize),
+    /// A character is not a hex digit; holds it and its position after
+    /// any `0x` prefix.
+    InvalidCharacter { character: char, index: usize },
+}
+
+impl fmt::Display for DigestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(
+                f,
+                "digest has {} hex digits, expected {}",
+                len,
+                Digest::LEN * 2
+            ),
+            Self::InvalidCharacter { character, index } => write!(
+                f,
+                "invalid character {:?} at position {} of digest",
+                character, index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DigestError {}
+
+/// How a [`Digest`] is written in rendered output.
+///
+/// Reports always store full hex; this only affects what people and
+/// downstream tools see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DigestFormat {
+    /// 64 lowercase hex digits.
+    #[default]
+    Hex,
+    /// The first 12 hex digits, like an abbreviated git commit; enough to
+    /// tell digests apart in a table.
+    Short,
+    /// Base58 with the Bitcoin alphabet, as Solana and IPFS tooling
+    /// write hashes.
+    Base58,
+    /// 64 lowercase hex digits after `0x`, as Ethereum tooling writes
+    /// hashes.
+    #[serde(rename = "0x")]
+    Prefixed,
+}
+
+impl DigestFormat {
+    /// All formats, in declaration order.
+    pub const ALL: [DigestFormat; 4] = [Self::Hex, Self::Short, Self::Base58, Self::Prefixed];
+
+    /// Hex digits kept by [`Short`](Self::Short).
+    pub const SHORT_LEN: usize = 12;
+
+    /// Returns the name used in JSON and configuration files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hex => "hex",
+            Self::Short => "short",
+            Self::Base58 => "base58",
+            Self::Prefixed => "0x",
+        }
+    }
+}
+
+impl fmt::Display for DigestFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DigestFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.as_str() == s)
+            .ok_or_else(|| format!("unknown digest format '{}'", s))
+    }
+}
+
+/// A 32-byte hash digest.
+#[derive(Clone, Copy, Eq)]
+pub struct Digest([u8; Digest::LEN]);
+
+impl Digest {
+    /// Length of a digest in bytes.
+    pub const LEN: usize = 32;
+
+    pub fn from_bytes(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; Self::LEN] {
+        &self.0
+    }
+
+    /// The digest as 64 lowercase hex digits with a `0x` prefix.
+    pub fn to_prefixed_hex(&self) -> String {
+        format!("0x{}", self)
+    }
+
+    /// The digest written in `format`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zkbench::{Digest, DigestFormat};
+    ///
+    /// let digest = Digest::from_bytes([0xab; 32]);
+    /// assert_eq!(digest.format(DigestFormat::Short), "abababababab");
+    /// assert_eq!(digest.format(DigestFormat::Hex), "ab".repeat(32));
+    /// ```
+    pub fn format(&self, format: DigestFormat) -> String {
+        match format {
+            DigestFormat::Hex => self.to_string(),
+            DigestFormat::Short => self.to_string()[..DigestFormat::SHORT_LEN].to_string(),
+            DigestFormat::Base58 => bs58::encode(self.0).into_string(),
+            DigestFormat::Prefixed => self.to_prefixed_hex(),
+        }
+    }
+}
+
+impl PartialEq for Digest {
+    /// Compares in constant time, so checking a digest an attacker
+    /// controls does not reveal how much of it matches.
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl Hash for Digest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl FromStr for Digest {
+    type Err = DigestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        let mut bytes = [0; Self::LEN];
+        let mut len = 0;
+        for (index, character) in hex.chars().enumerate() {
+            let Some(nibble) = character.to_digit(16) else {
+                return Err(DigestError::InvalidCharacter { character, index });
+            };
+            if let Some(byte) = bytes.get_mut(index / 2) {
+                *byte |= (nibble as u8) << if index % 2 == 0 { 4 } else { 0 };
+            }
+            len += 1;
+        }
+        if len != Self::LEN * 2 {
+            return Err(DigestError::InvalidLength(len));
+        }
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Digest({})", self)
+    }
+}
+
+impl Serialize for Digest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Digest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABC: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    #[test]
+    fn parses_and_formats_hex() {
+        let digest: Digest = ABC.parse().unwrap();
+        assert_eq!(digest.to_string(), ABC);
+        assert_eq!(digest.as_bytes()[0], 0xba);
+        assert_eq!(format!("0x{}", ABC.to_uppercase()).parse(), Ok(digest));
+        assert_eq!(digest.to_prefixed_hex(), format!("0x{ABC}"));
+        assert_ne!(Digest::from_bytes([0; 32]), digest);
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        assert_eq!(
+            "abc123".parse::<Digest>(),
+            Err(DigestError::InvalidLength(6))
+        );
+        assert_eq!(
+            format!("{ABC}00").parse::<Digest>(),
+            Err(DigestError::InvalidLength(66))
+        );
+        assert_eq!(
+            format!("sha256:{ABC}").parse::<Digest>(),
+            Err(DigestError::InvalidCharacter {
+                character: 's',
+                index: 0
+            })
+        );
+        let wide = format!("{}é", &ABC[..62]);
+        assert_eq!(
+            wide.parse::<Digest>(),
+            Err(DigestError::InvalidCharacter {
+                character: 'é',
+                index: 62
+            })
+        );
+    }
+
+    #[test]
+    fn formats_for_other_ecosystems() {
+        let digest: Digest = ABC.parse().unwrap();
+        assert_eq!(digest.format(DigestFormat::Hex), ABC);
+        assert_eq!(digest.format(DigestFormat::Short), &ABC[..12]);
+        assert_eq!(digest.format(DigestFormat::Prefixed), format!("0x{ABC}"));
+        // Python: base58.b58encode(hashlib.sha256(b"abc").digest())
+        assert_eq!(
+            digest.format(DigestFormat::Base58),
+            "DYu3G8aGTMBW1WrTw76zxQJQU4DHLw9MLyy7peG4LKkY"
+        );
+        assert_eq!(
+            Digest::from_bytes([0; 32]).format(DigestFormat::Base58),
+            "1".repeat(32)
+        );
+        for format in DigestFormat::ALL {
+            assert_eq!(format.as_str().parse(), Ok(format));
+            assert_eq!(
+                serde_json::to_string(&format).unwrap(),
+                format!("\"{format}\"")
+            );
+        }
+        assert!("base64".parse::<DigestFormat>().is_err());
+    }
+
+    #[test]
+    fn serializes_as_a_string() {
+        let digest: Digest = format!("0x{ABC}").parse().unwrap();
+        let json = serde_json::to_string(&digest).unwrap();
+        assert_eq!(json, format!("\"{ABC}\""));
+        assert_eq!(serde_json::from_str::<Digest>(&json).unwrap(), digest);
+        let err = serde_json::from_str::<Digest>("\"12\"").unwrap_err();
+        assert!(err.to_string().contains("expected 64"));
+    }
+}
@@ -0,0 +1,389 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Energy measurement from RAPL counters on Linux and `powermetrics` on
+//! macOS.
+//!
+//! Intel and AMD processors count the energy used by each package and its
+//! DRAM in Running Average Power Limit (RAPL) registers, which Linux
+//! exposes under [`POWERCAP_DIR`]. On macOS, including Apple Silicon,
+//! [`POWERMETRICS`] samples the power of the CPU, GPU and Neural Engine.
+//! An [`EnergyMeter`] measures around the timed region like a
+//! [`Stopwatch`](crate::Stopwatch) and records the energy per iteration as
+//! the `energy` counter of the result, in joules.
+//!
+//! Since the PLATYPUS side channel, `energy_uj` is readable only by root
+//! on most distributions, and `powermetrics` always needs root. Without
+//! energy counters or the permission to read them, [`EnergyMeter::new`]
+//! fails with an error that says which, so a runner can warn once and
+//! record results without energy.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::powermetrics::{POWERMETRICS, Powermetrics};
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Directory where Linux exposes power capping zones, RAPL among them.
+pub const POWERCAP_DIR: &str = "/sys/class/powercap";
+
+/// Name of the counter [`EnergyMeter::record`] sets.
+pub const ENERGY_COUNTER: &str = "energy";
+
+/// Why energy cannot be measured.
+#[derive(Debug)]
+pub enum EnergyError {
+    /// No RAPL package zone or `powermetrics` was found, e.g. on other
+    /// platforms, in virtual machines or without the `intel_rapl` driver.
+    Unsupported,
+    /// A counter or `powermetrics` exists but only root may use it.
+    PermissionDenied(PathBuf),
+    /// A zone or `powermetrics` could not be read or has unexpected
+    /// contents.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for EnergyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported => write!(
+                f,
+                "no RAPL energy counters in {} or {}",
+                POWERCAP_DIR, POWERMETRICS
+            ),
+            Self::PermissionDenied(path) => write!(
+                f,
+                "{}: permission denied; run as root to measure energy",
+                path.display()
+            ),
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for EnergyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A RAPL zone counted by the meter.
+#[derive(Debug, Clone)]
+struct Domain {
+    /// Directory name, e.g. `intel-rapl:0:2`, unique across sockets.
+    zone: String,
+    /// Zone name, e.g. `package-0` or `dram`.
+    name: String,
+    /// Value after which the counter wraps to zero, in microjoules.
+    max_range: u64,
+    started: Option<u64>,
+    accumulated: u64,
+}
+
+/// Energy used by the packages and DRAM, or on macOS by the CPU, GPU and
+/// Neural Engine, while the meter runs.
+///
+/// Counts accumulate across `start`/`stop` pairs until `reset`. Both
+/// sources count the whole machine, not just this process, so other load
+/// adds to the result. With RAPL, one counter wrap per `start`/`stop` pair
+/// is accounted for, which at 200 W leaves over 20 minutes per pair. With
+/// `powermetrics`, energy is sampled every 100 ms and the unreported part
+/// of the last sample is missed, so the timed region should last seconds.
+///
+/// # Example
+///
+/// ```no_run
+/// use zkbench::{BenchmarkResult, EnergyMeter};
+/// # fn prove() {}
+///
+/// let mut meter = match EnergyMeter::new() {
+///     Ok(meter) => Some(meter),
+///     Err(err) => {
+///         eprintln!("not measuring energy: {err}");
+///         None
+///     }
+/// };
+/// let mut result = BenchmarkResult {
+///     iterations: 10,
+///     ..Default::default()
+/// };
+/// if let Some(meter) = meter.as_mut() {
+///     meter.start()?;
+/// }
+/// for _ in 0..10 {
+///     prove();
+/// }
+/// if let Some(meter) = meter.as_mut() {
+///     meter.stop()?;
+///     meter.record(&mut result);
+/// }
+/// # Ok::<(), zkbench::EnergyError>(())
+/// ```
+#[derive(Debug)]
+pub struct EnergyMeter {
+    backend: Backend,
+}
+
+#[derive(Debug)]
+enum Backend {
+    Rapl { dir: PathBuf, domains: Vec<Domain> },
+    Powermetrics(Powermetrics),
+}
+
+impl EnergyMeter {
+    /// Checks that [`POWERMETRICS`] runs on macOS, or finds the RAPL zones
+    /// in [`POWERCAP_DIR`] and checks that they can be read elsewhere.
+    pub fn new() -> Result<Self, EnergyError> {
+        if cfg!(target_os = "macos") {
+            Self::powermetrics(POWERMETRICS)
+        } else {
+            Self::from_dir(POWERCAP_DIR)
+        }
+    }
+
+    /// Like [`new`](Self::new), with the zones in `dir`.
+    ///
+    /// Counts the `package-*` zones and the `dram` zones, which are
+    /// outside the package. Core, uncore and platform (`psys`) zones are
+    /// skipped because they overlap with those.
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, EnergyError> {
+        let dir = dir.as_ref().to_path_buf();
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(EnergyError::Unsupported);
+            }
+            Err(source) => return Err(EnergyError::Io { path: dir, source }),
+        };
+        let mut domains = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|source| EnergyError::Io {
+                path: dir.clone(),
+                source,
+            })?;
+            let zone = entry.file_name().to_string_lossy().into_owned();
+            let path = entry.path();
+            if !path.join("energy_uj").exists() {
+                continue;
+            }
+            let name = read_file(&path.join("name"))?;
+            if !(name.starts_with("package") || name == "dram") {
+                continue;
+            }
+            let max_range = read_number(&path.join("max_energy_range_uj"))?;
+            domains.push(Domain {
+                zone,
+                name,
+                max_range,
+                started: None,
+                accumulated: 0,
+            });
+        }
+        if !domains
+            .iter()
+            .any(|domain| domain.name.starts_with("package"))
+        {
+            return Err(EnergyError::Unsupported);
+        }
+        domains.sort_by(|a, b| a.zone.cmp(&b.zone));
+        // Fails now rather than in the timed region when only root may
+        // read the counters.
+        for domain in &domains {
+            read_energy(&dir, domain)?;
+        }
+        Ok(Self {
+            backend: Backend::Rapl { dir, domains },
+        })
+    }
+
+    /// Measures with the `powermetrics` at `program`, after taking one
+    /// sample to check that it runs as root.
+    ///
+    /// Reports the `cpu`, `gpu` and `ane` (Neural Engine) domains on Apple
+    /// Silicon and the `package` domain on Intel.
+    pub fn powermetrics(program: impl AsRef<Path>) -> Result<Self, EnergyError> {
+        Ok(Self {
+            backend: Backend::Powermetrics(Powermetrics::new(program.as_ref())?),
+        })
+    }
+
+    /// Starts measuring. Has no effect if already running.
+    pub fn start(&mut self) -> Result<(), EnergyError> {
+        match &mut self.backend {
+            Backend::Rapl { dir, domains } => {
+                for domain in domains {
+                    if domain.started.is_none() {
+                        domain.started = Some(read_energy(dir, domain)?);
+                    }
+                }
+                Ok(())
+            }
+            Backend::Powermetrics(powermetrics) => powermetrics.start(),
+        }
+    }
+
+    /// Stops measuring and accumulates the energy used since
+    /// [`start`](Self::start). Has no effect if already stopped.
+    pub fn stop(&mut self) -> Result<(), EnergyError> {
+        match &mut self.backend {
+            Backend::Rapl { dir, domains } => {
+                for domain in domains {
+                    if let Some(started) = domain.started {
+                        let energy = read_energy(dir, domain)?;
+                        domain.accumulated += if energy >= started {
+                            energy - started
+                        } else {
+                            domain.max_range - started + energy
+                        };
+                        domain.started = None;
+                    }
+                }
+                Ok(())
+            }
+            Backend::Powermetrics(powermetrics) => powermetrics.stop(),
+        }
+    }
+
+    /// Clears the accumulated energy and stops the meter.
+    pub fn reset(&mut self) {
+        match &mut self.backend {
+            Backend::Rapl { domains, .. } => {
+                for domain in domains {
+                    domain.started = None;
+                    domain.accumulated = 0;
+                }
+            }
+            Backend::Powermetrics(powermetrics) => powermetrics.reset(),
+        }
+    }
+
+    /// Accumulated energy of each domain in joules. RAPL zones are keyed by
+    /// directory and name, e.g. `intel-rapl:0 (package-0)`.
+    pub fn domains(&self) -> BTreeMap<String, f64> {
+        match &self.backend {
+            Backend::Rapl { domains, .. } => domains
+                .iter()
+                .map(|domain| {
+                    (
+                        format!("{} ({})", domain.zone, domain.name),
+                        domain.accumulated as f64 / 1e6,
+                    )
+                })
+                .collect(),
+            Backend::Powermetrics(powermetrics) => powermetrics.domains(),
+        }
+    }
+
+    /// Total accumulated energy in joules.
+    pub fn joules(&self) -> f64 {
+        self.domains().values().sum()
+    }
+
+    /// Records the energy as the [`ENERGY_COUNTER`] of `result`, divided
+    /// by its iterations so it is per iteration like its latency.
+    pub fn record(&self, result: &mut BenchmarkResult) {
+        let iterations = result.iterations.max(1) as f64;
+        result.counters.insert(
+            ENERGY_COUNTER.to_string(),
+            MetricValue::new(self.joules() / iterations, "J"),
+        );
+    }
+}
+
+fn read_energy(dir: &Path, domain: &Domain) -> Result<u64, EnergyError> {
+    read_number(&dir.join(&domain.zone).join("energy_uj"))
+}
+
+fn read_file(path: &Path) -> Result<String, EnergyError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents.trim().to_string()),
+        Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+            Err(EnergyError::PermissionDenied(path.to_path_buf()))
+        }
+        Err(source) => Err(EnergyError::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+fn read_number(path: &Path) -> Result<u64, EnergyError> {
+    read_file(path)?.parse().map_err(|err| EnergyError::Io {
+        path: path.to_path_buf(),
+        source: io::Error::new(io::ErrorKind::InvalidData, err),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone(dir: &Path, zone: &str, name: &str, energy: u64) {
+        let path = dir.join(zone);
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join("name"), format!("{name}\n")).unwrap();
+        fs::write(path.join("energy_uj"), format!("{energy}\n")).unwrap();
+        fs::write(path.join("max_energy_range_uj"), "1000000000\n").unwrap();
+    }
+
+    #[test]
+    fn measures_packages_and_dram() {
+        let dir = std::env::temp_dir().join(format!("zkbench-rapl-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        zone(&dir, "intel-rapl:0", "package-0", 5_000_000);
+        zone(&dir, "intel-rapl:0:0", "core", 0);
+        zone(&dir, "intel-rapl:0:2", "dram", 999_999_900);
+        zone(&dir, "intel-rapl:1", "psys", 0);
+        fs::create_dir_all(dir.join("intel-rapl")).unwrap();
+
+        let mut meter = EnergyMeter::from_dir(&dir).unwrap();
+        meter.start().unwrap();
+        zone(&dir, "intel-rapl:0", "package-0", 7_500_000);
+        // The DRAM counter wraps at 1000 J.
+        zone(&dir, "intel-rapl:0:2", "dram", 399_900);
+        zone(&dir, "intel-rapl:0:0", "core", 1_000_000);
+        meter.stop().unwrap();
+        assert_eq!(
+            meter.domains(),
+            BTreeMap::from([
+                ("intel-rapl:0 (package-0)".to_string(), 2.5),
+                ("intel-rapl:0:2 (dram)".to_string(), 0.4),
+            ])
+        );
+
+        let mut result = BenchmarkResult {
+            iterations: 2,
+            ..Default::default()
+        };
+        meter.record(&mut result);
+        let energy = &result.counters[ENERGY_COUNTER];
+        assert_eq!(energy.unit, "J");
+        assert!((energy.value - 1.45).abs() < 1e-9);
+
+        meter.reset();
+        assert_eq!(meter.joules(), 0.0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reports_missing_rapl() {
+        let dir = std::env::temp_dir().join(format!("zkbench-no-rapl-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        assert!(matches!(
+            EnergyMeter::from_dir(&dir),
+            Err(EnergyError::Unsupported)
+        ));
+        zone(&dir, "intel-rapl:1", "psys", 0);
+        assert!(matches!(
+            EnergyMeter::from_dir(&dir),
+            Err(EnergyError::Unsupported)
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Output location and run identity from the environment.
+//!
+//! Every integration needs to know where to write its report and which
+//! CI run produced it. [`RunEnvironment`] resolves both the same way
+//! everywhere:
+//!
+//! - `ZKBENCH_OUTPUT_DIR` is the output directory, defaulting to
+//!   `$CARGO_TARGET_DIR/zkbench/reports` (or `target/zkbench/reports`).
+//! - `ZKBENCH_RUN_ID` is the run id, defaulting to the CI provider's run
+//!   (or job) id.
+//! - On GitHub Actions, GitLab CI and Buildkite, the provider's variables
+//!   supply the commit, branch, tag and a link to the run, which a
+//!   detached CI checkout cannot tell git.
+//!
+//! [`Metadata::create`] applies the environment, so reports carry the run
+//! id and link and can be correlated with the CI run that produced them.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::Metadata;
+use crate::store::component;
+
+/// Variable naming the directory reports are written to.
+pub const OUTPUT_DIR_ENV: &str = "ZKBENCH_OUTPUT_DIR";
+
+/// Variable naming the run, overriding the CI provider's run id.
+pub const RUN_ID_ENV: &str = "ZKBENCH_RUN_ID";
+
+/// A CI system recognized from its environment variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CiProvider {
+    GithubActions,
+    Gitlab,
+    Buildkite,
+    /// A CI system that only sets `CI`.
+    Other,
+}
+
+impl fmt::Display for CiProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::GithubActions => "GitHub Actions",
+            Self::Gitlab => "GitLab CI",
+            Self::Buildkite => "Buildkite",
+            Self::Other => "CI",
+        })
+    }
+}
+
+/// Output location and run identity resolved from the environment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunEnvironment {
+    pub output_dir: PathBuf,
+    pub run_id: Option<String>,
+    pub ci: Option<CiProvider>,
+    /// Commit the CI run built.
+    pub commit_sha: Option<String>,
+    pub branch: Option<String>,
+    pub git_tag: Option<String>,
+    /// Link to the CI run or job.
+    pub run_url: Option<String>,
+}
+
+impl RunEnvironment {
+    /// Resolves the environment of the current process. Empty variables
+    /// count as unset.
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Resolves the environment from a variable lookup, e.g. a map in
+    /// tests or the environment of another process. Empty values count
+    /// as unset.
+    pub fn from_vars(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let var = |name: &str| lookup(name).filter(|value| !value.is_empty());
+        let output_dir = var(OUTPUT_DIR_ENV).map(PathBuf::from).unwrap_or_else(|| {
+            var("CARGO_TARGET_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from("target"))
+                .join("zkbench")
+                .join("reports")
+        });
+        let mut env = Self {
+            output_dir,
+            run_id: None,
+            ci: None,
+            commit_sha: None,
+            branch: None,
+            git_tag: None,
+            run_url: None,
+        };
+        if var("GITHUB_ACTIONS").as_deref() == Some("true") {
+            env.ci = Some(CiProvider::GithubActions);
+            env.commit_sha = var("GITHUB_SHA");
+            let ref_name = var("GITHUB_REF_NAME");
+            match var("GITHUB_REF_TYPE").as_deref() {
+                Some("tag") => env.git_tag = ref_name,
+                // Pull requests run on a merge ref; the head branch is
+                // the one being benchmarked.
+                _ => env.branch = var("GITHUB_HEAD_REF").or(ref_name),
+            }
+            if let Some(run) = var("GITHUB_RUN_ID") {
+                let attempt = var("GITHUB_RUN_ATTEMPT").unwrap_or_else(|| "1".to_string());
+                env.run_id = Some(format!("{run}-{attempt}"));
+                if let (Some(server), Some(repo)) =
+                    (var("GITHUB_SERVER_URL"), var("GITHUB_REPOSITORY"))
+                {
+                    env.run_url = Some(format!(
+                        "{server}/{repo}/actions/runs/{run}/attempts/{attempt}"
+                    ));
+                }
+            }
+        } else if var("GITLAB_CI").is_some() {
+            env.ci = Some(CiProvider::Gitlab);
+            env.commit_sha = var("CI_COMMIT_SHA");
+            env.branch =
+                var("CI_MERGE_REQUEST_SOURCE_BRANCH_NAME").or_else(|| var("CI_COMMIT_BRANCH"));
+            env.git_tag = var("CI_COMMIT_TAG");
+            env.run_id = var("CI_JOB_ID");
+            env.run_url = var("CI_JOB_URL");
+        } else if var("BUILDKITE").is_some() {
+            env.ci = Some(CiProvider::Buildkite);
+            env.commit_sha = var("BUILDKITE_COMMIT");
+            env.git_tag = var("BUILDKITE_TAG");
+            env.branch = var("BUILDKITE_BRANCH").filter(|_| env.git_tag.is_none());
+            env.run_id = var("BUILDKITE_JOB_ID");
+            env.run_url = var("BUILDKITE_BUILD_URL");
+        } else if var("CI").is_some() {
+            env.ci = Some(CiProvider::Other);
+        }
+        if let Some(run_id) = var(RUN_ID_ENV) {
+            env.run_id = Some(run_id);
+        }
+        env
+    }
+
+    /// Fills in what git could not tell: the commit when it is
+    /// `unknown`, and the branch and tag when missing (CI checkouts are
+    /// usually detached). The run id and link are recorded when not set
+    /// already.
+    pub fn apply(&self, metadata: &mut Metadata) {
+        if metadata.commit_sha == "unknown"
+            && let Some(sha) = &self.commit_sha
+        {
+            metadata.commit_sha = sha.chars().take(12).collect();
+        }
+        if metadata.branch.is_none() {
+            metadata.branch = self.branch.clone();
+        }
+        if metadata.git_tag.is_none() {
+            metadata.git_tag = self.git_tag.clone();
+        }
+        if metadata.run_id.is_none() {
+            metadata.run_id = self.run_id.clone();
+        }
+        if metadata.run_url.is_none() {
+            metadata.run_url = self.run_url.clone();
+        }
+    }
+
+    /// Where a run's report goes:
+    /// `<output dir>/<implementation>/<run id or timestamp>.json`, with
+    /// the components made safe as file names.
+    pub fn report_path(&self, metadata: &Metadata) -> PathBuf {
+        let name = metadata.run_id.as_deref().unwrap_or(&metadata.timestamp);
+        self.output_dir
+            .join(component(&metadata.implementation))
+            .join(format!("{}.json", component(name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> RunEnvironment {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        RunEnvironment::from_vars(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn defaults_outside_ci() {
+        let env = env(&[("CARGO_TARGET_DIR", "/build")]);
+        assert_eq!(env.output_dir, PathBuf::from("/build/zkbench/reports"));
+        assert_eq!(env.ci, None);
+        assert_eq!(env.run_id, None);
+    }
+
+    #[test]
+    fn resolves_github_actions() {
+        let env = env(&[
+            ("GITHUB_ACTIONS", "true"),
+            ("GITHUB_SHA", "0123456789abcdef0123"),
+            ("GITHUB_REF_TYPE", "branch"),
+            ("GITHUB_REF_NAME", "42/merge"),
+            ("GITHUB_HEAD_REF", "fast-msm"),
+            ("GITHUB_RUN_ID", "9001"),
+            ("GITHUB_RUN_ATTEMPT", "2"),
+            ("GITHUB_SERVER_URL", "https://github.com"),
+            ("GITHUB_REPOSITORY", "org/prover"),
+            (OUTPUT_DIR_ENV, "out"),
+        ]);
+        assert_eq!(env.ci, Some(CiProvider::GithubActions));
+        assert_eq!(env.branch.as_deref(), Some("fast-msm"));
+        assert_eq!(env.run_id.as_deref(), Some("9001-2"));
+        assert_eq!(
+            env.run_url.as_deref(),
+            Some("https://github.com/org/prover/actions/runs/9001/attempts/2")
+        );
+
+        let mut metadata = Metadata::create("my/impl", "1.0.0");
+        metadata.commit_sha = "unknown".to_string();
+        metadata.branch = None;
+        metadata.run_id = None;
+        metadata.run_url = None;
+        env.apply(&mut metadata);
+        assert_eq!(metadata.commit_sha, "0123456789ab");
+        assert_eq!(metadata.branch.as_deref(), Some("fast-msm"));
+        assert_eq!(
+            env.report_path(&metadata),
+            PathBuf::from("out/my_impl/9001-2.json")
+        );
+    }
+
+    #[test]
+    fn resolves_tags_and_overrides() {
+        let gitlab = env(&[
+            ("GITLAB_CI", "true"),
+            ("CI_COMMIT_TAG", "v1.0.0"),
+            ("CI_JOB_ID", "77"),
+            (RUN_ID_ENV, "nightly-3"),
+        ]);
+        assert_eq!(gitlab.ci, Some(CiProvider::Gitlab));
+        assert_eq!(gitlab.git_tag.as_deref(), Some("v1.0.0"));
+        assert_eq!(gitlab.run_id.as_deref(), Some("nightly-3"));
+
+        let github = env(&[
+            ("GITHUB_ACTIONS", "true"),
+            ("GITHUB_REF_TYPE", "tag"),
+            ("GITHUB_REF_NAME", "v2.0.0"),
+            ("GITHUB_HEAD_REF", ""),
+        ]);
+        assert_eq!(github.git_tag.as_deref(), Some("v2.0.0"));
+        assert_eq!(github.branch, None);
+        assert_eq!(env(&[("CI", "1")]).ci, Some(CiProvider::Other));
+    }
+}
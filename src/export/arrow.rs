@@ -0,0 +1,170 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Apache Arrow and Parquet export (feature `arrow`).
+//!
+//! Reports are flattened into one row per metric (see
+//! [`flatten_report`](crate::flatten_report)) so years of nightly reports
+//! can be concatenated into a single Parquet file and queried with
+//! DataFusion or DuckDB.
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef, Float64Array, RecordBatch, StringArray, TimestampMicrosecondArray, UInt64Array,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+
+use crate::flatten::{MetricRow, flatten_report};
+use crate::schema::BenchmarkReport;
+
+/// Arrow schema of the flattened metric rows.
+pub fn arrow_schema() -> SchemaRef {
+    let utf8 = |name: &str, nullable: bool| Field::new(name, DataType::Utf8, nullable);
+    Arc::new(Schema::new(vec![
+        utf8("implementation", false),
+        utf8("version", false),
+        utf8("commit_sha", false),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            true,
+        ),
+        utf8("os", false),
+        utf8("arch", false),
+        Field::new("cpu_count", DataType::UInt64, false),
+        utf8("cpu_vendor", true),
+        utf8("gpu_vendor", true),
+        utf8("benchmark", false),
+        utf8("metric", false),
+        Field::new("value", DataType::Float64, false),
+        utf8("unit", false),
+        Field::new("lower_value", DataType::Float64, true),
+        Field::new("upper_value", DataType::Float64, true),
+        Field::new("iterations", DataType::UInt64, false),
+    ]))
+}
+
+/// Converts reports into a single Arrow record batch with
+/// [`arrow_schema`]. Timestamps that are not valid RFC 3339 become null.
+pub fn to_record_batch(reports: &[BenchmarkReport]) -> Result<RecordBatch, ArrowError> {
+    let rows: Vec<MetricRow> = reports.iter().flat_map(flatten_report).collect();
+    let strings = |f: fn(&MetricRow) -> Option<&str>| -> ArrayRef {
+        Arc::new(rows.iter().map(f).collect::<StringArray>())
+    };
+    let floats = |f: fn(&MetricRow) -> Option<f64>| -> ArrayRef {
+        Arc::new(rows.iter().map(f).collect::<Float64Array>())
+    };
+    let timestamps: TimestampMicrosecondArray = rows
+        .iter()
+        .map(|r| {
+            chrono::DateTime::parse_from_rfc3339(&r.timestamp)
+                .ok()
+                .map(|t| t.timestamp_micros())
+        })
+        .collect();
+    let columns: Vec<ArrayRef> = vec![
+        strings(|r| Some(&r.implementation)),
+        strings(|r| Some(&r.version)),
+        strings(|r| Some(&r.commit_sha)),
+        Arc::new(timestamps.with_timezone("UTC")),
+        strings(|r| Some(&r.os)),
+        strings(|r| Some(&r.arch)),
+        Arc::new(
+            rows.iter()
+                .map(|r| Some(r.cpu_count as u64))
+                .collect::<UInt64Array>(),
+        ),
+        strings(|r| r.cpu_vendor.as_deref()),
+        strings(|r| r.gpu_vendor.as_deref()),
+        strings(|r| Some(&r.benchmark)),
+        strings(|r| Some(&r.metric)),
+        floats(|r| Some(r.value)),
+        strings(|r| Some(&r.unit)),
+        floats(|r| r.lower_value),
+        floats(|r| r.upper_value),
+        Arc::new(
+            rows.iter()
+                .map(|r| Some(r.iterations as u64))
+                .collect::<UInt64Array>(),
+        ),
+    ];
+    RecordBatch::try_new(arrow_schema(), columns)
+}
+
+/// Writes reports to a Snappy-compressed Parquet file.
+pub fn write_parquet(
+    reports: &[BenchmarkReport],
+    path: impl AsRef<Path>,
+) -> Result<(), ParquetError> {
+    let batch = to_record_batch(reports)?;
+    let props = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), Some(props))?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{BenchmarkResult, MetricValue};
+    use crate::testing::ReportBuilder;
+    use arrow_array::Array;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    fn prove() -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(2.0, "s", 1.9, 2.1)),
+            memory: Some(MetricValue::new(4.0, "GB")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn record_batch_matches_schema() {
+        let batch = to_record_batch(&[
+            ReportBuilder::new().result("prove", prove()).build(),
+            ReportBuilder::new().result("prove", prove()).build(),
+        ])
+        .unwrap();
+        assert_eq!(batch.num_rows(), 4);
+        assert_eq!(batch.schema(), arrow_schema());
+        let timestamps = batch
+            .column_by_name("timestamp")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        assert!(timestamps.is_valid(0));
+        let upper = batch.column_by_name("upper_value").unwrap();
+        assert!(upper.is_valid(0));
+        assert!(upper.is_null(1));
+    }
+
+    #[test]
+    fn parquet_roundtrip() {
+        let path =
+            std::env::temp_dir().join(format!("zkbench-export-{}.parquet", std::process::id()));
+        write_parquet(
+            &[ReportBuilder::new().result("prove", prove()).build()],
+            &path,
+        )
+        .unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(rows, 2);
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bencher Metric Format (BMF) export.
+//!
+//! BMF is the JSON that `bencher run --adapter json` ingests: an object
+//! keyed by benchmark name, each holding measures keyed by slug with a
+//! `value` and optional `lower_value`/`upper_value`. Bencher's `latency`
+//! measure is in nanoseconds, so latencies are converted; memory is
+//! written in bytes under `memory`, throughput unchanged under
+//! `throughput`, and counters under their own names.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{BenchmarkReport, MetricValue};
+use crate::units::{is_memory_unit, is_time_unit, to_base};
+
+/// One measure of one benchmark.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BmfMetric {
+    pub(crate) value: f64,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub(crate) lower_value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub(crate) upper_value: Option<f64>,
+}
+
+impl BmfMetric {
+    /// Converts a metric to its unit family's base unit (ns, B).
+    fn from_metric(metric: &MetricValue) -> Self {
+        let base = |value: f64| to_base(value, &metric.unit);
+        Self {
+            value: base(metric.value),
+            lower_value: metric.lower_value.map(base),
+            upper_value: metric.upper_value.map(base),
+        }
+    }
+}
+
+/// Benchmarks keyed by name, then measures keyed by slug.
+pub(crate) type Bmf = BTreeMap<String, BTreeMap<String, BmfMetric>>;
+
+/// Exports a report as BMF JSON.
+///
+/// Latencies not recorded in a time unit and memory not recorded in a
+/// memory unit are skipped, since Bencher would misread them.
+///
+/// # Arguments
+/// * `pretty` - if true, output is indented (2 spaces); otherwise
+///   single-line.
+pub fn export_bmf(report: &BenchmarkReport, pretty: bool) -> Result<String, serde_json::Error> {
+    let mut bmf = Bmf::new();
+    for (name, result) in &report.benchmarks {
+        let measures = bmf.entry(name.clone()).or_default();
+        if let Some(latency) = result.latency.as_ref().filter(|m| is_time_unit(&m.unit)) {
+            measures.insert("latency".to_string(), BmfMetric::from_metric(latency));
+        }
+        if let Some(memory) = result.memory.as_ref().filter(|m| is_memory_unit(&m.unit)) {
+            measures.insert("memory".to_string(), BmfMetric::from_metric(memory));
+        }
+        if let Some(throughput) = &result.throughput {
+            measures.insert("throughput".to_string(), BmfMetric::from_metric(throughput));
+        }
+        for (counter, metric) in &result.counters {
+            measures.insert(counter.clone(), BmfMetric::from_metric(metric));
+        }
+    }
+    if pretty {
+        serde_json::to_string_pretty(&bmf)
+    } else {
+        serde_json::to_string(&bmf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn converts_to_base_units() {
+        let report = ReportBuilder::new()
+            .result(
+                "prove",
+                BenchmarkResult {
+                    latency: Some(MetricValue::with_bounds(1.5, "ms", 1.0, 2.0)),
+                    memory: Some(MetricValue::new(2.0, "KiB")),
+                    throughput: Some(MetricValue::new(4.0, "proofs/s")),
+                    counters: [("constraints".to_string(), MetricValue::new(7.0, "rows"))]
+                        .into_iter()
+                        .collect(),
+                    ..Default::default()
+                },
+            )
+            .build();
+        assert_eq!(
+            export_bmf(&report, false).unwrap(),
+            r#"{"prove":{"constraints":{"value":7.0},"latency":{"value":1500000.0,"lower_value":1000000.0,"upper_value":2000000.0},"memory":{"value":2048.0},"throughput":{"value":4.0}}}"#
+        );
+    }
+}
@@ -0,0 +1,270 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! cargo-criterion compatible machine-readable messages.
+//!
+//! `cargo criterion --message-format=json` prints one JSON object per line
+//! as benchmarks finish: a `benchmark-complete` message per benchmark and
+//! a `group-complete` message when all benchmarks of a group are done.
+//! [`CriterionMessageWriter`] produces the same stream from zkbench
+//! results so editor integrations and CI parsers keep working.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::schema::{BenchmarkReport, BenchmarkResult, MetricValue};
+use crate::statistics::quantile;
+use crate::units::to_base;
+
+#[derive(Serialize)]
+struct Estimate {
+    estimate: f64,
+    lower_bound: f64,
+    upper_bound: f64,
+    unit: &'static str,
+}
+
+impl Estimate {
+    fn point(estimate: f64) -> Self {
+        Self {
+            estimate,
+            lower_bound: estimate,
+            upper_bound: estimate,
+            unit: "ns",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Throughput {
+    per_iteration: u64,
+    unit: String,
+}
+
+#[derive(Serialize)]
+struct BenchmarkComplete<'a> {
+    reason: &'static str,
+    id: &'a str,
+    report_directory: String,
+    iteration_count: Vec<u64>,
+    measured_values: Vec<f64>,
+    unit: &'static str,
+    throughput: Vec<Throughput>,
+    typical: Estimate,
+    mean: Estimate,
+    median: Estimate,
+    median_abs_dev: Estimate,
+    slope: Option<Estimate>,
+    change: Option<()>,
+}
+
+#[derive(Serialize)]
+struct GroupComplete<'a> {
+    reason: &'static str,
+    group_name: &'a str,
+    benchmarks: &'a [String],
+    report_directory: String,
+}
+
+/// Streams cargo-criterion `--message-format=json` lines to a writer.
+///
+/// Call [`benchmark_complete`](Self::benchmark_complete) as each
+/// benchmark finishes and [`group_complete`](Self::group_complete) once a
+/// group is done. Durations are converted to nanoseconds, the unit
+/// cargo-criterion always reports.
+pub struct CriterionMessageWriter<W: Write> {
+    out: W,
+    report_root: String,
+}
+
+impl<W: Write> CriterionMessageWriter<W> {
+    /// Creates a writer reporting `target/criterion/reports` as the HTML
+    /// report root, matching cargo-criterion's default layout.
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            report_root: "target/criterion/reports".to_string(),
+        }
+    }
+
+    /// Emits a `benchmark-complete` message for a single result.
+    ///
+    /// Results without a latency metric are skipped, since every
+    /// criterion estimate is a duration.
+    pub fn benchmark_complete(&mut self, id: &str, result: &BenchmarkResult) -> io::Result<()> {
+        let Some(latency) = &result.latency else {
+            return Ok(());
+        };
+        let to_ns = to_base(1.0, &latency.unit);
+        let typical = estimate(latency, to_ns);
+        let mut samples: Vec<f64> = result.samples.iter().map(|s| s * to_ns).collect();
+        samples.sort_by(f64::total_cmp);
+        let (median, median_abs_dev) = if samples.is_empty() {
+            (Estimate::point(typical.estimate), Estimate::point(0.0))
+        } else {
+            let median = quantile(&samples, 0.5);
+            let mut deviations: Vec<f64> = samples.iter().map(|s| (s - median).abs()).collect();
+            deviations.sort_by(f64::total_cmp);
+            (
+                Estimate::point(median),
+                Estimate::point(quantile(&deviations, 0.5)),
+            )
+        };
+        let message = BenchmarkComplete {
+            reason: "benchmark-complete",
+            id,
+            report_directory: format!("{}/{}", self.report_root, id),
+            iteration_count: vec![1; result.samples.len()],
+            measured_values: result.samples.iter().map(|s| s * to_ns).collect(),
+            unit: "ns",
+            throughput: throughput(result, latency, to_ns).into_iter().collect(),
+            typical: estimate(latency, to_ns),
+            mean: typical,
+            median,
+            median_abs_dev,
+            slope: None,
+            change: None,
+        };
+        self.write_line(&message)
+    }
+
+    /// Emits a `group-complete` message listing the benchmarks of a group.
+    pub fn group_complete(&mut self, group_name: &str, benchmarks: &[String]) -> io::Result<()> {
+        let message = GroupComplete {
+            reason: "group-complete",
+            group_name,
+            benchmarks,
+            report_directory: format!("{}/{}", self.report_root, group_name),
+        };
+        self.write_line(&message)
+    }
+
+    /// Emits messages for every benchmark of a finished report, grouping
+    /// benchmarks by the part of their name before the first `/`.
+    pub fn write_report(&mut self, report: &BenchmarkReport) -> io::Result<()> {
+        let mut groups: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for (name, result) in report.sorted_benchmarks() {
+            self.benchmark_complete(name, result)?;
+            if result.latency.is_some() {
+                let group = name.split('/').next().unwrap_or(name);
+                groups.entry(group).or_default().push(name.clone());
+            }
+        }
+        for (group, benchmarks) in groups {
+            self.group_complete(group, &benchmarks)?;
+        }
+        Ok(())
+    }
+
+    /// Consumes the writer, returning the underlying output.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn write_line(&mut self, message: &impl Serialize) -> io::Result<()> {
+        serde_json::to_writer(&mut self.out, message)?;
+        self.out.write_all(b"\n")?;
+        self.out.flush()
+    }
+}
+
+fn estimate(metric: &MetricValue, to_ns: f64) -> Estimate {
+    Estimate {
+        estimate: metric.value * to_ns,
+        lower_bound: metric.lower_value.unwrap_or(metric.value) * to_ns,
+        upper_bound: metric.upper_value.unwrap_or(metric.value) * to_ns,
+        unit: "ns",
+    }
+}
+
+/// Recovers criterion's per-iteration throughput (e.g. bytes processed per
+/// iteration) from a `<unit>/s` rate and the iteration latency.
+fn throughput(result: &BenchmarkResult, latency: &MetricValue, to_ns: f64) -> Option<Throughput> {
+    let rate = result.throughput.as_ref()?;
+    let unit = rate.unit.strip_suffix("/s")?;
+    let per_iteration = rate.value * latency.value * to_ns / 1e9;
+    let unit = match unit {
+        "B" | "bytes" => "bytes",
+        "elem" | "elements" => "elements",
+        _ => return None,
+    };
+    Some(Throughput {
+        per_iteration: per_iteration.round() as u64,
+        unit: unit.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    use serde_json::Value;
+
+    fn lines(out: Vec<u8>) -> Vec<Value> {
+        String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn benchmark_complete_converts_to_ns() {
+        let mut writer = CriterionMessageWriter::new(Vec::new());
+        let result = BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(2.0, "us", 1.5, 2.5)),
+            samples: vec![1.0, 2.0, 4.0],
+            throughput: Some(MetricValue::new(512_000_000.0, "bytes/s")),
+            ..Default::default()
+        };
+        writer.benchmark_complete("hash/sha256", &result).unwrap();
+        let msg = &lines(writer.into_inner())[0];
+        assert_eq!(msg["reason"], "benchmark-complete");
+        assert_eq!(msg["id"], "hash/sha256");
+        assert_eq!(msg["unit"], "ns");
+        assert_eq!(msg["typical"]["estimate"], 2000.0);
+        assert_eq!(msg["typical"]["lower_bound"], 1500.0);
+        assert_eq!(msg["median"]["estimate"], 2000.0);
+        assert_eq!(msg["median_abs_dev"]["estimate"], 1000.0);
+        assert_eq!(msg["measured_values"][2], 4000.0);
+        assert_eq!(msg["iteration_count"][0], 1);
+        assert_eq!(msg["throughput"][0]["per_iteration"], 1024);
+        assert_eq!(msg["throughput"][0]["unit"], "bytes");
+        assert!(msg["change"].is_null());
+    }
+
+    #[test]
+    fn skips_results_without_latency() {
+        let mut writer = CriterionMessageWriter::new(Vec::new());
+        writer
+            .benchmark_complete("x", &BenchmarkResult::default())
+            .unwrap();
+        assert!(writer.into_inner().is_empty());
+    }
+
+    #[test]
+    fn write_report_emits_groups_last() {
+        let report = ReportBuilder::new()
+            .latencies_ms(&[("msm/2^10", 1.0), ("msm/2^12", 1.0), ("fft", 1.0)])
+            .build();
+        let mut writer = CriterionMessageWriter::new(Vec::new());
+        writer.write_report(&report).unwrap();
+        let msgs = lines(writer.into_inner());
+        let reasons: Vec<_> = msgs.iter().map(|m| m["reason"].as_str().unwrap()).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                "benchmark-complete",
+                "benchmark-complete",
+                "benchmark-complete",
+                "group-complete",
+                "group-complete"
+            ]
+        );
+        assert_eq!(msgs[4]["group_name"], "msm");
+        assert_eq!(msgs[4]["benchmarks"].as_array().unwrap().len(), 2);
+    }
+}
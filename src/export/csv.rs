@@ -0,0 +1,117 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! CSV export, one row per metric.
+//!
+//! The columns are the fields of [`MetricRow`], in declaration order, so
+//! a spreadsheet or `pandas.read_csv` sees the same table the dataframe
+//! and SQL exports produce. [`import_csv`](crate::import_csv) reads the
+//! files back.
+
+use crate::flatten::{MetricRow, flatten_report};
+use crate::schema::BenchmarkReport;
+
+/// Header of the exported CSV, matching [`MetricRow`]'s fields.
+pub(crate) const CSV_COLUMNS: [&str; 16] = [
+    "implementation",
+    "version",
+    "commit_sha",
+    "timestamp",
+    "os",
+    "arch",
+    "cpu_count",
+    "cpu_vendor",
+    "gpu_vendor",
+    "benchmark",
+    "metric",
+    "value",
+    "unit",
+    "lower_value",
+    "upper_value",
+    "iterations",
+];
+
+/// Exports reports as RFC 4180 CSV with a header row.
+///
+/// Missing optional values are empty fields; fields containing commas,
+/// quotes or line breaks are quoted.
+pub fn export_csv(reports: &[BenchmarkReport]) -> String {
+    let mut out = CSV_COLUMNS.join(",");
+    out.push_str("\r\n");
+    for row in reports.iter().flat_map(flatten_report) {
+        let fields = row_fields(&row);
+        let quoted: Vec<_> = fields.iter().map(|f| quote(f)).collect();
+        out.push_str(&quoted.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+fn row_fields(row: &MetricRow) -> [String; 16] {
+    let optional = |value: &Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+    [
+        row.implementation.clone(),
+        row.version.clone(),
+        row.commit_sha.clone(),
+        row.timestamp.clone(),
+        row.os.clone(),
+        row.arch.clone(),
+        row.cpu_count.to_string(),
+        row.cpu_vendor.clone().unwrap_or_default(),
+        row.gpu_vendor.clone().unwrap_or_default(),
+        row.benchmark.clone(),
+        row.metric.clone(),
+        row.value.to_string(),
+        row.unit.clone(),
+        optional(&row.lower_value),
+        optional(&row.upper_value),
+        row.iterations.to_string(),
+    ]
+}
+
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{BenchmarkResult, MetricValue};
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn one_line_per_metric() {
+        let report = ReportBuilder::new()
+            .version("1.0")
+            .commit("abc")
+            .day(1)
+            .metadata(|metadata| {
+                metadata.platform.cpu_vendor = None;
+                metadata.platform.gpu_vendor = None;
+                metadata.platform.os = "linux".to_string();
+                metadata.platform.arch = "x86_64".to_string();
+                metadata.platform.cpu_count = 8;
+            })
+            .result(
+                "msm, g1",
+                BenchmarkResult {
+                    latency: Some(MetricValue::with_bounds(1.5, "ms", 1.0, 2.0)),
+                    iterations: 3,
+                    ..Default::default()
+                },
+            )
+            .build();
+        let csv = export_csv(&[report]);
+        let lines: Vec<_> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], CSV_COLUMNS.join(","));
+        assert_eq!(
+            lines[1],
+            "impl,1.0,abc,2026-01-01T00:00:00+00:00,linux,x86_64,8,,,\"msm, g1\",latency,1.5,ms,1,2,3"
+        );
+        assert_eq!(lines[2], "");
+    }
+}
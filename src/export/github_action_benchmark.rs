@@ -0,0 +1,145 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Output for `benchmark-action/github-action-benchmark`.
+//!
+//! The action's `customSmallerIsBetter` tool reads a JSON array of
+//! `{name, unit, value, range?, extra?}` objects. Latency and memory map
+//! onto it directly; throughput is omitted because larger is better.
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{BenchmarkReport, MetricValue};
+
+/// One data point in the `customSmallerIsBetter` format.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GithubBenchmarkEntry {
+    pub name: String,
+    pub unit: String,
+    pub value: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<String>,
+}
+
+impl GithubBenchmarkEntry {
+    fn from_metric(name: String, metric: &MetricValue, extra: Option<String>) -> Self {
+        let range = metric
+            .lower_value
+            .zip(metric.upper_value)
+            .map(|(lower, upper)| {
+                let half_width = (metric.value - lower)
+                    .abs()
+                    .max((upper - metric.value).abs());
+                format!("± {}", half_width)
+            });
+        Self {
+            name,
+            unit: metric.unit.clone(),
+            value: metric.value,
+            range,
+            extra,
+        }
+    }
+}
+
+/// Exports a report as `customSmallerIsBetter` JSON.
+///
+/// Each benchmark contributes its latency under its own name and its
+/// memory under `<name> (memory)`. Entries are sorted by name so the
+/// action's history graphs keep a stable order.
+///
+/// # Arguments
+/// * `pretty` - if true, output is indented (2 spaces); otherwise
+///   single-line.
+pub fn export_github_action_benchmark(
+    report: &BenchmarkReport,
+    pretty: bool,
+) -> Result<String, serde_json::Error> {
+    let mut entries = Vec::new();
+    for (name, result) in report.sorted_benchmarks() {
+        let extra = (result.iterations > 0).then(|| {
+            format!(
+                "iterations: {}\ncommit: {}",
+                result.iterations, report.metadata.commit_sha
+            )
+        });
+        if let Some(latency) = &result.latency {
+            entries.push(GithubBenchmarkEntry::from_metric(
+                name.clone(),
+                latency,
+                extra.clone(),
+            ));
+        }
+        if let Some(memory) = &result.memory {
+            entries.push(GithubBenchmarkEntry::from_metric(
+                format!("{} (memory)", name),
+                memory,
+                extra,
+            ));
+        }
+    }
+    if pretty {
+        serde_json::to_string_pretty(&entries)
+    } else {
+        serde_json::to_string(&entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    fn prove() -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(100.0, "ms", 95.0, 104.0)),
+            memory: Some(MetricValue::new(512.0, "MB")),
+            throughput: Some(MetricValue::new(10.0, "proofs/s")),
+            iterations: 10,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn emits_latency_and_memory_entries() {
+        let json = export_github_action_benchmark(
+            &ReportBuilder::new()
+                .result("prove", prove())
+                .latency("hash", MetricValue::new(3.0, "us"))
+                .build(),
+            false,
+        )
+        .unwrap();
+        let entries: Vec<GithubBenchmarkEntry> = serde_json::from_str(&json).unwrap();
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["hash", "prove", "prove (memory)"]);
+        assert!(!json.contains("proofs/s"));
+    }
+
+    #[test]
+    fn range_and_extra() {
+        let json = export_github_action_benchmark(
+            &ReportBuilder::new()
+                .result("prove", prove())
+                .latency("hash", MetricValue::new(3.0, "us"))
+                .build(),
+            true,
+        )
+        .unwrap();
+        let entries: Vec<GithubBenchmarkEntry> = serde_json::from_str(&json).unwrap();
+        assert_eq!(entries[1].range.as_deref(), Some("± 5"));
+        assert!(
+            entries[1]
+                .extra
+                .as_deref()
+                .unwrap()
+                .starts_with("iterations: 10")
+        );
+        assert!(entries[0].range.is_none());
+        assert!(entries[0].extra.is_none());
+        assert!(!json.contains("\"range\": null"));
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! JUnit XML export for CI systems.
+//!
+//! Jenkins, GitLab and most CI dashboards render JUnit XML natively, so
+//! representing each benchmark as a test case makes latency regressions
+//! show up next to ordinary test failures.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::compare::{CompareOptions, compare};
+use crate::schema::BenchmarkReport;
+use crate::units::format_metric;
+
+/// Exports a report as JUnit XML, one `<testcase>` per benchmark.
+///
+/// When a baseline is given, a benchmark whose latency regressed (see
+/// [`compare`](crate::compare); `threshold` is a fraction, `0.05` is 5%,
+/// used when no samples or confidence bounds are available) is reported as
+/// a failed test case with the delta and decision rule in the failure
+/// message. Without a baseline every benchmark passes. Formatted metrics
+/// are attached as `<system-out>`.
+pub fn export_junit(
+    report: &BenchmarkReport,
+    baseline: Option<&BenchmarkReport>,
+    threshold: f64,
+) -> String {
+    let mut failures = HashMap::new();
+    if let Some(baseline) = baseline {
+        let opts = CompareOptions {
+            threshold,
+            ..Default::default()
+        };
+        let comparison = compare(baseline, report, &opts);
+        for (name, delta) in comparison.regressions() {
+            if delta.metric == "latency" {
+                failures.insert(
+                    name.to_string(),
+                    format!(
+                        "latency regressed by {:+.1}% ({} -> {}), {}",
+                        delta.change * 100.0,
+                        format_metric(&delta.baseline),
+                        format_metric(&delta.candidate),
+                        delta.rule
+                    ),
+                );
+            }
+        }
+    }
+
+    let meta = &report.metadata;
+    let suite = format!("{} {}", meta.implementation, meta.version);
+    let tests = report.benchmarks.len();
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<testsuites name=\"zkbench\" tests=\"{tests}\" failures=\"{}\">",
+        failures.len()
+    );
+    let _ = writeln!(
+        out,
+        "  <testsuite name=\"{}\" tests=\"{tests}\" failures=\"{}\" timestamp=\"{}\">",
+        escape(&suite),
+        failures.len(),
+        escape(&meta.timestamp)
+    );
+    out.push_str("    <properties>\n");
+    for (key, value) in [
+        ("commit_sha", meta.commit_sha.as_str()),
+        ("os", meta.platform.os.as_str()),
+        ("arch", meta.platform.arch.as_str()),
+    ] {
+        let _ = writeln!(
+            out,
+            "      <property name=\"{key}\" value=\"{}\"/>",
+            escape(value)
+        );
+    }
+    out.push_str("    </properties>\n");
+    for (name, result) in report.sorted_benchmarks() {
+        let _ = writeln!(
+            out,
+            "    <testcase classname=\"{}\" name=\"{}\">",
+            escape(&meta.implementation),
+            escape(name)
+        );
+        if let Some(message) = failures.get(name.as_str()) {
+            let _ = writeln!(
+                out,
+                "      <failure type=\"regression\" message=\"{}\"/>",
+                escape(message)
+            );
+        }
+        let metrics: Vec<String> = [
+            ("latency", &result.latency),
+            ("throughput", &result.throughput),
+            ("memory", &result.memory),
+        ]
+        .into_iter()
+        .filter_map(|(label, metric)| {
+            metric
+                .as_ref()
+                .map(|m| format!("{label}: {}", format_metric(m)))
+        })
+        .collect();
+        if !metrics.is_empty() {
+            let _ = writeln!(
+                out,
+                "      <system-out>{}</system-out>",
+                escape(&metrics.join("\n"))
+            );
+        }
+        out.push_str("    </testcase>\n");
+    }
+    out.push_str("  </testsuite>\n</testsuites>\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn without_baseline_everything_passes() {
+        let xml = export_junit(
+            &ReportBuilder::new()
+                .latencies_ms(&[("a", 1.0), ("b", 2.0)])
+                .build(),
+            None,
+            0.05,
+        );
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("tests=\"2\" failures=\"0\""));
+        assert_eq!(xml.matches("<testcase ").count(), 2);
+        assert!(!xml.contains("<failure"));
+        assert!(xml.contains("<system-out>latency: 1 ms</system-out>"));
+    }
+
+    #[test]
+    fn regressions_fail_with_delta() {
+        let baseline = ReportBuilder::new()
+            .latencies_ms(&[("a", 100.0), ("b", 100.0)])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies_ms(&[("a", 120.0), ("b", 101.0)])
+            .build();
+        let xml = export_junit(&candidate, Some(&baseline), 0.05);
+        assert!(xml.contains("failures=\"1\""));
+        assert!(xml.contains(
+            "message=\"latency regressed by +20.0% (100 ms -&gt; 120 ms), threshold 5.0%\""
+        ));
+    }
+
+    #[test]
+    fn names_are_escaped() {
+        let xml = export_junit(
+            &ReportBuilder::new()
+                .latencies_ms(&[("a<\"b\">", 1.0)])
+                .build(),
+            None,
+            0.05,
+        );
+        assert!(xml.contains("name=\"a&lt;&quot;b&quot;&gt;\""));
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Machine-readable exports of benchmark reports for third-party tools.
+
+#[cfg(feature = "arrow")]
+mod arrow;
+mod bmf;
+mod criterion_messages;
+mod csv;
+mod github_action_benchmark;
+mod junit;
+#[cfg(feature = "otel")]
+mod otel;
+#[cfg(feature = "polars")]
+mod polars;
+mod prometheus;
+mod shields;
+mod sql;
+mod vega_lite;
+
+#[cfg(feature = "arrow")]
+pub use arrow::{arrow_schema, to_record_batch, write_parquet};
+pub use bmf::export_bmf;
+pub(crate) use bmf::{Bmf, BmfMetric};
+pub use criterion_messages::CriterionMessageWriter;
+pub use csv::export_csv;
+pub use github_action_benchmark::{GithubBenchmarkEntry, export_github_action_benchmark};
+pub use junit::export_junit;
+#[cfg(feature = "otel")]
+pub use otel::{OtelError, export_otlp, otel_resource, record_otel_metrics};
+#[cfg(feature = "polars")]
+pub use polars::reports_to_dataframe;
+pub use prometheus::export_prometheus;
+pub use shields::{ShieldsBadge, shields_badge};
+#[cfg(feature = "sqlx")]
+pub use sql::write_report_postgres;
+pub use sql::{SQL_SCHEMA, report_id, sql_inserts};
+pub use vega_lite::{vega_lite_comparison, vega_lite_distribution};
@@ -0,0 +1,244 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! OpenTelemetry metrics export (feature `otel`).
+//!
+//! Every benchmark metric becomes a gauge data point tagged with the
+//! benchmark name, and the report's implementation, commit and platform
+//! become resource attributes, so observability pipelines can ingest
+//! benchmark results like any other telemetry.
+
+use std::fmt;
+
+use opentelemetry::KeyValue;
+use opentelemetry::metrics::{Meter, MeterProvider};
+use opentelemetry_otlp::{ExporterBuildError, MetricExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::error::OTelSdkError;
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+
+use crate::schema::{BenchmarkReport, MetricValue};
+use crate::units::{base_unit, to_base};
+
+/// Error returned by [`export_otlp`].
+#[derive(Debug)]
+pub enum OtelError {
+    /// The OTLP exporter could not be constructed (e.g. invalid endpoint).
+    Build(ExporterBuildError),
+    /// Flushing or shutting down the meter provider failed.
+    Export(OTelSdkError),
+}
+
+impl fmt::Display for OtelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Build(e) => write!(f, "failed to build OTLP exporter: {}", e),
+            Self::Export(e) => write!(f, "failed to export metrics: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for OtelError {}
+
+/// Builds the OpenTelemetry resource describing where a report came from.
+///
+/// Uses semantic-convention keys where one exists (`service.name`,
+/// `os.type`, `host.arch`, ...) and `zkbench.*` keys otherwise.
+pub fn otel_resource(report: &BenchmarkReport) -> Resource {
+    let meta = &report.metadata;
+    let platform = &meta.platform;
+    let mut attributes = vec![
+        KeyValue::new("service.name", meta.implementation.clone()),
+        KeyValue::new("service.version", meta.version.clone()),
+        KeyValue::new("vcs.ref.head.revision", meta.commit_sha.clone()),
+        KeyValue::new("os.type", platform.os.clone()),
+        KeyValue::new("host.arch", platform.arch.clone()),
+        KeyValue::new("zkbench.platform.cpu_count", platform.cpu_count as i64),
+    ];
+    if let Some(cpu) = &platform.cpu_vendor {
+        attributes.push(KeyValue::new("host.cpu.model.name", cpu.clone()));
+    }
+    if let Some(gpu) = &platform.gpu_vendor {
+        attributes.push(KeyValue::new("zkbench.platform.gpu", gpu.clone()));
+    }
+    Resource::builder_empty()
+        .with_attributes(attributes)
+        .build()
+}
+
+/// A single gauge observation derived from a report.
+struct DataPoint {
+    instrument: &'static str,
+    unit: &'static str,
+    value: f64,
+    attributes: Vec<KeyValue>,
+}
+
+/// Flattens a report into gauge observations. Latency is reported in
+/// seconds and memory in bytes, per OpenTelemetry unit conventions;
+/// metrics in units that cannot be converted are skipped.
+fn data_points(report: &BenchmarkReport) -> Vec<DataPoint> {
+    let mut points = Vec::new();
+    for (name, result) in report.sorted_benchmarks() {
+        let benchmark = KeyValue::new("zkbench.benchmark", name.clone());
+        if let Some(value) = result.latency.as_ref().and_then(seconds) {
+            points.push(DataPoint {
+                instrument: "zkbench.benchmark.latency",
+                unit: "s",
+                value,
+                attributes: vec![benchmark.clone()],
+            });
+        }
+        if let Some(value) = result.memory.as_ref().and_then(bytes) {
+            points.push(DataPoint {
+                instrument: "zkbench.benchmark.memory",
+                unit: "By",
+                value,
+                attributes: vec![benchmark.clone()],
+            });
+        }
+        if let Some(throughput) = &result.throughput {
+            points.push(DataPoint {
+                instrument: "zkbench.benchmark.throughput",
+                unit: "1/s",
+                value: throughput.value,
+                attributes: vec![
+                    benchmark.clone(),
+                    KeyValue::new("zkbench.unit", throughput.unit.clone()),
+                ],
+            });
+        }
+        if result.iterations > 0 {
+            points.push(DataPoint {
+                instrument: "zkbench.benchmark.iterations",
+                unit: "{iteration}",
+                value: result.iterations as f64,
+                attributes: vec![benchmark],
+            });
+        }
+    }
+    points
+}
+
+fn seconds(metric: &MetricValue) -> Option<f64> {
+    (base_unit(&metric.unit) == "ns").then(|| to_base(metric.value, &metric.unit) / 1e9)
+}
+
+fn bytes(metric: &MetricValue) -> Option<f64> {
+    (base_unit(&metric.unit) == "B").then(|| to_base(metric.value, &metric.unit))
+}
+
+/// Records every metric of a report as gauge observations on `meter`.
+///
+/// Use this to feed an application-managed meter provider; the resource
+/// attributes from [`otel_resource`] should be set on that provider.
+pub fn record_otel_metrics(report: &BenchmarkReport, meter: &Meter) {
+    for point in data_points(report) {
+        meter
+            .f64_gauge(point.instrument)
+            .with_unit(point.unit)
+            .build()
+            .record(point.value, &point.attributes);
+    }
+}
+
+/// Pushes a report to an OTLP/HTTP metrics endpoint
+/// (e.g. `http://localhost:4318/v1/metrics`) and waits for the export to
+/// complete.
+pub fn export_otlp(report: &BenchmarkReport, endpoint: &str) -> Result<(), OtelError> {
+    let exporter = MetricExporter::builder()
+        .with_http()
+        .with_endpoint(endpoint)
+        .build()
+        .map_err(OtelError::Build)?;
+    let provider = SdkMeterProvider::builder()
+        .with_resource(otel_resource(report))
+        .with_periodic_exporter(exporter)
+        .build();
+    record_otel_metrics(report, &provider.meter("zkbench"));
+    provider.force_flush().map_err(OtelError::Export)?;
+    provider.shutdown().map_err(OtelError::Export)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+    use opentelemetry::{Key, Value};
+    use std::collections::HashMap;
+
+    fn prove() -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::new(250.0, "ms")),
+            memory: Some(MetricValue::new(2.0, "KB")),
+            throughput: Some(MetricValue::new(4.0, "proofs/s")),
+            iterations: 8,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn resource_carries_platform() {
+        let report = ReportBuilder::new()
+            .implementation("my-prover")
+            .version("1.2.3")
+            .result("prove", prove())
+            .build();
+        let resource = otel_resource(&report);
+        assert_eq!(
+            resource.get(&Key::from_static_str("service.name")),
+            Some(Value::from("my-prover"))
+        );
+        assert_eq!(
+            resource.get(&Key::from_static_str("host.arch")),
+            Some(Value::from(report.metadata.platform.arch.clone()))
+        );
+    }
+
+    #[test]
+    fn data_points_use_otel_units() {
+        let points = data_points(
+            &ReportBuilder::new()
+                .implementation("my-prover")
+                .version("1.2.3")
+                .result("prove", prove())
+                .build(),
+        );
+        let by_name: HashMap<_, _> = points.iter().map(|p| (p.instrument, p)).collect();
+        assert!((by_name["zkbench.benchmark.latency"].value - 0.25).abs() < 1e-12);
+        assert_eq!(by_name["zkbench.benchmark.memory"].value, 2048.0);
+        assert_eq!(by_name["zkbench.benchmark.throughput"].attributes.len(), 2);
+        assert_eq!(by_name["zkbench.benchmark.iterations"].value, 8.0);
+    }
+
+    #[test]
+    fn unconvertible_units_are_skipped() {
+        let mut report = ReportBuilder::new()
+            .implementation("my-prover")
+            .version("1.2.3")
+            .result("prove", prove())
+            .build();
+        report.benchmarks.get_mut("prove").unwrap().latency = Some(MetricValue::new(3.0, "cycles"));
+        assert!(
+            data_points(&report)
+                .iter()
+                .all(|p| p.instrument != "zkbench.benchmark.latency")
+        );
+    }
+
+    #[test]
+    fn invalid_endpoint_is_a_build_error() {
+        assert!(matches!(
+            export_otlp(
+                &ReportBuilder::new()
+                    .implementation("my-prover")
+                    .version("1.2.3")
+                    .result("prove", prove())
+                    .build(),
+                "not a url"
+            ),
+            Err(OtelError::Build(_))
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Polars DataFrame conversion (feature `polars`).
+//!
+//! Produces the same flattened rows and columns as the Arrow export (see
+//! [`flatten_report`](crate::flatten_report)), ready for analysis in a
+//! notebook without any JSON handling.
+
+use polars::prelude::*;
+
+use crate::flatten::{MetricRow, flatten_report};
+use crate::schema::BenchmarkReport;
+
+impl BenchmarkReport {
+    /// Converts the report into a DataFrame with one row per metric.
+    ///
+    /// Columns match [`arrow_schema`](crate::arrow_schema); `timestamp` is
+    /// a UTC datetime in microseconds, null when not valid RFC 3339.
+    pub fn to_dataframe(&self) -> PolarsResult<DataFrame> {
+        reports_to_dataframe(std::slice::from_ref(self))
+    }
+}
+
+/// Converts several reports into one DataFrame, e.g. a history of nightly
+/// runs, with the columns of [`BenchmarkReport::to_dataframe`].
+pub fn reports_to_dataframe(reports: &[BenchmarkReport]) -> PolarsResult<DataFrame> {
+    let rows: Vec<MetricRow> = reports.iter().flat_map(flatten_report).collect();
+    let strings = |name: &str, f: fn(&MetricRow) -> Option<&str>| {
+        Column::new(name.into(), rows.iter().map(f).collect::<Vec<_>>())
+    };
+    let floats = |name: &str, f: fn(&MetricRow) -> Option<f64>| {
+        Column::new(name.into(), rows.iter().map(f).collect::<Vec<_>>())
+    };
+    let counts = |name: &str, f: fn(&MetricRow) -> usize| {
+        Column::new(
+            name.into(),
+            rows.iter().map(|r| f(r) as u64).collect::<Vec<_>>(),
+        )
+    };
+    let timestamps: Int64Chunked = rows
+        .iter()
+        .map(|r| {
+            chrono::DateTime::parse_from_rfc3339(&r.timestamp)
+                .ok()
+                .map(|t| t.timestamp_micros())
+        })
+        .collect();
+    let timestamps = timestamps
+        .with_name("timestamp".into())
+        .into_datetime(TimeUnit::Microseconds, Some(TimeZone::UTC))
+        .into_column();
+    DataFrame::new(vec![
+        strings("implementation", |r| Some(&r.implementation)),
+        strings("version", |r| Some(&r.version)),
+        strings("commit_sha", |r| Some(&r.commit_sha)),
+        timestamps,
+        strings("os", |r| Some(&r.os)),
+        strings("arch", |r| Some(&r.arch)),
+        counts("cpu_count", |r| r.cpu_count),
+        strings("cpu_vendor", |r| r.cpu_vendor.as_deref()),
+        strings("gpu_vendor", |r| r.gpu_vendor.as_deref()),
+        strings("benchmark", |r| Some(&r.benchmark)),
+        strings("metric", |r| Some(&r.metric)),
+        floats("value", |r| Some(r.value)),
+        strings("unit", |r| Some(&r.unit)),
+        floats("lower_value", |r| r.lower_value),
+        floats("upper_value", |r| r.upper_value),
+        counts("iterations", |r| r.iterations),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{BenchmarkResult, MetricValue};
+    use crate::testing::ReportBuilder;
+
+    fn prove() -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(10.0, "ms", 9.0, 11.0)),
+            memory: Some(MetricValue::new(3.0, "MB")),
+            iterations: 5,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn one_row_per_metric() {
+        let df = ReportBuilder::new()
+            .timestamp("2026-01-01T00:00:00Z")
+            .result("prove", prove())
+            .build()
+            .to_dataframe()
+            .unwrap();
+        assert_eq!(df.shape(), (2, 16));
+        assert_eq!(
+            df.column("metric").unwrap().str().unwrap().get(1),
+            Some("memory")
+        );
+        assert_eq!(
+            df.column("lower_value").unwrap().f64().unwrap().get(0),
+            Some(9.0)
+        );
+        assert_eq!(df.column("lower_value").unwrap().null_count(), 1);
+        assert!(matches!(
+            df.column("timestamp").unwrap().dtype(),
+            DataType::Datetime(TimeUnit::Microseconds, Some(_))
+        ));
+    }
+
+    #[test]
+    fn concatenates_reports() {
+        let df = reports_to_dataframe(&[
+            ReportBuilder::new()
+                .timestamp("2026-01-01T00:00:00Z")
+                .result("prove", prove())
+                .build(),
+            ReportBuilder::new()
+                .timestamp("not a date")
+                .result("prove", prove())
+                .build(),
+        ])
+        .unwrap();
+        assert_eq!(df.height(), 4);
+        assert_eq!(df.column("timestamp").unwrap().null_count(), 2);
+        assert_eq!(
+            df.column("iterations").unwrap().u64().unwrap().get(3),
+            Some(5)
+        );
+    }
+}
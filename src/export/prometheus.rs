@@ -0,0 +1,168 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Prometheus text exposition format.
+//!
+//! Written to a file for node_exporter's textfile collector, or pushed
+//! to a Pushgateway, this lets benchmark results be graphed and alerted
+//! on next to production metrics. Every metric is a gauge in base units,
+//! labelled with the benchmark and the report's identity:
+//!
+//! ```text
+//! # HELP zkbench_latency_seconds Benchmark latency.
+//! # TYPE zkbench_latency_seconds gauge
+//! zkbench_latency_seconds{implementation="my-impl",version="0.1.0",commit="abc123",os="linux",arch="x86_64",benchmark="prove"} 0.0015
+//! ```
+
+use std::fmt::Write;
+
+use crate::schema::{BenchmarkReport, MetricValue};
+use crate::units::{is_memory_unit, is_time_unit, to_base};
+
+/// Exports a report in the Prometheus text exposition format.
+///
+/// Latency is `zkbench_latency_seconds` and memory `zkbench_memory_bytes`
+/// (skipped when not recorded in a time or memory unit), throughput is
+/// `zkbench_throughput` and counters are `zkbench_counter`, the latter
+/// two with a `unit` label and counters with a `counter` label.
+pub fn export_prometheus(report: &BenchmarkReport) -> String {
+    let meta = &report.metadata;
+    let report_labels = [
+        ("implementation", meta.implementation.as_str()),
+        ("version", &meta.version),
+        ("commit", &meta.commit_sha),
+        ("os", &meta.platform.os),
+        ("arch", &meta.platform.arch),
+    ];
+    let benchmarks = report.sorted_benchmarks();
+    let mut out = String::new();
+
+    let mut family = |name: &str, help: &str, samples: Vec<(Vec<(&str, &str)>, f64)>| {
+        if samples.is_empty() {
+            return;
+        }
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} gauge");
+        for (labels, value) in samples {
+            let labels: Vec<_> = report_labels
+                .iter()
+                .chain(&labels)
+                .map(|(key, value)| format!("{key}=\"{}\"", escape(value)))
+                .collect();
+            let _ = writeln!(out, "{name}{{{}}} {}", labels.join(","), number(value));
+        }
+    };
+
+    let base = |metric: &MetricValue| to_base(metric.value, &metric.unit);
+    family(
+        "zkbench_latency_seconds",
+        "Benchmark latency.",
+        benchmarks
+            .iter()
+            .filter_map(|(name, r)| Some((name, r.latency.as_ref()?)))
+            .filter(|(_, m)| is_time_unit(&m.unit))
+            .map(|(name, m)| (vec![("benchmark", name.as_str())], base(m) / 1e9))
+            .collect(),
+    );
+    family(
+        "zkbench_memory_bytes",
+        "Benchmark memory usage.",
+        benchmarks
+            .iter()
+            .filter_map(|(name, r)| Some((name, r.memory.as_ref()?)))
+            .filter(|(_, m)| is_memory_unit(&m.unit))
+            .map(|(name, m)| (vec![("benchmark", name.as_str())], base(m)))
+            .collect(),
+    );
+    family(
+        "zkbench_throughput",
+        "Benchmark throughput.",
+        benchmarks
+            .iter()
+            .filter_map(|(name, r)| Some((name, r.throughput.as_ref()?)))
+            .map(|(name, m)| {
+                let labels = vec![("benchmark", name.as_str()), ("unit", m.unit.as_str())];
+                (labels, m.value)
+            })
+            .collect(),
+    );
+    let mut counters = Vec::new();
+    for (name, result) in &benchmarks {
+        let mut sorted: Vec<_> = result.counters.iter().collect();
+        sorted.sort_by_key(|(counter, _)| *counter);
+        for (counter, m) in sorted {
+            let labels = vec![
+                ("benchmark", name.as_str()),
+                ("counter", counter.as_str()),
+                ("unit", m.unit.as_str()),
+            ];
+            counters.push((labels, m.value));
+        }
+    }
+    family("zkbench_counter", "Benchmark counter.", counters);
+    out
+}
+
+/// Escapes a label value: backslash, double quote and line feed.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn number(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn writes_gauges_in_base_units() {
+        let report = ReportBuilder::new()
+            .version("1.0")
+            .commit("abc")
+            .metadata(|metadata| {
+                metadata.platform.os = "linux".to_string();
+                metadata.platform.arch = "x86_64".to_string();
+            })
+            .result(
+                "hash \"sha\"",
+                BenchmarkResult {
+                    latency: Some(MetricValue::new(1.5, "ms")),
+                    memory: Some(MetricValue::new(2.0, "KiB")),
+                    counters: [("rounds".to_string(), MetricValue::new(7.0, "rounds"))]
+                        .into_iter()
+                        .collect(),
+                    ..Default::default()
+                },
+            )
+            .build();
+        let labels = r#"implementation="impl",version="1.0",commit="abc",os="linux",arch="x86_64",benchmark="hash \"sha\"""#;
+        assert_eq!(
+            export_prometheus(&report),
+            format!(
+                "# HELP zkbench_latency_seconds Benchmark latency.\n\
+                 # TYPE zkbench_latency_seconds gauge\n\
+                 zkbench_latency_seconds{{{labels}}} 0.0015\n\
+                 # HELP zkbench_memory_bytes Benchmark memory usage.\n\
+                 # TYPE zkbench_memory_bytes gauge\n\
+                 zkbench_memory_bytes{{{labels}}} 2048\n\
+                 # HELP zkbench_counter Benchmark counter.\n\
+                 # TYPE zkbench_counter gauge\n\
+                 zkbench_counter{{{labels},counter=\"rounds\",unit=\"rounds\"}} 7\n"
+            )
+        );
+        assert_eq!(number(f64::NEG_INFINITY), "-Inf");
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! shields.io endpoint badges.
+//!
+//! A JSON file in the shields.io "endpoint" schema, published alongside
+//! reports, lets a README show a live badge such as `prove time: 1.3 s`
+//! via `https://img.shields.io/endpoint?url=<json-url>`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{BenchmarkReport, MetricKind};
+use crate::units::format_metric;
+
+/// A badge in the shields.io endpoint schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShieldsBadge {
+    /// Always 1, the only endpoint schema version.
+    pub schema_version: u32,
+    pub label: String,
+    pub message: String,
+    /// Any shields.io color name or hex code.
+    pub color: String,
+}
+
+impl ShieldsBadge {
+    /// Creates a blue badge.
+    pub fn new(label: &str, message: &str) -> Self {
+        Self {
+            schema_version: 1,
+            label: label.to_string(),
+            message: message.to_string(),
+            color: "blue".to_string(),
+        }
+    }
+
+    /// Replaces the badge color.
+    pub fn with_color(mut self, color: &str) -> Self {
+        self.color = color.to_string();
+        self
+    }
+
+    /// Serializes the badge as endpoint JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+/// Builds a badge showing one metric of one benchmark, scaled to a
+/// readable unit (`prove time: 1.3 s`). Returns `None` if the benchmark
+/// or the metric is missing from the report.
+pub fn shields_badge(
+    report: &BenchmarkReport,
+    benchmark: &str,
+    metric: MetricKind,
+    label: &str,
+) -> Option<ShieldsBadge> {
+    let value = metric.get(report.benchmarks.get(benchmark)?)?;
+    Some(ShieldsBadge::new(label, &format_metric(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::MetricValue;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn badge_from_report() {
+        let badge = shields_badge(
+            &ReportBuilder::new()
+                .latency("keccak/prove", MetricValue::new(1_300_000_000.0, "ns"))
+                .build(),
+            "keccak/prove",
+            MetricKind::Latency,
+            "prove time",
+        )
+        .unwrap()
+        .with_color("green");
+        assert_eq!(
+            badge.to_json().unwrap(),
+            r#"{"schemaVersion":1,"label":"prove time","message":"1.3 s","color":"green"}"#
+        );
+    }
+
+    #[test]
+    fn missing_benchmark_or_metric() {
+        assert!(
+            shields_badge(
+                &ReportBuilder::new()
+                    .latency("keccak/prove", MetricValue::new(1_300_000_000.0, "ns"))
+                    .build(),
+                "nope",
+                MetricKind::Latency,
+                "x"
+            )
+            .is_none()
+        );
+        assert!(
+            shields_badge(
+                &ReportBuilder::new()
+                    .latency("keccak/prove", MetricValue::new(1_300_000_000.0, "ns"))
+                    .build(),
+                "keccak/prove",
+                MetricKind::Memory,
+                "x"
+            )
+            .is_none()
+        );
+    }
+}
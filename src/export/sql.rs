@@ -0,0 +1,246 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Relational export of reports.
+//!
+//! [`SQL_SCHEMA`] is the published schema: one row per report, one per
+//! benchmark result and one per metric. [`sql_inserts`] renders a report
+//! as portable `INSERT` statements (PostgreSQL and SQLite), and with the
+//! `sqlx` feature [`write_report_postgres`] inserts it directly through a
+//! Postgres connection pool.
+//!
+//! Report ids are the reports'
+//! [`content_hash`](BenchmarkReport::content_hash), the key the stores
+//! deduplicate on, so inserting the same report twice, or its re-upload
+//! by a retried CI job, is a no-op (`ON CONFLICT DO NOTHING`).
+
+use std::fmt::Write;
+
+use crate::schema::BenchmarkReport;
+
+/// DDL for the relational report schema.
+pub const SQL_SCHEMA: &str = "\
+CREATE TABLE IF NOT EXISTS zkbench_reports (
+    id TEXT PRIMARY KEY,
+    implementation TEXT NOT NULL,
+    version TEXT NOT NULL,
+    commit_sha TEXT NOT NULL,
+    timestamp TEXT NOT NULL,
+    os TEXT NOT NULL,
+    arch TEXT NOT NULL,
+    cpu_count BIGINT NOT NULL,
+    cpu_vendor TEXT,
+    gpu_vendor TEXT
+);
+
+CREATE TABLE IF NOT EXISTS zkbench_results (
+    report_id TEXT NOT NULL REFERENCES zkbench_reports (id),
+    benchmark TEXT NOT NULL,
+    iterations BIGINT NOT NULL,
+    metadata TEXT,
+    PRIMARY KEY (report_id, benchmark)
+);
+
+CREATE TABLE IF NOT EXISTS zkbench_metrics (
+    report_id TEXT NOT NULL,
+    benchmark TEXT NOT NULL,
+    metric TEXT NOT NULL,
+    value DOUBLE PRECISION NOT NULL,
+    unit TEXT NOT NULL,
+    lower_value DOUBLE PRECISION,
+    upper_value DOUBLE PRECISION,
+    PRIMARY KEY (report_id, benchmark, metric),
+    FOREIGN KEY (report_id, benchmark) REFERENCES zkbench_results (report_id, benchmark)
+);
+";
+
+/// Returns the id a report is stored under: its
+/// [`content_hash`](BenchmarkReport::content_hash).
+pub fn report_id(report: &BenchmarkReport) -> String {
+    report.content_hash()
+}
+
+fn text(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn nullable_text(value: Option<&str>) -> String {
+    value.map_or_else(|| "NULL".to_string(), text)
+}
+
+fn real(value: Option<f64>) -> String {
+    match value {
+        Some(v) if v.is_finite() => format!("{:?}", v),
+        _ => "NULL".to_string(),
+    }
+}
+
+/// Renders a report as `INSERT` statements against [`SQL_SCHEMA`].
+///
+/// Metrics with a non-finite value are skipped, since SQL has no portable
+/// literal for them.
+pub fn sql_inserts(report: &BenchmarkReport) -> String {
+    let id = text(&report_id(report));
+    let meta = &report.metadata;
+    let platform = &meta.platform;
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "INSERT INTO zkbench_reports (id, implementation, version, commit_sha, timestamp, os, arch, cpu_count, cpu_vendor, gpu_vendor) \
+         VALUES ({id}, {}, {}, {}, {}, {}, {}, {}, {}, {}) ON CONFLICT DO NOTHING;",
+        text(&meta.implementation),
+        text(&meta.version),
+        text(&meta.commit_sha),
+        text(&meta.timestamp),
+        text(&platform.os),
+        text(&platform.arch),
+        platform.cpu_count,
+        nullable_text(platform.cpu_vendor.as_deref()),
+        nullable_text(platform.gpu_vendor.as_deref()),
+    );
+    for (name, result) in report.sorted_benchmarks() {
+        let metadata = (!result.metadata.is_empty())
+            .then(|| serde_json::to_value(&result.metadata).expect("JSON values serialize"))
+            .map(|v| v.to_string());
+        let _ = writeln!(
+            out,
+            "INSERT INTO zkbench_results (report_id, benchmark, iterations, metadata) \
+             VALUES ({id}, {}, {}, {}) ON CONFLICT DO NOTHING;",
+            text(name),
+            result.iterations,
+            nullable_text(metadata.as_deref()),
+        );
+        for (metric_name, metric) in result.metrics() {
+            if !metric.value.is_finite() {
+                continue;
+            }
+            let _ = writeln!(
+                out,
+                "INSERT INTO zkbench_metrics (report_id, benchmark, metric, value, unit, lower_value, upper_value) \
+                 VALUES ({id}, {}, {}, {}, {}, {}, {}) ON CONFLICT DO NOTHING;",
+                text(name),
+                text(metric_name),
+                real(Some(metric.value)),
+                text(&metric.unit),
+                real(metric.lower_value),
+                real(metric.upper_value),
+            );
+        }
+    }
+    out
+}
+
+/// Inserts a report into a Postgres database that has [`SQL_SCHEMA`]
+/// applied, in a single transaction. Returns the report id.
+#[cfg(feature = "sqlx")]
+pub async fn write_report_postgres(
+    pool: &sqlx::PgPool,
+    report: &BenchmarkReport,
+) -> Result<String, sqlx::Error> {
+    let id = report_id(report);
+    let meta = &report.metadata;
+    let platform = &meta.platform;
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "INSERT INTO zkbench_reports (id, implementation, version, commit_sha, timestamp, os, arch, cpu_count, cpu_vendor, gpu_vendor) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT DO NOTHING",
+    )
+    .bind(&id)
+    .bind(&meta.implementation)
+    .bind(&meta.version)
+    .bind(&meta.commit_sha)
+    .bind(&meta.timestamp)
+    .bind(&platform.os)
+    .bind(&platform.arch)
+    .bind(platform.cpu_count as i64)
+    .bind(&platform.cpu_vendor)
+    .bind(&platform.gpu_vendor)
+    .execute(&mut *tx)
+    .await?;
+    for (name, result) in report.sorted_benchmarks() {
+        let metadata = (!result.metadata.is_empty())
+            .then(|| serde_json::to_string(&result.metadata).expect("JSON values serialize"));
+        sqlx::query(
+            "INSERT INTO zkbench_results (report_id, benchmark, iterations, metadata) \
+             VALUES ($1, $2, $3, $4) ON CONFLICT DO NOTHING",
+        )
+        .bind(&id)
+        .bind(name)
+        .bind(result.iterations as i64)
+        .bind(metadata)
+        .execute(&mut *tx)
+        .await?;
+        for (metric_name, metric) in result.metrics() {
+            if !metric.value.is_finite() {
+                continue;
+            }
+            sqlx::query(
+                "INSERT INTO zkbench_metrics (report_id, benchmark, metric, value, unit, lower_value, upper_value) \
+                 VALUES ($1, $2, $3, $4, $5, $6, $7) ON CONFLICT DO NOTHING",
+            )
+            .bind(&id)
+            .bind(name)
+            .bind(metric_name)
+            .bind(metric.value)
+            .bind(&metric.unit)
+            .bind(metric.lower_value)
+            .bind(metric.upper_value)
+            .execute(&mut *tx)
+            .await?;
+        }
+    }
+    tx.commit().await?;
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{BenchmarkResult, MetricValue};
+    use crate::testing::ReportBuilder;
+
+    fn prove() -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(2.5, "s", 2.0, 3.0)),
+            memory: Some(MetricValue::new(f64::NAN, "MB")),
+            iterations: 3,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn inserts_escape_and_skip_non_finite() {
+        let sql = sql_inserts(
+            &ReportBuilder::new()
+                .implementation("o'neil")
+                .result("prove", prove())
+                .build(),
+        );
+        assert_eq!(sql.lines().count(), 3);
+        assert!(sql.contains("'o''neil'"));
+        assert!(sql.contains("'latency', 2.5, 's', 2.0, 3.0)"));
+        assert!(!sql.contains("memory"));
+        assert!(sql.lines().all(|l| l.ends_with("ON CONFLICT DO NOTHING;")));
+    }
+
+    #[test]
+    fn report_id_is_stable() {
+        let mut report = ReportBuilder::new()
+            .implementation("o'neil")
+            .result("prove", prove())
+            .build();
+        report.benchmarks.get_mut("prove").unwrap().memory = None;
+        let id = report_id(&report);
+        assert_eq!(id.len(), 64);
+        let reparsed = BenchmarkReport::from_json(&report.to_json(false).unwrap()).unwrap();
+        assert_eq!(report_id(&reparsed), id);
+        assert_eq!(id, report.content_hash());
+    }
+
+    #[test]
+    fn schema_declares_all_tables() {
+        for table in ["zkbench_reports", "zkbench_results", "zkbench_metrics"] {
+            assert!(SQL_SCHEMA.contains(&format!("CREATE TABLE IF NOT EXISTS {table}")));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Vega-Lite chart specifications.
+//!
+//! Specs embed their data inline (`data.values`), so a dashboard can hand
+//! them straight to `vega-embed` without any ETL step.
+
+use serde_json::{Value, json};
+
+use crate::schema::{BenchmarkReport, MetricKind};
+use crate::units::{base_unit, scale, to_base};
+
+const SCHEMA: &str = "https://vega.github.io/schema/vega-lite/v5.json";
+
+fn label(report: &BenchmarkReport) -> String {
+    format!(
+        "{} {}",
+        report.metadata.implementation, report.metadata.version
+    )
+}
+
+/// Builds a Vega-Lite spec of one benchmark's latency distribution across
+/// reports.
+///
+/// Reports with raw samples are drawn as box plots; reports without
+/// samples contribute their point estimate and confidence interval as an
+/// error bar. All values are converted to one display unit. Returns
+/// `None` if no report has a latency for `benchmark`.
+pub fn vega_lite_distribution(reports: &[BenchmarkReport], benchmark: &str) -> Option<Value> {
+    let latencies: Vec<_> = reports
+        .iter()
+        .filter_map(|r| {
+            let result = r.benchmarks.get(benchmark)?;
+            Some((label(r), result, result.latency.as_ref()?))
+        })
+        .collect();
+    let reference = &latencies.first()?.2.unit;
+    let max = latencies
+        .iter()
+        .map(|(_, _, l)| to_base(l.upper_value.unwrap_or(l.value), &l.unit))
+        .fold(f64::MIN, f64::max)
+        / to_base(1.0, reference);
+    let (_, factor, unit) = scale(max, reference);
+
+    let mut samples = Vec::new();
+    let mut estimates = Vec::new();
+    for (label, result, latency) in &latencies {
+        let to_unit = to_base(1.0, &latency.unit) / to_base(1.0, reference) * factor;
+        if result.samples.is_empty() {
+            estimates.push(json!({
+                "report": label,
+                "value": latency.value * to_unit,
+                "lower": latency.lower_value.unwrap_or(latency.value) * to_unit,
+                "upper": latency.upper_value.unwrap_or(latency.value) * to_unit,
+            }));
+        } else {
+            samples.extend(
+                result
+                    .samples
+                    .iter()
+                    .map(|s| json!({ "report": label, "value": s * to_unit })),
+            );
+        }
+    }
+
+    let x =
+        json!({ "field": "value", "type": "quantitative", "title": format!("latency ({})", unit) });
+    let y = json!({ "field": "report", "type": "nominal", "title": null });
+    let mut layers = Vec::new();
+    if !samples.is_empty() {
+        layers.push(json!({
+            "data": { "values": samples },
+            "mark": { "type": "boxplot", "extent": "min-max" },
+            "encoding": { "x": x, "y": y, "color": { "field": "report", "legend": null } },
+        }));
+    }
+    if !estimates.is_empty() {
+        layers.push(json!({
+            "data": { "values": estimates },
+            "layer": [
+                {
+                    "mark": "rule",
+                    "encoding": {
+                        "x": { "field": "lower", "type": "quantitative", "title": x["title"] },
+                        "x2": { "field": "upper" },
+                        "y": y,
+                    },
+                },
+                { "mark": "point", "encoding": { "x": x, "y": y } },
+            ],
+        }));
+    }
+    Some(json!({
+        "$schema": SCHEMA,
+        "title": benchmark,
+        "width": 600,
+        "layer": layers,
+    }))
+}
+
+/// Builds a Vega-Lite grouped bar chart comparing one metric across
+/// implementations, one group per benchmark and one bar per report, with
+/// error bars where confidence bounds exist.
+///
+/// Values are normalized to the smallest unit of their family (ns, B) and
+/// drawn on a log scale, since benchmarks in one suite routinely differ by
+/// orders of magnitude.
+pub fn vega_lite_comparison(reports: &[BenchmarkReport], metric: MetricKind) -> Value {
+    let mut values = Vec::new();
+    let mut unit: Option<String> = None;
+    for report in reports {
+        for (name, result) in report.sorted_benchmarks() {
+            let Some(m) = metric.get(result) else {
+                continue;
+            };
+            let base = |v: f64| to_base(v, &m.unit);
+            let unit = unit.get_or_insert_with(|| base_unit(&m.unit));
+            values.push(json!({
+                "benchmark": name,
+                "implementation": label(report),
+                "value": base(m.value),
+                "lower": base(m.lower_value.unwrap_or(m.value)),
+                "upper": base(m.upper_value.unwrap_or(m.value)),
+                "unit": unit,
+            }));
+        }
+    }
+    let title = match &unit {
+        Some(unit) => format!("{} ({})", metric, unit),
+        None => metric.to_string(),
+    };
+    json!({
+        "$schema": SCHEMA,
+        "title": format!("{} by implementation", metric),
+        "data": { "values": values },
+        "encoding": {
+            "x": { "field": "benchmark", "type": "nominal", "title": null },
+            "xOffset": { "field": "implementation" },
+            "color": { "field": "implementation", "type": "nominal" },
+        },
+        "layer": [
+            {
+                "mark": "bar",
+                "encoding": {
+                    "y": {
+                        "field": "value",
+                        "type": "quantitative",
+                        "title": title,
+                        "scale": { "type": "log" },
+                    },
+                    "tooltip": [
+                        { "field": "benchmark" },
+                        { "field": "implementation" },
+                        { "field": "value", "type": "quantitative" },
+                        { "field": "unit" },
+                    ],
+                },
+            },
+            {
+                "mark": { "type": "errorbar", "ticks": true },
+                "encoding": {
+                    "y": { "field": "lower", "type": "quantitative" },
+                    "y2": { "field": "upper" },
+                },
+            },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{BenchmarkResult, MetricValue};
+    use crate::testing::ReportBuilder;
+
+    fn prove(latency: MetricValue, samples: Vec<f64>) -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(latency),
+            samples,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn distribution_layers() {
+        let reports = [
+            ReportBuilder::new()
+                .implementation("a")
+                .result(
+                    "prove",
+                    prove(MetricValue::new(2.0, "ms"), vec![1.5, 2.0, 2.5]),
+                )
+                .build(),
+            ReportBuilder::new()
+                .implementation("b")
+                .result(
+                    "prove",
+                    prove(
+                        MetricValue::with_bounds(3000.0, "us", 2900.0, 3100.0),
+                        vec![],
+                    ),
+                )
+                .build(),
+        ];
+        let spec = vega_lite_distribution(&reports, "prove").unwrap();
+        assert_eq!(spec["$schema"], SCHEMA);
+        let layers = spec["layer"].as_array().unwrap();
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[0]["mark"]["type"], "boxplot");
+        assert_eq!(layers[0]["data"]["values"].as_array().unwrap().len(), 3);
+        let estimate = &layers[1]["data"]["values"][0];
+        assert_eq!(estimate["report"], "b 1.0.0");
+        assert!((estimate["value"].as_f64().unwrap() - 3.0).abs() < 1e-9);
+        assert!(vega_lite_distribution(&reports, "missing").is_none());
+    }
+
+    #[test]
+    fn comparison_normalizes_units() {
+        let reports = [
+            ReportBuilder::new()
+                .implementation("a")
+                .result("prove", prove(MetricValue::new(2.0, "ms"), vec![]))
+                .build(),
+            ReportBuilder::new()
+                .implementation("b")
+                .result("prove", prove(MetricValue::new(1500.0, "us"), vec![]))
+                .build(),
+        ];
+        let spec = vega_lite_comparison(&reports, MetricKind::Latency);
+        let values = spec["data"]["values"].as_array().unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0]["value"], 2_000_000.0);
+        assert_eq!(values[1]["value"], 1_500_000.0);
+        assert_eq!(values[1]["unit"], "ns");
+        assert_eq!(spec["layer"][0]["encoding"]["y"]["title"], "latency (ns)");
+    }
+}
@@ -0,0 +1,512 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fixture bundles: benchmark workloads distributed as one file.
+//!
+//! A [`FixtureBundle`] holds the inputs of a set of benchmarks together
+//! with their parameters, the hashes of their expected outputs and the
+//! seed they were generated from, so provers written in any language run
+//! byte-identical workloads and can be checked against the same answers.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::atomic::write_atomic;
+use crate::digest::Digest;
+use crate::hash::{HashAlgorithm, TestVectorError, TestVectorMismatch, digest_with};
+use crate::schema::TestVectors;
+
+/// Version of the fixture bundle format written by this crate.
+pub const FIXTURE_BUNDLE_VERSION: u32 = 1;
+
+/// First bytes of every fixture bundle.
+const MAGIC: &[u8; 8] = b"ZKBUNDLE";
+
+/// Error returned when reading, writing or checking a [`FixtureBundle`].
+#[derive(Debug)]
+pub enum FixtureError {
+    /// The bundle could not be read or written.
+    Io(io::Error),
+    /// The manifest is not valid JSON or does not match the format.
+    Parse(serde_json::Error),
+    /// The file is not a fixture bundle, or is truncated.
+    InvalidFormat(String),
+    /// The bundle was written by a newer version of the format.
+    UnsupportedVersion(u32),
+    /// The bundle is hashed with an algorithm this build does not
+    /// support.
+    UnsupportedAlgorithm(HashAlgorithm),
+    /// Two fixtures have the same name.
+    DuplicateName(String),
+    /// No fixture has this name.
+    NotFound(String),
+    /// An input does not hash to the digest in the manifest.
+    CorruptInput {
+        name: String,
+        expected: Digest,
+        actual: Digest,
+    },
+    /// The output does not match the expected one.
+    Mismatch {
+        name: String,
+        source: Box<TestVectorMismatch>,
+    },
+}
+
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(source) => write!(f, "fixture bundle: {}", source),
+            Self::Parse(source) => write!(f, "invalid fixture bundle manifest: {}", source),
+            Self::InvalidFormat(message) => write!(f, "invalid fixture bundle: {}", message),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "fixture bundle version {} is newer than the supported version {}",
+                version, FIXTURE_BUNDLE_VERSION
+            ),
+            Self::UnsupportedAlgorithm(algorithm) => {
+                write!(f, "{} support is not enabled", algorithm)
+            }
+            Self::DuplicateName(name) => write!(f, "duplicate fixture '{}'", name),
+            Self::NotFound(name) => write!(f, "no fixture named '{}'", name),
+            Self::CorruptInput {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "input of fixture '{}' hashes to {} instead of {}",
+                name, actual, expected
+            ),
+            Self::Mismatch { name, source } => write!(f, "fixture '{}': {}", name, source),
+        }
+    }
+}
+
+impl std::error::Error for FixtureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(source) => Some(source),
+            Self::Parse(source) => Some(source),
+            Self::Mismatch { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FixtureError {
+    fn from(source: io::Error) -> Self {
+        Self::Io(source)
+    }
+}
+
+/// One workload of a [`FixtureBundle`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixture {
+    pub name: String,
+    /// Raw input of the computation.
+    pub input: Vec<u8>,
+    /// Parameters of this workload, e.g. its size.
+    pub parameters: BTreeMap<String, Value>,
+    /// Hashes of the input and of the expected output.
+    pub expected: TestVectors,
+}
+
+impl Fixture {
+    /// Checks an output computed from [`input`](Self::input), returning
+    /// the test vectors to record in the report.
+    pub fn check(&self, output: &[u8]) -> Result<TestVectors, TestVectorError> {
+        self.expected.check(&self.input, output)
+    }
+}
+
+/// Named set of fixtures with shared parameters and generation seed.
+///
+/// # File format
+///
+/// The layout is simple enough to read without this crate:
+///
+/// | Bytes | Contents |
+/// |-------|----------|
+/// | 8 | The magic `ZKBUNDLE`. |
+/// | 8 | Length of the manifest, as a little-endian `u64`. |
+/// | manifest length | The manifest, UTF-8 JSON. |
+/// | rest | The inputs of the fixtures, concatenated in manifest order. |
+///
+/// The manifest holds `schema_version` ([`FIXTURE_BUNDLE_VERSION`]), the
+/// bundle `name`, the optional generation `seed`, the hash `algorithm`
+/// (SHA-256 when absent), bundle-wide `parameters` and the `fixtures`.
+/// Each fixture has a `name`, the `input_size` in bytes, the
+/// `input_hash` and `output_hash` as hex digests and its own
+/// `parameters`. Readers reject files with trailing bytes or inputs that
+/// do not match their hash.
+///
+/// # Example
+///
+/// ```no_run
+/// use zkbench::FixtureBundle;
+/// # fn prove(_: &[u8]) -> Vec<u8> { unimplemented!() }
+///
+/// let bundle = FixtureBundle::read("fixtures/msm.zkb")?;
+/// for fixture in bundle.fixtures() {
+///     let proof = prove(&fixture.input);
+///     let test_vectors = fixture.check(&proof)?;
+///     assert!(test_vectors.verified);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixtureBundle {
+    pub name: String,
+    /// Seed the inputs were generated from, so they can be regenerated.
+    pub seed: Option<u64>,
+    /// Parameters shared by all fixtures, e.g. the curve.
+    pub parameters: BTreeMap<String, Value>,
+    algorithm: HashAlgorithm,
+    fixtures: Vec<Fixture>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Manifest {
+    schema_version: u32,
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "HashAlgorithm::is_default")]
+    algorithm: HashAlgorithm,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    parameters: BTreeMap<String, Value>,
+    fixtures: Vec<FixtureEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FixtureEntry {
+    name: String,
+    input_size: u64,
+    input_hash: Digest,
+    output_hash: Digest,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    parameters: BTreeMap<String, Value>,
+}
+
+impl FixtureBundle {
+    /// Creates an empty bundle whose hashes are computed with
+    /// `algorithm`.
+    pub fn new(name: impl Into<String>, algorithm: HashAlgorithm) -> Self {
+        Self {
+            name: name.into(),
+            seed: None,
+            parameters: BTreeMap::new(),
+            algorithm,
+            fixtures: Vec::new(),
+        }
+    }
+
+    /// Sets the seed the inputs were generated from.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets a parameter shared by all fixtures.
+    pub fn with_parameter(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.parameters.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// The fixtures, in the order they were added.
+    pub fn fixtures(&self) -> &[Fixture] {
+        &self.fixtures
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Fixture> {
+        self.fixtures.iter().find(|fixture| fixture.name == name)
+    }
+
+    /// Adds a fixture with the output expected from `input`, returning it
+    /// so parameters can be set.
+    ///
+    /// # Panics
+    ///
+    /// If the algorithm is not [supported](HashAlgorithm::is_supported)
+    /// by this build.
+    pub fn add(
+        &mut self,
+        name: impl Into<String>,
+        input: Vec<u8>,
+        output: &[u8],
+    ) -> Result<&mut Fixture, FixtureError> {
+        let name = name.into();
+        if self.get(&name).is_some() {
+            return Err(FixtureError::DuplicateName(name));
+        }
+        let expected = TestVectors::compute(self.algorithm, &input, output);
+        self.fixtures.push(Fixture {
+            name,
+            input,
+            parameters: BTreeMap::new(),
+            expected,
+        });
+        Ok(self.fixtures.last_mut().expect("just pushed"))
+    }
+
+    /// Checks an output computed from the input of fixture `name`.
+    pub fn verify(&self, name: &str, output: &[u8]) -> Result<TestVectors, FixtureError> {
+        self.get(name)
+            .ok_or_else(|| FixtureError::NotFound(name.to_string()))?
+            .check(output)
+            .map_err(|e| match e {
+                TestVectorError::UnsupportedAlgorithm(algorithm) => {
+                    FixtureError::UnsupportedAlgorithm(algorithm)
+                }
+                TestVectorError::Mismatch(source) => FixtureError::Mismatch {
+                    name: name.to_string(),
+                    source,
+                },
+            })
+    }
+
+    /// Writes the bundle in the [file format](Self#file-format).
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        let manifest = Manifest {
+            schema_version: FIXTURE_BUNDLE_VERSION,
+            name: self.name.clone(),
+            seed: self.seed,
+            algorithm: self.algorithm,
+            parameters: self.parameters.clone(),
+            fixtures: self
+                .fixtures
+                .iter()
+                .map(|fixture| FixtureEntry {
+                    name: fixture.name.clone(),
+                    input_size: fixture.input.len() as u64,
+                    input_hash: fixture.expected.input_hash,
+                    output_hash: fixture.expected.output_hash,
+                    parameters: fixture.parameters.clone(),
+                })
+                .collect(),
+        };
+        let manifest = serde_json::to_vec(&manifest)?;
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(manifest.len() as u64).to_le_bytes())?;
+        writer.write_all(&manifest)?;
+        for fixture in &self.fixtures {
+            writer.write_all(&fixture.input)?;
+        }
+        writer.flush()
+    }
+
+    /// The bundle in the [file format](Self#file-format).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_to(&mut bytes)
+            .expect("writing to a Vec cannot fail");
+        bytes
+    }
+
+    /// Writes the bundle to `path`, replacing any existing file
+    /// atomically.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), FixtureError> {
+        Ok(write_atomic(path, self.to_bytes())?)
+    }
+
+    /// Reads a bundle, checking every input against its hash.
+    pub fn read_from(mut reader: impl Read) -> Result<Self, FixtureError> {
+        let mut header = [0; 16];
+        read_exact(&mut reader, &mut header, "header")?;
+        if &header[..8] != MAGIC {
+            return Err(FixtureError::InvalidFormat(
+                "not a fixture bundle".to_string(),
+            ));
+        }
+        let length = u64::from_le_bytes(header[8..].try_into().expect("8 bytes"));
+        let manifest = read_sized(&mut reader, length, "manifest")?;
+        let manifest: Manifest = serde_json::from_slice(&manifest).map_err(FixtureError::Parse)?;
+        if manifest.schema_version > FIXTURE_BUNDLE_VERSION {
+            return Err(FixtureError::UnsupportedVersion(manifest.schema_version));
+        }
+        if !manifest.algorithm.is_supported() {
+            return Err(FixtureError::UnsupportedAlgorithm(manifest.algorithm));
+        }
+
+        let mut names = HashSet::new();
+        let mut fixtures = Vec::with_capacity(manifest.fixtures.len());
+        for entry in manifest.fixtures {
+            if !names.insert(entry.name.clone()) {
+                return Err(FixtureError::DuplicateName(entry.name));
+            }
+            let input = read_sized(&mut reader, entry.input_size, &entry.name)?;
+            let input_hash = digest_with(manifest.algorithm, &input);
+            if input_hash != entry.input_hash {
+                return Err(FixtureError::CorruptInput {
+                    name: entry.name,
+                    expected: entry.input_hash,
+                    actual: input_hash,
+                });
+            }
+            fixtures.push(Fixture {
+                name: entry.name,
+                input,
+                parameters: entry.parameters,
+                expected: TestVectors {
+                    input_hash,
+                    output_hash: entry.output_hash,
+                    verified: false,
+                    algorithm: manifest.algorithm,
+                    hmac: None,
+                },
+            });
+        }
+        if reader.read(&mut [0])? != 0 {
+            return Err(FixtureError::InvalidFormat(
+                "trailing bytes after the last input".to_string(),
+            ));
+        }
+        Ok(Self {
+            name: manifest.name,
+            seed: manifest.seed,
+            parameters: manifest.parameters,
+            algorithm: manifest.algorithm,
+            fixtures,
+        })
+    }
+
+    /// Reads the bundle at `path`; see [`read_from`](Self::read_from).
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, FixtureError> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+fn read_exact(reader: &mut impl Read, buffer: &mut [u8], what: &str) -> Result<(), FixtureError> {
+    reader.read_exact(buffer).map_err(|err| match err.kind() {
+        io::ErrorKind::UnexpectedEof => truncated(what),
+        _ => FixtureError::Io(err),
+    })
+}
+
+/// Reads exactly `size` bytes without trusting `size` for the allocation.
+fn read_sized(reader: &mut impl Read, size: u64, what: &str) -> Result<Vec<u8>, FixtureError> {
+    let mut bytes = Vec::new();
+    reader.take(size).read_to_end(&mut bytes)?;
+    if (bytes.len() as u64) < size {
+        return Err(truncated(what));
+    }
+    Ok(bytes)
+}
+
+fn truncated(what: &str) -> FixtureError {
+    FixtureError::InvalidFormat(format!("truncated in {}", what))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle() -> FixtureBundle {
+        let mut bundle = FixtureBundle::new("msm", HashAlgorithm::Sha256)
+            .with_seed(42)
+            .with_parameter("curve", "bn254");
+        bundle
+            .add("small", vec![1, 2, 3], b"small proof")
+            .unwrap()
+            .parameters
+            .insert("log_size".to_string(), 10.into());
+        bundle.add("empty", Vec::new(), b"").unwrap();
+        bundle.add("large", vec![7; 5000], b"large proof").unwrap();
+        bundle
+    }
+
+    #[test]
+    fn round_trips_through_a_file() {
+        let bundle = bundle();
+        let path = std::env::temp_dir().join(format!("zkbench-fixture-{}.zkb", std::process::id()));
+        bundle.write(&path).unwrap();
+        let loaded = FixtureBundle::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, bundle);
+        assert_eq!(loaded.seed, Some(42));
+        assert_eq!(loaded.fixtures()[0].parameters["log_size"], 10);
+        let names: Vec<_> = loaded.fixtures().iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["small", "empty", "large"]);
+    }
+
+    #[test]
+    fn layout_is_readable_without_the_crate() {
+        let bytes = bundle().to_bytes();
+        assert_eq!(&bytes[..8], b"ZKBUNDLE");
+        let length = u64::from_le_bytes(bytes[8..16].try_into().unwrap()) as usize;
+        let manifest: Value = serde_json::from_slice(&bytes[16..16 + length]).unwrap();
+        assert_eq!(manifest["schema_version"], FIXTURE_BUNDLE_VERSION);
+        assert_eq!(manifest["parameters"]["curve"], "bn254");
+        assert!(manifest.get("algorithm").is_none());
+        let small = &manifest["fixtures"][0];
+        assert_eq!(small["input_size"], 3);
+        assert_eq!(small["input_hash"], crate::compute_hash(&[1, 2, 3]));
+        assert_eq!(&bytes[16 + length..16 + length + 3], [1, 2, 3]);
+        assert_eq!(bytes.len(), 16 + length + 3 + 5000);
+    }
+
+    #[test]
+    fn verifies_outputs() {
+        let bundle = bundle();
+        assert!(bundle.verify("small", b"small proof").unwrap().verified);
+        assert!(matches!(
+            bundle.verify("small", b"forged"),
+            Err(FixtureError::Mismatch { .. })
+        ));
+        assert!(matches!(
+            bundle.verify("missing", b""),
+            Err(FixtureError::NotFound(_))
+        ));
+        let mut bundle = bundle;
+        assert!(matches!(
+            bundle.add("small", Vec::new(), b""),
+            Err(FixtureError::DuplicateName(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_damaged_bundles() {
+        let bytes = bundle().to_bytes();
+        let read = |bytes: &[u8]| FixtureBundle::read_from(bytes).unwrap_err();
+
+        let mut flipped = bytes.clone();
+        *flipped.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            read(&flipped),
+            FixtureError::CorruptInput { name, .. } if name == "large"
+        ));
+        assert!(matches!(
+            read(&bytes[..bytes.len() - 1]),
+            FixtureError::InvalidFormat(message) if message == "truncated in large"
+        ));
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(read(&trailing), FixtureError::InvalidFormat(_)));
+        assert!(matches!(
+            read(b"PK\x03\x04"),
+            FixtureError::InvalidFormat(_)
+        ));
+        let mut other = bytes.clone();
+        other[..8].copy_from_slice(b"NOTABUND");
+        assert!(matches!(read(&other), FixtureError::InvalidFormat(_)));
+
+        let mut newer = bytes.clone();
+        let at = bytes
+            .windows(18)
+            .position(|window| window == br#""schema_version":1"#)
+            .unwrap();
+        newer[at + 17] = b'9';
+        assert!(matches!(read(&newer), FixtureError::UnsupportedVersion(9)));
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Flat, tabular view of benchmark reports.
+//!
+//! Reports are nested (metadata → benchmarks → metrics); columnar
+//! formats, SQL tables and dataframes want one record per observation.
+//! [`flatten_report`] produces one [`MetricRow`] per recorded metric,
+//! repeating the report and platform columns on every row.
+
+use serde::Serialize;
+
+use crate::schema::BenchmarkReport;
+
+/// One metric of one benchmark, together with its report context.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricRow {
+    pub implementation: String,
+    pub version: String,
+    pub commit_sha: String,
+    pub timestamp: String,
+    pub os: String,
+    pub arch: String,
+    pub cpu_count: usize,
+    pub cpu_vendor: Option<String>,
+    pub gpu_vendor: Option<String>,
+    pub benchmark: String,
+    /// Metric name, e.g. `latency` or a counter name.
+    pub metric: String,
+    pub value: f64,
+    pub unit: String,
+    pub lower_value: Option<f64>,
+    pub upper_value: Option<f64>,
+    pub iterations: usize,
+}
+
+/// Flattens a report into one row per (benchmark, metric), ordered by
+/// benchmark name and then metric.
+pub fn flatten_report(report: &BenchmarkReport) -> Vec<MetricRow> {
+    let meta = &report.metadata;
+    let platform = &meta.platform;
+    let mut rows = Vec::new();
+    for (name, result) in report.sorted_benchmarks() {
+        for (metric_name, metric) in result.metrics() {
+            rows.push(MetricRow {
+                implementation: meta.implementation.clone(),
+                version: meta.version.clone(),
+                commit_sha: meta.commit_sha.clone(),
+                timestamp: meta.timestamp.clone(),
+                os: platform.os.clone(),
+                arch: platform.arch.clone(),
+                cpu_count: platform.cpu_count,
+                cpu_vendor: platform.cpu_vendor.clone(),
+                gpu_vendor: platform.gpu_vendor.clone(),
+                benchmark: name.clone(),
+                metric: metric_name.to_string(),
+                value: metric.value,
+                unit: metric.unit.clone(),
+                lower_value: metric.lower_value,
+                upper_value: metric.upper_value,
+                iterations: result.iterations,
+            });
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{BenchmarkResult, MetricValue};
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn one_row_per_metric() {
+        let report = ReportBuilder::new()
+            .result(
+                "b",
+                BenchmarkResult {
+                    latency: Some(MetricValue::with_bounds(1.0, "ms", 0.9, 1.1)),
+                    throughput: Some(MetricValue::new(1000.0, "ops/s")),
+                    iterations: 5,
+                    ..Default::default()
+                },
+            )
+            .result(
+                "a",
+                BenchmarkResult {
+                    memory: Some(MetricValue::new(3.0, "MB")),
+                    ..Default::default()
+                },
+            )
+            .build();
+        let rows = flatten_report(&report);
+        let keys: Vec<_> = rows
+            .iter()
+            .map(|r| (r.benchmark.as_str(), r.metric.as_str()))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![("a", "memory"), ("b", "latency"), ("b", "throughput")]
+        );
+        assert_eq!(rows[1].lower_value, Some(0.9));
+        assert_eq!(rows[1].iterations, 5);
+        assert_eq!(rows[0].implementation, "impl");
+    }
+}
@@ -0,0 +1,555 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! CPU frequency and thermal throttling during the measured region.
+//!
+//! A laptop or a cramped CI runner that heats up mid-run clocks down, and
+//! the benchmark looks like a code regression. [`FrequencyMonitor`] is
+//! used like a [`Stopwatch`](crate::Stopwatch) around the measured region:
+//! while it runs, a background thread samples the effective frequency of
+//! the monitored cores, from the APERF/MPERF registers where
+//! `/dev/cpu/*/msr` is readable and from cpufreq's `scaling_cur_freq`
+//! otherwise. [`FrequencyMonitor::record`] stores the average and minimum
+//! and tags throttled runs with [`THROTTLED_TAG`].
+//!
+//! Only Linux is supported.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Default time between samples.
+pub const CPU_FREQUENCY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Tag added to results of throttled runs, see
+/// [`FrequencyStats::is_throttled`]; list it in the gate's `exempt_tags`
+/// to keep them from failing it.
+pub const THROTTLED_TAG: &str = "throttled";
+
+/// Directory of the per-core cpufreq and thermal throttle files.
+const SYSFS_CPU_DIR: &str = "/sys/devices/system/cpu";
+
+/// What a [`FrequencyMonitor`] measured.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrequencyStats {
+    samples: u64,
+    sum: f64,
+    min: f64,
+    base: Option<f64>,
+    throttle_events: Option<u64>,
+    /// Whether the samples are effective clocks from APERF/MPERF.
+    msr: bool,
+}
+
+impl FrequencyStats {
+    /// Number of samples taken.
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    /// Mean effective frequency in MHz, or zero without samples.
+    pub fn average(&self) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            self.sum / self.samples as f64
+        }
+    }
+
+    /// Lowest sampled frequency in MHz, or zero without samples.
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Base (non-turbo) frequency in MHz, when known.
+    pub fn base(&self) -> Option<f64> {
+        self.base
+    }
+
+    /// Times the cores or packages were thermally throttled, where the
+    /// kernel counts it.
+    pub fn throttle_events(&self) -> Option<u64> {
+        self.throttle_events
+    }
+
+    /// Whether the run was throttled: the kernel counted throttling
+    /// events, or APERF/MPERF saw a busy core below the base frequency,
+    /// which only thermal or power limits make it do. cpufreq's clock
+    /// drops below base whenever the governor clocks a core down, so it
+    /// is left to the throttling events.
+    pub fn is_throttled(&self) -> bool {
+        self.throttle_events.is_some_and(|events| events > 0)
+            || (self.msr
+                && self
+                    .base
+                    .is_some_and(|base| self.samples > 0 && self.min < base))
+    }
+
+    /// Records the stats in `result`: the average and minimum frequency as
+    /// the `cpu_frequency` and `cpu_frequency_min` counters in `MHz`, the
+    /// throttling events as `throttle_events`, and [`THROTTLED_TAG`] when
+    /// throttled.
+    pub fn record(&self, result: &mut BenchmarkResult) {
+        if self.samples > 0 {
+            result.counters.insert(
+                "cpu_frequency".to_string(),
+                MetricValue::new(self.average(), "MHz"),
+            );
+            result.counters.insert(
+                "cpu_frequency_min".to_string(),
+                MetricValue::new(self.min, "MHz"),
+            );
+        }
+        if let Some(events) = self.throttle_events {
+            result.counters.insert(
+                "throttle_events".to_string(),
+                MetricValue::new(events as f64, "count"),
+            );
+        }
+        if self.is_throttled() && !result.tags.iter().any(|tag| tag == THROTTLED_TAG) {
+            result.tags.push(THROTTLED_TAG.to_string());
+        }
+    }
+
+    fn add(&mut self, mhz: f64) {
+        self.min = if self.samples == 0 {
+            mhz
+        } else {
+            self.min.min(mhz)
+        };
+        self.samples += 1;
+        self.sum += mhz;
+    }
+
+    fn merge(&mut self, other: &FrequencyStats) {
+        if other.samples > 0 {
+            self.min = if self.samples == 0 {
+                other.min
+            } else {
+                self.min.min(other.min)
+            };
+            self.samples += other.samples;
+            self.sum += other.sum;
+        }
+        self.base = self.base.or(other.base);
+        self.msr |= other.msr;
+        self.throttle_events = match (self.throttle_events, other.throttle_events) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+    }
+}
+
+/// Where frequencies are read from.
+#[derive(Debug, Clone)]
+enum Source {
+    /// `/dev/cpu/<core>/msr`, scaled by the base frequency in MHz.
+    Msr { base: f64 },
+    /// `<dir>/cpu<core>/cpufreq/scaling_cur_freq`.
+    Sysfs,
+}
+
+/// Samples the effective CPU frequency in the background while running.
+///
+/// Like a [`Stopwatch`](crate::Stopwatch), stats accumulate across
+/// `start`/`stop` pairs until `reset`. Each sample is the mean over the
+/// monitored cores. cpufreq reports idle cores at their idle clock, so
+/// monitor the cores the benchmark runs on, e.g. those of a
+/// [`CpuPinning`](crate::CpuPinning); APERF/MPERF only count while a
+/// core is busy.
+///
+/// # Example
+///
+/// ```no_run
+/// use zkbench::{BenchmarkResult, FrequencyMonitor};
+/// # fn prove() {}
+///
+/// let mut frequency = FrequencyMonitor::new()?;
+/// frequency.start();
+/// prove();
+/// frequency.stop();
+/// let mut result = BenchmarkResult::default();
+/// frequency.record(&mut result);
+/// if frequency.stats().is_throttled() {
+///     eprintln!("throttled down to {} MHz", frequency.stats().min());
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct FrequencyMonitor {
+    dir: PathBuf,
+    source: Source,
+    cores: Vec<usize>,
+    interval: Duration,
+    running: Option<(Sender<()>, JoinHandle<FrequencyStats>)>,
+    stats: FrequencyStats,
+}
+
+impl FrequencyMonitor {
+    /// Monitors the cores the calling thread may run on, so a thread
+    /// pinned before creating the monitor gets its own cores, through
+    /// APERF/MPERF when the MSRs are readable and cpufreq otherwise.
+    /// Fails with [`io::ErrorKind::Unsupported`] where neither is
+    /// available.
+    pub fn new() -> io::Result<Self> {
+        if cfg!(not(target_os = "linux")) {
+            return Err(io::ErrorKind::Unsupported.into());
+        }
+        let mut monitor = Self::from_sysfs(SYSFS_CPU_DIR)?;
+        if let Ok(allowed) = crate::affinity::allowed_cores() {
+            monitor.cores.retain(|core| allowed.contains(core));
+        }
+        if let Some(base) = msr_base_frequency(&monitor.dir, &monitor.cores) {
+            monitor.source = Source::Msr { base };
+        } else if !monitor
+            .cores
+            .iter()
+            .any(|&core| cur_freq_path(&monitor.dir, core).exists())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "neither MSRs nor cpufreq are available",
+            ));
+        }
+        Ok(monitor)
+    }
+
+    /// Monitors all cores through cpufreq, with `dir` in place of
+    /// `/sys/devices/system/cpu`.
+    pub fn from_sysfs(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        let mut cores: Vec<usize> = fs::read_dir(&dir)?
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name();
+                let core = name.to_str()?.strip_prefix("cpu")?;
+                core.parse().ok()
+            })
+            .collect();
+        cores.sort_unstable();
+        Ok(Self {
+            dir,
+            source: Source::Sysfs,
+            cores,
+            interval: CPU_FREQUENCY_INTERVAL,
+            running: None,
+            stats: FrequencyStats::default(),
+        })
+    }
+
+    /// Monitors only `cores`.
+    pub fn with_cores(mut self, cores: &[usize]) -> Self {
+        self.cores = cores.to_vec();
+        self
+    }
+
+    /// Sets the time between samples, [`CPU_FREQUENCY_INTERVAL`] by
+    /// default.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The monitored cores.
+    pub fn cores(&self) -> &[usize] {
+        &self.cores
+    }
+
+    /// Starts sampling. Has no effect if already running.
+    pub fn start(&mut self) {
+        if self.running.is_some() {
+            return;
+        }
+        let (stop, stopped) = mpsc::channel();
+        let (dir, source, cores, interval) = (
+            self.dir.clone(),
+            self.source.clone(),
+            self.cores.clone(),
+            self.interval,
+        );
+        let thread = thread::spawn(move || {
+            sample(&dir, &source, &cores, interval, |timeout| {
+                !matches!(
+                    stopped.recv_timeout(timeout),
+                    Err(RecvTimeoutError::Timeout)
+                )
+            })
+        });
+        self.running = Some((stop, thread));
+    }
+
+    /// Takes a last sample, stops sampling and accumulates the stats. Has
+    /// no effect if already stopped.
+    pub fn stop(&mut self) {
+        if let Some((stop, thread)) = self.running.take() {
+            let _ = stop.send(());
+            let stats = thread.join().expect("frequency sampler panicked");
+            self.stats.merge(&stats);
+        }
+    }
+
+    /// Clears the stats and stops sampling.
+    pub fn reset(&mut self) {
+        self.stop();
+        self.stats = FrequencyStats::default();
+    }
+
+    /// The stats accumulated so far, not including a running
+    /// `start`/`stop` pair.
+    pub fn stats(&self) -> &FrequencyStats {
+        &self.stats
+    }
+
+    /// Records the stats in `result`; see [`FrequencyStats::record`].
+    pub fn record(&self, result: &mut BenchmarkResult) {
+        self.stats.record(result);
+    }
+}
+
+impl Drop for FrequencyMonitor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Samples the frequency of `cores` until `wait` returns true, which it
+/// does once stopped rather than after the timeout it is given, then once
+/// more.
+fn sample(
+    dir: &Path,
+    source: &Source,
+    cores: &[usize],
+    interval: Duration,
+    mut wait: impl FnMut(Duration) -> bool,
+) -> FrequencyStats {
+    let started_throttles = throttle_count(dir, cores);
+    let mut stats = FrequencyStats {
+        base: match source {
+            Source::Msr { base } => Some(*base),
+            Source::Sysfs => read_khz(&dir.join(format!(
+                "cpu{}/cpufreq/base_frequency",
+                cores.first().copied().unwrap_or(0)
+            )))
+            .map(|khz| khz / 1e3),
+        },
+        msr: matches!(source, Source::Msr { .. }),
+        ..Default::default()
+    };
+    let mut counters = MsrCounters::default();
+    let mut stopped = false;
+    loop {
+        let mhz = match source {
+            Source::Msr { base } => counters.sample(cores).map(|ratio| ratio * base),
+            Source::Sysfs => {
+                let khz: Vec<f64> = cores
+                    .iter()
+                    .filter_map(|&core| read_khz(&cur_freq_path(dir, core)))
+                    .collect();
+                (!khz.is_empty()).then(|| khz.iter().sum::<f64>() / khz.len() as f64 / 1e3)
+            }
+        };
+        if let Some(mhz) = mhz {
+            stats.add(mhz);
+        }
+        if stopped {
+            break;
+        }
+        stopped = wait(interval);
+    }
+    stats.throttle_events = started_throttles
+        .zip(throttle_count(dir, cores))
+        .map(|(started, stopped)| stopped.saturating_sub(started));
+    stats
+}
+
+fn cur_freq_path(dir: &Path, core: usize) -> PathBuf {
+    dir.join(format!("cpu{core}/cpufreq/scaling_cur_freq"))
+}
+
+fn read_khz(path: &Path) -> Option<f64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Sums the core and package throttling counts of `cores`, which only
+/// Intel CPUs report.
+fn throttle_count(dir: &Path, cores: &[usize]) -> Option<u64> {
+    let mut total = None;
+    for &core in cores {
+        for counter in ["core_throttle_count", "package_throttle_count"] {
+            let path = dir.join(format!("cpu{core}/thermal_throttle/{counter}"));
+            if let Some(count) = fs::read_to_string(path)
+                .ok()
+                .and_then(|count| count.trim().parse::<u64>().ok())
+            {
+                *total.get_or_insert(0) += count;
+            }
+        }
+    }
+    total
+}
+
+/// Last APERF and MPERF readings per core.
+#[derive(Debug, Default)]
+struct MsrCounters {
+    last: Vec<Option<(u64, u64)>>,
+}
+
+impl MsrCounters {
+    /// The mean ratio of actual to base clock cycles over the busy cores
+    /// since the last call, or `None` on the first call or with no core
+    /// busy.
+    fn sample(&mut self, cores: &[usize]) -> Option<f64> {
+        self.last.resize(cores.len(), None);
+        let mut ratios = Vec::new();
+        for (last, &core) in self.last.iter_mut().zip(cores) {
+            let now = read_msr(core, MSR_APERF).zip(read_msr(core, MSR_MPERF));
+            if let (Some((aperf, mperf)), Some((last_aperf, last_mperf))) = (now, *last) {
+                // MPERF only counts while the core is busy.
+                let busy = mperf.wrapping_sub(last_mperf);
+                if busy > 0 {
+                    ratios.push(aperf.wrapping_sub(last_aperf) as f64 / busy as f64);
+                }
+            }
+            *last = now;
+        }
+        (!ratios.is_empty()).then(|| ratios.iter().sum::<f64>() / ratios.len() as f64)
+    }
+}
+
+const MSR_MPERF: u64 = 0xe7;
+const MSR_APERF: u64 = 0xe8;
+const MSR_PLATFORM_INFO: u64 = 0xce;
+
+#[cfg(target_os = "linux")]
+fn read_msr(core: usize, register: u64) -> Option<u64> {
+    use std::os::unix::fs::FileExt;
+
+    let file = fs::File::open(format!("/dev/cpu/{core}/msr")).ok()?;
+    let mut value = [0; 8];
+    file.read_exact_at(&mut value, register).ok()?;
+    Some(u64::from_le_bytes(value))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_msr(_core: usize, _register: u64) -> Option<u64> {
+    None
+}
+
+/// The base frequency in MHz to scale APERF/MPERF with, if every core's
+/// counters are readable: from cpufreq, or else Intel's platform info
+/// register, whose bits 15:8 are the base ratio in 100 MHz steps.
+fn msr_base_frequency(dir: &Path, cores: &[usize]) -> Option<f64> {
+    let first = *cores.first()?;
+    if !cores
+        .iter()
+        .all(|&core| read_msr(core, MSR_APERF).is_some())
+    {
+        return None;
+    }
+    read_khz(&dir.join(format!("cpu{first}/cpufreq/base_frequency")))
+        .map(|khz| khz / 1e3)
+        .or_else(|| {
+            let ratio = (read_msr(first, MSR_PLATFORM_INFO)? >> 8) & 0xff;
+            (ratio > 0).then_some(ratio as f64 * 100.0)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: PathBuf, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn samples_cpufreq_and_throttling() {
+        let dir = std::env::temp_dir().join(format!("zkbench-cpufreq-{}", std::process::id()));
+        for (core, khz) in [(0, 3_000_000), (1, 2_000_000), (2, 800_000)] {
+            write(cur_freq_path(&dir, core), &format!("{khz}\n"));
+            write(
+                dir.join(format!("cpu{core}/thermal_throttle/core_throttle_count")),
+                "4\n",
+            );
+        }
+        write(dir.join("cpu0/cpufreq/base_frequency"), "2400000\n");
+
+        let mut monitor = FrequencyMonitor::from_sysfs(&dir)
+            .unwrap()
+            .with_cores(&[0, 1])
+            .with_interval(Duration::from_millis(5));
+        monitor.start();
+        thread::sleep(Duration::from_millis(30));
+        write(dir.join("cpu1/thermal_throttle/core_throttle_count"), "6\n");
+        monitor.stop();
+        let stats = *monitor.stats();
+        assert!(stats.samples() >= 2, "{stats:?}");
+        assert_eq!(stats.average(), 2500.0);
+        assert_eq!(stats.min(), 2500.0);
+        assert_eq!(stats.base(), Some(2400.0));
+        assert_eq!(stats.throttle_events(), Some(2));
+        assert!(stats.is_throttled());
+
+        let mut result = BenchmarkResult::default();
+        monitor.record(&mut result);
+        monitor.record(&mut result);
+        assert_eq!(
+            result.counters["cpu_frequency"],
+            MetricValue::new(2500.0, "MHz")
+        );
+        assert_eq!(result.counters["throttle_events"].value, 2.0);
+        assert_eq!(result.tags, [THROTTLED_TAG]);
+
+        // cpufreq below the base frequency without throttling events.
+        write(dir.join("cpu2/cpufreq/base_frequency"), "2400000\n");
+        let mut monitor = FrequencyMonitor::from_sysfs(&dir).unwrap().with_cores(&[2]);
+        assert_eq!(
+            FrequencyMonitor::from_sysfs(&dir).unwrap().cores(),
+            [0, 1, 2]
+        );
+        monitor.start();
+        monitor.stop();
+        assert_eq!(monitor.stats().throttle_events(), Some(0));
+        assert_eq!(monitor.stats().min(), 800.0);
+        assert!(!monitor.stats().is_throttled());
+        monitor.reset();
+        assert_eq!(*monitor.stats(), FrequencyStats::default());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn merges_and_flags_throttling() {
+        let mut stats = FrequencyStats {
+            base: Some(3000.0),
+            msr: true,
+            ..Default::default()
+        };
+        assert!(!stats.is_throttled());
+        stats.add(3500.0);
+        assert!(!stats.is_throttled());
+        let mut later = FrequencyStats {
+            throttle_events: Some(0),
+            ..Default::default()
+        };
+        later.add(2900.0);
+        stats.merge(&later);
+        assert_eq!(
+            (stats.samples(), stats.average(), stats.min()),
+            (2, 3200.0, 2900.0)
+        );
+        assert_eq!(stats.throttle_events(), Some(0));
+        assert!(stats.is_throttled());
+        stats.msr = false;
+        assert!(!stats.is_throttled());
+
+        let mut result = BenchmarkResult::default();
+        FrequencyStats::default().record(&mut result);
+        assert!(result.counters.is_empty() && result.tags.is_empty());
+    }
+}
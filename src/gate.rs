@@ -0,0 +1,296 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! CI gating on comparison results.
+//!
+//! [`gate`] turns a [`Comparison`] into a pass/fail decision with a
+//! machine-readable list of violations. Benchmarks carrying an exempt tag
+//! (e.g. `flaky` or `experimental`) are reported but never fail the gate.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::budget::BudgetStatus;
+use crate::compare::{CompareOptions, Comparison, DecisionRule, Verdict, compare};
+use crate::schema::BenchmarkReport;
+
+/// Failure policy of a gate, the `[gate]` table of
+/// [`ComparisonConfig`](crate::ComparisonConfig).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GatePolicy {
+    /// Benchmarks tagged with any of these (in the candidate, or in the
+    /// baseline for removed benchmarks) never fail the gate.
+    pub exempt_tags: Vec<String>,
+    /// Number of non-exempt violations tolerated before failing.
+    pub max_violations: usize,
+}
+
+impl GatePolicy {
+    /// Verdict for a comparison with a number of non-exempt violations.
+    /// A [refused](Verdict::Refused) comparison stays refused.
+    pub(crate) fn verdict(&self, comparison: &Comparison, violations: usize) -> Verdict {
+        if comparison.verdict == Verdict::Refused {
+            Verdict::Refused
+        } else if violations > self.max_violations {
+            Verdict::Fail
+        } else {
+            Verdict::Pass
+        }
+    }
+}
+
+/// Why a benchmark violates the gate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ViolationKind {
+    /// A metric regressed beyond its threshold or significance test.
+    Regression {
+        metric: String,
+        /// Relative change, `candidate / baseline - 1`.
+        change: f64,
+        rule: DecisionRule,
+    },
+    /// The benchmark is missing from the candidate and
+    /// [`CompareOptions::fail_on_removed`] is set.
+    Removed,
+}
+
+/// A benchmark that violates the gate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Violation {
+    pub benchmark: String,
+    #[serde(flatten)]
+    pub kind: ViolationKind,
+    /// Exempt tag that waived this violation, if any, or
+    /// [`BUDGET_EXEMPTION`](crate::BUDGET_EXEMPTION) when the regression
+    /// budget paid for it.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub exempted_by: Option<String>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ViolationKind::Regression {
+                metric,
+                change,
+                rule,
+            } => write!(
+                f,
+                "{}: {} regressed by {:+.1}% ({})",
+                self.benchmark,
+                metric,
+                change * 100.0,
+                rule
+            )?,
+            ViolationKind::Removed => write!(f, "{}: removed", self.benchmark)?,
+        }
+        match &self.exempted_by {
+            Some(tag) => write!(f, " [exempt: {}]", tag),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Outcome of [`gate`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateResult {
+    pub verdict: Verdict,
+    /// Violations that count against the gate.
+    pub violations: Vec<Violation>,
+    /// Violations waived by an exempt tag or the regression budget.
+    pub exempted: Vec<Violation>,
+    pub comparison: Comparison,
+    /// Regression budget standing, from
+    /// [`gate_with_budget`](crate::gate_with_budget).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub budget: Option<BudgetStatus>,
+}
+
+impl GateResult {
+    /// Whether the gate passed.
+    pub fn passed(&self) -> bool {
+        self.verdict == Verdict::Pass
+    }
+
+    /// Process exit code for CI: 0 on pass, 1 on failure.
+    pub fn exit_code(&self) -> i32 {
+        if self.passed() { 0 } else { 1 }
+    }
+}
+
+impl fmt::Display for GateResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self.verdict {
+            Verdict::Pass => "passed",
+            Verdict::Fail => "failed",
+            Verdict::Refused => "refused",
+        };
+        writeln!(
+            f,
+            "benchmark gate {}: {} violation(s), {} exempted",
+            status,
+            self.violations.len(),
+            self.exempted.len()
+        )?;
+        for violation in self.violations.iter().chain(&self.exempted) {
+            writeln!(f, "  {}", violation)?;
+        }
+        if let Some(budget) = &self.budget {
+            writeln!(f, "{}", budget)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares `candidate` against `baseline` and decides whether CI should
+/// fail.
+///
+/// Every regression found by [`compare`] under `opts` (including its
+/// per-metric and per-benchmark thresholds), and every removed benchmark
+/// when [`CompareOptions::fail_on_removed`] is set, is a violation. A
+/// violation is exempted when its benchmark carries one of
+/// `policy.exempt_tags`. The gate fails when more than
+/// `policy.max_violations` violations remain, and is
+/// [refused](Verdict::Refused) when the comparison was.
+pub fn gate(
+    baseline: &BenchmarkReport,
+    candidate: &BenchmarkReport,
+    opts: &CompareOptions,
+    policy: &GatePolicy,
+) -> GateResult {
+    let comparison = compare(baseline, candidate, opts);
+    let exempt_tag = |name: &str| {
+        let tags = candidate
+            .benchmarks
+            .get(name)
+            .or_else(|| baseline.benchmarks.get(name))
+            .map(|result| result.tags.as_slice())
+            .unwrap_or_default();
+        policy
+            .exempt_tags
+            .iter()
+            .find(|tag| tags.contains(tag))
+            .cloned()
+    };
+
+    let regressions = comparison.regressions().map(|(name, delta)| {
+        (
+            name,
+            ViolationKind::Regression {
+                metric: delta.metric.clone(),
+                change: delta.change,
+                rule: delta.rule,
+            },
+        )
+    });
+    let removed = comparison
+        .removed
+        .iter()
+        .filter(|_| opts.fail_on_removed)
+        .map(|name| (name.as_str(), ViolationKind::Removed));
+    let (exempted, violations): (Vec<Violation>, Vec<Violation>) = regressions
+        .chain(removed)
+        .map(|(name, kind)| Violation {
+            benchmark: name.to_string(),
+            kind,
+            exempted_by: exempt_tag(name),
+        })
+        .partition(|v| v.exempted_by.is_some());
+
+    GateResult {
+        verdict: policy.verdict(&comparison, violations.len()),
+        violations,
+        exempted,
+        comparison,
+        budget: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::guardrail::{PlatformAction, PlatformPolicy};
+
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn regressions_fail_unless_exempt() {
+        let baseline = ReportBuilder::new()
+            .latencies_ms(&[("fft", 100.0), ("msm", 100.0)])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies_ms(&[("fft", 120.0), ("msm", 150.0)])
+            .tags("msm", &["flaky"])
+            .build();
+        let policy = GatePolicy {
+            exempt_tags: vec!["flaky".to_string()],
+            ..Default::default()
+        };
+        let result = gate(&baseline, &candidate, &CompareOptions::default(), &policy);
+        assert!(!result.passed());
+        assert_eq!(result.exit_code(), 1);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].benchmark, "fft");
+        assert_eq!(result.exempted[0].exempted_by.as_deref(), Some("flaky"));
+        assert_eq!(
+            result.to_string(),
+            "benchmark gate failed: 1 violation(s), 1 exempted\n  \
+             fft: latency regressed by +20.0% (threshold 5.0%)\n  \
+             msm: latency regressed by +50.0% (threshold 5.0%) [exempt: flaky]\n"
+        );
+
+        let lenient = GatePolicy {
+            max_violations: 1,
+            ..policy
+        };
+        assert!(gate(&baseline, &candidate, &CompareOptions::default(), &lenient).passed());
+    }
+
+    #[test]
+    fn removed_benchmarks_use_baseline_tags() {
+        let baseline = ReportBuilder::new()
+            .latencies_ms(&[("old", 1.0), ("gone", 1.0)])
+            .tags("old", &["experimental"])
+            .build();
+        let candidate = ReportBuilder::new().build();
+        let opts = CompareOptions {
+            fail_on_removed: true,
+            ..Default::default()
+        };
+        let policy = GatePolicy {
+            exempt_tags: vec!["experimental".to_string()],
+            ..Default::default()
+        };
+        let result = gate(&baseline, &candidate, &opts, &policy);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].kind, ViolationKind::Removed);
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["verdict"], "fail");
+        assert_eq!(json["violations"][0]["kind"], "removed");
+        assert_eq!(json["violations"][0]["benchmark"], "gone");
+        assert_eq!(json["exempted"][0]["exempted_by"], "experimental");
+    }
+
+    #[test]
+    fn refused_comparisons_fail() {
+        let baseline = ReportBuilder::new().latencies_ms(&[("fft", 100.0)]).build();
+        let mut candidate = ReportBuilder::new().latencies_ms(&[("fft", 100.0)]).build();
+        candidate.metadata.platform.cpu_count = baseline.metadata.platform.cpu_count + 1;
+        let opts = CompareOptions {
+            platform: PlatformPolicy {
+                action: PlatformAction::Refuse,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = gate(&baseline, &candidate, &opts, &GatePolicy::default());
+        assert_eq!(result.verdict, Verdict::Refused);
+        assert!(result.violations.is_empty());
+        assert!(!result.passed());
+        assert_eq!(result.exit_code(), 1);
+        assert!(result.to_string().starts_with("benchmark gate refused: "));
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Grafana JSON datasource helpers.
+//!
+//! Request and response types for the `/search`, `/query` and
+//! `/annotations` endpoints of the Grafana JSON datasource plugin, with a
+//! [`GrafanaDatasource`] answering them from stored reports. A web server
+//! only has to deserialize the request body, call the matching method and
+//! serialize the result:
+//!
+//! ```ignore
+//! async fn query(Json(request): Json<GrafanaQueryRequest>) -> Json<Vec<GrafanaTimeSeries>> {
+//!     Json(GrafanaDatasource::new(&load_reports()).query(&request))
+//! }
+//! ```
+//!
+//! Targets are named `<implementation>:<benchmark>:<metric>`, e.g.
+//! `my-prover:msm/2^16:latency`, and values are reported in the base unit of
+//! their family (nanoseconds, bytes, `<x>/s`) so series stay comparable
+//! when reports record different units.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::BenchmarkReport;
+use crate::units::to_base;
+
+/// Body of a `/search` request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GrafanaSearchRequest {
+    /// Substring the returned targets must contain; empty matches all.
+    #[serde(default)]
+    pub target: String,
+}
+
+/// Time range of a query, as RFC 3339 timestamps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrafanaRange {
+    pub from: String,
+    pub to: String,
+}
+
+impl GrafanaRange {
+    /// Whether `timestamp` lies within the range. Unparseable bounds are
+    /// treated as open.
+    fn contains(&self, timestamp: DateTime<Utc>) -> bool {
+        let after = parse(&self.from).is_none_or(|from| timestamp >= from);
+        let before = parse(&self.to).is_none_or(|to| timestamp <= to);
+        after && before
+    }
+}
+
+/// One target of a `/query` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrafanaTarget {
+    pub target: String,
+    #[serde(default)]
+    pub ref_id: Option<String>,
+}
+
+/// Body of a `/query` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrafanaQueryRequest {
+    pub range: GrafanaRange,
+    pub targets: Vec<GrafanaTarget>,
+    /// When set, only the most recent points are returned.
+    #[serde(default)]
+    pub max_data_points: Option<usize>,
+}
+
+/// A time series in a `/query` response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrafanaTimeSeries {
+    pub target: String,
+    /// `[value, unix_millis]` pairs in ascending time order.
+    pub datapoints: Vec<(f64, i64)>,
+}
+
+/// Annotation settings sent with an `/annotations` request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GrafanaAnnotationQuery {
+    #[serde(default)]
+    pub name: String,
+    /// Implementation name the annotations are restricted to; empty
+    /// matches all.
+    #[serde(default)]
+    pub query: String,
+}
+
+/// Body of an `/annotations` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrafanaAnnotationRequest {
+    pub range: GrafanaRange,
+    #[serde(default)]
+    pub annotation: GrafanaAnnotationQuery,
+}
+
+/// An annotation in an `/annotations` response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrafanaAnnotation {
+    /// Unix time in milliseconds.
+    pub time: i64,
+    pub title: String,
+    pub text: String,
+    pub tags: Vec<String>,
+}
+
+/// Answers Grafana JSON datasource requests from a set of reports.
+pub struct GrafanaDatasource<'a> {
+    reports: Vec<(DateTime<Utc>, &'a BenchmarkReport)>,
+}
+
+impl<'a> GrafanaDatasource<'a> {
+    /// Indexes reports by timestamp. Reports without a valid RFC 3339
+    /// timestamp cannot be placed on a time axis and are ignored.
+    pub fn new(reports: &'a [BenchmarkReport]) -> Self {
+        let mut reports: Vec<_> = reports
+            .iter()
+            .filter_map(|report| Some((parse(&report.metadata.timestamp)?, report)))
+            .collect();
+        reports.sort_by_key(|(timestamp, _)| *timestamp);
+        Self { reports }
+    }
+
+    /// Lists all targets containing the requested substring, sorted.
+    pub fn search(&self, request: &GrafanaSearchRequest) -> Vec<String> {
+        let mut targets: Vec<String> = self
+            .reports
+            .iter()
+            .flat_map(|(_, report)| {
+                report
+                    .sorted_benchmarks()
+                    .into_iter()
+                    .flat_map(move |(name, result)| {
+                        result.metrics().into_iter().map(move |(metric, _)| {
+                            format!("{}:{}:{}", report.metadata.implementation, name, metric)
+                        })
+                    })
+            })
+            .filter(|target| target.contains(&request.target))
+            .collect();
+        targets.sort();
+        targets.dedup();
+        targets
+    }
+
+    /// Returns one time series per requested target, with a point for
+    /// every report in range that recorded the metric.
+    pub fn query(&self, request: &GrafanaQueryRequest) -> Vec<GrafanaTimeSeries> {
+        request
+            .targets
+            .iter()
+            .map(|target| {
+                let mut datapoints: Vec<(f64, i64)> = split_target(&target.target)
+                    .map(|(implementation, benchmark, metric)| {
+                        self.in_range(&request.range)
+                            .filter(|(_, report)| report.metadata.implementation == implementation)
+                            .filter_map(|(timestamp, report)| {
+                                let result = report.benchmarks.get(benchmark)?;
+                                let (_, value) = result
+                                    .metrics()
+                                    .into_iter()
+                                    .find(|(name, _)| *name == metric)?;
+                                Some((
+                                    to_base(value.value, &value.unit),
+                                    timestamp.timestamp_millis(),
+                                ))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                if let Some(max) = request.max_data_points {
+                    datapoints.drain(..datapoints.len().saturating_sub(max));
+                }
+                GrafanaTimeSeries {
+                    target: target.target.clone(),
+                    datapoints,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns an annotation for every report in range, titled with the
+    /// implementation and version and tagged with the implementation and
+    /// platform fingerprint.
+    pub fn annotations(&self, request: &GrafanaAnnotationRequest) -> Vec<GrafanaAnnotation> {
+        let filter = &request.annotation.query;
+        self.in_range(&request.range)
+            .filter(|(_, report)| filter.is_empty() || report.metadata.implementation == *filter)
+            .map(|(timestamp, report)| {
+                let metadata = &report.metadata;
+                GrafanaAnnotation {
+                    time: timestamp.timestamp_millis(),
+                    title: format!("{} {}", metadata.implementation, metadata.version),
+                    text: format!("commit {}", metadata.commit_sha),
+                    tags: vec![
+                        metadata.implementation.clone(),
+                        metadata.platform.fingerprint(),
+                    ],
+                }
+            })
+            .collect()
+    }
+
+    fn in_range<'b>(
+        &'b self,
+        range: &'b GrafanaRange,
+    ) -> impl Iterator<Item = (DateTime<Utc>, &'a BenchmarkReport)> + 'b {
+        self.reports
+            .iter()
+            .copied()
+            .filter(|(timestamp, _)| range.contains(*timestamp))
+    }
+}
+
+fn parse(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Splits `<implementation>:<benchmark>:<metric>`; the benchmark itself
+/// may contain colons.
+fn split_target(target: &str) -> Option<(&str, &str, &str)> {
+    let (implementation, rest) = target.split_once(':')?;
+    let (benchmark, metric) = rest.rsplit_once(':')?;
+    Some((implementation, benchmark, metric))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    fn reports() -> Vec<BenchmarkReport> {
+        [
+            ("a", "2026-01-02T00:00:00Z", 2.0),
+            ("a", "2026-01-01T00:00:00Z", 1.0),
+            ("b", "2026-01-01T12:00:00Z", 5.0),
+            ("a", "bogus", 9.0),
+        ]
+        .into_iter()
+        .map(|(implementation, timestamp, latency_ms)| {
+            ReportBuilder::new()
+                .implementation(implementation)
+                .timestamp(timestamp)
+                .commit("abc")
+                .latencies_ms(&[("msm/2^16", latency_ms)])
+                .build()
+        })
+        .collect()
+    }
+
+    fn range(from: &str, to: &str) -> GrafanaRange {
+        GrafanaRange {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    #[test]
+    fn search_lists_targets() {
+        let reports = reports();
+        let datasource = GrafanaDatasource::new(&reports);
+        let all = datasource.search(&GrafanaSearchRequest::default());
+        assert_eq!(all, ["a:msm/2^16:latency", "b:msm/2^16:latency"]);
+        let filtered = datasource.search(&GrafanaSearchRequest {
+            target: "b:".to_string(),
+        });
+        assert_eq!(filtered, ["b:msm/2^16:latency"]);
+    }
+
+    #[test]
+    fn query_returns_sorted_datapoints_in_base_units() {
+        let reports = reports();
+        let request: GrafanaQueryRequest = serde_json::from_str(
+            r#"{
+                "range": {"from": "2025-12-31T00:00:00Z", "to": "2026-01-03T00:00:00Z"},
+                "intervalMs": 60000,
+                "targets": [
+                    {"target": "a:msm/2^16:latency", "refId": "A"},
+                    {"target": "nonsense", "refId": "B"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let series = GrafanaDatasource::new(&reports).query(&request);
+        assert_eq!(series.len(), 2);
+        assert_eq!(
+            series[0].datapoints,
+            vec![(1e6, 1767225600000), (2e6, 1767312000000)]
+        );
+        assert!(series[1].datapoints.is_empty());
+        assert_eq!(
+            serde_json::to_string(&series[0]).unwrap(),
+            r#"{"target":"a:msm/2^16:latency","datapoints":[[1000000.0,1767225600000],[2000000.0,1767312000000]]}"#
+        );
+
+        let limited = GrafanaQueryRequest {
+            max_data_points: Some(1),
+            ..request
+        };
+        let series = GrafanaDatasource::new(&reports).query(&limited);
+        assert_eq!(series[0].datapoints, vec![(2e6, 1767312000000)]);
+    }
+
+    #[test]
+    fn annotations_respect_range_and_filter() {
+        let reports = reports();
+        let datasource = GrafanaDatasource::new(&reports);
+        let request = GrafanaAnnotationRequest {
+            range: range("2026-01-01T06:00:00Z", "2026-01-03T00:00:00Z"),
+            annotation: GrafanaAnnotationQuery::default(),
+        };
+        let annotations = datasource.annotations(&request);
+        assert_eq!(annotations.len(), 2);
+        assert_eq!(annotations[0].title, "b 1.0.0");
+        assert_eq!(annotations[0].text, "commit abc");
+
+        let request = GrafanaAnnotationRequest {
+            annotation: GrafanaAnnotationQuery {
+                name: "runs".to_string(),
+                query: "a".to_string(),
+            },
+            ..request
+        };
+        let annotations = datasource.annotations(&request);
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].time, 1767312000000);
+        assert_eq!(annotations[0].tags[0], "a");
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Platform guardrails for comparisons.
+//!
+//! Comparing a report from a 64-core server against one from a laptop
+//! produces regression alerts that say nothing about the code. A
+//! [`PlatformPolicy`] names the platform fields that must match; when
+//! they differ, [`compare`](crate::compare) either annotates the
+//! comparison with a [`PlatformMismatch`] or refuses to classify any
+//! change at all.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::normalize::Normalization;
+use crate::platform::Platform;
+
+/// A field of [`Platform`] a policy can require to match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlatformField {
+    Os,
+    Arch,
+    CpuCount,
+    /// The CPU vendor and model string.
+    CpuVendor,
+    GpuVendor,
+    CpuMhz,
+}
+
+impl PlatformField {
+    /// Value of the field, `None` when the platform does not record it.
+    fn value(&self, platform: &Platform) -> Option<String> {
+        match self {
+            Self::Os => Some(platform.os.clone()),
+            Self::Arch => Some(platform.arch.clone()),
+            Self::CpuCount => Some(platform.cpu_count.to_string()),
+            Self::CpuVendor => platform.cpu_vendor.clone(),
+            Self::GpuVendor => platform.gpu_vendor.clone(),
+            Self::CpuMhz => platform.cpu_mhz.map(|mhz| mhz.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for PlatformField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Os => "OS",
+            Self::Arch => "architecture",
+            Self::CpuCount => "core count",
+            Self::CpuVendor => "CPU",
+            Self::GpuVendor => "GPU",
+            Self::CpuMhz => "clock (MHz)",
+        })
+    }
+}
+
+/// What to do when the required fields differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlatformAction {
+    /// Classify changes as usual and record the mismatch.
+    #[default]
+    Annotate,
+    /// Classify no change: every delta is
+    /// [`Inconclusive`](crate::Classification::Inconclusive), and the
+    /// verdict is [`Refused`](crate::Verdict::Refused), which fails a
+    /// gate.
+    Refuse,
+}
+
+/// Platform fields two compared reports must share, the
+/// `[compare.platform]` table of `zkbench.toml`:
+///
+/// ```toml
+/// [compare.platform]
+/// require = ["cpu_vendor", "cpu_count", "arch"]
+/// action = "refuse"
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlatformPolicy {
+    /// Fields that must be equal. A field missing on either side is not
+    /// a difference.
+    pub require: Vec<PlatformField>,
+    pub action: PlatformAction,
+}
+
+impl Default for PlatformPolicy {
+    /// Annotates comparisons across CPU models or core counts.
+    fn default() -> Self {
+        Self {
+            require: vec![PlatformField::CpuVendor, PlatformField::CpuCount],
+            action: PlatformAction::Annotate,
+        }
+    }
+}
+
+impl PlatformPolicy {
+    /// Checks two platforms against the policy. Fields a scaling
+    /// `normalization` accounts for (the core count for
+    /// [`Normalization::PerCore`], the clock for
+    /// [`Normalization::PerGhz`]) may differ when it was applied.
+    pub fn check(
+        &self,
+        baseline: &Platform,
+        candidate: &Platform,
+        normalization: Option<Normalization>,
+    ) -> Option<PlatformMismatch> {
+        let differences: Vec<PlatformDifference> = self
+            .require
+            .iter()
+            .filter(|field| {
+                !matches!(
+                    (field, normalization),
+                    (PlatformField::CpuCount, Some(Normalization::PerCore))
+                        | (PlatformField::CpuMhz, Some(Normalization::PerGhz))
+                )
+            })
+            .filter_map(|field| {
+                let before = field.value(baseline)?;
+                let after = field.value(candidate)?;
+                (before != after).then_some(PlatformDifference {
+                    field: *field,
+                    baseline: before,
+                    candidate: after,
+                })
+            })
+            .collect();
+        (!differences.is_empty()).then_some(PlatformMismatch {
+            differences,
+            refused: self.action == PlatformAction::Refuse,
+        })
+    }
+}
+
+/// A required platform field that differs between two reports.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlatformDifference {
+    pub field: PlatformField,
+    pub baseline: String,
+    pub candidate: String,
+}
+
+/// Platform differences found by a [`PlatformPolicy`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlatformMismatch {
+    pub differences: Vec<PlatformDifference>,
+    /// Whether the comparison refused to classify changes.
+    pub refused: bool,
+}
+
+impl fmt::Display for PlatformMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("reports come from different platforms (")?;
+        for (i, d) in self.differences.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}: {} vs {}", d.field, d.baseline, d.candidate)?;
+        }
+        f.write_str(")")?;
+        if self.refused {
+            f.write_str("; changes were not classified")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn platform(cpu_count: usize, cpu_vendor: Option<&str>) -> Platform {
+        Platform {
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            cpu_count,
+            cpu_vendor: cpu_vendor.map(str::to_string),
+            gpu_vendor: None,
+            cpu_mhz: None,
+        }
+    }
+
+    #[test]
+    fn reports_required_differences() {
+        let server = platform(64, Some("AMD EPYC 9654"));
+        let laptop = platform(8, Some("Intel Core i7"));
+        let mismatch = PlatformPolicy::default()
+            .check(&server, &laptop, None)
+            .unwrap();
+        assert!(!mismatch.refused);
+        assert_eq!(mismatch.differences.len(), 2);
+        assert_eq!(
+            mismatch.to_string(),
+            "reports come from different platforms \
+             (CPU: AMD EPYC 9654 vs Intel Core i7, core count: 64 vs 8)"
+        );
+        assert!(
+            PlatformPolicy::default()
+                .check(&server, &server, None)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn missing_and_normalized_fields_are_not_differences() {
+        let policy = PlatformPolicy {
+            require: vec![PlatformField::CpuCount, PlatformField::CpuVendor],
+            action: PlatformAction::Refuse,
+        };
+        let a = platform(64, None);
+        let b = platform(8, Some("Intel Core i7"));
+        assert!(policy.check(&a, &b, Some(Normalization::PerCore)).is_none());
+        let mismatch = policy.check(&a, &b, None).unwrap();
+        assert!(mismatch.refused);
+        assert!(
+            mismatch
+                .to_string()
+                .ends_with("; changes were not classified")
+        );
+    }
+}
@@ -1,14 +1,658 @@
 // Copyright 2026 zkbench-rust Authors
 // SPDX-License-Identifier: Apache-2.0
 
-use sha2::{Digest, Sha256};
+//! Hashing of test vectors, proofs and reports.
+//!
+//! Digests are lowercase hex strings. SHA-256 is the default
+//! [`HashAlgorithm`]; BLAKE3 (feature `blake3`) hashes multi-gigabyte
+//! witness files several times faster, across all cores with the `rayon`
+//! feature, and Keccak-256 and SHA3-256 (feature `sha3`) give the digests
+//! EVM verifiers and Ethereum-style commitments expect. Poseidon and
+//! Poseidon2 (feature `poseidon`) digests can also be recomputed inside a
+//! circuit. Files and directory trees are hashed as streams, so fixture
+//! sets of any size can be referenced by digest, and structured inputs
+//! through their canonical JSON, so equal values hash equally in every
+//! implementation. For other algorithms, [`hash_file_chunked_with`]
+//! hashes large files in chunks that the `rayon` feature spreads across
+//! cores.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest as _, Sha256};
+
+use crate::digest::{Digest, DigestFormat};
+#[cfg(feature = "poseidon")]
+use crate::poseidon::PoseidonHasher;
+use crate::schema::{BenchmarkReport, BenchmarkResult, MetricValue, TestVectors};
+
+/// Hash function used for test-vector digests.
+///
+/// Every variant can be recorded in and read from reports; computing a
+/// BLAKE3 digest needs the `blake3` feature, Keccak-256 and SHA3-256
+/// digests the `sha3` feature, and Poseidon digests the `poseidon`
+/// feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    #[default]
+    Sha256,
+    Blake3,
+    /// Keccak-256 as used by Ethereum (`keccak256` in Solidity), which
+    /// differs from SHA3-256 only in padding.
+    Keccak256,
+    /// SHA3-256 as standardized in FIPS 202.
+    #[serde(rename = "sha3-256")]
+    Sha3_256,
+    /// Poseidon sponge over the BN254 scalar field. Bytes are packed into
+    /// field elements as described in [`HashAlgorithm::is_circuit_native`].
+    #[serde(rename = "poseidon-bn254")]
+    PoseidonBn254,
+    /// Poseidon2 sponge over the BN254 scalar field.
+    #[serde(rename = "poseidon2-bn254")]
+    Poseidon2Bn254,
+    /// Poseidon sponge over the BLS12-381 scalar field.
+    #[serde(rename = "poseidon-bls12-381")]
+    PoseidonBls12_381,
+    /// Poseidon2 sponge over the BLS12-381 scalar field.
+    #[serde(rename = "poseidon2-bls12-381")]
+    Poseidon2Bls12_381,
+}
+
+impl HashAlgorithm {
+    /// Every algorithm, in the order they were added.
+    pub const ALL: [HashAlgorithm; 8] = [
+        Self::Sha256,
+        Self::Blake3,
+        Self::Keccak256,
+        Self::Sha3_256,
+        Self::PoseidonBn254,
+        Self::Poseidon2Bn254,
+        Self::PoseidonBls12_381,
+        Self::Poseidon2Bls12_381,
+    ];
+
+    /// Name used in reports and on the command line.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+            Self::Keccak256 => "keccak256",
+            Self::Sha3_256 => "sha3-256",
+            Self::PoseidonBn254 => "poseidon-bn254",
+            Self::Poseidon2Bn254 => "poseidon2-bn254",
+            Self::PoseidonBls12_381 => "poseidon-bls12-381",
+            Self::Poseidon2Bls12_381 => "poseidon2-bls12-381",
+        }
+    }
+
+    /// Whether this build can compute the algorithm's digests.
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::Sha256 => true,
+            Self::Blake3 => cfg!(feature = "blake3"),
+            Self::Keccak256 | Self::Sha3_256 => cfg!(feature = "sha3"),
+            Self::PoseidonBn254
+            | Self::Poseidon2Bn254
+            | Self::PoseidonBls12_381
+            | Self::Poseidon2Bls12_381 => cfg!(feature = "poseidon"),
+        }
+    }
+
+    /// Whether the algorithm is an arithmetic hash a circuit can
+    /// recompute cheaply, i.e. Poseidon or Poseidon2.
+    ///
+    /// Their digests are defined over bytes so that they can stand in for
+    /// any other algorithm:
+    ///
+    /// 1. The input is followed by a `0x01` byte, then zero bytes up to a
+    ///    multiple of 31 bytes.
+    /// 2. Each 31-byte chunk is read as a little-endian integer, which is
+    ///    always a field element.
+    /// 3. The sponge state is three elements `[capacity, rate0, rate1]`,
+    ///    all zero initially. Elements are added to the rate two at a
+    ///    time, the last pair padded with a zero element, each pair
+    ///    followed by one permutation with t = 3, α = 5, 8 full and 56
+    ///    partial rounds and the round constants of the reference
+    ///    implementation.
+    /// 4. The digest is `rate0` as 32 big-endian bytes.
+    pub fn is_circuit_native(&self) -> bool {
+        matches!(
+            self,
+            Self::PoseidonBn254
+                | Self::Poseidon2Bn254
+                | Self::PoseidonBls12_381
+                | Self::Poseidon2Bls12_381
+        )
+    }
+
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.as_str() == s)
+            .ok_or_else(|| format!("unknown hash algorithm '{}'", s))
+    }
+}
 
 /// Computes the SHA-256 hash of raw bytes.
 ///
 /// Returns a 64-character lowercase hex string.
 pub fn compute_hash(data: &[u8]) -> String {
-    let digest = Sha256::digest(data);
-    format!("{:x}", digest)
+    compute_hash_with(HashAlgorithm::Sha256, data)
+}
+
+/// Computes the hash of raw bytes with `algorithm`.
+///
+/// Returns a 64-character lowercase hex string for every algorithm.
+///
+/// # Panics
+///
+/// If the algorithm is not [supported](HashAlgorithm::is_supported) by
+/// this build.
+pub fn compute_hash_with(algorithm: HashAlgorithm, data: &[u8]) -> String {
+    let mut hasher = Hasher::with_algorithm(algorithm);
+    hasher.update(data);
+    hasher.finalize()
+}
+
+/// Like [`compute_hash_with`], returning a [`Digest`].
+pub(crate) fn digest_with(algorithm: HashAlgorithm, data: &[u8]) -> Digest {
+    let mut hasher = Hasher::with_algorithm(algorithm);
+    hasher.update(data);
+    hasher.finalize_digest()
+}
+
+/// Incremental hasher for inputs too large to hold in memory.
+///
+/// Feeding data in chunks, or through [`io::Write`] (e.g. with
+/// [`io::copy`]), gives the same digest as [`compute_hash_with`] over
+/// the concatenated input.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{Hasher, compute_hash};
+///
+/// let mut hasher = Hasher::new();
+/// std::io::copy(&mut &b"abc"[..], &mut hasher).unwrap();
+/// assert_eq!(hasher.finalize(), compute_hash(b"abc"));
+/// ```
+#[derive(Clone)]
+pub struct Hasher {
+    state: HasherState,
+}
+
+#[derive(Clone)]
+enum HasherState {
+    Sha256(Sha256),
+    #[cfg(feature = "blake3")]
+    Blake3(Box<blake3::Hasher>),
+    #[cfg(feature = "sha3")]
+    Keccak256(sha3::Keccak256),
+    #[cfg(feature = "sha3")]
+    Sha3_256(sha3::Sha3_256),
+    #[cfg(feature = "poseidon")]
+    Poseidon(PoseidonHasher),
+}
+
+impl Hasher {
+    /// Creates a SHA-256 hasher.
+    pub fn new() -> Self {
+        Self::with_algorithm(HashAlgorithm::Sha256)
+    }
+
+    /// Creates a hasher for `algorithm`.
+    ///
+    /// # Panics
+    ///
+    /// If the algorithm is not [supported](HashAlgorithm::is_supported)
+    /// by this build; see [`try_with_algorithm`](Self::try_with_algorithm)
+    /// for algorithms read from reports or fixtures.
+    pub fn with_algorithm(algorithm: HashAlgorithm) -> Self {
+        Self::try_with_algorithm(algorithm)
+            .unwrap_or_else(|| panic!("{} support is not enabled in this build", algorithm))
+    }
+
+    /// Creates a hasher for `algorithm`, or `None` if the algorithm is
+    /// not [supported](HashAlgorithm::is_supported) by this build.
+    pub fn try_with_algorithm(algorithm: HashAlgorithm) -> Option<Self> {
+        let state = match algorithm {
+            HashAlgorithm::Sha256 => HasherState::Sha256(Sha256::new()),
+            #[cfg(feature = "blake3")]
+            HashAlgorithm::Blake3 => HasherState::Blake3(Box::default()),
+            #[cfg(feature = "sha3")]
+            HashAlgorithm::Keccak256 => HasherState::Keccak256(sha3::Keccak256::new()),
+            #[cfg(feature = "sha3")]
+            HashAlgorithm::Sha3_256 => HasherState::Sha3_256(sha3::Sha3_256::new()),
+            #[cfg(feature = "poseidon")]
+            _ => HasherState::Poseidon(PoseidonHasher::new(algorithm)?),
+            #[cfg(not(feature = "poseidon"))]
+            _ => return None,
+        };
+        Some(Self { state })
+    }
+
+    /// The algorithm this hasher computes.
+    pub fn algorithm(&self) -> HashAlgorithm {
+        match self.state {
+            HasherState::Sha256(_) => HashAlgorithm::Sha256,
+            #[cfg(feature = "blake3")]
+            HasherState::Blake3(_) => HashAlgorithm::Blake3,
+            #[cfg(feature = "sha3")]
+            HasherState::Keccak256(_) => HashAlgorithm::Keccak256,
+            #[cfg(feature = "sha3")]
+            HasherState::Sha3_256(_) => HashAlgorithm::Sha3_256,
+            #[cfg(feature = "poseidon")]
+            HasherState::Poseidon(ref hasher) => hasher.algorithm(),
+        }
+    }
+
+    /// Feeds `data` into the hash.
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            HasherState::Sha256(hasher) => sha2::Digest::update(hasher, data),
+            #[cfg(feature = "blake3")]
+            HasherState::Blake3(hasher) => {
+                // Splitting smaller inputs across threads costs more than
+                // it saves.
+                #[cfg(feature = "rayon")]
+                if data.len() >= 128 * 1024 {
+                    hasher.update_rayon(data);
+                    return;
+                }
+                hasher.update(data);
+            }
+            #[cfg(feature = "sha3")]
+            HasherState::Keccak256(hasher) => sha2::Digest::update(hasher, data),
+            #[cfg(feature = "sha3")]
+            HasherState::Sha3_256(hasher) => sha2::Digest::update(hasher, data),
+            #[cfg(feature = "poseidon")]
+            HasherState::Poseidon(hasher) => hasher.update(data),
+        }
+    }
+
+    /// Returns the digest of everything fed so far as lowercase hex.
+    pub fn finalize(self) -> String {
+        self.finalize_digest().to_string()
+    }
+
+    /// Returns the digest of everything fed so far.
+    pub fn finalize_digest(self) -> Digest {
+        let bytes = match self.state {
+            HasherState::Sha256(hasher) => hasher.finalize().into(),
+            #[cfg(feature = "blake3")]
+            HasherState::Blake3(hasher) => *hasher.finalize().as_bytes(),
+            #[cfg(feature = "sha3")]
+            HasherState::Keccak256(hasher) => hasher.finalize().into(),
+            #[cfg(feature = "sha3")]
+            HasherState::Sha3_256(hasher) => hasher.finalize().into(),
+            #[cfg(feature = "poseidon")]
+            HasherState::Poseidon(hasher) => hasher.finalize(),
+        };
+        Digest::from_bytes(bytes)
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Hasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hasher")
+            .field("algorithm", &self.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+impl io::Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Bytes read from a file at a time; enough for BLAKE3 to split each read
+/// across threads.
+const READ_BUFFER_SIZE: u64 = 1 << 20;
+
+/// Size of the chunks hashed by [`hash_file_chunked_with`].
+pub const HASH_CHUNK_SIZE: u64 = 4 << 20;
+
+/// Computes the SHA-256 hash of a file, streaming its contents.
+pub fn hash_file(path: impl AsRef<Path>) -> io::Result<String> {
+    hash_file_with(HashAlgorithm::Sha256, path)
+}
+
+/// Computes the hash of a file with `algorithm`, streaming its contents.
+///
+/// # Panics
+///
+/// If the algorithm is not [supported](HashAlgorithm::is_supported) by
+/// this build.
+pub fn hash_file_with(algorithm: HashAlgorithm, path: impl AsRef<Path>) -> io::Result<String> {
+    let mut hasher = Hasher::with_algorithm(algorithm);
+    copy_into(&mut File::open(path)?, &mut hasher)?;
+    Ok(hasher.finalize())
+}
+
+/// Computes the chunked SHA-256 hash of a file; see
+/// [`hash_file_chunked_with`].
+pub fn hash_file_chunked(path: impl AsRef<Path>) -> io::Result<String> {
+    hash_file_chunked_with(HashAlgorithm::Sha256, path)
+}
+
+/// Computes a chunked hash of a file, whose chunks are hashed in parallel
+/// with the `rayon` feature.
+///
+/// The file is split into chunks of [`HASH_CHUNK_SIZE`] bytes, the last
+/// one possibly shorter, and the result is the hash of the 32-byte
+/// digests of the chunks in order, followed by the file length as a
+/// little-endian `u64`. The digest differs from [`hash_file_with`], so
+/// fixtures must record which of the two they use; it does not depend on
+/// the feature or the number of threads. BLAKE3 parallelizes
+/// [`hash_file_with`] by itself, so this is for the other algorithms.
+/// One chunk per thread is held in memory.
+///
+/// # Panics
+///
+/// If the algorithm is not [supported](HashAlgorithm::is_supported) by
+/// this build.
+pub fn hash_file_chunked_with(
+    algorithm: HashAlgorithm,
+    path: impl AsRef<Path>,
+) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Hasher::with_algorithm(algorithm);
+    let mut chunks = vec![Vec::new(); parallelism()];
+    let mut len = 0;
+    loop {
+        let mut filled = 0;
+        for chunk in &mut chunks {
+            len += read_chunk(&mut file, chunk, HASH_CHUNK_SIZE)?;
+            if chunk.is_empty() {
+                break;
+            }
+            filled += 1;
+        }
+        for digest in digest_chunks(algorithm, &chunks[..filled]) {
+            hasher.update(digest.as_bytes());
+        }
+        if filled < chunks.len() {
+            break;
+        }
+    }
+    hasher.update(&len.to_le_bytes());
+    Ok(hasher.finalize())
+}
+
+#[cfg(feature = "rayon")]
+fn parallelism() -> usize {
+    rayon::current_num_threads()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn parallelism() -> usize {
+    1
+}
+
+#[cfg(feature = "rayon")]
+fn digest_chunks(algorithm: HashAlgorithm, chunks: &[Vec<u8>]) -> Vec<Digest> {
+    use rayon::prelude::*;
+    chunks
+        .par_iter()
+        .map(|chunk| digest_with(algorithm, chunk))
+        .collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn digest_chunks(algorithm: HashAlgorithm, chunks: &[Vec<u8>]) -> Vec<Digest> {
+    chunks
+        .iter()
+        .map(|chunk| digest_with(algorithm, chunk))
+        .collect()
+}
+
+/// Replaces the contents of `buffer` with the next `size` bytes of
+/// `reader`, or fewer at its end, and returns how many were read.
+fn read_chunk(reader: &mut impl Read, buffer: &mut Vec<u8>, size: u64) -> io::Result<u64> {
+    buffer.clear();
+    Ok(reader.take(size).read_to_end(buffer)? as u64)
+}
+
+/// Feeds the rest of `reader` into `hasher` in large reads.
+fn copy_into(reader: &mut impl Read, hasher: &mut Hasher) -> io::Result<()> {
+    let mut buffer = Vec::new();
+    while read_chunk(reader, &mut buffer, READ_BUFFER_SIZE)? > 0 {
+        hasher.update(&buffer);
+    }
+    Ok(())
+}
+
+/// Computes the SHA-256 tree hash of a directory; see [`hash_dir_with`].
+pub fn hash_dir(path: impl AsRef<Path>) -> io::Result<String> {
+    hash_dir_with(HashAlgorithm::Sha256, path)
+}
+
+/// Computes a deterministic tree hash of every file below a directory.
+///
+/// Files are ordered by their path relative to `path`, with `/` as the
+/// separator on every OS, compared byte-wise. For each file the hash is
+/// fed the relative path in UTF-8, a NUL byte, the content length as a
+/// little-endian `u64` and the contents. Only paths and contents count:
+/// empty directories, timestamps and permissions do not change the hash,
+/// so a fixture set hashes the same wherever it is checked out. Symbolic
+/// links are followed; a link back to a directory being walked is an
+/// [`InvalidData`](io::ErrorKind::InvalidData) error.
+///
+/// # Panics
+///
+/// If the algorithm is not [supported](HashAlgorithm::is_supported) by
+/// this build.
+pub fn hash_dir_with(algorithm: HashAlgorithm, path: impl AsRef<Path>) -> io::Result<String> {
+    let root = path.as_ref();
+    let mut files = Vec::new();
+    collect_files(root, root, &mut Vec::new(), &mut files)?;
+    files.sort();
+    let mut hasher = Hasher::with_algorithm(algorithm);
+    for relative in files {
+        let mut file = File::open(root.join(&relative))?;
+        hasher.update(relative.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(&file.metadata()?.len().to_le_bytes());
+        copy_into(&mut file, &mut hasher)?;
+    }
+    Ok(hasher.finalize())
+}
+
+/// Walks `dir`, tracking the canonical paths of the directories being
+/// walked in `ancestors` so a symbolic link cycle fails instead of
+/// recursing forever.
+fn collect_files(
+    root: &Path,
+    dir: &Path,
+    ancestors: &mut Vec<PathBuf>,
+    files: &mut Vec<String>,
+) -> io::Result<()> {
+    let canonical = fs::canonicalize(dir)?;
+    if ancestors.contains(&canonical) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} links back to {}", dir.display(), canonical.display()),
+        ));
+    }
+    ancestors.push(canonical);
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(root, &path, ancestors, files)?;
+            continue;
+        }
+        let relative = path.strip_prefix(root).expect("below the root");
+        let components: Option<Vec<&str>> = relative
+            .components()
+            .map(|c| c.as_os_str().to_str())
+            .collect();
+        let components = components.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not valid UTF-8", path.display()),
+            )
+        })?;
+        files.push(components.join("/"));
+    }
+    ancestors.pop();
+    Ok(())
+}
+
+/// Computes the SHA-256 hash of the [canonical JSON](to_canonical_json)
+/// of a value.
+///
+/// Two implementations hashing equal public inputs get the same digest
+/// regardless of field order or how their serializers format numbers.
+///
+/// # Example
+///
+/// ```
+/// use serde_json::json;
+/// use zkbench::hash_canonical;
+///
+/// let a = hash_canonical(&json!({ "log_n": 20, "curve": "bn254" })).unwrap();
+/// let b = hash_canonical(&json!({ "curve": "bn254", "log_n": 20.0 })).unwrap();
+/// assert_eq!(a, b);
+/// ```
+pub fn hash_canonical<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    hash_canonical_with(HashAlgorithm::Sha256, value)
+}
+
+/// Computes the hash of the [canonical JSON](to_canonical_json) of a
+/// value with `algorithm`.
+///
+/// # Panics
+///
+/// If the algorithm is not [supported](HashAlgorithm::is_supported) by
+/// this build.
+pub fn hash_canonical_with<T: Serialize + ?Sized>(
+    algorithm: HashAlgorithm,
+    value: &T,
+) -> Result<String, serde_json::Error> {
+    Ok(compute_hash_with(
+        algorithm,
+        to_canonical_json(value)?.as_bytes(),
+    ))
+}
+
+/// Serializes a value as canonical JSON following the JSON
+/// Canonicalization Scheme (RFC 8785): no whitespace, object keys sorted
+/// by their UTF-16 code units, strings escaped minimally and numbers
+/// formatted like ECMAScript's `Number.prototype.toString`, so `1.0` and
+/// `1` are both `1` and `1e30` is `1e+30`.
+///
+/// Unlike RFC 8785, integers beyond 2^53 are written exactly instead of
+/// being rounded to the nearest `f64`. Non-finite floats serialize as
+/// `null`, as with `serde_json`.
+pub fn to_canonical_json<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    let mut out = String::new();
+    write_canonical(&serde_json::to_value(value)?, &mut out);
+    Ok(out)
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Null | Value::Bool(_) | Value::String(_) => out.push_str(&value.to_string()),
+        Value::Number(number) => match (number.as_i64(), number.as_u64(), number.as_f64()) {
+            (Some(n), _, _) => out.push_str(&n.to_string()),
+            (_, Some(n), _) => out.push_str(&n.to_string()),
+            (_, _, Some(n)) => out.push_str(&format_number(n)),
+            _ => out.push_str(&number.to_string()),
+        },
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(item, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// Formats a finite `f64` like ECMAScript's `Number.prototype.toString`:
+/// the shortest digits that round-trip, in positional notation for
+/// magnitudes in [1e-6, 1e21) and exponential notation otherwise.
+fn format_number(n: f64) -> String {
+    if n == 0.0 {
+        return "0".to_string();
+    }
+    if n < 0.0 {
+        return format!("-{}", format_number(-n));
+    }
+    // `{:e}` yields the shortest round-trip digits, e.g. `1.2345e-7`.
+    let scientific = format!("{n:e}");
+    let (mantissa, exponent) = scientific.split_once('e').expect("`{:e}` has an exponent");
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().expect("integer exponent") + 1;
+    if k <= n && n <= 21 {
+        format!("{digits}{}", "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n as usize);
+        format!("{int}.{frac}")
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat(-n as usize))
+    } else {
+        let sign = if n - 1 < 0 { '-' } else { '+' };
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() {
+            String::new()
+        } else {
+            format!(".{rest}")
+        };
+        format!("{first}{rest}e{sign}{}", (n - 1).abs())
+    }
 }
 
 /// Computes the SHA-256 hash of a typed slice by re-interpreting it as
@@ -29,9 +673,338 @@ pub fn compute_array_hash<T: bytemuck::Pod>(data: &[T]) -> String {
     compute_hash(bytemuck::cast_slice(data))
 }
 
+impl BenchmarkReport {
+    /// Canonical content hash: the SHA-256 of the report's
+    /// [canonical JSON](to_canonical_json) with the metadata's
+    /// `timestamp`, `run_id` and `run_url` left out.
+    ///
+    /// A CI job that is retried re-uploads the same results under a new
+    /// timestamp and run; the content hash identifies such reports as
+    /// duplicates, while any other difference in metadata or
+    /// measurements changes it.
+    pub fn content_hash(&self) -> String {
+        let mut value = serde_json::to_value(self).expect("reports always serialize");
+        if let Some(metadata) = value.get_mut("metadata").and_then(|m| m.as_object_mut()) {
+            for key in ["timestamp", "run_id", "run_url"] {
+                metadata.remove(key);
+            }
+        }
+        hash_canonical(&value).expect("reports always serialize")
+    }
+}
+
+/// Counter [`record_proof`] stores the proof size in, in bytes.
+pub const PROOF_SIZE_COUNTER: &str = "proof_size";
+
+/// Key of the result [`metadata`](BenchmarkResult::metadata) that
+/// [`record_proof`] stores the proof hash under.
+pub const PROOF_HASH_KEY: &str = "proof_hash";
+
+/// Environment variable holding the secret key of
+/// [`TestVectors::authenticate_from_env`].
+pub const VECTOR_KEY_ENV: &str = "ZKBENCH_VECTOR_KEY";
+
+/// Records the size and hash of a serialized proof in `result`: the
+/// [`PROOF_SIZE_COUNTER`] counter, and the SHA-256 of the bytes in
+/// `sha256:<hex>` form under [`PROOF_HASH_KEY`].
+///
+/// Deterministic provers produce the same proof for the same inputs, so
+/// equal hashes across runs or implementations show identical proofs.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{BenchmarkResult, record_proof};
+///
+/// let mut result = BenchmarkResult::default();
+/// record_proof(&mut result, b"proof");
+/// assert_eq!(result.counters["proof_size"].value, 5.0);
+/// assert!(result.proof_hash().unwrap().starts_with("sha256:"));
+/// ```
+pub fn record_proof(result: &mut BenchmarkResult, proof_bytes: &[u8]) {
+    result.counters.insert(
+        PROOF_SIZE_COUNTER.to_string(),
+        MetricValue::new(proof_bytes.len() as f64, "B"),
+    );
+    result.metadata.insert(
+        PROOF_HASH_KEY.to_string(),
+        Value::String(format!("sha256:{}", compute_hash(proof_bytes))),
+    );
+}
+
+impl BenchmarkResult {
+    /// The proof hash stored by [`record_proof`].
+    pub fn proof_hash(&self) -> Option<&str> {
+        self.metadata.get(PROOF_HASH_KEY)?.as_str()
+    }
+}
+
+impl TestVectors {
+    /// Creates unverified test vectors from SHA-256 digests, e.g. the
+    /// expected hashes of a fixture.
+    pub fn new(input_hash: Digest, output_hash: Digest) -> Self {
+        Self {
+            input_hash,
+            output_hash,
+            verified: false,
+            algorithm: HashAlgorithm::Sha256,
+            hmac: None,
+        }
+    }
+
+    /// Hashes `input` and `output` with `algorithm`, unverified.
+    ///
+    /// # Panics
+    ///
+    /// If the algorithm is not [supported](HashAlgorithm::is_supported)
+    /// by this build.
+    pub fn compute(algorithm: HashAlgorithm, input: &[u8], output: &[u8]) -> Self {
+        Self {
+            input_hash: digest_with(algorithm, input),
+            output_hash: digest_with(algorithm, output),
+            verified: false,
+            algorithm,
+            hmac: None,
+        }
+    }
+
+    /// Hashes a run's `input` and `output` with this algorithm and
+    /// returns the result to record in the report: the computed hashes,
+    /// `verified` when both equal the expected ones in `self`.
+    ///
+    /// Fails with [`TestVectorError::UnsupportedAlgorithm`] if the
+    /// algorithm, e.g. one named by a report from another build, is not
+    /// [supported](HashAlgorithm::is_supported) by this build.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zkbench::{HashAlgorithm, TestVectors};
+    ///
+    /// let expected = TestVectors::compute(HashAlgorithm::Sha256, b"witness", b"proof");
+    /// assert!(expected.verify(b"witness", b"proof")?.verified);
+    /// assert!(!expected.verify(b"witness", b"forged")?.verified);
+    /// # Ok::<(), zkbench::TestVectorError>(())
+    /// ```
+    pub fn verify(&self, input: &[u8], output: &[u8]) -> Result<TestVectors, TestVectorError> {
+        if !self.algorithm.is_supported() {
+            return Err(TestVectorError::UnsupportedAlgorithm(self.algorithm));
+        }
+        let mut actual = Self::compute(self.algorithm, input, output);
+        actual.verified =
+            self.input_hash == actual.input_hash && self.output_hash == actual.output_hash;
+        Ok(actual)
+    }
+
+    /// Like [`verify`](Self::verify), but fails with the expected and
+    /// computed hashes when they differ.
+    pub fn check(&self, input: &[u8], output: &[u8]) -> Result<TestVectors, TestVectorError> {
+        let actual = self.verify(input, output)?;
+        if actual.verified {
+            Ok(actual)
+        } else {
+            Err(TestVectorError::Mismatch(Box::new(TestVectorMismatch {
+                expected: self.clone(),
+                actual,
+            })))
+        }
+    }
+
+    /// Sets [`hmac`](Self::hmac) to the HMAC-SHA256 of the digests under
+    /// `key`.
+    ///
+    /// A leaderboard that runs submissions against its official fixtures
+    /// with a secret key can later tell, with
+    /// [`is_authentic`](Self::is_authentic), which results came from
+    /// those fixtures: without the key, the tag of self-generated inputs
+    /// cannot be computed. The tag covers
+    /// `"<algorithm>:<input_hash>:<output_hash>"` with lowercase digests,
+    /// the encoding of the leaves of a [`MerkleTree`](crate::MerkleTree),
+    /// but not `verified`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zkbench::{HashAlgorithm, TestVectors};
+    ///
+    /// let mut vectors = TestVectors::compute(HashAlgorithm::Sha256, b"witness", b"proof");
+    /// vectors.authenticate(b"leaderboard secret");
+    /// assert!(vectors.is_authentic(b"leaderboard secret"));
+    /// assert!(!vectors.is_authentic(b"guess"));
+    /// ```
+    pub fn authenticate(&mut self, key: &[u8]) {
+        let tag = self.mac(key).finalize().into_bytes();
+        self.hmac = Some(Digest::from_bytes(tag.into()));
+    }
+
+    /// Authenticates with the key in [`VECTOR_KEY_ENV`], if it is set and
+    /// not empty, and returns whether it was.
+    pub fn authenticate_from_env(&mut self) -> bool {
+        match std::env::var_os(VECTOR_KEY_ENV) {
+            Some(key) if !key.is_empty() => {
+                self.authenticate(key.as_encoded_bytes());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether [`hmac`](Self::hmac) is present and matches the digests
+    /// under `key`. The tag is compared in constant time.
+    pub fn is_authentic(&self, key: &[u8]) -> bool {
+        self.hmac
+            .is_some_and(|tag| self.mac(key).verify_slice(tag.as_bytes()).is_ok())
+    }
+
+    fn mac(&self, key: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+        mac.update(
+            format!(
+                "{}:{}:{}",
+                self.algorithm, self.input_hash, self.output_hash
+            )
+            .as_bytes(),
+        );
+        mac
+    }
+}
+
+/// Error returned by [`TestVectors::verify`] and [`TestVectors::check`].
+#[derive(Debug, Clone)]
+pub enum TestVectorError {
+    /// The test vectors are hashed with an algorithm this build does not
+    /// support.
+    UnsupportedAlgorithm(HashAlgorithm),
+    /// The run's input or output does not hash to the expected digest.
+    Mismatch(Box<TestVectorMismatch>),
+}
+
+impl fmt::Display for TestVectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedAlgorithm(algorithm) => {
+                write!(f, "{} support is not enabled", algorithm)
+            }
+            Self::Mismatch(mismatch) => mismatch.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for TestVectorError {}
+
+/// Mismatch reported by [`TestVectors::check`] when a run's input or
+/// output does not hash to the expected digest.
+#[derive(Debug, Clone)]
+pub struct TestVectorMismatch {
+    pub expected: TestVectors,
+    /// Hashes of the run, with `verified` unset.
+    pub actual: TestVectors,
+}
+
+impl TestVectorMismatch {
+    /// Whether the input differs, i.e. the run did not use the fixture.
+    pub fn input_differs(&self) -> bool {
+        self.expected.input_hash != self.actual.input_hash
+    }
+
+    /// Whether the output differs, i.e. the run computed a wrong result.
+    pub fn output_differs(&self) -> bool {
+        self.expected.output_hash != self.actual.output_hash
+    }
+
+    /// The error message with digests written in `format`; the
+    /// [`Display`](fmt::Display) message uses full hex.
+    pub fn describe(&self, format: DigestFormat) -> String {
+        let mut differences = Vec::new();
+        if self.input_differs() {
+            differences.push(format!(
+                "input hashes to {} instead of {}",
+                self.actual.input_hash.format(format),
+                self.expected.input_hash.format(format)
+            ));
+        }
+        if self.output_differs() {
+            differences.push(format!(
+                "output hashes to {} instead of {}",
+                self.actual.output_hash.format(format),
+                self.expected.output_hash.format(format)
+            ));
+        }
+        format!(
+            "test vector mismatch ({}): {}",
+            self.actual.algorithm,
+            differences.join("; ")
+        )
+    }
+}
+
+impl fmt::Display for TestVectorMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe(DigestFormat::Hex))
+    }
+}
+
+impl std::error::Error for TestVectorMismatch {}
+
+/// Drops reports with the [`content_hash`](BenchmarkReport::content_hash)
+/// of an earlier report, keeping the first of each in order.
+pub fn dedup_reports(reports: Vec<BenchmarkReport>) -> Vec<BenchmarkReport> {
+    let mut seen = HashSet::new();
+    reports
+        .into_iter()
+        .filter(|report| seen.insert(report.content_hash()))
+        .collect()
+}
+
+/// Borrowing variant of [`dedup_reports`].
+pub(crate) fn unique_reports<'a>(
+    reports: impl IntoIterator<Item = &'a BenchmarkReport>,
+) -> Vec<&'a BenchmarkReport> {
+    let mut seen = HashSet::new();
+    reports
+        .into_iter()
+        .filter(|report| seen.insert(report.content_hash()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::schema::{BenchmarkResult, MetricValue};
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn content_hash_ignores_timestamp_and_run() {
+        let a = ReportBuilder::new().latencies_ms(&[("prove", 1.0)]).build();
+        let mut retried = a.clone();
+        retried.metadata.timestamp = "2030-01-01T00:00:00+00:00".to_string();
+        retried.metadata.run_id = Some("9001-2".to_string());
+        retried.metadata.run_url = Some("https://ci.example.com/runs/9001".to_string());
+        assert_eq!(a.content_hash(), retried.content_hash());
+        let mut other_commit = a.clone();
+        other_commit.metadata.commit_sha = "other".to_string();
+        assert_ne!(a.content_hash(), other_commit.content_hash());
+        assert_ne!(
+            a.content_hash(),
+            ReportBuilder::new()
+                .latencies_ms(&[("prove", 2.0)])
+                .build()
+                .content_hash()
+        );
+    }
+
+    #[test]
+    fn dedup_keeps_first_occurrence() {
+        let mut retried = ReportBuilder::new().latencies_ms(&[("prove", 1.0)]).build();
+        retried.metadata.timestamp = "2030-01-01T00:00:00+00:00".to_string();
+        let reports = dedup_reports(vec![
+            ReportBuilder::new().latencies_ms(&[("prove", 1.0)]).build(),
+            ReportBuilder::new().latencies_ms(&[("prove", 2.0)]).build(),
+            retried,
+        ]);
+        assert_eq!(reports.len(), 2);
+        assert_ne!(reports[0].metadata.timestamp, "2030-01-01T00:00:00+00:00");
+    }
 
     #[test]
     fn empty_input() {
@@ -49,6 +1022,355 @@ mod tests {
         );
     }
 
+    #[test]
+    fn algorithm_names_round_trip() {
+        for algorithm in HashAlgorithm::ALL {
+            assert_eq!(algorithm.as_str().parse(), Ok(algorithm));
+            assert_eq!(
+                serde_json::to_string(&algorithm).unwrap(),
+                format!("\"{algorithm}\"")
+            );
+        }
+        assert!("md5".parse::<HashAlgorithm>().is_err());
+        assert_eq!(
+            compute_hash_with(HashAlgorithm::Sha256, b"abc"),
+            compute_hash(b"abc")
+        );
+    }
+
+    #[test]
+    fn streaming_matches_one_shot() {
+        let data: Vec<u8> = (0..100_000u32).flat_map(|v| v.to_le_bytes()).collect();
+        let mut algorithms = vec![HashAlgorithm::Sha256];
+        if HashAlgorithm::Blake3.is_supported() {
+            algorithms.push(HashAlgorithm::Blake3);
+        }
+        for algorithm in algorithms {
+            let mut hasher = Hasher::with_algorithm(algorithm);
+            for chunk in data.chunks(4093) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.algorithm(), algorithm);
+            assert_eq!(hasher.finalize(), compute_hash_with(algorithm, &data));
+
+            let mut hasher = Hasher::with_algorithm(algorithm);
+            io::copy(&mut data.as_slice(), &mut hasher).unwrap();
+            assert_eq!(hasher.finalize(), compute_hash_with(algorithm, &data));
+        }
+        assert_eq!(Hasher::new().finalize(), compute_hash(b""));
+    }
+
+    #[test]
+    fn hashes_files_and_directories() {
+        let dir = std::env::temp_dir().join(format!("zkbench-hash-dir-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("a/nested")).unwrap();
+        fs::create_dir_all(dir.join("b")).unwrap();
+        fs::write(dir.join("a/nested/input.bin"), [1u8, 2, 3]).unwrap();
+        fs::write(dir.join("b/params.json"), "{}").unwrap();
+        assert_eq!(
+            hash_file(dir.join("a/nested/input.bin")).unwrap(),
+            compute_hash(&[1, 2, 3])
+        );
+
+        let mut expected = Vec::new();
+        for (path, contents) in [
+            ("a/nested/input.bin", &[1u8, 2, 3][..]),
+            ("b/params.json", b"{}"),
+        ] {
+            expected.extend_from_slice(path.as_bytes());
+            expected.push(0);
+            expected.extend_from_slice(&(contents.len() as u64).to_le_bytes());
+            expected.extend_from_slice(contents);
+        }
+        let tree = hash_dir(&dir).unwrap();
+        assert_eq!(tree, compute_hash(&expected));
+
+        fs::create_dir_all(dir.join("empty")).unwrap();
+        assert_eq!(hash_dir(&dir).unwrap(), tree);
+        fs::rename(dir.join("b/params.json"), dir.join("b/other.json")).unwrap();
+        assert_ne!(hash_dir(&dir).unwrap(), tree);
+        assert!(hash_file(dir.join("missing")).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_symlink_cycles() {
+        let dir = std::env::temp_dir().join(format!("zkbench-hash-cycle-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("a")).unwrap();
+        fs::write(dir.join("a/input.bin"), [1u8]).unwrap();
+        std::os::unix::fs::symlink(dir.join("a"), dir.join("linked")).unwrap();
+        let tree = hash_dir(&dir).unwrap();
+        assert_ne!(tree, hash_dir(dir.join("a")).unwrap());
+
+        std::os::unix::fs::symlink(&dir, dir.join("a/loop")).unwrap();
+        let err = hash_dir(&dir).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn hashes_large_files_in_chunks() {
+        let path =
+            std::env::temp_dir().join(format!("zkbench-hash-chunked-{}", std::process::id()));
+        let chunk = HASH_CHUNK_SIZE as usize;
+        let data: Vec<u8> = (0..chunk * 5 / 2).map(|i| (i % 251) as u8).collect();
+        fs::write(&path, &data).unwrap();
+        assert_eq!(hash_file(&path).unwrap(), compute_hash(&data));
+
+        let mut expected = Vec::new();
+        for part in data.chunks(chunk) {
+            expected.extend_from_slice(digest_with(HashAlgorithm::Sha256, part).as_bytes());
+        }
+        expected.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        assert_eq!(hash_file_chunked(&path).unwrap(), compute_hash(&expected));
+
+        fs::write(&path, &data[..chunk]).unwrap();
+        let mut expected = digest_with(HashAlgorithm::Sha256, &data[..chunk])
+            .as_bytes()
+            .to_vec();
+        expected.extend_from_slice(&(chunk as u64).to_le_bytes());
+        assert_eq!(hash_file_chunked(&path).unwrap(), compute_hash(&expected));
+
+        fs::write(&path, b"").unwrap();
+        assert_eq!(
+            hash_file_chunked(&path).unwrap(),
+            compute_hash(&0u64.to_le_bytes())
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn blake3_large_updates_match() {
+        let data: Vec<u8> = (0..1 << 20).map(|i| (i % 251) as u8).collect();
+        let mut hasher = Hasher::with_algorithm(HashAlgorithm::Blake3);
+        hasher.update(&data[..300_000]);
+        hasher.update(&data[300_000..]);
+        assert_eq!(hasher.finalize(), blake3::hash(&data).to_hex().to_string());
+    }
+
+    #[test]
+    fn canonical_json_follows_rfc_8785() {
+        // The first number is RFC 8785's 333333333.33333329, written as
+        // the shortest literal of the same `f64`.
+        let value = serde_json::json!({
+            "numbers": [333333333.3333333, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+            "string": "\u{20ac}$\u{000F}\u{000a}A'\u{0042}\u{0022}\u{005c}\\\"/",
+            "literals": [null, true, false]
+        });
+        assert_eq!(
+            to_canonical_json(&value).unwrap(),
+            r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+        );
+    }
+
+    #[test]
+    fn canonical_json_sorts_keys_by_utf16() {
+        let value = serde_json::json!({
+            "\u{20ac}": 0, "\r": 0, "\u{fb33}": 0, "1": 0, "\u{1f600}": 0, "\u{0080}": 0,
+            "\u{00f6}": 0
+        });
+        assert_eq!(
+            to_canonical_json(&value).unwrap(),
+            "{\"\\r\":0,\"1\":0,\"\u{0080}\":0,\"\u{00f6}\":0,\"\u{20ac}\":0,\"\u{1f600}\":0,\"\u{fb33}\":0}"
+        );
+    }
+
+    #[test]
+    fn canonical_hash_ignores_order_and_number_format() {
+        #[derive(Serialize)]
+        struct Inputs {
+            log_n: u32,
+            curve: &'static str,
+        }
+        let typed = hash_canonical(&Inputs {
+            log_n: 20,
+            curve: "bn254",
+        })
+        .unwrap();
+        let loose = serde_json::json!({ "curve": "bn254", "log_n": 20.0 });
+        assert_eq!(typed, hash_canonical(&loose).unwrap());
+        assert_eq!(format_number(123e-20), "1.23e-18");
+        assert_eq!(format_number(-0.000001), "-0.000001");
+        assert_eq!(format_number(1e21), "1e+21");
+        assert_eq!(
+            format_number(123456789012345680000.0),
+            "123456789012345680000"
+        );
+        assert_eq!(
+            to_canonical_json(&u64::MAX).unwrap(),
+            "18446744073709551615"
+        );
+    }
+
+    #[test]
+    fn records_proof_size_and_hash() {
+        let mut result = BenchmarkResult::default();
+        assert_eq!(result.proof_hash(), None);
+        record_proof(&mut result, b"abc");
+        assert_eq!(
+            result.counters[PROOF_SIZE_COUNTER],
+            MetricValue::new(3.0, "B")
+        );
+        assert_eq!(
+            result.proof_hash(),
+            Some("sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        let mut other = BenchmarkResult::default();
+        record_proof(&mut other, b"abd");
+        assert_ne!(other.proof_hash(), result.proof_hash());
+    }
+
+    #[test]
+    fn verifies_test_vectors() {
+        let expected = TestVectors::new(
+            compute_hash(b"input").to_uppercase().parse().unwrap(),
+            format!("0x{}", compute_hash(b"output")).parse().unwrap(),
+        );
+        let actual = expected.check(b"input", b"output").unwrap();
+        assert!(actual.verified);
+        assert_eq!(actual.input_hash.to_string(), compute_hash(b"input"));
+
+        let Err(TestVectorError::Mismatch(mismatch)) = expected.check(b"input", b"wrong") else {
+            panic!("expected a mismatch");
+        };
+        assert!(!mismatch.input_differs());
+        assert!(mismatch.output_differs());
+        assert!(!mismatch.actual.verified);
+        assert_eq!(
+            mismatch.to_string(),
+            format!(
+                "test vector mismatch (sha256): output hashes to {} instead of {}",
+                compute_hash(b"wrong"),
+                compute_hash(b"output")
+            )
+        );
+        assert!(!expected.verify(b"other", b"output").unwrap().verified);
+        assert_eq!(
+            mismatch.describe(DigestFormat::Short),
+            format!(
+                "test vector mismatch (sha256): output hashes to {} instead of {}",
+                &compute_hash(b"wrong")[..12],
+                &compute_hash(b"output")[..12]
+            )
+        );
+
+        if let Some(algorithm) = HashAlgorithm::ALL.into_iter().find(|a| !a.is_supported()) {
+            assert!(Hasher::try_with_algorithm(algorithm).is_none());
+            let unsupported = TestVectors {
+                algorithm,
+                ..expected
+            };
+            assert!(matches!(
+                unsupported.check(b"input", b"output"),
+                Err(TestVectorError::UnsupportedAlgorithm(a)) if a == algorithm
+            ));
+        }
+    }
+
+    #[test]
+    fn authenticates_test_vectors() {
+        let mut vectors = TestVectors::compute(HashAlgorithm::Sha256, b"input", b"output");
+        assert!(!vectors.is_authentic(b"key"));
+        vectors.authenticate(b"key");
+        // Python: hmac.new(b"key", b"sha256:<input>:<output>", sha256)
+        assert_eq!(
+            vectors.hmac.unwrap().to_string(),
+            "2f98034590e575175770198961828101e68397a40a28436e1ba562cfd533b9e0"
+        );
+        assert!(vectors.is_authentic(b"key"));
+        assert!(!vectors.is_authentic(b"other key"));
+
+        let json = serde_json::to_string(&vectors).unwrap();
+        let loaded: TestVectors = serde_json::from_str(&json).unwrap();
+        assert!(loaded.is_authentic(b"key"));
+
+        let forged = TestVectors {
+            output_hash: digest_with(HashAlgorithm::Sha256, b"forged"),
+            ..vectors
+        };
+        assert!(!forged.is_authentic(b"key"));
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn blake3_abc() {
+        assert_eq!(
+            compute_hash_with(HashAlgorithm::Blake3, b"abc"),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn keccak256_and_sha3_256() {
+        // Keccak-256 of the empty string is Ethereum's empty code hash.
+        assert_eq!(
+            compute_hash_with(HashAlgorithm::Keccak256, b""),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            compute_hash_with(HashAlgorithm::Sha3_256, b"abc"),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+        let mut hasher = Hasher::with_algorithm(HashAlgorithm::Keccak256);
+        hasher.update(b"a");
+        hasher.update(b"bc");
+        assert_eq!(hasher.algorithm(), HashAlgorithm::Keccak256);
+        assert_eq!(
+            hasher.finalize(),
+            compute_hash_with(HashAlgorithm::Keccak256, b"abc")
+        );
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    fn poseidon_digests() {
+        let circuit_native: Vec<_> = HashAlgorithm::ALL
+            .into_iter()
+            .filter(HashAlgorithm::is_circuit_native)
+            .collect();
+        assert_eq!(circuit_native.len(), 4);
+        let data: Vec<u8> = (0..100).collect();
+        let mut digests = HashSet::new();
+        for algorithm in circuit_native {
+            let digest = compute_hash_with(algorithm, &data);
+            assert_eq!(digest.len(), 64);
+            assert!(digests.insert(digest.clone()), "{algorithm}");
+            // Chunks straddling the 31-byte element boundaries.
+            let mut hasher = Hasher::with_algorithm(algorithm);
+            for chunk in data.chunks(7) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.algorithm(), algorithm);
+            assert_eq!(hasher.finalize(), digest, "{algorithm}");
+            // Padding keeps trailing zeros and block boundaries apart.
+            for len in [0, 1, 30, 31, 62] {
+                let zeros = vec![0; len];
+                assert!(
+                    digests.insert(compute_hash_with(algorithm, &zeros)),
+                    "{algorithm} {len}"
+                );
+            }
+        }
+        // Pins the byte encoding, which circuits reproduce.
+        assert_eq!(
+            compute_hash_with(HashAlgorithm::PoseidonBn254, b"abc"),
+            "07b2c720a5ec680e09c43a435f41bc1a97528c98675fdeef0cc07f2ada48b240"
+        );
+        assert_eq!(
+            compute_hash_with(HashAlgorithm::Poseidon2Bn254, b"abc"),
+            "0c34903ca3a583d350e1923805e50e16a42c46c12638f3c10a45c22bff4c64d8"
+        );
+        assert_eq!(
+            compute_hash_with(HashAlgorithm::Poseidon2Bn254, &data),
+            "10487bc780032b25c2b0b12df4834fe7d56f43d08b615112e6e5d93f849ca00b"
+        );
+    }
+
     #[test]
     fn uint32_array_le() {
         let data: Vec<u8> = [1u32, 2u32, 3u32]
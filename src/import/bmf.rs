@@ -0,0 +1,76 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bencher Metric Format (BMF) import.
+//!
+//! Reads the JSON written by [`export_bmf`](crate::export_bmf) or by any
+//! other tool producing Bencher's `json` adapter input.
+
+use std::collections::HashMap;
+
+use crate::export::{Bmf, BmfMetric};
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Imports BMF JSON.
+///
+/// The `latency` measure becomes the latency in nanoseconds and `memory`
+/// the memory in bytes, as Bencher records them. `throughput` has no unit
+/// in BMF and is imported as `ops/s`; any other measure becomes a counter
+/// whose unit is the measure's slug.
+pub fn import_bmf(json: &str) -> Result<HashMap<String, BenchmarkResult>, serde_json::Error> {
+    let bmf: Bmf = serde_json::from_str(json)?;
+    let mut benchmarks = HashMap::new();
+    for (name, measures) in bmf {
+        let mut result = BenchmarkResult::default();
+        for (measure, metric) in measures {
+            match measure.as_str() {
+                "latency" => result.latency = Some(to_metric(metric, "ns")),
+                "memory" => result.memory = Some(to_metric(metric, "B")),
+                "throughput" => result.throughput = Some(to_metric(metric, "ops/s")),
+                _ => {
+                    let metric = to_metric(metric, &measure);
+                    result.counters.insert(measure, metric);
+                }
+            }
+        }
+        benchmarks.insert(name, result);
+    }
+    Ok(benchmarks)
+}
+
+fn to_metric(metric: BmfMetric, unit: &str) -> MetricValue {
+    MetricValue {
+        value: metric.value,
+        unit: unit.to_string(),
+        lower_value: metric.lower_value,
+        upper_value: metric.upper_value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn imports_measures() {
+        let json = r#"{
+            "prove": {
+                "latency": {"value": 1500000.0, "lower_value": 1000000.0, "upper_value": 2000000.0},
+                "memory": {"value": 2048},
+                "instructions": {"value": 12}
+            }
+        }"#;
+        let benchmarks = import_bmf(json).unwrap();
+        let prove = &benchmarks["prove"];
+        assert_eq!(
+            prove.latency,
+            Some(MetricValue::with_bounds(1.5e6, "ns", 1e6, 2e6))
+        );
+        assert_eq!(prove.memory, Some(MetricValue::new(2048.0, "B")));
+        assert_eq!(
+            prove.counters["instructions"],
+            MetricValue::new(12.0, "instructions")
+        );
+        assert!(import_bmf("[]").is_err());
+    }
+}
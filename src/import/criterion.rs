@@ -0,0 +1,338 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Criterion.rs result import.
+//!
+//! Criterion stores each benchmark under `target/criterion/<id>/new/`:
+//! `benchmark.json` holds the id and throughput, `estimates.json` the
+//! statistics (in nanoseconds) and `sample.json` the raw measurements.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use crate::schema::{BenchmarkReport, BenchmarkResult, Metadata, MetricValue};
+
+#[derive(Deserialize)]
+struct CriterionBenchmark {
+    full_id: String,
+    #[serde(default)]
+    throughput: Option<HashMap<String, f64>>,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+    #[serde(default)]
+    median: Option<Estimate>,
+    #[serde(default)]
+    slope: Option<Estimate>,
+    #[serde(default)]
+    std_dev: Option<Estimate>,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+    confidence_interval: ConfidenceInterval,
+}
+
+#[derive(Deserialize)]
+struct ConfidenceInterval {
+    lower_bound: f64,
+    upper_bound: f64,
+}
+
+#[derive(Deserialize)]
+struct Samples {
+    iters: Vec<f64>,
+    times: Vec<f64>,
+}
+
+/// Imports every benchmark found under a `target/criterion` directory.
+///
+/// Benchmarks are keyed by Criterion's full id (`group/function/value`).
+/// The latency is the slope estimate when Criterion computed one (linear
+/// sampling) and the mean otherwise, in nanoseconds with its confidence
+/// interval. Per-iteration sample times become samples, `Bytes` and
+/// `Elements` throughput is converted to `B/s` and `elements/s`, and the
+/// median and standard deviation are kept in the metadata.
+pub fn import_criterion(dir: impl AsRef<Path>) -> io::Result<HashMap<String, BenchmarkResult>> {
+    let mut benchmarks = HashMap::new();
+    collect_benchmarks(dir.as_ref(), &mut benchmarks)?;
+    Ok(benchmarks)
+}
+
+/// Scans a monorepo for `target/criterion` directories and merges them
+/// into a single report.
+///
+/// Every directory below `root` containing a `target/criterion` directory
+/// is treated as a crate; its benchmarks are prefixed with the package
+/// name from its `Cargo.toml` (or the directory name if there is none),
+/// e.g. `prover/fft/radix2/1024`. Hidden directories and the contents of
+/// `target` directories are not searched further.
+pub fn aggregate_criterion(
+    root: impl AsRef<Path>,
+    metadata: Metadata,
+) -> io::Result<BenchmarkReport> {
+    let mut report = BenchmarkReport {
+        metadata,
+        benchmarks: HashMap::new(),
+    };
+    aggregate_into(root.as_ref(), &mut report.benchmarks)?;
+    Ok(report)
+}
+
+fn aggregate_into(dir: &Path, benchmarks: &mut HashMap<String, BenchmarkResult>) -> io::Result<()> {
+    let criterion_dir = dir.join("target").join("criterion");
+    if criterion_dir.is_dir() {
+        let prefix = package_name(dir).unwrap_or_else(|| {
+            dir.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        });
+        for (name, result) in import_criterion(&criterion_dir)? {
+            benchmarks.insert(format!("{}/{}", prefix, name), result);
+        }
+    }
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if entry.file_type()?.is_dir() && name != "target" && !name.starts_with('.') {
+            aggregate_into(&entry.path(), benchmarks)?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads `[package] name` from a crate's `Cargo.toml`.
+fn package_name(dir: &Path) -> Option<String> {
+    let manifest = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    let mut in_package = false;
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+        } else if in_package
+            && let Some((key, value)) = line.split_once('=')
+            && key.trim() == "name"
+        {
+            return Some(value.trim().trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+fn collect_benchmarks(
+    dir: &Path,
+    benchmarks: &mut HashMap<String, BenchmarkResult>,
+) -> io::Result<()> {
+    let new_dir = dir.join("new");
+    if new_dir.join("benchmark.json").is_file() {
+        let (name, result) = read_benchmark(&new_dir)?;
+        benchmarks.insert(name, result);
+    }
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        // `base`, `change` and `report` hold Criterion's own history and
+        // HTML, never further benchmarks.
+        let name = entry.file_name();
+        if entry.file_type()?.is_dir()
+            && !matches!(name.to_str(), Some("new" | "base" | "change" | "report"))
+        {
+            collect_benchmarks(&entry.path(), benchmarks)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> io::Result<T> {
+    let json = fs::read_to_string(path)?;
+    serde_json::from_str(&json).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+fn read_benchmark(dir: &Path) -> io::Result<(String, BenchmarkResult)> {
+    let benchmark: CriterionBenchmark = read_json(&dir.join("benchmark.json"))?;
+    let estimates: Estimates = read_json(&dir.join("estimates.json"))?;
+    let samples: Option<Samples> = read_json(&dir.join("sample.json")).ok();
+
+    let primary = estimates.slope.as_ref().unwrap_or(&estimates.mean);
+    let ns = primary.point_estimate;
+    let mut result = BenchmarkResult {
+        latency: Some(MetricValue::with_bounds(
+            ns,
+            "ns",
+            primary.confidence_interval.lower_bound,
+            primary.confidence_interval.upper_bound,
+        )),
+        ..Default::default()
+    };
+    if let Some(samples) = samples {
+        result.iterations = samples.iters.iter().sum::<f64>() as usize;
+        result.samples = samples
+            .times
+            .iter()
+            .zip(&samples.iters)
+            .map(|(time, iters)| time / iters)
+            .collect();
+    }
+    if let Some((kind, &amount)) = benchmark.throughput.as_ref().and_then(|t| t.iter().next())
+        && ns > 0.0
+    {
+        let unit = match kind.as_str() {
+            "Bytes" | "BytesDecimal" => Some("B/s"),
+            "Elements" => Some("elements/s"),
+            _ => None,
+        };
+        if let Some(unit) = unit {
+            result.throughput = Some(MetricValue::new(amount * 1e9 / ns, unit));
+        }
+    }
+    if let Some(median) = &estimates.median {
+        result.metadata.insert(
+            "median".to_string(),
+            json!(MetricValue::new(median.point_estimate, "ns")),
+        );
+    }
+    if let Some(std_dev) = &estimates.std_dev {
+        result.metadata.insert(
+            "std_dev".to_string(),
+            json!(MetricValue::new(std_dev.point_estimate, "ns")),
+        );
+    }
+    Ok((benchmark.full_id, result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn estimate(value: f64) -> serde_json::Value {
+        json!({
+            "point_estimate": value,
+            "standard_error": 1.0,
+            "confidence_interval": {
+                "confidence_level": 0.95,
+                "lower_bound": value - 2.0,
+                "upper_bound": value + 2.0,
+            },
+        })
+    }
+
+    fn write_benchmark(criterion: &Path, id: &str, throughput: serde_json::Value, slope: bool) {
+        let new_dir = criterion.join(id).join("new");
+        fs::create_dir_all(&new_dir).unwrap();
+        let benchmark = json!({"full_id": id, "directory_name": id, "throughput": throughput});
+        let estimates = json!({
+            "mean": estimate(110.0),
+            "median": estimate(105.0),
+            "std_dev": estimate(3.0),
+            "slope": if slope { estimate(100.0) } else { serde_json::Value::Null },
+        });
+        let sample =
+            json!({"sampling_mode": "Linear", "iters": [1.0, 2.0], "times": [100.0, 220.0]});
+        fs::write(new_dir.join("benchmark.json"), benchmark.to_string()).unwrap();
+        fs::write(new_dir.join("estimates.json"), estimates.to_string()).unwrap();
+        fs::write(new_dir.join("sample.json"), sample.to_string()).unwrap();
+        // Criterion's previous run; must not be imported.
+        let base_dir = criterion.join(id).join("base");
+        fs::create_dir_all(&base_dir).unwrap();
+        fs::write(base_dir.join("benchmark.json"), benchmark.to_string()).unwrap();
+    }
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("zkbench-criterion-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn imports_estimates_and_samples() {
+        let root = temp_root("single");
+        let criterion = root.join("target/criterion");
+        write_benchmark(&criterion, "fft/radix2/1024", json!({"Bytes": 1000}), true);
+        write_benchmark(&criterion, "hash", serde_json::Value::Null, false);
+
+        let benchmarks = import_criterion(&criterion).unwrap();
+        assert_eq!(benchmarks.len(), 2);
+
+        let fft = &benchmarks["fft/radix2/1024"];
+        let latency = fft.latency.as_ref().unwrap();
+        assert_eq!((latency.value, latency.unit.as_str()), (100.0, "ns"));
+        assert_eq!(latency.lower_value, Some(98.0));
+        assert_eq!(fft.samples, vec![100.0, 110.0]);
+        assert_eq!(fft.iterations, 3);
+        let throughput = fft.throughput.as_ref().unwrap();
+        assert_eq!((throughput.value, throughput.unit.as_str()), (1e10, "B/s"));
+        assert_eq!(fft.metadata["median"]["value"], 105.0);
+
+        // Without a slope estimate the mean is used.
+        assert_eq!(benchmarks["hash"].latency.as_ref().unwrap().value, 110.0);
+        assert!(benchmarks["hash"].throughput.is_none());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn aggregates_crates_with_prefixes() {
+        let root = temp_root("workspace");
+        let prover = root.join("crates/prover");
+        fs::create_dir_all(&prover).unwrap();
+        fs::write(
+            prover.join("Cargo.toml"),
+            "[package]\nname = \"zk-prover\"\nversion = \"0.1.0\"\n\n[dependencies]\nname = \"x\"\n",
+        )
+        .unwrap();
+        write_benchmark(&prover.join("target/criterion"), "prove", json!(null), true);
+        let verifier = root.join("crates/verifier");
+        write_benchmark(
+            &verifier.join("target/criterion"),
+            "verify",
+            json!({"Elements": 10}),
+            true,
+        );
+        // Hidden directories are skipped.
+        write_benchmark(
+            &root.join(".cache/target/criterion"),
+            "hidden",
+            json!(null),
+            true,
+        );
+
+        let report = aggregate_criterion(&root, Metadata::create("monorepo", "1.0")).unwrap();
+        let mut names: Vec<_> = report.benchmarks.keys().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(names, ["verifier/verify", "zk-prover/prove"]);
+        assert_eq!(
+            report.benchmarks["verifier/verify"]
+                .throughput
+                .as_ref()
+                .unwrap()
+                .unit,
+            "elements/s"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn malformed_json_is_an_error() {
+        let root = temp_root("malformed");
+        let new_dir = root.join("bench/new");
+        fs::create_dir_all(&new_dir).unwrap();
+        fs::write(new_dir.join("benchmark.json"), "{").unwrap();
+        let err = import_criterion(&root).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Import of the CSV written by [`export_csv`](crate::export_csv).
+//!
+//! Unlike the harness importers, the CSV carries report metadata, so it
+//! is read back into whole reports: rows are grouped by implementation,
+//! version, commit and timestamp, in order of first appearance.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::platform::Platform;
+use crate::schema::{BenchmarkReport, Metadata, MetricValue, SCHEMA_VERSION};
+
+/// Columns a CSV must have to be imported; the others are optional.
+const REQUIRED_COLUMNS: [&str; 4] = ["benchmark", "metric", "value", "unit"];
+
+/// Error returned by [`import_csv`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvError {
+    /// 1-based line of the offending record.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+/// Reads reports from CSV with a header row naming the columns of
+/// [`MetricRow`](crate::MetricRow).
+///
+/// Columns may come in any order and unknown columns are ignored; only
+/// `benchmark`, `metric`, `value` and `unit` are required. Rows whose
+/// metric is not `latency`, `memory` or `throughput` become counters.
+/// Platform fields are taken from a report's first row.
+pub fn import_csv(csv: &str) -> Result<Vec<BenchmarkReport>, CsvError> {
+    let mut records = parse_records(csv)?.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Ok(Vec::new());
+    };
+    let columns: HashMap<&str, usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.trim(), i))
+        .collect();
+    if let Some(missing) = REQUIRED_COLUMNS.iter().find(|c| !columns.contains_key(*c)) {
+        return Err(CsvError {
+            line: 1,
+            message: format!("missing column `{missing}`"),
+        });
+    }
+
+    let mut reports: Vec<BenchmarkReport> = Vec::new();
+    for (line, record) in records {
+        if record.iter().all(String::is_empty) {
+            continue;
+        }
+        let field = |name: &str| {
+            columns
+                .get(name)
+                .and_then(|&i| record.get(i))
+                .map_or("", String::as_str)
+        };
+        let number = |name: &str| -> Result<Option<f64>, CsvError> {
+            match field(name) {
+                "" => Ok(None),
+                text => text.parse().map(Some).map_err(|_| CsvError {
+                    line,
+                    message: format!("`{name}` is not a number: `{text}`"),
+                }),
+            }
+        };
+        let metadata = Metadata {
+            schema_version: SCHEMA_VERSION,
+            implementation: field("implementation").to_string(),
+            version: field("version").to_string(),
+            commit_sha: field("commit_sha").to_string(),
+            timestamp: field("timestamp").to_string(),
+            platform: Platform {
+                os: field("os").to_string(),
+                arch: field("arch").to_string(),
+                cpu_count: number("cpu_count")?.map_or(0, |n| n as usize),
+                cpu_vendor: Some(field("cpu_vendor").to_string()).filter(|s| !s.is_empty()),
+                gpu_vendor: Some(field("gpu_vendor").to_string()).filter(|s| !s.is_empty()),
+                cpu_mhz: None,
+            },
+            branch: None,
+            git_tag: None,
+            dirty: false,
+            run_id: None,
+            run_url: None,
+            test_vector_commitment: None,
+            cpu_pinning: None,
+        };
+        let metric = MetricValue {
+            value: number("value")?.ok_or_else(|| CsvError {
+                line,
+                message: "`value` is empty".to_string(),
+            })?,
+            unit: field("unit").to_string(),
+            lower_value: number("lower_value")?,
+            upper_value: number("upper_value")?,
+        };
+        let iterations = number("iterations")?.map_or(0, |n| n as usize);
+
+        let existing = reports
+            .iter()
+            .position(|r| run_key(&r.metadata) == run_key(&metadata));
+        let report = match existing {
+            Some(i) => &mut reports[i],
+            None => {
+                reports.push(BenchmarkReport {
+                    metadata,
+                    benchmarks: HashMap::new(),
+                });
+                reports.last_mut().expect("just pushed")
+            }
+        };
+        let result = report
+            .benchmarks
+            .entry(field("benchmark").to_string())
+            .or_default();
+        result.iterations = result.iterations.max(iterations);
+        match field("metric") {
+            "latency" => result.latency = Some(metric),
+            "memory" => result.memory = Some(metric),
+            "throughput" => result.throughput = Some(metric),
+            counter => {
+                result.counters.insert(counter.to_string(), metric);
+            }
+        }
+    }
+    Ok(reports)
+}
+
+/// Fields identifying the run a row belongs to.
+fn run_key(metadata: &Metadata) -> (&str, &str, &str, &str) {
+    (
+        &metadata.implementation,
+        &metadata.version,
+        &metadata.commit_sha,
+        &metadata.timestamp,
+    )
+}
+
+/// Splits RFC 4180 CSV into records, each with the line it starts on.
+fn parse_records(csv: &str) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.is_empty() => in_quotes = true,
+            '\n' if in_quotes => {
+                line += 1;
+                field.push(c);
+            }
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push((start, std::mem::take(&mut record)));
+                line += 1;
+                start = line;
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(CsvError {
+            line: start,
+            message: "unterminated quoted field".to_string(),
+        });
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((start, record));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::export_csv;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn round_trips_exported_csv() {
+        let report = ReportBuilder::new()
+            .version("1.0")
+            .day(1)
+            .result(
+                "msm, \"g1\"",
+                BenchmarkResult {
+                    latency: Some(MetricValue::with_bounds(1.5, "ms", 1.0, 2.0)),
+                    memory: Some(MetricValue::new(3.0, "MiB")),
+                    counters: [("constraints".to_string(), MetricValue::new(7.0, "rows"))]
+                        .into_iter()
+                        .collect(),
+                    iterations: 3,
+                    ..Default::default()
+                },
+            )
+            .build();
+        let imported = import_csv(&export_csv(std::slice::from_ref(&report))).unwrap();
+        assert_eq!(imported.len(), 1);
+        let result = &imported[0].benchmarks["msm, \"g1\""];
+        let original = &report.benchmarks["msm, \"g1\""];
+        assert_eq!(result.latency, original.latency);
+        assert_eq!(result.memory, original.memory);
+        assert_eq!(result.counters, original.counters);
+        assert_eq!(result.iterations, 3);
+        assert_eq!(imported[0].metadata.commit_sha, report.metadata.commit_sha);
+    }
+
+    #[test]
+    fn groups_rows_and_reports_errors() {
+        let csv = "metric,benchmark,value,unit,commit_sha\n\
+                   latency,a,1,ms,c1\n\
+                   latency,a,2,ms,c2\n\
+                   memory,a,3,B,c1\n";
+        let reports = import_csv(csv).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert!(reports[0].benchmarks["a"].memory.is_some());
+        assert!(reports[1].benchmarks["a"].memory.is_none());
+
+        let err = import_csv("benchmark,metric,value,unit\na,latency,fast,ms\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: `value` is not a number: `fast`");
+        assert_eq!(
+            import_csv("benchmark,metric,value\n").unwrap_err().message,
+            "missing column `unit`"
+        );
+        assert!(import_csv("").unwrap().is_empty());
+    }
+}
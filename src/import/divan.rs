@@ -0,0 +1,299 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! divan result import.
+//!
+//! divan only prints a tree-shaped table, so this importer parses its
+//! terminal output:
+//!
+//! ```text
+//! Timer precision: 20 ns
+//! hash                 fastest       │ slowest       │ median        │ mean          │ samples │ iters
+//! ╰─ sha256                          │               │               │               │         │
+//!    ├─ 64             210.1 ns      │ 250.3 ns      │ 212.4 ns      │ 215.2 ns      │ 100     │ 3200
+//!    │                 304.6 MB/s    │ 255.6 MB/s    │ 301.2 MB/s    │ 297.3 MB/s    │         │
+//!    ╰─ 1024           2.01 µs       │ 2.4 µs        │ 2.05 µs       │ 2.1 µs        │ 100     │ 400
+//!                      509.4 MB/s    │ 426.6 MB/s    │ 499.5 MB/s    │ 487.6 MB/s    │         │
+//! ```
+//!
+//! Columns are located by the position of the `fastest` header, so names
+//! and values never have to be told apart by content.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Imports the table divan prints to stdout.
+///
+/// Benchmarks are named by their path in the tree, starting with the
+/// bench target (`hash/sha256/64` above). The mean time becomes the
+/// latency and the fastest, slowest and median times are kept in the
+/// metadata. Counter rows are normalized to base units (`B/s`, `items/s`,
+/// `chars/s`) and stored as `<kind>_throughput` counters, the first one
+/// also becoming the result's throughput. With divan's `AllocProfiler`,
+/// each allocation statistic is stored as `<stat>_count` and
+/// `<stat>_bytes` counters, and the mean `max alloc` bytes become the
+/// memory metric.
+///
+/// Lines that are not part of a table are ignored, so the full output of
+/// `cargo bench` can be passed in.
+pub fn import_divan(output: &str) -> HashMap<String, BenchmarkResult> {
+    let mut benchmarks = HashMap::new();
+    let mut value_column = None;
+    let mut path: Vec<String> = Vec::new();
+    let mut current: Option<(String, BenchmarkResult)> = None;
+    let mut alloc_stat: Option<(String, usize)> = None;
+
+    for line in output.lines() {
+        let chars: Vec<char> = line.chars().collect();
+        if let Some(column) = find(&chars, "fastest") {
+            flush(&mut current, &mut benchmarks);
+            let root = chars[..column].iter().collect::<String>();
+            path = vec![root.trim().to_string()];
+            value_column = Some(column);
+            continue;
+        }
+        let Some(column) = value_column else {
+            continue;
+        };
+        if chars.len() <= column && !chars.iter().any(|c| is_tree(*c)) {
+            // A blank or unrelated line ends the table.
+            flush(&mut current, &mut benchmarks);
+            value_column = None;
+            continue;
+        }
+
+        let head: String = chars[..column.min(chars.len())].iter().collect();
+        let cells: Vec<String> = chars[column.min(chars.len())..]
+            .iter()
+            .collect::<String>()
+            .split('│')
+            .map(|cell| cell.trim().to_string())
+            .collect();
+        let name_start = head.find(|c: char| !is_tree(c) && !c.is_whitespace());
+
+        if let Some(start) = name_start {
+            flush(&mut current, &mut benchmarks);
+            alloc_stat = None;
+            let depth = head[..start].chars().count() / 3;
+            path.truncate(depth.max(1));
+            path.push(head[start..].trim().to_string());
+            if let Some(result) = timing_row(&cells) {
+                current = Some((path.join("/"), result));
+            }
+            continue;
+        }
+
+        let Some((_, result)) = current.as_mut() else {
+            continue;
+        };
+        let first = cells.first().map(String::as_str).unwrap_or("");
+        if let Some(stat) = first.strip_suffix(':') {
+            alloc_stat = Some((stat.replace(' ', "_"), 0));
+        } else if let Some((stat, row)) = alloc_stat.as_mut() {
+            // Allocation statistics are a count row followed by a size row.
+            let mean = cells.get(3).map(String::as_str).unwrap_or("");
+            if *row == 0 {
+                if let Ok(count) = mean.parse::<f64>() {
+                    result
+                        .counters
+                        .insert(format!("{}_count", stat), MetricValue::new(count, "count"));
+                }
+            } else if let Some(bytes) = parse_bytes(mean) {
+                if stat == "max_alloc" {
+                    result.memory = Some(MetricValue::new(bytes, "B"));
+                }
+                result
+                    .counters
+                    .insert(format!("{}_bytes", stat), MetricValue::new(bytes, "B"));
+                alloc_stat = None;
+            }
+            if let Some((_, row)) = alloc_stat.as_mut() {
+                *row += 1;
+            }
+        } else if let Some((kind, rate)) = cells.get(3).and_then(|mean| parse_rate(mean)) {
+            if result.throughput.is_none() {
+                result.throughput = Some(rate.clone());
+            }
+            result.counters.insert(format!("{}_throughput", kind), rate);
+        }
+    }
+    flush(&mut current, &mut benchmarks);
+    benchmarks
+}
+
+fn flush(
+    current: &mut Option<(String, BenchmarkResult)>,
+    benchmarks: &mut HashMap<String, BenchmarkResult>,
+) {
+    if let Some((name, result)) = current.take() {
+        benchmarks.insert(name, result);
+    }
+}
+
+fn find(haystack: &[char], needle: &str) -> Option<usize> {
+    let needle: Vec<char> = needle.chars().collect();
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn is_tree(c: char) -> bool {
+    matches!(c, '│' | '├' | '╰' | '─')
+}
+
+/// Parses `fastest │ slowest │ median │ mean │ samples │ iters`.
+fn timing_row(cells: &[String]) -> Option<BenchmarkResult> {
+    let [fastest, slowest, median, mean, _samples, iters] = cells else {
+        return None;
+    };
+    let mean = parse_time(mean)?;
+    let mut result = BenchmarkResult {
+        latency: Some(mean),
+        iterations: iters.parse().unwrap_or(0),
+        ..Default::default()
+    };
+    for (key, cell) in [
+        ("fastest", fastest),
+        ("slowest", slowest),
+        ("median", median),
+    ] {
+        if let Some(time) = parse_time(cell) {
+            result.metadata.insert(key.to_string(), json!(time));
+        }
+    }
+    Some(result)
+}
+
+fn split_unit(cell: &str) -> Option<(f64, &str)> {
+    let (value, unit) = cell.split_once(' ')?;
+    Some((value.parse().ok()?, unit.trim()))
+}
+
+fn parse_time(cell: &str) -> Option<MetricValue> {
+    let (value, unit) = split_unit(cell)?;
+    Some(match unit {
+        "ps" => MetricValue::new(value / 1000.0, "ns"),
+        "ns" | "ms" | "s" => MetricValue::new(value, unit),
+        "µs" | "us" => MetricValue::new(value, "us"),
+        _ => return None,
+    })
+}
+
+/// Splits a decimal (`K`, `M`, ...) or binary (`Ki`, `Mi`, ...) prefix off
+/// a unit and returns the multiplier with the remaining base unit.
+fn strip_prefix(unit: &str) -> (f64, &str) {
+    for (prefix, factor) in [
+        ("Ki", 1024f64),
+        ("Mi", 1024f64.powi(2)),
+        ("Gi", 1024f64.powi(3)),
+        ("Ti", 1024f64.powi(4)),
+        ("K", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+    ] {
+        if let Some(base) = unit.strip_prefix(prefix)
+            && matches!(base, "B" | "item" | "char")
+        {
+            return (factor, base);
+        }
+    }
+    (1.0, unit)
+}
+
+fn parse_bytes(cell: &str) -> Option<f64> {
+    let (value, unit) = split_unit(cell)?;
+    match strip_prefix(unit) {
+        (factor, "B") => Some(value * factor),
+        _ => None,
+    }
+}
+
+fn parse_rate(cell: &str) -> Option<(&'static str, MetricValue)> {
+    let (value, unit) = split_unit(cell)?;
+    let (factor, base) = strip_prefix(unit.strip_suffix("/s")?);
+    let (kind, unit) = match base {
+        "B" => ("bytes", "B/s"),
+        "item" => ("items", "items/s"),
+        "char" => ("chars", "chars/s"),
+        _ => return None,
+    };
+    Some((kind, MetricValue::new(value * factor, unit)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "\
+     Running benches/hash.rs (target/release/deps/hash-1234)
+Timer precision: 20 ns
+hash                 fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ poseidon          1.5 µs        │ 2 µs          │ 1.6 µs        │ 1.62 µs       │ 100     │ 800
+╰─ sha256                          │               │               │               │         │
+   ├─ 64             210.1 ns      │ 250.3 ns      │ 212.4 ns      │ 215.2 ns      │ 100     │ 3200
+   │                 304.6 MB/s    │ 255.6 MB/s    │ 301.2 MB/s    │ 297.3 MB/s    │         │
+   │                 4.76 Mitem/s  │ 3.99 Mitem/s  │ 4.7 Mitem/s   │ 4.64 Mitem/s  │         │
+   ╰─ 1024           2.01 µs       │ 2.4 µs        │ 2.05 µs       │ 2.1 µs        │ 100     │ 400
+                     509.4 MB/s    │ 426.6 MB/s    │ 499.5 MB/s    │ 487.6 MB/s    │         │
+
+";
+
+    #[test]
+    fn tree_paths_become_names() {
+        let benchmarks = import_divan(OUTPUT);
+        let mut names: Vec<_> = benchmarks.keys().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(
+            names,
+            ["hash/poseidon", "hash/sha256/1024", "hash/sha256/64"]
+        );
+
+        let result = &benchmarks["hash/poseidon"];
+        let latency = result.latency.as_ref().unwrap();
+        assert_eq!((latency.value, latency.unit.as_str()), (1.62, "us"));
+        assert_eq!(result.iterations, 800);
+        assert_eq!(result.metadata["fastest"]["value"], 1.5);
+        assert!(result.throughput.is_none());
+    }
+
+    #[test]
+    fn counters_are_normalized() {
+        let benchmarks = import_divan(OUTPUT);
+        let result = &benchmarks["hash/sha256/64"];
+        let throughput = result.throughput.as_ref().unwrap();
+        assert_eq!(throughput.unit, "B/s");
+        assert_eq!(throughput.value, 297.3e6);
+        let items = &result.counters["items_throughput"];
+        assert_eq!((items.value, items.unit.as_str()), (4.64e6, "items/s"));
+        assert_eq!(result.counters["bytes_throughput"].value, 297.3e6);
+        assert_eq!(benchmarks["hash/sha256/1024"].counters.len(), 1);
+    }
+
+    #[test]
+    fn allocation_profile() {
+        let output = "\
+alloc                fastest       │ slowest       │ median        │ mean          │ samples │ iters
+╰─ build_tree        10 µs         │ 12 µs         │ 10.5 µs       │ 10.6 µs       │ 100     │ 100
+                     max alloc:    │               │               │               │         │
+                       3           │ 3             │ 3             │ 3             │         │
+                       1.5 KiB     │ 1.5 KiB       │ 1.5 KiB       │ 1.5 KiB       │         │
+                     alloc:        │               │               │               │         │
+                       12          │ 12            │ 12            │ 12            │         │
+                       4 KB        │ 4 KB          │ 4 KB          │ 4 KB          │         │
+";
+        let benchmarks = import_divan(output);
+        let result = &benchmarks["alloc/build_tree"];
+        assert_eq!(result.memory.as_ref().unwrap().value, 1536.0);
+        assert_eq!(result.counters["max_alloc_count"].value, 3.0);
+        assert_eq!(result.counters["alloc_count"].value, 12.0);
+        assert_eq!(result.counters["alloc_bytes"].value, 4000.0);
+        assert!(result.throughput.is_none());
+    }
+
+    #[test]
+    fn unrelated_output_is_ignored() {
+        assert!(import_divan("test result: ok. 3 passed\n").is_empty());
+    }
+}
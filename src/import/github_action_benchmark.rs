@@ -0,0 +1,57 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Import of `benchmark-action/github-action-benchmark` custom tool JSON.
+
+use std::collections::HashMap;
+
+use crate::export::GithubBenchmarkEntry;
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Imports a `customSmallerIsBetter` JSON array, such as one written by
+/// [`export_github_action_benchmark`](crate::export_github_action_benchmark).
+///
+/// Entries named `<name> (memory)` become the memory of `<name>`; every
+/// other entry is a latency. A `± x` range becomes symmetric bounds.
+pub fn import_github_action_benchmark(
+    json: &str,
+) -> Result<HashMap<String, BenchmarkResult>, serde_json::Error> {
+    let entries: Vec<GithubBenchmarkEntry> = serde_json::from_str(json)?;
+    let mut benchmarks: HashMap<String, BenchmarkResult> = HashMap::new();
+    for entry in entries {
+        let mut metric = MetricValue::new(entry.value, &entry.unit);
+        if let Some(half_width) = entry
+            .range
+            .as_deref()
+            .and_then(|range| range.trim_start_matches('±').trim().parse::<f64>().ok())
+        {
+            metric.lower_value = Some(entry.value - half_width);
+            metric.upper_value = Some(entry.value + half_width);
+        }
+        match entry.name.strip_suffix(" (memory)") {
+            Some(name) => benchmarks.entry(name.to_string()).or_default().memory = Some(metric),
+            None => benchmarks.entry(entry.name).or_default().latency = Some(metric),
+        }
+    }
+    Ok(benchmarks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn imports_latency_and_memory() {
+        let json = r#"[
+            {"name": "prove", "unit": "ms", "value": 100, "range": "± 5"},
+            {"name": "prove (memory)", "unit": "MiB", "value": 512}
+        ]"#;
+        let benchmarks = import_github_action_benchmark(json).unwrap();
+        let prove = &benchmarks["prove"];
+        assert_eq!(
+            prove.latency,
+            Some(MetricValue::with_bounds(100.0, "ms", 95.0, 105.0))
+        );
+        assert_eq!(prove.memory, Some(MetricValue::new(512.0, "MiB")));
+    }
+}
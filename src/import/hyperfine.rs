@@ -0,0 +1,156 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! hyperfine result import.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::schema::{BenchmarkResult, MetricValue};
+use crate::statistics::calculate_confidence_interval_default;
+
+#[derive(Deserialize)]
+struct HyperfineExport {
+    results: Vec<HyperfineRun>,
+}
+
+#[derive(Deserialize)]
+struct HyperfineRun {
+    command: String,
+    mean: f64,
+    /// `null` when only a single run was made.
+    #[serde(default)]
+    stddev: Option<f64>,
+    median: f64,
+    user: f64,
+    system: f64,
+    min: f64,
+    max: f64,
+    #[serde(default)]
+    times: Vec<f64>,
+    #[serde(default)]
+    exit_codes: Vec<Option<i32>>,
+    #[serde(default)]
+    parameters: BTreeMap<String, String>,
+}
+
+/// Imports the JSON written by `hyperfine --export-json`.
+///
+/// Each command becomes a benchmark named after the command string (or the
+/// `--command-name`, which hyperfine stores in the same field). The mean
+/// wall-clock time becomes the latency in seconds, with a 95% confidence
+/// interval derived from the standard deviation, and the individual run
+/// times become samples. Median, min/max, user and system time, scan
+/// parameters and any non-zero exit codes are kept in the result metadata.
+pub fn import_hyperfine(json: &str) -> Result<HashMap<String, BenchmarkResult>, serde_json::Error> {
+    let export: HyperfineExport = serde_json::from_str(json)?;
+    let mut benchmarks = HashMap::new();
+    for run in export.results {
+        let n = run.times.len();
+        let latency = match run.stddev {
+            Some(stddev) if n > 1 => {
+                let (lower, upper) = calculate_confidence_interval_default(run.mean, stddev, n);
+                MetricValue::with_bounds(run.mean, "s", lower, upper)
+            }
+            _ => MetricValue::new(run.mean, "s"),
+        };
+
+        let mut result = BenchmarkResult {
+            latency: Some(latency),
+            iterations: n,
+            samples: run.times,
+            ..Default::default()
+        };
+        for (key, value) in [
+            ("median_s", run.median),
+            ("min_s", run.min),
+            ("max_s", run.max),
+            ("user_s", run.user),
+            ("system_s", run.system),
+        ] {
+            result.metadata.insert(key.to_string(), json!(value));
+        }
+        if let Some(stddev) = run.stddev {
+            result
+                .metadata
+                .insert("stddev_s".to_string(), json!(stddev));
+        }
+        if !run.parameters.is_empty() {
+            result
+                .metadata
+                .insert("parameters".to_string(), json!(run.parameters));
+        }
+        if run.exit_codes.iter().any(|code| *code != Some(0)) {
+            result
+                .metadata
+                .insert("exit_codes".to_string(), json!(run.exit_codes));
+        }
+        benchmarks.insert(run.command, result);
+    }
+    Ok(benchmarks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"{"results": [
+        {
+            "command": "prover --circuit keccak --size 1024",
+            "mean": 1.25,
+            "stddev": 0.05,
+            "median": 1.24,
+            "user": 4.1,
+            "system": 0.2,
+            "min": 1.2,
+            "max": 1.31,
+            "times": [1.2, 1.24, 1.25, 1.31],
+            "exit_codes": [0, 0, 0, 0],
+            "parameters": {"size": "1024"}
+        },
+        {
+            "command": "verifier",
+            "mean": 0.01,
+            "stddev": null,
+            "median": 0.01,
+            "user": 0.0,
+            "system": 0.0,
+            "min": 0.01,
+            "max": 0.01,
+            "times": [0.01],
+            "exit_codes": [1]
+        }
+    ]}"#;
+
+    #[test]
+    fn commands_become_latency_benchmarks() {
+        let benchmarks = import_hyperfine(EXPORT).unwrap();
+        let result = &benchmarks["prover --circuit keccak --size 1024"];
+        let latency = result.latency.as_ref().unwrap();
+        assert_eq!(latency.unit, "s");
+        assert_eq!(latency.value, 1.25);
+        assert!(latency.lower_value.unwrap() < 1.25);
+        assert!(latency.upper_value.unwrap() > 1.25);
+        assert_eq!(result.samples, vec![1.2, 1.24, 1.25, 1.31]);
+        assert_eq!(result.iterations, 4);
+        assert_eq!(result.metadata["user_s"], 4.1);
+        assert_eq!(result.metadata["parameters"]["size"], "1024");
+        assert!(!result.metadata.contains_key("exit_codes"));
+    }
+
+    #[test]
+    fn single_run_has_no_interval() {
+        let benchmarks = import_hyperfine(EXPORT).unwrap();
+        let result = &benchmarks["verifier"];
+        assert!(result.latency.as_ref().unwrap().lower_value.is_none());
+        assert!(!result.metadata.contains_key("stddev_s"));
+        assert_eq!(result.metadata["exit_codes"], json!([1]));
+    }
+
+    #[test]
+    fn invalid_json_is_an_error() {
+        assert!(import_hyperfine("[]").is_err());
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! iai-callgrind result import.
+//!
+//! Parses the summary iai-callgrind prints for each benchmark:
+//!
+//! ```text
+//! prover_bench::msm::bench_msm small:1 << 10
+//!   Instructions:                  17341|17298                (+0.24859%) [+1.00249x]
+//!   L1 Hits:                       23588|23510                (+0.33177%) [+1.00332x]
+//!   LL Hits:                          12|12                   (No change)
+//!   RAM Hits:                          3|3                    (No change)
+//!   Total read+write:              23603|23525                (+0.33156%) [+1.00332x]
+//!   Estimated Cycles:              23753|23675                (+0.32946%) [+1.00329x]
+//! ```
+//!
+//! Older releases print `17341 (17298)` instead of `17341|17298`; both
+//! forms are accepted and only the current (left) value is imported.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Imports the summaries printed by iai-callgrind.
+///
+/// The module path and id become the benchmark name (`::` replaced by
+/// `/`, so the example above becomes `prover_bench/msm/bench_msm/small`)
+/// and the benchmark arguments are kept in the `args` metadata entry.
+/// Every metric line becomes a counter with unit `count`, keyed by the
+/// snake-cased label (`instructions`, `l1_hits`, `ll_hits`, `ram_hits`,
+/// `total_read_write`, `estimated_cycles`, ...). Callgrind measurements are
+/// deterministic, so results carry a single iteration and no latency.
+///
+/// Lines that do not belong to a summary are ignored, so the full output of
+/// `cargo bench` can be passed in.
+pub fn import_iai_callgrind(output: &str) -> HashMap<String, BenchmarkResult> {
+    let mut benchmarks = HashMap::new();
+    let mut current: Option<(String, BenchmarkResult)> = None;
+    for line in output.lines() {
+        if line.starts_with(char::is_whitespace) {
+            if let Some((_, result)) = current.as_mut()
+                && let Some((name, value)) = metric_line(line)
+            {
+                result
+                    .counters
+                    .insert(name, MetricValue::new(value, "count"));
+            }
+            continue;
+        }
+        if let Some((name, result)) = current.take()
+            && !result.counters.is_empty()
+        {
+            benchmarks.insert(name, result);
+        }
+        current = header_line(line);
+    }
+    if let Some((name, result)) = current
+        && !result.counters.is_empty()
+    {
+        benchmarks.insert(name, result);
+    }
+    benchmarks
+}
+
+/// Parses `module::path::function [id:args]`.
+fn header_line(line: &str) -> Option<(String, BenchmarkResult)> {
+    let line = line.trim();
+    let (path, rest) = line.split_once(' ').unwrap_or((line, ""));
+    if !path.contains("::") || path.split("::").any(str::is_empty) {
+        return None;
+    }
+    let mut name = path.replace("::", "/");
+    let mut result = BenchmarkResult {
+        iterations: 1,
+        ..Default::default()
+    };
+    if let Some((id, args)) = rest.split_once(':') {
+        name.push('/');
+        name.push_str(id.trim());
+        result
+            .metadata
+            .insert("args".to_string(), json!(args.trim()));
+    }
+    Some((name, result))
+}
+
+/// Parses `  Label:   new|old   (change)` into a counter name and value.
+fn metric_line(line: &str) -> Option<(String, f64)> {
+    let (label, rest) = line.trim().split_once(':')?;
+    let value = rest
+        .split(|c: char| c == '|' || c.is_whitespace())
+        .find(|token| !token.is_empty())?
+        .parse::<f64>()
+        .ok()?;
+    let mut name = String::new();
+    for c in label.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_lowercase());
+        } else if !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    Some((name.trim_end_matches('_').to_string(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "\
+     Running benches/iai.rs (target/release/deps/iai-1234)
+prover_bench::msm::bench_msm small:1 << 10
+  Instructions:                  17341|17298                (+0.24859%) [+1.00249x]
+  L1 Hits:                       23588|23510                (+0.33177%) [+1.00332x]
+  LL Hits:                          12|12                   (No change)
+  RAM Hits:                          3|3                    (No change)
+  Total read+write:              23603|23525                (+0.33156%) [+1.00332x]
+  Estimated Cycles:              23753|23675                (+0.32946%) [+1.00329x]
+prover_bench::hash::bench_poseidon
+  Instructions:                    900 (No change)
+  Estimated Cycles:               1200 (1100)
+";
+
+    #[test]
+    fn summaries_become_counters() {
+        let benchmarks = import_iai_callgrind(OUTPUT);
+        assert_eq!(benchmarks.len(), 2);
+
+        let result = &benchmarks["prover_bench/msm/bench_msm/small"];
+        assert_eq!(result.counters["instructions"].value, 17341.0);
+        assert_eq!(result.counters["instructions"].unit, "count");
+        assert_eq!(result.counters["l1_hits"].value, 23588.0);
+        assert_eq!(result.counters["total_read_write"].value, 23603.0);
+        assert_eq!(result.counters["estimated_cycles"].value, 23753.0);
+        assert_eq!(result.counters.len(), 6);
+        assert_eq!(result.iterations, 1);
+        assert_eq!(result.metadata["args"], "1 << 10");
+        assert!(result.latency.is_none());
+    }
+
+    #[test]
+    fn legacy_format_without_id() {
+        let benchmarks = import_iai_callgrind(OUTPUT);
+        let result = &benchmarks["prover_bench/hash/bench_poseidon"];
+        assert_eq!(result.counters["instructions"].value, 900.0);
+        assert_eq!(result.counters["estimated_cycles"].value, 1200.0);
+        assert!(!result.metadata.contains_key("args"));
+    }
+
+    #[test]
+    fn unavailable_values_are_skipped() {
+        let benchmarks = import_iai_callgrind(
+            "a::b\n  Instructions:   N/A|100   (*********)\n  L1 Hits:   5|N/A\n",
+        );
+        let result = &benchmarks["a/b"];
+        assert!(!result.counters.contains_key("instructions"));
+        assert_eq!(result.counters["l1_hits"].value, 5.0);
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! JMH (Java Microbenchmark Harness) result import.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JmhRun {
+    benchmark: String,
+    mode: String,
+    #[serde(default)]
+    threads: Option<u64>,
+    #[serde(default)]
+    forks: Option<u64>,
+    #[serde(default)]
+    jdk_version: Option<String>,
+    #[serde(default)]
+    measurement_iterations: Option<u64>,
+    #[serde(default)]
+    params: BTreeMap<String, String>,
+    primary_metric: JmhMetric,
+    #[serde(default)]
+    secondary_metrics: BTreeMap<String, JmhMetric>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JmhMetric {
+    score: f64,
+    /// Numbers, or the string `"NaN"` when JMH could not compute a bound.
+    #[serde(default)]
+    score_confidence: Option<[Value; 2]>,
+    score_unit: String,
+    #[serde(default)]
+    raw_data: Vec<Vec<f64>>,
+}
+
+/// Imports the JSON written by JMH's `-rf json` result format.
+///
+/// Average-time, sample-time and single-shot runs become latency metrics
+/// (`ns/op` → `ns`), throughput runs become `ops/s` throughput metrics.
+/// Score confidence intervals map onto metric bounds and raw per-iteration
+/// scores of time-based modes become samples. Each JMH parameter value is
+/// appended to the benchmark name as a path segment (in key order), and the
+/// mode, parameters, JDK version and secondary metrics are kept in the
+/// result metadata.
+pub fn import_jmh(json: &str) -> Result<HashMap<String, BenchmarkResult>, serde_json::Error> {
+    let runs: Vec<JmhRun> = serde_json::from_str(json)?;
+    let mut benchmarks = HashMap::new();
+    for run in runs {
+        let mut name = run.benchmark.clone();
+        for value in run.params.values() {
+            name.push('/');
+            name.push_str(value);
+        }
+
+        let mut result = BenchmarkResult {
+            iterations: (run.measurement_iterations.unwrap_or(0) * run.forks.unwrap_or(1)) as usize,
+            ..Default::default()
+        };
+        let metric = &run.primary_metric;
+        if run.mode == "thrpt" {
+            result.throughput = metric_per_second(metric);
+        } else {
+            result.latency = metric_per_op(metric);
+            if result.latency.is_some() {
+                result.samples = metric.raw_data.iter().flatten().copied().collect();
+            }
+        }
+
+        result.metadata.insert("mode".to_string(), json!(run.mode));
+        if !run.params.is_empty() {
+            result
+                .metadata
+                .insert("params".to_string(), json!(run.params));
+        }
+        if let Some(threads) = run.threads {
+            result
+                .metadata
+                .insert("threads".to_string(), json!(threads));
+        }
+        if let Some(jdk) = run.jdk_version {
+            result
+                .metadata
+                .insert("jdk_version".to_string(), json!(jdk));
+        }
+        if !run.secondary_metrics.is_empty() {
+            let secondary: BTreeMap<&String, Value> = run
+                .secondary_metrics
+                .iter()
+                .map(|(key, m)| (key, json!({"score": m.score, "unit": m.score_unit})))
+                .collect();
+            result
+                .metadata
+                .insert("secondary_metrics".to_string(), json!(secondary));
+        }
+        benchmarks.insert(name, result);
+    }
+    Ok(benchmarks)
+}
+
+/// Converts a `<time>/op` score into a latency metric.
+fn metric_per_op(metric: &JmhMetric) -> Option<MetricValue> {
+    let unit = match metric.score_unit.strip_suffix("/op")? {
+        "ns" => "ns",
+        "us" | "µs" => "us",
+        "ms" => "ms",
+        "s" => "s",
+        _ => return None,
+    };
+    Some(with_confidence(metric, unit, 1.0))
+}
+
+/// Converts an `ops/<time>` score into an `ops/s` throughput metric.
+fn metric_per_second(metric: &JmhMetric) -> Option<MetricValue> {
+    let scale = match metric.score_unit.strip_prefix("ops/")? {
+        "ns" => 1e9,
+        "us" | "µs" => 1e6,
+        "ms" => 1e3,
+        "s" => 1.0,
+        "min" => 1.0 / 60.0,
+        _ => return None,
+    };
+    Some(with_confidence(metric, "ops/s", scale))
+}
+
+fn with_confidence(metric: &JmhMetric, unit: &str, scale: f64) -> MetricValue {
+    let bounds = metric
+        .score_confidence
+        .as_ref()
+        .and_then(|[lower, upper]| finite(lower).zip(finite(upper)));
+    match bounds {
+        Some((lower, upper)) => {
+            MetricValue::with_bounds(metric.score * scale, unit, lower * scale, upper * scale)
+        }
+        None => MetricValue::new(metric.score * scale, unit),
+    }
+}
+
+fn finite(value: &Value) -> Option<f64> {
+    value.as_f64().filter(|v| v.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AVGT: &str = r#"[{
+        "jmhVersion": "1.37",
+        "benchmark": "org.zk.VerifierBench.verify",
+        "mode": "avgt",
+        "threads": 1,
+        "forks": 2,
+        "jdkVersion": "21.0.2",
+        "measurementIterations": 3,
+        "params": {"circuit": "keccak", "logN": "16"},
+        "primaryMetric": {
+            "score": 1.5,
+            "scoreError": 0.1,
+            "scoreConfidence": [1.4, 1.6],
+            "scoreUnit": "ms/op",
+            "rawData": [[1.4, 1.5, 1.6], [1.5, 1.5, 1.5]]
+        },
+        "secondaryMetrics": {
+            "·gc.alloc.rate.norm": {"score": 2048.0, "scoreUnit": "B/op"}
+        }
+    }]"#;
+
+    #[test]
+    fn average_time_becomes_latency() {
+        let benchmarks = import_jmh(AVGT).unwrap();
+        let result = &benchmarks["org.zk.VerifierBench.verify/keccak/16"];
+        let latency = result.latency.as_ref().unwrap();
+        assert_eq!(latency.unit, "ms");
+        assert_eq!(latency.value, 1.5);
+        assert_eq!(latency.lower_value, Some(1.4));
+        assert_eq!(result.samples.len(), 6);
+        assert_eq!(result.iterations, 6);
+        assert_eq!(result.metadata["mode"], "avgt");
+        assert_eq!(result.metadata["params"]["logN"], "16");
+        assert_eq!(
+            result.metadata["secondary_metrics"]["·gc.alloc.rate.norm"]["unit"],
+            "B/op"
+        );
+    }
+
+    #[test]
+    fn throughput_is_normalized_to_seconds() {
+        let json = r#"[{
+            "benchmark": "a.B.c",
+            "mode": "thrpt",
+            "primaryMetric": {
+                "score": 2.0,
+                "scoreConfidence": [1.5, 2.5],
+                "scoreUnit": "ops/ms",
+                "rawData": [[2.0]]
+            }
+        }]"#;
+        let benchmarks = import_jmh(json).unwrap();
+        let result = &benchmarks["a.B.c"];
+        let throughput = result.throughput.as_ref().unwrap();
+        assert_eq!(throughput.unit, "ops/s");
+        assert_eq!(throughput.value, 2000.0);
+        assert_eq!(throughput.upper_value, Some(2500.0));
+        assert!(result.latency.is_none());
+        assert!(result.samples.is_empty());
+    }
+
+    #[test]
+    fn nan_confidence_is_dropped() {
+        // JMH writes "NaN" confidence bounds for single-iteration runs.
+        let json = r#"[{
+            "benchmark": "a.B.c",
+            "mode": "ss",
+            "primaryMetric": {"score": 3.0, "scoreConfidence": ["NaN", "NaN"], "scoreUnit": "s/op"}
+        }]"#;
+        let benchmarks = import_jmh(json).unwrap();
+        let latency = benchmarks["a.B.c"].latency.as_ref().unwrap();
+        assert_eq!(latency.unit, "s");
+        assert!(latency.lower_value.is_none());
+    }
+
+    #[test]
+    fn invalid_json_is_an_error() {
+        assert!(import_jmh("{}").is_err());
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Importers converting other benchmark harnesses' output into zkbench
+//! results.
+//!
+//! Importers return the converted benchmarks keyed by name, so they can be
+//! merged into any report's `benchmarks` map alongside native results.
+
+mod bmf;
+mod criterion;
+mod csv;
+mod divan;
+mod github_action_benchmark;
+mod hyperfine;
+mod iai_callgrind;
+mod jmh;
+
+pub use bmf::import_bmf;
+pub use criterion::{aggregate_criterion, import_criterion};
+pub use csv::{CsvError, import_csv};
+pub use divan::import_divan;
+pub use github_action_benchmark::import_github_action_benchmark;
+pub use hyperfine::import_hyperfine;
+pub use iai_callgrind::import_iai_callgrind;
+pub use jmh::import_jmh;
@@ -0,0 +1,249 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Disk and I/O counters of the benchmark process.
+//!
+//! Provers that stream witnesses or an SRS from disk spend phases waiting
+//! on storage, which looks like a slow CPU in the latency alone.
+//! [`IoCounters`] snapshots the process I/O accounting before and after
+//! the measured region, from `/proc/self/io` on Linux,
+//! `proc_pid_rusage` on macOS and `GetProcessIoCounters` on Windows, and
+//! records the difference as counters of the result.
+
+use std::io;
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// I/O of the current process, all threads included. Counts a platform
+/// does not report are `None`.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{BenchmarkResult, IoCounters};
+/// # fn load_srs_and_prove() {}
+///
+/// let before = IoCounters::now()?;
+/// load_srs_and_prove();
+/// let io = IoCounters::now()?.since(&before);
+/// let mut result = BenchmarkResult {
+///     iterations: 1,
+///     ..Default::default()
+/// };
+/// io.record(&mut result);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IoCounters {
+    /// Bytes returned by read calls, whether from disk or the page cache.
+    pub read_bytes: Option<u64>,
+    /// Bytes passed to write calls.
+    pub written_bytes: Option<u64>,
+    /// Number of read calls.
+    pub read_syscalls: Option<u64>,
+    /// Number of write calls.
+    pub write_syscalls: Option<u64>,
+    /// Bytes fetched from storage, including through memory-mapped files.
+    pub disk_read_bytes: Option<u64>,
+    /// Bytes sent to storage.
+    pub disk_written_bytes: Option<u64>,
+    /// Nanoseconds the main thread waited for block I/O. Linux only
+    /// counts it with `kernel.task_delayacct` enabled.
+    pub io_wait_ns: Option<u64>,
+}
+
+impl IoCounters {
+    /// The counts since the process started.
+    pub fn now() -> io::Result<Self> {
+        now()
+    }
+
+    /// The counts since `earlier`, a snapshot of the same process.
+    pub fn since(&self, earlier: &IoCounters) -> Self {
+        let delta = |now: Option<u64>, earlier: Option<u64>| Some(now?.saturating_sub(earlier?));
+        Self {
+            read_bytes: delta(self.read_bytes, earlier.read_bytes),
+            written_bytes: delta(self.written_bytes, earlier.written_bytes),
+            read_syscalls: delta(self.read_syscalls, earlier.read_syscalls),
+            write_syscalls: delta(self.write_syscalls, earlier.write_syscalls),
+            disk_read_bytes: delta(self.disk_read_bytes, earlier.disk_read_bytes),
+            disk_written_bytes: delta(self.disk_written_bytes, earlier.disk_written_bytes),
+            io_wait_ns: delta(self.io_wait_ns, earlier.io_wait_ns),
+        }
+    }
+
+    /// Records the counts as counters of `result`, divided by its
+    /// iterations so they are per iteration like its latency: bytes as
+    /// `io_read_bytes`, `io_written_bytes`, `disk_read_bytes` and
+    /// `disk_written_bytes` in `B`, calls as `io_read_syscalls` and
+    /// `io_write_syscalls`, and the wait as `io_wait` in `ns`.
+    pub fn record(&self, result: &mut BenchmarkResult) {
+        let iterations = result.iterations.max(1) as f64;
+        for (name, count, unit) in [
+            ("io_read_bytes", self.read_bytes, "B"),
+            ("io_written_bytes", self.written_bytes, "B"),
+            ("io_read_syscalls", self.read_syscalls, "count"),
+            ("io_write_syscalls", self.write_syscalls, "count"),
+            ("disk_read_bytes", self.disk_read_bytes, "B"),
+            ("disk_written_bytes", self.disk_written_bytes, "B"),
+            ("io_wait", self.io_wait_ns, "ns"),
+        ] {
+            if let Some(count) = count {
+                result.counters.insert(
+                    name.to_string(),
+                    MetricValue::new(count as f64 / iterations, unit),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn now() -> io::Result<IoCounters> {
+    let io = std::fs::read_to_string("/proc/self/io")?;
+    let field = |name: &str| {
+        io.lines().find_map(|line| {
+            line.strip_prefix(name)?
+                .strip_prefix(':')?
+                .trim()
+                .parse()
+                .ok()
+        })
+    };
+    Ok(IoCounters {
+        read_bytes: field("rchar"),
+        written_bytes: field("wchar"),
+        read_syscalls: field("syscr"),
+        write_syscalls: field("syscw"),
+        disk_read_bytes: field("read_bytes"),
+        disk_written_bytes: field("write_bytes"),
+        io_wait_ns: io_wait_ns(),
+    })
+}
+
+/// Reads `delayacct_blkio_ticks` from `/proc/self/stat`, when delay
+/// accounting is on; otherwise it is always zero.
+#[cfg(target_os = "linux")]
+fn io_wait_ns() -> Option<u64> {
+    let enabled = std::fs::read_to_string("/proc/sys/kernel/task_delayacct").ok()?;
+    if enabled.trim() != "1" {
+        return None;
+    }
+    let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+    // Fields after the parenthesized command name start at field 3, and
+    // the name may contain spaces; see proc(5).
+    let ticks: u64 = stat
+        .rsplit_once(')')?
+        .1
+        .split_whitespace()
+        .nth(42 - 3)?
+        .parse()
+        .ok()?;
+    // SAFETY: sysconf has no preconditions.
+    let ticks_per_second = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    (ticks_per_second > 0).then(|| ticks * 1_000_000_000 / ticks_per_second as u64)
+}
+
+#[cfg(target_os = "macos")]
+fn now() -> io::Result<IoCounters> {
+    // SAFETY: `rusage_info_v2` is plain data, for which all zeroes is valid.
+    let mut info: libc::rusage_info_v2 = unsafe { std::mem::zeroed() };
+    // SAFETY: `info` is a writable `rusage_info_v2`, as the flavor asks.
+    let status = unsafe {
+        libc::proc_pid_rusage(libc::getpid(), libc::RUSAGE_INFO_V2, (&raw mut info).cast())
+    };
+    if status != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(IoCounters {
+        disk_read_bytes: Some(info.ri_diskio_bytesread),
+        disk_written_bytes: Some(info.ri_diskio_byteswritten),
+        ..Default::default()
+    })
+}
+
+#[cfg(windows)]
+fn now() -> io::Result<IoCounters> {
+    use windows_sys::Win32::System::Threading::{
+        GetCurrentProcess, GetProcessIoCounters, IO_COUNTERS,
+    };
+
+    let mut counters = IO_COUNTERS::default();
+    // SAFETY: the pseudo handle of the current process needs no closing,
+    // and `counters` is a writable `IO_COUNTERS`.
+    if unsafe { GetProcessIoCounters(GetCurrentProcess(), &mut counters) } == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(IoCounters {
+        read_bytes: Some(counters.ReadTransferCount),
+        written_bytes: Some(counters.WriteTransferCount),
+        read_syscalls: Some(counters.ReadOperationCount),
+        write_syscalls: Some(counters.WriteOperationCount),
+        ..Default::default()
+    })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn now() -> io::Result<IoCounters> {
+    Ok(IoCounters::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_file_io() {
+        let path = std::env::temp_dir().join(format!("zkbench-io-{}", std::process::id()));
+        let before = IoCounters::now().unwrap();
+        std::fs::write(&path, vec![7u8; 1 << 20]).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap().len(), 1 << 20);
+        std::fs::remove_file(&path).unwrap();
+        let io = IoCounters::now().unwrap().since(&before);
+        if cfg!(any(target_os = "linux", windows)) {
+            assert!(io.written_bytes.unwrap() >= 1 << 20, "{io:?}");
+            assert!(io.read_bytes.unwrap() >= 1 << 20, "{io:?}");
+            assert!(io.read_syscalls.unwrap() > 0);
+        }
+
+        let io = IoCounters {
+            disk_read_bytes: Some(4096),
+            io_wait_ns: Some(1_000),
+            ..Default::default()
+        };
+        let mut result = BenchmarkResult {
+            iterations: 2,
+            ..Default::default()
+        };
+        io.record(&mut result);
+        assert_eq!(
+            result.counters["disk_read_bytes"],
+            MetricValue::new(2048.0, "B")
+        );
+        assert_eq!(result.counters["io_wait"], MetricValue::new(500.0, "ns"));
+        assert_eq!(result.counters.len(), 2);
+    }
+
+    #[test]
+    fn subtracts_snapshots() {
+        let earlier = IoCounters {
+            read_bytes: Some(100),
+            disk_written_bytes: Some(8192),
+            ..Default::default()
+        };
+        let later = IoCounters {
+            read_bytes: Some(350),
+            disk_written_bytes: Some(8192),
+            io_wait_ns: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(
+            later.since(&earlier),
+            IoCounters {
+                read_bytes: Some(250),
+                disk_written_bytes: Some(0),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,374 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON Schema of the report format.
+//!
+//! [`report_json_schema`] describes reports as this crate writes them at
+//! [`SCHEMA_VERSION`], so validators and code generators for other
+//! languages can work from the same definition as the Rust types. Reports
+//! of older versions are upgraded with [`migrate_json`](crate::migrate_json)
+//! before they are checked against it.
+
+use serde_json::{Value, json};
+
+use crate::schema::SCHEMA_VERSION;
+
+/// JSON Schema dialect of [`report_json_schema`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Returns the JSON Schema (draft 2020-12) of a [`BenchmarkReport`]
+/// written at [`SCHEMA_VERSION`].
+///
+/// Optional fields that are omitted when empty are not `required`; fields
+/// unknown to this version are allowed, as readers ignore them.
+///
+/// [`BenchmarkReport`]: crate::BenchmarkReport
+pub fn report_json_schema() -> Value {
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "$id": format!("urn:zkbench:report:v{SCHEMA_VERSION}"),
+        "title": "zkbench benchmark report",
+        "description": format!("Benchmark report, schema version {SCHEMA_VERSION}."),
+        "type": "object",
+        "required": ["metadata", "benchmarks"],
+        "properties": {
+            "metadata": { "$ref": "#/$defs/Metadata" },
+            "benchmarks": {
+                "description": "Results by benchmark name.",
+                "type": "object",
+                "additionalProperties": { "$ref": "#/$defs/BenchmarkResult" }
+            }
+        },
+        "$defs": {
+            "Metadata": {
+                "type": "object",
+                "required": [
+                    "schema_version", "implementation", "version", "commit_sha",
+                    "timestamp", "platform"
+                ],
+                "properties": {
+                    "schema_version": {
+                        "description": "Version of the schema the report was written with.",
+                        "const": SCHEMA_VERSION
+                    },
+                    "implementation": { "type": "string" },
+                    "version": {
+                        "description": "Version of the implementation.",
+                        "type": "string"
+                    },
+                    "commit_sha": { "type": "string" },
+                    "timestamp": { "type": "string", "format": "date-time" },
+                    "platform": { "$ref": "#/$defs/Platform" },
+                    "branch": {
+                        "description": "Branch the commit was checked out on, when not detached.",
+                        "type": "string"
+                    },
+                    "git_tag": {
+                        "description": "Tag pointing at the commit.",
+                        "type": "string"
+                    },
+                    "dirty": {
+                        "description": "Whether the working tree had uncommitted changes.",
+                        "type": "boolean",
+                        "default": false
+                    },
+                    "run_id": {
+                        "description": "Id of the run that produced the report, such as a CI run id.",
+                        "type": "string"
+                    },
+                    "run_url": {
+                        "description": "Link to the CI run or job that produced the report.",
+                        "type": "string",
+                        "format": "uri"
+                    },
+                    "test_vector_commitment": { "$ref": "#/$defs/TestVectorCommitment" },
+                    "cpu_pinning": { "$ref": "#/$defs/CpuPinning" }
+                }
+            },
+            "CpuPinning": {
+                "description": "Cores the benchmark threads were pinned to.",
+                "type": "object",
+                "required": ["cores"],
+                "properties": {
+                    "cores": {
+                        "type": "array",
+                        "items": { "type": "integer", "minimum": 0 }
+                    },
+                    "performance_cores_only": {
+                        "description": "Whether the cores were chosen as the performance cores.",
+                        "type": "boolean",
+                        "default": false
+                    }
+                }
+            },
+            "Platform": {
+                "type": "object",
+                "required": ["os", "arch", "cpu_count"],
+                "properties": {
+                    "os": { "type": "string" },
+                    "arch": { "type": "string" },
+                    "cpu_count": { "type": "integer", "minimum": 0 },
+                    "cpu_vendor": { "type": "string" },
+                    "gpu_vendor": { "type": "string" },
+                    "cpu_mhz": {
+                        "description": "Maximum CPU clock frequency in MHz.",
+                        "type": "integer",
+                        "minimum": 0
+                    }
+                }
+            },
+            "BenchmarkResult": {
+                "type": "object",
+                "properties": {
+                    "latency": { "$ref": "#/$defs/MetricValue" },
+                    "memory": { "$ref": "#/$defs/MetricValue" },
+                    "throughput": { "$ref": "#/$defs/MetricValue" },
+                    "memory_profile": { "$ref": "#/$defs/MemoryProfile" },
+                    "iterations": { "type": "integer", "minimum": 0, "default": 0 },
+                    "test_vectors": { "$ref": "#/$defs/TestVectors" },
+                    "samples": {
+                        "description": "Raw per-iteration latency samples, in the unit of latency.",
+                        "type": "array",
+                        "items": { "type": "number" }
+                    },
+                    "artifacts": {
+                        "type": "array",
+                        "items": { "$ref": "#/$defs/Artifact" }
+                    },
+                    "counters": {
+                        "description": "Additional named metrics beyond latency, memory and throughput.",
+                        "type": "object",
+                        "additionalProperties": { "$ref": "#/$defs/MetricValue" }
+                    },
+                    "tags": {
+                        "type": "array",
+                        "items": { "type": "string" }
+                    },
+                    "metadata": {
+                        "description": "Free-form data about the benchmark.",
+                        "type": "object"
+                    }
+                }
+            },
+            "MetricValue": {
+                "type": "object",
+                "required": ["value", "unit"],
+                "properties": {
+                    "value": { "type": "number" },
+                    "unit": {
+                        "description": "Unit such as ns, ms, s, B, MiB or ops/s.",
+                        "type": "string"
+                    },
+                    "lower_value": {
+                        "description": "Lower confidence bound.",
+                        "type": "number"
+                    },
+                    "upper_value": {
+                        "description": "Upper confidence bound.",
+                        "type": "number"
+                    }
+                }
+            },
+            "MemoryProfile": {
+                "description": "Memory use in bytes.",
+                "type": "object",
+                "properties": {
+                    "peak_rss": { "type": "integer", "minimum": 0 },
+                    "rss": { "type": "integer", "minimum": 0 },
+                    "heap_peak": { "type": "integer", "minimum": 0 },
+                    "virtual_size": { "type": "integer", "minimum": 0 }
+                }
+            },
+            "TestVectors": {
+                "type": "object",
+                "required": ["input_hash", "output_hash", "verified"],
+                "properties": {
+                    "input_hash": { "$ref": "#/$defs/Digest" },
+                    "output_hash": { "$ref": "#/$defs/Digest" },
+                    "verified": { "type": "boolean" },
+                    "algorithm": {
+                        "description": "Hash function of both digests.",
+                        "$ref": "#/$defs/HashAlgorithm"
+                    },
+                    "hmac": {
+                        "description": "HMAC-SHA256 of the digests under a secret key.",
+                        "$ref": "#/$defs/Digest"
+                    }
+                }
+            },
+            "TestVectorCommitment": {
+                "description": "Merkle root over an ordered set of test vectors.",
+                "type": "object",
+                "required": ["root", "leaves"],
+                "properties": {
+                    "root": { "type": "string" },
+                    "leaves": { "type": "integer", "minimum": 0 },
+                    "algorithm": {
+                        "description": "Hash function of the tree.",
+                        "$ref": "#/$defs/HashAlgorithm"
+                    }
+                }
+            },
+            "Digest": {
+                "description": "32-byte digest as hex, optionally prefixed with 0x.",
+                "type": "string",
+                "pattern": "^(0[xX])?[0-9a-fA-F]{64}$"
+            },
+            "HashAlgorithm": {
+                "enum": [
+                    "sha256", "blake3", "keccak256", "sha3-256", "poseidon-bn254",
+                    "poseidon2-bn254", "poseidon-bls12-381", "poseidon2-bls12-381"
+                ],
+                "default": "sha256"
+            },
+            "Artifact": {
+                "type": "object",
+                "required": ["kind", "location"],
+                "properties": {
+                    "kind": {
+                        "description": "What the artifact contains; readers map unknown kinds to other.",
+                        "type": "string",
+                        "examples": [
+                            "flamegraph", "perf_data", "profile", "gpu_trace", "proof",
+                            "witness", "other"
+                        ]
+                    },
+                    "location": {
+                        "description": "Local path or URL of the artifact.",
+                        "type": "string"
+                    },
+                    "content_hash": {
+                        "description": "Content hash in sha256:<hex> form.",
+                        "type": "string"
+                    },
+                    "size_bytes": { "type": "integer", "minimum": 0 }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::affinity::CpuPinning;
+    use crate::artifact::{Artifact, ArtifactKind};
+    use crate::hash::HashAlgorithm;
+    use crate::memory::MemoryProfile;
+    use crate::merkle::TestVectorCommitment;
+    use crate::schema::{BenchmarkReport, BenchmarkResult, Metadata, MetricValue, TestVectors};
+
+    /// Checks `value` against the subset of JSON Schema used above.
+    fn check(root: &Value, schema: &Value, value: &Value, path: &str) {
+        if let Some(target) = schema["$ref"].as_str() {
+            let name = target.trim_start_matches("#/$defs/");
+            return check(root, &root["$defs"][name], value, path);
+        }
+        if let Some(expected) = schema.get("const") {
+            assert_eq!(value, expected, "{path}");
+        }
+        match schema["type"].as_str() {
+            Some("object") => {
+                let object = value
+                    .as_object()
+                    .unwrap_or_else(|| panic!("{path}: not an object"));
+                for key in schema["required"].as_array().into_iter().flatten() {
+                    let key = key.as_str().unwrap();
+                    assert!(object.contains_key(key), "{path}: missing {key}");
+                }
+                for (key, field) in object {
+                    let path = format!("{path}/{key}");
+                    match schema["properties"].get(key) {
+                        Some(property) => check(root, property, field, &path),
+                        None => match schema.get("additionalProperties") {
+                            Some(items) => check(root, items, field, &path),
+                            // Free-form objects declare no properties.
+                            None if schema["properties"].is_null() => {}
+                            None => panic!("{path}: not in the schema"),
+                        },
+                    }
+                }
+            }
+            Some("array") => {
+                let items = value
+                    .as_array()
+                    .unwrap_or_else(|| panic!("{path}: not an array"));
+                for (i, item) in items.iter().enumerate() {
+                    check(root, &schema["items"], item, &format!("{path}/{i}"));
+                }
+            }
+            Some("string") => assert!(value.is_string(), "{path}"),
+            Some("number") => assert!(value.is_number(), "{path}"),
+            Some("integer") => assert!(value.is_u64() || value.is_i64(), "{path}"),
+            Some("boolean") => assert!(value.is_boolean(), "{path}"),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn describes_every_field_written() {
+        let mut metadata = Metadata::create("impl", "1.0.0");
+        metadata.branch = Some("main".to_string());
+        metadata.git_tag = Some("v1.0.0".to_string());
+        metadata.dirty = true;
+        metadata.run_id = Some("42".to_string());
+        metadata.run_url = Some("https://ci.example.com/42".to_string());
+        metadata.platform.cpu_vendor = Some("cpu".to_string());
+        metadata.platform.gpu_vendor = Some("gpu".to_string());
+        metadata.platform.cpu_mhz = Some(3000);
+        metadata.test_vector_commitment = Some(TestVectorCommitment {
+            root: "00".to_string(),
+            leaves: 1,
+            algorithm: HashAlgorithm::Keccak256,
+        });
+        metadata.cpu_pinning = Some(CpuPinning {
+            cores: vec![0, 2],
+            performance_cores_only: true,
+        });
+        let result = BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(10.0, "ms", 9.0, 11.0)),
+            memory: Some(MetricValue::new(1.0, "MiB")),
+            throughput: Some(MetricValue::new(100.0, "ops/s")),
+            memory_profile: Some(MemoryProfile {
+                peak_rss: Some(2 << 20),
+                rss: Some(1 << 20),
+                heap_peak: None,
+                virtual_size: Some(8 << 20),
+            }),
+            iterations: 10,
+            test_vectors: Some(TestVectors {
+                verified: true,
+                algorithm: HashAlgorithm::Blake3,
+                hmac: Some("ab".repeat(32).parse().unwrap()),
+                ..TestVectors::compute(HashAlgorithm::Sha256, b"in", b"out")
+            }),
+            samples: vec![9.5, 10.5],
+            artifacts: vec![Artifact {
+                kind: ArtifactKind::Flamegraph,
+                location: "flame.svg".to_string(),
+                content_hash: Some("sha256:00".to_string()),
+                size_bytes: Some(1),
+            }],
+            counters: [("instructions".to_string(), MetricValue::new(5.0, "count"))].into(),
+            tags: vec!["gpu".to_string()],
+            metadata: [("curve".to_string(), json!("bn254"))].into(),
+        };
+        let report = BenchmarkReport {
+            metadata,
+            benchmarks: [("prove".to_string(), result)].into(),
+        };
+        let schema = report_json_schema();
+        let value = serde_json::to_value(&report).unwrap();
+        check(&schema, &schema, &value, "");
+    }
+
+    #[test]
+    fn pins_the_schema_version() {
+        let schema = report_json_schema();
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(
+            schema["$defs"]["Metadata"]["properties"]["schema_version"]["const"],
+            SCHEMA_VERSION
+        );
+    }
+}
@@ -0,0 +1,427 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cross-implementation leaderboards.
+//!
+//! Ranks implementations per benchmark and overall, from one report per
+//! implementation, for public comparison pages. Rendering lives in
+//! [`render_leaderboard_markdown`](crate::render_leaderboard_markdown) and
+//! [`render_leaderboard_html`](crate::render_leaderboard_html).
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{BenchmarkReport, MetricKind, MetricValue};
+use crate::statistics::geometric_mean;
+use crate::trend::parse_timestamp;
+use crate::units::to_base;
+
+/// One implementation's result for one benchmark.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// Competition rank: ties share a rank and the next rank is skipped.
+    pub rank: usize,
+    pub implementation: String,
+    pub version: String,
+    pub value: MetricValue,
+    /// How far behind the best entry this one is, as a factor `>= 1`
+    /// (`2.0` is twice as slow, or half the throughput).
+    pub relative_to_best: f64,
+}
+
+/// Ranking of all implementations that ran a benchmark, best first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkRanking {
+    pub benchmark: String,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// An implementation's standing across all benchmarks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverallEntry {
+    pub rank: usize,
+    pub implementation: String,
+    pub version: String,
+    /// Geometric mean of [`LeaderboardEntry::relative_to_best`] over the
+    /// benchmarks the implementation ran; `1.0` means best everywhere.
+    pub score: f64,
+    /// Number of ranked benchmarks the implementation ran.
+    pub benchmarks: usize,
+    /// Number of benchmarks it ranked first in.
+    pub wins: usize,
+}
+
+/// Leaderboard built by [`LeaderboardBuilder`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Leaderboard {
+    /// Metric the implementations are ranked by.
+    pub metric: MetricKind,
+    /// Overall ranking, best score first.
+    pub overall: Vec<OverallEntry>,
+    /// Per-benchmark rankings, sorted by benchmark name.
+    pub benchmarks: Vec<BenchmarkRanking>,
+}
+
+/// Builds a [`Leaderboard`] from a set of reports.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{BenchmarkReport, BenchmarkResult, LeaderboardBuilder, Metadata, MetricValue};
+///
+/// let report = |implementation: &str, ms: f64| BenchmarkReport {
+///     metadata: Metadata::create(implementation, "1.0.0"),
+///     benchmarks: [(
+///         "prove".to_string(),
+///         BenchmarkResult {
+///             latency: Some(MetricValue::new(ms, "ms")),
+///             ..Default::default()
+///         },
+///     )]
+///     .into_iter()
+///     .collect(),
+/// };
+/// let leaderboard = LeaderboardBuilder::new()
+///     .with_reports([report("slow", 200.0), report("fast", 100.0)])
+///     .build();
+/// assert_eq!(leaderboard.overall[0].implementation, "fast");
+/// assert_eq!(leaderboard.overall[1].score, 2.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LeaderboardBuilder {
+    metric: MetricKind,
+    min_entries: usize,
+    vector_key: Option<Vec<u8>>,
+    reports: Vec<BenchmarkReport>,
+}
+
+impl Default for LeaderboardBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LeaderboardBuilder {
+    /// Creates a builder ranking by latency.
+    pub fn new() -> Self {
+        Self {
+            metric: MetricKind::Latency,
+            min_entries: 1,
+            vector_key: None,
+            reports: Vec::new(),
+        }
+    }
+
+    /// Ranks by `metric` instead of latency.
+    pub fn with_metric(mut self, metric: MetricKind) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    /// Leaves out benchmarks that fewer than `min_entries`
+    /// implementations ran, e.g. `2` to drop benchmarks nobody competes
+    /// in. Defaults to 1.
+    pub fn with_min_entries(mut self, min_entries: usize) -> Self {
+        self.min_entries = min_entries;
+        self
+    }
+
+    /// Only ranks results whose test vectors are
+    /// [authenticated](crate::TestVectors::authenticate) with `key`, so
+    /// that submissions must have run the official fixtures.
+    pub fn with_vector_key(mut self, key: impl Into<Vec<u8>>) -> Self {
+        self.vector_key = Some(key.into());
+        self
+    }
+
+    /// Adds a report.
+    pub fn with_report(mut self, report: BenchmarkReport) -> Self {
+        self.reports.push(report);
+        self
+    }
+
+    /// Adds several reports.
+    pub fn with_reports(mut self, reports: impl IntoIterator<Item = BenchmarkReport>) -> Self {
+        self.reports.extend(reports);
+        self
+    }
+
+    /// Ranks the implementations.
+    ///
+    /// When several reports come from the same implementation, only the
+    /// most recent one (by timestamp) is used.
+    pub fn build(self) -> Leaderboard {
+        let mut latest: HashMap<&str, &BenchmarkReport> = HashMap::new();
+        for report in &self.reports {
+            let implementation = report.metadata.implementation.as_str();
+            let newer = latest.get(implementation).is_none_or(|current| {
+                parse_timestamp(&report.metadata.timestamp)
+                    >= parse_timestamp(&current.metadata.timestamp)
+            });
+            if newer {
+                latest.insert(implementation, report);
+            }
+        }
+
+        let mut by_benchmark: BTreeMap<&str, Vec<(&BenchmarkReport, &MetricValue)>> =
+            BTreeMap::new();
+        for report in latest.values() {
+            for (name, result) in &report.benchmarks {
+                if let Some(key) = &self.vector_key
+                    && !result
+                        .test_vectors
+                        .as_ref()
+                        .is_some_and(|vectors| vectors.is_authentic(key))
+                {
+                    continue;
+                }
+                if let Some(value) = self.metric.get(result)
+                    && value.value.is_finite()
+                    && value.value > 0.0
+                {
+                    by_benchmark.entry(name).or_default().push((report, value));
+                }
+            }
+        }
+
+        let higher_is_better = self.metric.higher_is_better();
+        let benchmarks: Vec<BenchmarkRanking> = by_benchmark
+            .into_iter()
+            .filter(|(_, entries)| entries.len() >= self.min_entries)
+            .map(|(benchmark, mut entries)| {
+                let key = |value: &MetricValue| to_base(value.value, &value.unit);
+                entries.sort_by(|(ra, a), (rb, b)| {
+                    let order = key(a).total_cmp(&key(b));
+                    let order = if higher_is_better {
+                        order.reverse()
+                    } else {
+                        order
+                    };
+                    order.then_with(|| ra.metadata.implementation.cmp(&rb.metadata.implementation))
+                });
+                let best = key(entries[0].1);
+                let relative: Vec<f64> = entries
+                    .iter()
+                    .map(|(_, value)| {
+                        if higher_is_better {
+                            best / key(value)
+                        } else {
+                            key(value) / best
+                        }
+                    })
+                    .collect();
+                let ranks = competition_ranks(&relative);
+                BenchmarkRanking {
+                    benchmark: benchmark.to_string(),
+                    entries: entries
+                        .into_iter()
+                        .zip(relative.into_iter().zip(ranks))
+                        .map(
+                            |((report, value), (relative_to_best, rank))| LeaderboardEntry {
+                                rank,
+                                implementation: report.metadata.implementation.clone(),
+                                version: report.metadata.version.clone(),
+                                value: value.clone(),
+                                relative_to_best,
+                            },
+                        )
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let mut standings: BTreeMap<&str, (&str, Vec<f64>, usize)> = BTreeMap::new();
+        for entry in benchmarks.iter().flat_map(|b| &b.entries) {
+            let (_, ratios, wins) =
+                standings
+                    .entry(&entry.implementation)
+                    .or_insert((&entry.version, Vec::new(), 0));
+            ratios.push(entry.relative_to_best);
+            if entry.rank == 1 {
+                *wins += 1;
+            }
+        }
+        let mut overall: Vec<OverallEntry> = standings
+            .into_iter()
+            .filter_map(|(implementation, (version, ratios, wins))| {
+                Some(OverallEntry {
+                    rank: 0,
+                    implementation: implementation.to_string(),
+                    version: version.to_string(),
+                    score: geometric_mean(&ratios)?,
+                    benchmarks: ratios.len(),
+                    wins,
+                })
+            })
+            .collect();
+        overall.sort_by(|a, b| {
+            a.score
+                .total_cmp(&b.score)
+                .then_with(|| b.benchmarks.cmp(&a.benchmarks))
+                .then_with(|| a.implementation.cmp(&b.implementation))
+        });
+        let scores: Vec<f64> = overall.iter().map(|e| e.score).collect();
+        for (entry, rank) in overall.iter_mut().zip(competition_ranks(&scores)) {
+            entry.rank = rank;
+        }
+
+        Leaderboard {
+            metric: self.metric,
+            overall,
+            benchmarks,
+        }
+    }
+}
+
+/// Ranks of ascending-sorted scores, with ties sharing a rank.
+fn competition_ranks(sorted: &[f64]) -> Vec<usize> {
+    let mut ranks = Vec::with_capacity(sorted.len());
+    for (i, score) in sorted.iter().enumerate() {
+        let rank = if i > 0 && *score == sorted[i - 1] {
+            ranks[i - 1]
+        } else {
+            i + 1
+        };
+        ranks.push(rank);
+    }
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::HashAlgorithm;
+    use crate::schema::{BenchmarkResult, TestVectors};
+    use crate::testing::ReportBuilder;
+
+    fn timed(ms: f64) -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::new(ms, "ms")),
+            throughput: Some(MetricValue::new(1000.0 / ms, "ops/s")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn ranks_per_benchmark_and_overall() {
+        let leaderboard = LeaderboardBuilder::new()
+            .with_reports([
+                ReportBuilder::new()
+                    .implementation("a")
+                    .results([("fft", timed(10.0)), ("msm", timed(40.0))])
+                    .build(),
+                ReportBuilder::new()
+                    .implementation("b")
+                    .results([("fft", timed(20.0)), ("msm", timed(10.0))])
+                    .build(),
+                ReportBuilder::new()
+                    .implementation("c")
+                    .results([("fft", timed(10.0))])
+                    .build(),
+            ])
+            .build();
+        let fft = &leaderboard.benchmarks[0];
+        assert_eq!(fft.benchmark, "fft");
+        let ranks: Vec<_> = fft
+            .entries
+            .iter()
+            .map(|e| (e.implementation.as_str(), e.rank, e.relative_to_best))
+            .collect();
+        assert_eq!(ranks, [("a", 1, 1.0), ("c", 1, 1.0), ("b", 3, 2.0)]);
+
+        let overall: Vec<_> = leaderboard
+            .overall
+            .iter()
+            .map(|e| (e.implementation.as_str(), e.rank, e.wins))
+            .collect();
+        // c is best on the one benchmark it ran; b scores sqrt(2 * 1)
+        // and a sqrt(1 * 4).
+        assert_eq!(overall, [("c", 1, 1), ("b", 2, 1), ("a", 3, 1)]);
+        assert!((leaderboard.overall[1].score - 2f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn throughput_ranks_higher_first() {
+        let leaderboard = LeaderboardBuilder::new()
+            .with_metric(MetricKind::Throughput)
+            .with_reports([
+                ReportBuilder::new()
+                    .implementation("a")
+                    .results([("fft", timed(20.0))])
+                    .build(),
+                ReportBuilder::new()
+                    .implementation("b")
+                    .results([("fft", timed(10.0))])
+                    .build(),
+            ])
+            .build();
+        let entries = &leaderboard.benchmarks[0].entries;
+        assert_eq!(entries[0].implementation, "b");
+        assert!((entries[1].relative_to_best - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn latest_report_wins_and_min_entries_filters() {
+        let mut old = ReportBuilder::new()
+            .implementation("a")
+            .results([("fft", timed(100.0))])
+            .build();
+        old.metadata.timestamp = "2026-01-01T00:00:00Z".to_string();
+        let leaderboard = LeaderboardBuilder::new()
+            .with_min_entries(2)
+            .with_report(
+                ReportBuilder::new()
+                    .implementation("a")
+                    .results([("fft", timed(10.0)), ("solo", timed(1.0))])
+                    .build(),
+            )
+            .with_report(old)
+            .with_report(
+                ReportBuilder::new()
+                    .implementation("b")
+                    .results([("fft", timed(20.0))])
+                    .build(),
+            )
+            .build();
+        assert_eq!(leaderboard.benchmarks.len(), 1);
+        assert_eq!(leaderboard.benchmarks[0].entries[0].implementation, "a");
+        let json = serde_json::to_value(&leaderboard).unwrap();
+        assert_eq!(json["metric"], "latency");
+        assert_eq!(json["overall"][0]["rank"], 1);
+    }
+
+    #[test]
+    fn vector_key_requires_authenticated_results() {
+        let mut reports = [
+            ReportBuilder::new()
+                .implementation("official")
+                .results([("fft", timed(20.0))])
+                .build(),
+            ReportBuilder::new()
+                .implementation("forged")
+                .results([("fft", timed(10.0))])
+                .build(),
+            ReportBuilder::new()
+                .implementation("untested")
+                .results([("fft", timed(5.0))])
+                .build(),
+        ];
+        for (report, key) in reports.iter_mut().zip([&b"secret"[..], b"guess"]) {
+            let result = report.benchmarks.get_mut("fft").unwrap();
+            let mut vectors = TestVectors::compute(HashAlgorithm::Sha256, b"in", b"out");
+            vectors.authenticate(key);
+            result.test_vectors = Some(vectors);
+        }
+        let leaderboard = LeaderboardBuilder::new()
+            .with_vector_key("secret")
+            .with_reports(reports.clone())
+            .build();
+        let entries = &leaderboard.benchmarks[0].entries;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].implementation, "official");
+
+        let leaderboard = LeaderboardBuilder::new().with_reports(reports).build();
+        assert_eq!(leaderboard.benchmarks[0].entries.len(), 3);
+    }
+}
@@ -29,16 +29,183 @@
 //! let json = serde_json::to_string_pretty(&report).unwrap();
 //! ```
 
+mod affinity;
+mod anonymize;
+mod artifact;
+mod atomic;
+pub mod baseline;
+mod bisect;
+mod budget;
+mod callgrind;
+mod compare;
+mod config;
+mod digest;
+mod energy;
+mod env;
+mod export;
+mod fixture;
+mod flatten;
+mod frequency;
+mod gate;
+mod grafana;
+mod guardrail;
 mod hash;
+mod import;
+mod io_counters;
+mod json_schema;
+mod leaderboard;
+mod matching;
+mod memory;
+mod merge;
+mod merkle;
+mod migrate;
+mod noise;
+mod normalize;
+mod notify;
+#[cfg(feature = "nvml")]
+mod nvml;
+mod parallel;
+mod pareto;
+#[cfg(all(feature = "perf", target_os = "linux"))]
+mod perf;
 mod platform;
+#[cfg(feature = "plot")]
+pub mod plot;
+#[cfg(feature = "poseidon")]
+mod poseidon;
+mod powermetrics;
+#[cfg(all(feature = "pprof", unix))]
+mod profiler;
+#[cfg(any(feature = "publish", feature = "object-store"))]
+pub mod publish;
+mod render;
+mod rusage;
+mod scaling;
 mod schema;
+mod score;
+mod speedup;
 mod statistics;
 mod stopwatch;
+pub mod store;
+mod sweep;
+#[cfg(test)]
+pub(crate) mod testing;
+mod trend;
+mod units;
+mod validate;
+pub mod vectors;
+mod watch;
 
-pub use hash::{compute_array_hash, compute_hash};
-pub use platform::{Platform, get_cpu_vendor, get_gpu_vendor};
-pub use schema::{BenchmarkReport, BenchmarkResult, Metadata, MetricValue, TestVectors};
+pub use affinity::{AffinityError, CpuPinning, CpuSelection, pin_current_thread};
+pub use anonymize::{AnonymizeOptions, Anonymized, anonymize};
+pub use artifact::{Artifact, ArtifactKind};
+pub use atomic::write_atomic;
+pub use bisect::{Bisect, BisectError, BisectOutcome, BisectStep, reports_by_commit};
+pub use budget::{
+    BUDGET_EXEMPTION, BudgetEntry, BudgetLedger, BudgetPolicy, BudgetStatus, gate_with_budget,
+    record_budget, suite_debt,
+};
+pub use callgrind::{CALLGRIND_BENCH_ENV, Callgrind, CallgrindError};
+pub use compare::{
+    BenchmarkComparison, BenchmarkThreshold, Classification, CompareOptions, Comparison,
+    DecisionRule, MetricDelta, Verdict, compare,
+};
+pub use config::{CONFIG_FILE_NAME, ComparisonConfig, ConfigError};
+pub use digest::{Digest, DigestError, DigestFormat};
+pub use energy::{ENERGY_COUNTER, EnergyError, EnergyMeter, POWERCAP_DIR};
+pub use env::{CiProvider, OUTPUT_DIR_ENV, RUN_ID_ENV, RunEnvironment};
+#[cfg(feature = "polars")]
+pub use export::reports_to_dataframe;
+#[cfg(feature = "sqlx")]
+pub use export::write_report_postgres;
+pub use export::{
+    CriterionMessageWriter, GithubBenchmarkEntry, SQL_SCHEMA, ShieldsBadge, export_bmf, export_csv,
+    export_github_action_benchmark, export_junit, export_prometheus, report_id, shields_badge,
+    sql_inserts, vega_lite_comparison, vega_lite_distribution,
+};
+#[cfg(feature = "otel")]
+pub use export::{OtelError, export_otlp, otel_resource, record_otel_metrics};
+#[cfg(feature = "arrow")]
+pub use export::{arrow_schema, to_record_batch, write_parquet};
+pub use fixture::{FIXTURE_BUNDLE_VERSION, Fixture, FixtureBundle, FixtureError};
+pub use flatten::{MetricRow, flatten_report};
+pub use frequency::{CPU_FREQUENCY_INTERVAL, FrequencyMonitor, FrequencyStats, THROTTLED_TAG};
+pub use gate::{GatePolicy, GateResult, Violation, ViolationKind, gate};
+pub use grafana::{
+    GrafanaAnnotation, GrafanaAnnotationQuery, GrafanaAnnotationRequest, GrafanaDatasource,
+    GrafanaQueryRequest, GrafanaRange, GrafanaSearchRequest, GrafanaTarget, GrafanaTimeSeries,
+};
+pub use guardrail::{
+    PlatformAction, PlatformDifference, PlatformField, PlatformMismatch, PlatformPolicy,
+};
+pub use hash::{
+    HASH_CHUNK_SIZE, HashAlgorithm, Hasher, PROOF_HASH_KEY, PROOF_SIZE_COUNTER, TestVectorError,
+    TestVectorMismatch, VECTOR_KEY_ENV, compute_array_hash, compute_hash, compute_hash_with,
+    dedup_reports, hash_canonical, hash_canonical_with, hash_dir, hash_dir_with, hash_file,
+    hash_file_chunked, hash_file_chunked_with, hash_file_with, record_proof, to_canonical_json,
+};
+pub use import::{
+    CsvError, aggregate_criterion, import_bmf, import_criterion, import_csv, import_divan,
+    import_github_action_benchmark, import_hyperfine, import_iai_callgrind, import_jmh,
+};
+pub use io_counters::IoCounters;
+pub use json_schema::{JSON_SCHEMA_DIALECT, report_json_schema};
+pub use leaderboard::{
+    BenchmarkRanking, Leaderboard, LeaderboardBuilder, LeaderboardEntry, OverallEntry,
+};
+pub use matching::normalize_benchmark_name;
+pub use memory::{MemoryProfile, TrackingAllocator};
+pub use merge::{DuplicatePolicy, MergeError, MergePolicy, merge_reports, merge_reports_with};
+pub use merkle::{MerkleProof, MerkleTree, TestVectorCommitment};
+pub use migrate::{Migrated, MigrationError, migrate_json, migrate_value, schema_version};
+pub use noise::NoiseProfile;
+pub use normalize::{Normalization, NormalizationNote, group_by_fingerprint, normalize_report};
+pub use notify::{BenchmarkChange, NotificationSummary, Webhook};
+#[cfg(feature = "nvml")]
+pub use nvml::{GPU_SAMPLE_INTERVAL, GpuSampler, GpuStats, SampleSummary};
+pub use parallel::{
+    THREADS_SEGMENT, ThreadPoint, ThreadScaling, ThreadTime, ThreadTimer, ThreadUsage,
+    record_parallel_efficiency, thread_scaling,
+};
+#[cfg(feature = "rayon")]
+pub use parallel::{run_threads, run_threads_pinned};
+pub use pareto::{ParetoAnalysis, ParetoPoint, pareto_frontier};
+#[cfg(all(feature = "perf", target_os = "linux"))]
+pub use perf::{PerfCounter, PerfCounters};
+pub use platform::{
+    EnvironmentWarning, Platform, check_environment, get_cpu_mhz, get_cpu_vendor, get_gpu_vendor,
+};
+pub use powermetrics::POWERMETRICS;
+#[cfg(all(feature = "pprof", unix))]
+pub use profiler::{
+    DEFAULT_PROFILE_FREQUENCY, Profile, ProfileError, SamplingProfiler, attach_regression_profiles,
+};
+pub use render::{
+    PR_COMMENT_MARKER, print_summary, render_comparison_html, render_comparison_markdown,
+    render_comparison_terminal, render_github_annotations, render_html, render_html_with,
+    render_leaderboard_html, render_leaderboard_markdown, render_markdown, render_markdown_with,
+    render_pr_comment, render_terminal, render_terminal_sorted, render_trends_terminal, sparkline,
+};
+pub use rusage::ResourceUsage;
+pub use scaling::{ScalingChange, ScalingComparison, ScalingFit, compare_scaling};
+pub use schema::{
+    BenchmarkReport, BenchmarkResult, Metadata, MetricKind, MetricValue, SCHEMA_VERSION,
+    TestVectors,
+};
+pub use score::{
+    BenchmarkWeight, SUITE_SCORE_BENCHMARK, SYNTHETIC_TAG, ScoreProfile, SuiteScore, suite_score,
+    weighted_suite_score,
+};
+pub use speedup::{SPEEDUP_UNIT, format_speedup, speedup};
 pub use statistics::{
     calculate_confidence_interval, calculate_confidence_interval_default, calculate_statistics,
 };
 pub use stopwatch::{ScopedStopwatch, Stopwatch};
+pub use sweep::{Sweep, SweepPoint, extract_sweeps};
+pub use trend::{
+    ChangePoint, LoadedReports, Trend, TrendOptions, TrendPoint, TrendStats, analyze_trends,
+    load_reports,
+};
+pub use units::{format_metric, format_value, scale_value};
+pub use validate::{Severity, ValidationIssue, validate_json, validate_report};
+pub use watch::{ChangeDetector, WatchConfig};
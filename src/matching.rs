@@ -0,0 +1,176 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pairing of benchmarks between reports across renames.
+//!
+//! Renaming `msm_g1_16` to `msm/g1/2^16` should not show up as one
+//! removed and one added benchmark with nothing compared. Besides exact
+//! names, benchmarks are paired through an explicit alias map, through
+//! normalized names and, optionally, by fuzzy similarity.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Normalizes a benchmark name for matching: lowercases it, treats `/`,
+/// `_`, `-`, `::`, `.` and whitespace alike as separators, and writes
+/// powers of two (`2^16`) as their exponent (`16`), the usual meaning of
+/// a bare size in ZK benchmark names.
+///
+/// ```
+/// use zkbench::normalize_benchmark_name;
+///
+/// assert_eq!(
+///     normalize_benchmark_name("MSM_G1_16"),
+///     normalize_benchmark_name("msm/g1/2^16")
+/// );
+/// ```
+pub fn normalize_benchmark_name(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| matches!(c, '/' | '_' | '-' | ':' | '.') || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.strip_prefix("2^").unwrap_or(token))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Similarity of two strings in `0.0..=1.0`: one minus their Levenshtein
+/// distance over the longer length.
+pub(crate) fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    1.0 - row[b.len()] as f64 / longest as f64
+}
+
+/// Pairs candidate benchmark names with baseline names, returning a map
+/// from candidate to baseline name.
+///
+/// Exact matches come first, then `aliases` (baseline name to candidate
+/// name), then equal [`normalize_benchmark_name`]s when `normalize` is
+/// set, then, with `fuzzy` set, the most similar remaining normalized
+/// names with a similarity of at least `fuzzy`. Every name is paired at
+/// most once.
+pub(crate) fn pair_names<'a>(
+    baseline: &[&'a str],
+    candidate: &[&'a str],
+    aliases: &BTreeMap<String, String>,
+    normalize: bool,
+    fuzzy: Option<f64>,
+) -> HashMap<&'a str, &'a str> {
+    let mut pairs: HashMap<&str, &str> = HashMap::new();
+    let mut unmatched_baseline: BTreeSet<&str> = baseline.iter().copied().collect();
+    let mut unmatched_candidate: BTreeSet<&str> = BTreeSet::new();
+    for name in candidate {
+        if unmatched_baseline.remove(name) {
+            pairs.insert(name, name);
+        } else {
+            unmatched_candidate.insert(name);
+        }
+    }
+
+    for b in unmatched_baseline.clone() {
+        if let Some(c) = aliases.get(b)
+            && let Some(&c) = unmatched_candidate.get(c.as_str())
+        {
+            pairs.insert(c, b);
+            unmatched_baseline.remove(b);
+            unmatched_candidate.remove(c);
+        }
+    }
+    if !normalize && fuzzy.is_none() {
+        return pairs;
+    }
+
+    let key = |name: &str| {
+        if normalize {
+            normalize_benchmark_name(name)
+        } else {
+            name.to_string()
+        }
+    };
+    if normalize {
+        let by_key: HashMap<String, &str> =
+            unmatched_candidate.iter().map(|c| (key(c), *c)).collect();
+        for b in unmatched_baseline.clone() {
+            if let Some(&c) = by_key.get(&key(b))
+                && unmatched_candidate.remove(c)
+            {
+                pairs.insert(c, b);
+                unmatched_baseline.remove(b);
+            }
+        }
+    }
+    if let Some(cutoff) = fuzzy {
+        let mut scored: Vec<(f64, &str, &str)> = unmatched_baseline
+            .iter()
+            .flat_map(|b| unmatched_candidate.iter().map(move |c| (*b, *c)))
+            .map(|(b, c)| (similarity(&key(b), &key(c)), b, c))
+            .filter(|(score, _, _)| *score >= cutoff)
+            .collect();
+        scored.sort_by(|x, y| {
+            y.0.total_cmp(&x.0)
+                .then_with(|| (x.1, x.2).cmp(&(y.1, y.2)))
+        });
+        for (_, b, c) in scored {
+            if unmatched_baseline.contains(b) && unmatched_candidate.contains(c) {
+                unmatched_baseline.remove(b);
+                unmatched_candidate.remove(c);
+                pairs.insert(c, b);
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_separators_and_powers() {
+        assert_eq!(normalize_benchmark_name("msm_g1_16"), "msm/g1/16");
+        assert_eq!(normalize_benchmark_name("msm/g1/2^16"), "msm/g1/16");
+        assert_eq!(
+            normalize_benchmark_name("Poseidon::Hash - 8"),
+            "poseidon/hash/8"
+        );
+    }
+
+    #[test]
+    fn levenshtein_similarity() {
+        assert_eq!(similarity("", ""), 1.0);
+        assert_eq!(similarity("kitten", "sitting"), 1.0 - 3.0 / 7.0);
+        assert_eq!(similarity("abc", "abc"), 1.0);
+    }
+
+    #[test]
+    fn pairs_in_order_of_confidence() {
+        let baseline = ["same", "old_name", "msm_g1_16", "keccak256/prove", "gone"];
+        let candidate = ["same", "new-name", "msm/g1/2^16", "keccak/prove", "added"];
+        let aliases = BTreeMap::from([("old_name".to_string(), "new-name".to_string())]);
+
+        let exact = pair_names(&baseline, &candidate, &BTreeMap::new(), false, None);
+        assert_eq!(exact, HashMap::from([("same", "same")]));
+
+        let pairs = pair_names(&baseline, &candidate, &aliases, true, None);
+        assert_eq!(pairs["new-name"], "old_name");
+        assert_eq!(pairs["msm/g1/2^16"], "msm_g1_16");
+        assert!(!pairs.contains_key("keccak/prove"));
+
+        let pairs = pair_names(&baseline, &candidate, &aliases, true, Some(0.75));
+        assert_eq!(pairs["keccak/prove"], "keccak256/prove");
+        assert!(!pairs.contains_key("added"));
+        assert_eq!(pairs.len(), 4);
+    }
+}
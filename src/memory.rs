@@ -0,0 +1,317 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Structured memory metrics: resident set, virtual size and heap.
+//!
+//! A single `memory` metric cannot tell a prover that maps a large SRS
+//! file (high virtual size, modest RSS) from one that allocates its
+//! witness on the heap. [`MemoryProfile`] records each, read from
+//! `/proc/self/status` on Linux, `proc_pidinfo` and `getrusage` on macOS
+//! and `GetProcessMemoryInfo` on Windows, and attaches them to the result.
+//!
+//! The heap high-water mark needs allocator statistics, which only exist
+//! when the bench binary installs [`TrackingAllocator`] as its global
+//! allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Memory use of the current process, in bytes. Sizes a platform or
+/// allocator does not report are `None`.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{BenchmarkResult, MemoryProfile};
+/// # fn prove() {}
+///
+/// MemoryProfile::reset_peaks()?;
+/// prove();
+/// let mut result = BenchmarkResult::default();
+/// MemoryProfile::current()?.record(&mut result);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryProfile {
+    /// Largest resident set size, since the process started or since
+    /// [`reset_peaks`](Self::reset_peaks) where it can be reset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_rss: Option<u64>,
+    /// Current resident set size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rss: Option<u64>,
+    /// Largest heap allocated through [`TrackingAllocator`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heap_peak: Option<u64>,
+    /// Virtual address space; on Windows, the committed private memory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtual_size: Option<u64>,
+}
+
+impl MemoryProfile {
+    /// The memory use of the current process.
+    pub fn current() -> io::Result<Self> {
+        let mut profile = os_profile()?;
+        if HEAP_TRACKED.load(Ordering::Relaxed) {
+            profile.heap_peak = Some(HEAP_PEAK.load(Ordering::Relaxed));
+        }
+        Ok(profile)
+    }
+
+    /// Starts new high-water marks for the next measured region: the heap
+    /// peak on all platforms and the peak RSS on Linux. Elsewhere the
+    /// peak RSS keeps counting from the start of the process.
+    pub fn reset_peaks() -> io::Result<()> {
+        HEAP_PEAK.store(HEAP_CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+        #[cfg(target_os = "linux")]
+        {
+            // Resets VmHWM to the current RSS; see proc(5).
+            std::fs::write("/proc/self/clear_refs", "5")?;
+        }
+        Ok(())
+    }
+
+    /// Attaches the profile to `result`, and sets its `memory` metric to
+    /// the peak RSS unless it already has one.
+    pub fn record(self, result: &mut BenchmarkResult) {
+        if result.memory.is_none()
+            && let Some(peak_rss) = self.peak_rss
+        {
+            result.memory = Some(MetricValue::new(peak_rss as f64, "B"));
+        }
+        result.memory_profile = Some(self);
+    }
+}
+
+static HEAP_TRACKED: AtomicBool = AtomicBool::new(false);
+static HEAP_CURRENT: AtomicU64 = AtomicU64::new(0);
+static HEAP_PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// A global allocator that tracks the heap size for
+/// [`MemoryProfile::heap_peak`], wrapping the system allocator or another
+/// one.
+///
+/// ```
+/// use zkbench::TrackingAllocator;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: TrackingAllocator = TrackingAllocator::new();
+/// # fn main() {}
+/// ```
+#[derive(Debug, Default)]
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+}
+
+impl TrackingAllocator {
+    /// Tracks the system allocator.
+    pub const fn new() -> Self {
+        Self { inner: System }
+    }
+}
+
+impl<A> TrackingAllocator<A> {
+    /// Tracks `inner`, e.g. jemalloc or mimalloc.
+    pub const fn wrap(inner: A) -> Self {
+        Self { inner }
+    }
+
+    fn grow(size: usize) {
+        if !HEAP_TRACKED.load(Ordering::Relaxed) {
+            HEAP_TRACKED.store(true, Ordering::Relaxed);
+        }
+        let current = HEAP_CURRENT.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        HEAP_PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        HEAP_CURRENT.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: all allocation is delegated to `inner`; only counters are added.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: forwarded with the caller's guarantees.
+        let ptr = unsafe { self.inner.alloc(layout) };
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: forwarded with the caller's guarantees.
+        let ptr = unsafe { self.inner.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: forwarded with the caller's guarantees.
+        unsafe { self.inner.dealloc(ptr, layout) };
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: forwarded with the caller's guarantees.
+        let new_ptr = unsafe { self.inner.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                Self::grow(new_size - layout.size());
+            } else {
+                Self::shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn os_profile() -> io::Result<MemoryProfile> {
+    let status = std::fs::read_to_string("/proc/self/status")?;
+    let field = |name: &str| {
+        status.lines().find_map(|line| {
+            let kib = line.strip_prefix(name)?.strip_prefix(':')?;
+            let kib = kib.trim().strip_suffix("kB")?.trim().parse::<u64>().ok()?;
+            Some(kib * 1024)
+        })
+    };
+    Ok(MemoryProfile {
+        peak_rss: field("VmHWM"),
+        rss: field("VmRSS"),
+        heap_peak: None,
+        virtual_size: field("VmSize"),
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn os_profile() -> io::Result<MemoryProfile> {
+    let mut profile = MemoryProfile::default();
+    // SAFETY: `rusage` is plain data, for which all zeroes is valid.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // SAFETY: `usage` is a valid, writable `rusage`.
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } == 0 {
+        // In bytes on macOS, unlike on other Unix systems.
+        profile.peak_rss = Some(usage.ru_maxrss as u64);
+    }
+    // SAFETY: as above, for `proc_taskinfo`.
+    let mut info: libc::proc_taskinfo = unsafe { std::mem::zeroed() };
+    let size = std::mem::size_of::<libc::proc_taskinfo>() as libc::c_int;
+    // SAFETY: `info` is writable and `size` bytes long.
+    let written = unsafe {
+        libc::proc_pidinfo(
+            libc::getpid(),
+            libc::PROC_PIDTASKINFO,
+            0,
+            (&raw mut info).cast(),
+            size,
+        )
+    };
+    if written != size {
+        return Err(io::Error::last_os_error());
+    }
+    profile.rss = Some(info.pti_resident_size);
+    profile.virtual_size = Some(info.pti_virtual_size);
+    Ok(profile)
+}
+
+#[cfg(all(unix, not(any(target_os = "linux", target_os = "macos"))))]
+fn os_profile() -> io::Result<MemoryProfile> {
+    // SAFETY: `rusage` is plain data, for which all zeroes is valid.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // SAFETY: `usage` is a valid, writable `rusage`.
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(MemoryProfile {
+        // In KiB on the BSDs.
+        peak_rss: Some(usage.ru_maxrss as u64 * 1024),
+        ..Default::default()
+    })
+}
+
+#[cfg(windows)]
+fn os_profile() -> io::Result<MemoryProfile> {
+    use windows_sys::Win32::System::ProcessStatus::{
+        GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS,
+    };
+    use windows_sys::Win32::System::Threading::GetCurrentProcess;
+
+    let mut counters = PROCESS_MEMORY_COUNTERS::default();
+    let size = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+    // SAFETY: the pseudo handle of the current process needs no closing,
+    // and `counters` is writable and `size` bytes long.
+    if unsafe { GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, size) } == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(MemoryProfile {
+        peak_rss: Some(counters.PeakWorkingSetSize as u64),
+        rss: Some(counters.WorkingSetSize as u64),
+        heap_peak: None,
+        virtual_size: Some(counters.PagefileUsage as u64),
+    })
+}
+
+#[cfg(not(any(unix, windows)))]
+fn os_profile() -> io::Result<MemoryProfile> {
+    Ok(MemoryProfile::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_process_memory() {
+        let profile = MemoryProfile::current().unwrap();
+        if cfg!(target_os = "linux") {
+            let (peak_rss, rss) = (profile.peak_rss.unwrap(), profile.rss.unwrap());
+            assert!(rss > 0 && peak_rss >= rss / 2, "{profile:?}");
+            assert!(profile.virtual_size.unwrap() >= rss);
+        }
+
+        let mut result = BenchmarkResult::default();
+        let profile = MemoryProfile {
+            peak_rss: Some(4096),
+            ..Default::default()
+        };
+        profile.record(&mut result);
+        assert_eq!(result.memory, Some(MetricValue::new(4096.0, "B")));
+        assert_eq!(result.memory_profile, Some(profile));
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            json["memory_profile"],
+            serde_json::json!({ "peak_rss": 4096 })
+        );
+    }
+
+    #[test]
+    fn tracks_heap_peak() {
+        // Not installed as the global allocator, so only these calls count.
+        MemoryProfile::reset_peaks().unwrap();
+        let allocator = TrackingAllocator::new();
+        let layout = Layout::from_size_align(1 << 20, 8).unwrap();
+        let base = HEAP_CURRENT.load(Ordering::Relaxed);
+        // SAFETY: the layout has a non-zero size, and each pointer is
+        // freed once with the layout it was allocated with.
+        unsafe {
+            let ptr = allocator.alloc(layout);
+            assert!(!ptr.is_null());
+            let ptr = allocator.realloc(ptr, layout, 3 << 20);
+            assert!(!ptr.is_null());
+            allocator.dealloc(ptr, Layout::from_size_align(3 << 20, 8).unwrap());
+        }
+        assert_eq!(HEAP_CURRENT.load(Ordering::Relaxed), base);
+        let heap_peak = MemoryProfile::current().unwrap().heap_peak.unwrap();
+        assert!(heap_peak >= base + (3 << 20), "{heap_peak}");
+    }
+}
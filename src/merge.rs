@@ -0,0 +1,244 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Combining the reports of sharded benchmark runs.
+//!
+//! CI often splits a suite across jobs, each writing a report with a
+//! share of the benchmarks. [`merge_reports`] joins them back into one
+//! report of the same implementation and commit. A [`MergePolicy`]
+//! decides what happens when shards overlap, e.g. after a retried job.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{BenchmarkReport, BenchmarkResult};
+use crate::units::to_base;
+
+/// What [`merge_reports_with`] does with a benchmark found in more than
+/// one report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicatePolicy {
+    /// Fail with [`MergeError::Duplicate`].
+    #[default]
+    Error,
+    /// Keep the result of the report given first.
+    KeepFirst,
+    /// Keep the result of the report given last.
+    KeepLast,
+    /// Keep the result with the lowest latency; results without a
+    /// latency lose to those with one.
+    KeepFastest,
+}
+
+/// Options of [`merge_reports_with`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MergePolicy {
+    pub on_duplicate: DuplicatePolicy,
+    /// Merge reports of different commits, keeping the first report's
+    /// commit. The implementation must always match.
+    pub allow_mixed_commits: bool,
+}
+
+/// Error returned by [`merge_reports`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeError {
+    /// No reports were given.
+    Empty,
+    /// A report is of another implementation or commit than the first.
+    Mismatch {
+        field: &'static str,
+        expected: String,
+        found: String,
+    },
+    /// Two reports contain the same benchmark.
+    Duplicate { benchmark: String },
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no reports to merge"),
+            Self::Mismatch {
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "cannot merge reports of different {}s: {} and {}",
+                field, expected, found
+            ),
+            Self::Duplicate { benchmark } => {
+                write!(f, "benchmark '{}' is in more than one report", benchmark)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Merges reports of one implementation and commit into one holding all
+/// their benchmarks, failing on duplicates. The metadata is the first
+/// report's, with the latest timestamp of all of them.
+pub fn merge_reports(reports: &[BenchmarkReport]) -> Result<BenchmarkReport, MergeError> {
+    merge_reports_with(reports, &MergePolicy::default())
+}
+
+/// Merges reports like [`merge_reports`], under a [`MergePolicy`]. The
+/// merged report is dirty if any of the reports is.
+pub fn merge_reports_with(
+    reports: &[BenchmarkReport],
+    policy: &MergePolicy,
+) -> Result<BenchmarkReport, MergeError> {
+    let (first, rest) = reports.split_first().ok_or(MergeError::Empty)?;
+    let mut merged = first.clone();
+    for report in rest {
+        for (field, expected, found) in [
+            (
+                "implementation",
+                &first.metadata.implementation,
+                &report.metadata.implementation,
+            ),
+            (
+                "commit",
+                &first.metadata.commit_sha,
+                &report.metadata.commit_sha,
+            ),
+        ] {
+            if field == "commit" && policy.allow_mixed_commits {
+                continue;
+            }
+            if expected != found {
+                return Err(MergeError::Mismatch {
+                    field,
+                    expected: expected.clone(),
+                    found: found.clone(),
+                });
+            }
+        }
+        for (name, result) in &report.benchmarks {
+            let Some(kept) = merged.benchmarks.get_mut(name) else {
+                merged.benchmarks.insert(name.clone(), result.clone());
+                continue;
+            };
+            let replace = match policy.on_duplicate {
+                DuplicatePolicy::Error => {
+                    return Err(MergeError::Duplicate {
+                        benchmark: name.clone(),
+                    });
+                }
+                DuplicatePolicy::KeepFirst => false,
+                DuplicatePolicy::KeepLast => true,
+                DuplicatePolicy::KeepFastest => {
+                    let latency = |r: &BenchmarkResult| {
+                        r.latency
+                            .as_ref()
+                            .map_or(f64::INFINITY, |m| to_base(m.value, &m.unit))
+                    };
+                    latency(result) < latency(kept)
+                }
+            };
+            if replace {
+                *kept = result.clone();
+            }
+        }
+        merged.metadata.dirty |= report.metadata.dirty;
+        // A commitment covers the vectors of one report, not the merge.
+        if merged.metadata.test_vector_commitment != report.metadata.test_vector_commitment {
+            merged.metadata.test_vector_commitment = None;
+        }
+        if report.metadata.timestamp > merged.metadata.timestamp {
+            merged.metadata.timestamp = report.metadata.timestamp.clone();
+        }
+    }
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::MetricValue;
+    use crate::testing::ReportBuilder;
+
+    fn shard(commit: &str, day: u32, benchmarks: &[&str]) -> BenchmarkReport {
+        ReportBuilder::new()
+            .commit(commit)
+            .day(day)
+            .results(
+                benchmarks
+                    .iter()
+                    .map(|name| (*name, BenchmarkResult::default())),
+            )
+            .build()
+    }
+
+    #[test]
+    fn merges_shards() {
+        let merged = merge_reports(&[shard("c1", 2, &["a"]), shard("c1", 3, &["b", "c"])]).unwrap();
+        let mut names: Vec<_> = merged.benchmarks.keys().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(names, ["a", "b", "c"]);
+        assert_eq!(merged.metadata.timestamp, "2026-01-03T00:00:00+00:00");
+
+        let mut committed = shard("c1", 2, &["a"]);
+        committed.metadata.test_vector_commitment =
+            Some(crate::MerkleTree::new(Default::default(), &[]).commitment());
+        let merged = merge_reports(&[committed.clone(), shard("c1", 3, &["b"])]).unwrap();
+        assert!(merged.metadata.test_vector_commitment.is_none());
+        let mut same = shard("c1", 3, &["b"]);
+        same.metadata.test_vector_commitment = committed.metadata.test_vector_commitment.clone();
+        let merged = merge_reports(&[committed, same]).unwrap();
+        assert!(merged.metadata.test_vector_commitment.is_some());
+    }
+
+    #[test]
+    fn rejects_conflicts() {
+        assert_eq!(merge_reports(&[]).unwrap_err(), MergeError::Empty);
+        assert_eq!(
+            merge_reports(&[shard("c1", 1, &["a"]), shard("c1", 1, &["a"])]).unwrap_err(),
+            MergeError::Duplicate {
+                benchmark: "a".to_string()
+            }
+        );
+        let err = merge_reports(&[shard("c1", 1, &["a"]), shard("c2", 1, &["b"])]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot merge reports of different commits: c1 and c2"
+        );
+    }
+
+    #[test]
+    fn policy_resolves_conflicts() {
+        let mut slow = shard("c1", 1, &["a", "b"]);
+        slow.benchmarks.get_mut("a").unwrap().latency = Some(MetricValue::new(2.0, "s"));
+        let mut fast = shard("c2", 2, &["a"]);
+        fast.benchmarks.get_mut("a").unwrap().latency = Some(MetricValue::new(900.0, "ms"));
+        fast.metadata.dirty = true;
+        let reports = [slow, fast];
+        let merge = |on_duplicate| {
+            let policy = MergePolicy {
+                on_duplicate,
+                allow_mixed_commits: true,
+            };
+            let merged = merge_reports_with(&reports, &policy).unwrap();
+            assert_eq!(merged.metadata.commit_sha, "c1");
+            assert!(merged.metadata.dirty);
+            merged.benchmarks["a"].latency.clone().unwrap().unit
+        };
+        assert_eq!(merge(DuplicatePolicy::KeepFirst), "s");
+        assert_eq!(merge(DuplicatePolicy::KeepLast), "ms");
+        assert_eq!(merge(DuplicatePolicy::KeepFastest), "ms");
+        assert!(matches!(
+            merge_reports_with(
+                &reports,
+                &MergePolicy {
+                    allow_mixed_commits: true,
+                    ..Default::default()
+                }
+            ),
+            Err(MergeError::Duplicate { .. })
+        ));
+    }
+}
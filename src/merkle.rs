@@ -0,0 +1,320 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Merkle commitments to sets of test vectors.
+//!
+//! A benchmark over thousands of golden vectors would bloat its report
+//! with one entry per vector. Instead the report records the root of a
+//! Merkle tree over the ordered vectors in
+//! [`Metadata::test_vector_commitment`](crate::Metadata::test_vector_commitment),
+//! and any single vector can later be shown to be part of the set with a
+//! [`MerkleProof`].
+//!
+//! The tree has the shape of RFC 6962 (Certificate Transparency): when a
+//! level has an odd number of nodes, the last one moves up unchanged.
+//! Leaves and inner nodes are hashed with distinct prefixes, so a leaf
+//! cannot pass for a subtree:
+//!
+//! - leaf: `H(0x00 || "<algorithm>:<input_hash>:<output_hash>")`, with
+//!   the digest algorithm and lowercase digests of the test vectors;
+//! - node: `H(0x01 || left || right)`, over the hex digests of the
+//!   children.
+//!
+//! The root of an empty set is `H("")`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::hash::{HashAlgorithm, Hasher, compute_hash_with};
+use crate::schema::{BenchmarkReport, TestVectors};
+
+/// The root of a Merkle tree over test vectors, as stored in a report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectorCommitment {
+    /// Hex digest of the root.
+    pub root: String,
+    /// Number of test vectors committed to.
+    pub leaves: usize,
+    /// Hash function of the tree; SHA-256 when absent.
+    #[serde(default, skip_serializing_if = "HashAlgorithm::is_default")]
+    pub algorithm: HashAlgorithm,
+}
+
+impl TestVectorCommitment {
+    /// Whether `proof` shows that `vectors` are part of the committed set.
+    pub fn verify(&self, vectors: &TestVectors, proof: &MerkleProof) -> bool {
+        proof.leaves == self.leaves
+            && proof.algorithm == self.algorithm
+            && proof.verify(&self.root, vectors)
+    }
+}
+
+/// Merkle tree over an ordered list of test vectors.
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    algorithm: HashAlgorithm,
+    /// Node digests, from the leaves up to the root.
+    levels: Vec<Vec<String>>,
+}
+
+impl MerkleTree {
+    /// Builds the tree over `vectors` in the given order.
+    ///
+    /// # Panics
+    ///
+    /// If the algorithm is not [supported](HashAlgorithm::is_supported)
+    /// by this build.
+    pub fn new(algorithm: HashAlgorithm, vectors: &[TestVectors]) -> Self {
+        let mut level: Vec<String> = vectors.iter().map(|v| leaf_hash(algorithm, v)).collect();
+        let mut levels = Vec::new();
+        while level.len() > 1 {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(algorithm, left, right),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(std::mem::replace(&mut level, next));
+        }
+        if level.is_empty() {
+            level.push(compute_hash_with(algorithm, b""));
+            levels.push(Vec::new());
+        }
+        levels.push(level);
+        Self { algorithm, levels }
+    }
+
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Number of test vectors in the tree.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hex digest of the root.
+    pub fn root(&self) -> &str {
+        &self.levels[self.levels.len() - 1][0]
+    }
+
+    /// The commitment to record in a report.
+    pub fn commitment(&self) -> TestVectorCommitment {
+        TestVectorCommitment {
+            root: self.root().to_string(),
+            leaves: self.len(),
+            algorithm: self.algorithm,
+        }
+    }
+
+    /// Proof that the vectors at `index` are part of the tree, or `None`
+    /// if the index is out of bounds.
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut siblings = Vec::new();
+        let mut position = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(position ^ 1) {
+                siblings.push(sibling.clone());
+            }
+            position /= 2;
+        }
+        Some(MerkleProof {
+            index,
+            leaves: self.len(),
+            siblings,
+            algorithm: self.algorithm,
+        })
+    }
+}
+
+/// Proof that test vectors are part of a [`MerkleTree`]: the digests of
+/// the siblings on the path from the leaf to the root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    /// Position of the vectors in the ordered set.
+    pub index: usize,
+    /// Number of vectors in the set, which determines the tree shape.
+    pub leaves: usize,
+    /// Sibling digests, from the leaf level up.
+    pub siblings: Vec<String>,
+    #[serde(default, skip_serializing_if = "HashAlgorithm::is_default")]
+    pub algorithm: HashAlgorithm,
+}
+
+impl MerkleProof {
+    /// Whether the proof leads from `vectors` to `root`. Hex digits are
+    /// compared case-insensitively.
+    ///
+    /// Returns `false` for algorithms this build does not support.
+    pub fn verify(&self, root: &str, vectors: &TestVectors) -> bool {
+        if self.index >= self.leaves || !self.algorithm.is_supported() {
+            return false;
+        }
+        let mut digest = leaf_hash(self.algorithm, vectors);
+        let mut siblings = self.siblings.iter();
+        let (mut position, mut width) = (self.index, self.leaves);
+        while width > 1 {
+            // The last node of an odd level has no sibling.
+            if position ^ 1 < width {
+                let Some(sibling) = siblings.next() else {
+                    return false;
+                };
+                digest = if position % 2 == 0 {
+                    node_hash(self.algorithm, &digest, sibling)
+                } else {
+                    node_hash(self.algorithm, sibling, &digest)
+                };
+            }
+            position /= 2;
+            width = width.div_ceil(2);
+        }
+        siblings.next().is_none() && digest.eq_ignore_ascii_case(root)
+    }
+}
+
+fn leaf_hash(algorithm: HashAlgorithm, vectors: &TestVectors) -> String {
+    let mut hasher = Hasher::with_algorithm(algorithm);
+    hasher.update(&[0x00]);
+    hasher.update(
+        format!(
+            "{}:{}:{}",
+            vectors.algorithm, vectors.input_hash, vectors.output_hash
+        )
+        .as_bytes(),
+    );
+    hasher.finalize()
+}
+
+fn node_hash(algorithm: HashAlgorithm, left: &str, right: &str) -> String {
+    let mut hasher = Hasher::with_algorithm(algorithm);
+    hasher.update(&[0x01]);
+    hasher.update(left.to_ascii_lowercase().as_bytes());
+    hasher.update(right.to_ascii_lowercase().as_bytes());
+    hasher.finalize()
+}
+
+impl BenchmarkReport {
+    /// Builds the Merkle tree over the test vectors of the benchmarks,
+    /// ordered by benchmark name, and returns it with the names of the
+    /// benchmarks at each leaf. Benchmarks without test vectors are
+    /// skipped.
+    pub fn test_vector_tree(&self, algorithm: HashAlgorithm) -> (Vec<&str>, MerkleTree) {
+        let mut benchmarks: Vec<_> = self
+            .benchmarks
+            .iter()
+            .filter_map(|(name, result)| Some((name.as_str(), result.test_vectors.clone()?)))
+            .collect();
+        benchmarks.sort_by_key(|(name, _)| *name);
+        let (names, vectors): (Vec<_>, Vec<_>) = benchmarks.into_iter().unzip();
+        (names, MerkleTree::new(algorithm, &vectors))
+    }
+
+    /// Commits to the test vectors of the benchmarks (see
+    /// [`test_vector_tree`](Self::test_vector_tree)) in the report's
+    /// metadata and returns the commitment.
+    pub fn commit_test_vectors(&mut self, algorithm: HashAlgorithm) -> TestVectorCommitment {
+        let commitment = self.test_vector_tree(algorithm).1.commitment();
+        self.metadata.test_vector_commitment = Some(commitment.clone());
+        commitment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::compute_hash;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    fn vectors(n: usize) -> Vec<TestVectors> {
+        (0..n)
+            .map(|i| {
+                TestVectors::compute(
+                    HashAlgorithm::Sha256,
+                    format!("in{i}").as_bytes(),
+                    format!("out{i}").as_bytes(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn proves_every_leaf() {
+        for n in 0..=9 {
+            let set = vectors(n);
+            let tree = MerkleTree::new(HashAlgorithm::Sha256, &set);
+            assert_eq!(tree.len(), n);
+            assert_eq!(tree.root().len(), 64);
+            let commitment = tree.commitment();
+            for (i, vectors) in set.iter().enumerate() {
+                let proof = tree.proof(i).unwrap();
+                assert!(commitment.verify(vectors, &proof), "{n} {i}");
+                let other = &set[(i + 1) % n];
+                assert_eq!(commitment.verify(other, &proof), n == 1, "{n} {i}");
+                let mut moved = proof.clone();
+                moved.index = (i + 1) % n;
+                assert_eq!(commitment.verify(vectors, &moved), n == 1, "{n} {i}");
+            }
+            assert!(tree.proof(n).is_none());
+        }
+    }
+
+    #[test]
+    fn matches_rfc_6962_shape() {
+        let set = vectors(3);
+        let leaf = |i: usize| leaf_hash(HashAlgorithm::Sha256, &set[i]);
+        let node = |l: &str, r: &str| node_hash(HashAlgorithm::Sha256, l, r);
+        let tree = MerkleTree::new(HashAlgorithm::Sha256, &set);
+        assert_eq!(tree.root(), node(&node(&leaf(0), &leaf(1)), &leaf(2)));
+        assert_eq!(
+            MerkleTree::new(HashAlgorithm::Sha256, &[]).root(),
+            compute_hash(b"")
+        );
+        // The root of a single vector is its leaf hash.
+        assert_eq!(
+            MerkleTree::new(HashAlgorithm::Sha256, &set[..1]).root(),
+            leaf(0)
+        );
+        assert_ne!(leaf(0), compute_hash(b""));
+    }
+
+    #[test]
+    fn commits_report_test_vectors() {
+        let set = vectors(3);
+        let mut report = ReportBuilder::new()
+            .results(
+                ["c", "a", "b"]
+                    .into_iter()
+                    .zip(&set)
+                    .map(|(name, vectors)| {
+                        let result = BenchmarkResult {
+                            test_vectors: Some(vectors.clone()),
+                            ..Default::default()
+                        };
+                        (name, result)
+                    }),
+            )
+            .result("untested", BenchmarkResult::default())
+            .build();
+
+        let commitment = report.commit_test_vectors(HashAlgorithm::Sha256);
+        assert_eq!(commitment.leaves, 3);
+        let (names, tree) = report.test_vector_tree(HashAlgorithm::Sha256);
+        assert_eq!(names, ["a", "b", "c"]);
+        let proof = tree.proof(2).unwrap();
+        let stored = report.metadata.test_vector_commitment.as_ref().unwrap();
+        assert!(stored.verify(&set[0], &proof));
+
+        let json = report.to_json(false).unwrap();
+        let loaded = BenchmarkReport::from_json(&json).unwrap();
+        assert_eq!(loaded.metadata.test_vector_commitment, Some(commitment));
+    }
+}
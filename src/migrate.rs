@@ -0,0 +1,376 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Migration of reports written with older schema versions.
+//!
+//! Every report records the [`SCHEMA_VERSION`] it was written with.
+//! [`migrate_json`] upgrades a report one version at a time, on its raw
+//! JSON, so a step can rename or restructure fields the current types no
+//! longer accept. The result is checked before it is handed back: it must
+//! parse into the current types, and serializing it again must keep
+//! every field that was set, so a migration never silently drops data.
+//!
+//! | Version | Change |
+//! |---------|--------|
+//! | 1 | Original layout, without `schema_version`. |
+//! | 2 | Records `schema_version`; memory units use IEC names (`KiB`, not the 1024-based `KB`) and microseconds are `us`. |
+//! | 3 | Test vector hashes are 32-byte [`Digest`]s in lowercase hex; reports with other hashes fail to migrate. |
+//!
+//! The stores migrate everything they hold in place with
+//! `DirStore::migrate` and `SqliteStore::migrate`.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::digest::{Digest, DigestError};
+use crate::schema::{BenchmarkReport, SCHEMA_VERSION};
+use crate::units::canonical;
+
+/// Error returned when a report cannot be migrated.
+#[derive(Debug)]
+pub enum MigrationError {
+    /// The input is not a report in any known schema version.
+    Parse(serde_json::Error),
+    /// The report was written by a newer version of the crate.
+    UnsupportedVersion(u32),
+    /// The migrated report does not round-trip: serializing it loses
+    /// these fields (JSON paths).
+    Lossy(Vec<String>),
+    /// A test vector hash (JSON path) is not a [`Digest`], so the test
+    /// vectors cannot be carried over.
+    InvalidDigest { path: String, source: DigestError },
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "not a report: {}", e),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "schema version {} is newer than the supported version {}",
+                version, SCHEMA_VERSION
+            ),
+            Self::Lossy(fields) => write!(f, "migration would drop fields: {}", fields.join(", ")),
+            Self::InvalidDigest { path, source } => write!(f, "{}: {}", path, source),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+            Self::InvalidDigest { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for MigrationError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// A report upgraded to the current schema version.
+#[derive(Debug, Clone)]
+pub struct Migrated {
+    pub report: BenchmarkReport,
+    /// Schema version the report was written with.
+    pub from_version: u32,
+}
+
+impl Migrated {
+    /// Whether the report was written with an older schema version.
+    pub fn changed(&self) -> bool {
+        self.from_version != SCHEMA_VERSION
+    }
+}
+
+/// Returns the schema version a report's JSON was written with.
+pub fn schema_version(report: &Value) -> u32 {
+    report
+        .pointer("/metadata/schema_version")
+        .and_then(Value::as_u64)
+        .map_or(1, |version| version as u32)
+}
+
+/// Parses a report written with any supported schema version and
+/// upgrades it to [`SCHEMA_VERSION`], verifying that the upgraded report
+/// round-trips through the current types without losing fields.
+pub fn migrate_json(json: &str) -> Result<Migrated, MigrationError> {
+    let mut value: Value = serde_json::from_str(json)?;
+    let from_version = migrate_value(&mut value)?;
+    let report: BenchmarkReport = serde_json::from_value(value.clone())?;
+    let written: Value = serde_json::from_str(&report.to_json(false)?)?;
+    let mut lost = Vec::new();
+    dropped_fields(&value, &written, "", &mut lost);
+    if !lost.is_empty() {
+        return Err(MigrationError::Lossy(lost));
+    }
+    Ok(Migrated {
+        report,
+        from_version,
+    })
+}
+
+/// Loads a report file, migrating it if it does not parse as the current
+/// schema. Files that are not reports, or that fail to migrate, are
+/// `InvalidData`; reports written by a newer version of the crate are
+/// `Unsupported`, since they are not corrupt.
+pub(crate) fn load_migrated(path: &Path) -> io::Result<BenchmarkReport> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    if let Ok(report) = BenchmarkReport::from_json(&json) {
+        return Ok(report);
+    }
+    migrate_json(&json).map(|m| m.report).map_err(|e| {
+        let kind = match e {
+            MigrationError::UnsupportedVersion(_) => io::ErrorKind::Unsupported,
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, format!("{}: {}", path.display(), e))
+    })
+}
+
+/// Upgrades a report's JSON to [`SCHEMA_VERSION`] in place and returns
+/// the version it was written with.
+pub fn migrate_value(report: &mut Value) -> Result<u32, MigrationError> {
+    let from_version = schema_version(report);
+    if from_version > SCHEMA_VERSION {
+        return Err(MigrationError::UnsupportedVersion(from_version));
+    }
+    for version in from_version..SCHEMA_VERSION {
+        match version {
+            1 => v1_to_v2(report),
+            2 => v2_to_v3(report)?,
+            _ => {}
+        }
+    }
+    if let Some(metadata) = report.get_mut("metadata").and_then(Value::as_object_mut) {
+        metadata.insert("schema_version".to_string(), SCHEMA_VERSION.into());
+    }
+    Ok(from_version)
+}
+
+/// Version 1 spelled 1024-based memory units `KB`/`MB`/... and allowed
+/// `µs`; version 2 writes the IEC and ASCII names.
+fn v1_to_v2(report: &mut Value) {
+    let Some(benchmarks) = report.get_mut("benchmarks").and_then(Value::as_object_mut) else {
+        return;
+    };
+    for result in benchmarks.values_mut() {
+        let Some(result) = result.as_object_mut() else {
+            continue;
+        };
+        let counters = result
+            .get_mut("counters")
+            .and_then(Value::as_object_mut)
+            .map(|counters| counters.values_mut().collect::<Vec<_>>())
+            .unwrap_or_default();
+        for metric in counters {
+            rename_unit(metric);
+        }
+        for name in ["latency", "memory", "throughput"] {
+            if let Some(metric) = result.get_mut(name) {
+                rename_unit(metric);
+            }
+        }
+    }
+}
+
+/// Version 2 held test vector hashes as free-form strings; version 3
+/// parses them as [`Digest`]s. Valid hashes are rewritten in canonical
+/// form. A hash that is not a digest, such as a truncated one, fails the
+/// migration rather than losing the test vectors.
+fn v2_to_v3(report: &mut Value) -> Result<(), MigrationError> {
+    let Some(benchmarks) = report.get_mut("benchmarks").and_then(Value::as_object_mut) else {
+        return Ok(());
+    };
+    for (name, result) in benchmarks.iter_mut() {
+        let Some(vectors) = result
+            .get_mut("test_vectors")
+            .and_then(Value::as_object_mut)
+        else {
+            continue;
+        };
+        for field in ["input_hash", "output_hash", "hmac"] {
+            // Missing or non-string hashes fail to parse afterwards.
+            if let Some(hash) = vectors.get_mut(field)
+                && let Some(text) = hash.as_str()
+            {
+                let digest: Digest =
+                    text.parse()
+                        .map_err(|source| MigrationError::InvalidDigest {
+                            path: format!(
+                                "/benchmarks/{}/test_vectors/{}",
+                                pointer_escape(name),
+                                field
+                            ),
+                            source,
+                        })?;
+                *hash = digest.to_string().into();
+            }
+        }
+    }
+    Ok(())
+}
+
+fn rename_unit(metric: &mut Value) {
+    if let Some(unit) = metric.get_mut("unit")
+        && let Some(name) = unit.as_str()
+        && canonical(name) != name
+    {
+        *unit = canonical(name).into();
+    }
+}
+
+/// Collects the paths of fields set in `original` that `written` lacks
+/// or holds a different value for. Fields left at their default (null,
+/// false, zero, empty) may be omitted by serialization and do not count.
+pub(crate) fn dropped_fields(
+    original: &Value,
+    written: &Value,
+    path: &str,
+    lost: &mut Vec<String>,
+) {
+    match (original, written) {
+        (Value::Object(original), Value::Object(written)) => {
+            for (key, value) in original {
+                let path = format!("{path}/{}", pointer_escape(key));
+                match written.get(key) {
+                    Some(written) => dropped_fields(value, written, &path, lost),
+                    None if !is_default(value) => lost.push(path),
+                    None => {}
+                }
+            }
+        }
+        (Value::Array(original), Value::Array(written)) if original.len() == written.len() => {
+            for (i, (original, written)) in original.iter().zip(written).enumerate() {
+                dropped_fields(original, written, &format!("{path}/{i}"), lost);
+            }
+        }
+        (Value::Number(original), Value::Number(written))
+            if original.as_f64() == written.as_f64() => {}
+        (original, written) if original == written => {}
+        _ => lost.push(path.to_string()),
+    }
+}
+
+/// Escapes a key for use in a JSON pointer (RFC 6901).
+pub(crate) fn pointer_escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn is_default(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Bool(b) => !b,
+        Value::Number(n) => n.as_f64() == Some(0.0),
+        Value::String(s) => s.is_empty(),
+        Value::Array(a) => a.is_empty(),
+        Value::Object(o) => o.is_empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::Metadata;
+
+    fn v1_report() -> String {
+        let mut metadata = serde_json::to_value(Metadata::create("impl", "1.0.0")).unwrap();
+        metadata.as_object_mut().unwrap().remove("schema_version");
+        serde_json::json!({
+            "metadata": metadata,
+            "benchmarks": {
+                "prove": {
+                    "latency": {"value": 12, "unit": "µs"},
+                    "memory": {"value": 3.5, "unit": "MB", "lower_value": 3.0},
+                    "iterations": 0,
+                    "counters": {"peak": {"value": 2, "unit": "KB"}}
+                }
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn upgrades_version_1_reports() {
+        let json = v1_report();
+        let legacy = BenchmarkReport::from_json(&json).unwrap();
+        assert_eq!(legacy.metadata.schema_version, 1);
+
+        let migrated = migrate_json(&json).unwrap();
+        assert_eq!(migrated.from_version, 1);
+        assert!(migrated.changed());
+        let report = migrated.report;
+        assert_eq!(report.metadata.schema_version, SCHEMA_VERSION);
+        let prove = &report.benchmarks["prove"];
+        assert_eq!(prove.latency.as_ref().unwrap().unit, "us");
+        assert_eq!(prove.memory.as_ref().unwrap().unit, "MiB");
+        assert_eq!(prove.memory.as_ref().unwrap().lower_value, Some(3.0));
+        assert_eq!(prove.counters["peak"].unit, "KiB");
+
+        let again = migrate_json(&report.to_json(false).unwrap()).unwrap();
+        assert!(!again.changed());
+        assert_eq!(again.report.content_hash(), report.content_hash());
+    }
+
+    #[test]
+    fn canonicalizes_or_rejects_legacy_test_vectors() {
+        let with_input_hash = |input_hash: &str| {
+            let mut value: Value = serde_json::from_str(&v1_report()).unwrap();
+            value["benchmarks"]["prove"]["test_vectors"] = serde_json::json!({
+                "input_hash": input_hash,
+                "output_hash": "cd".repeat(32),
+                "verified": true,
+            });
+            value.to_string()
+        };
+
+        let report = migrate_json(&with_input_hash(&format!("0x{}", "AB".repeat(32))))
+            .unwrap()
+            .report;
+        let vectors = report.benchmarks["prove"].test_vectors.as_ref().unwrap();
+        assert_eq!(vectors.input_hash.to_string(), "ab".repeat(32));
+        assert!(vectors.verified);
+        assert_eq!(
+            report.benchmarks["prove"].latency.as_ref().unwrap().unit,
+            "us"
+        );
+
+        for invalid in ["abcdef", &"zz".repeat(32)] {
+            let json = with_input_hash(invalid);
+            assert!(BenchmarkReport::from_json(&json).is_err());
+            let err = migrate_json(&json).unwrap_err();
+            assert!(
+                matches!(&err, MigrationError::InvalidDigest { path, .. }
+                    if path == "/benchmarks/prove/test_vectors/input_hash"),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_lossy_and_newer_reports() {
+        let mut value: Value = serde_json::from_str(&v1_report()).unwrap();
+        value["benchmarks"]["prove"]["latency"]["stddev"] = 0.5.into();
+        let err = migrate_json(&value.to_string()).unwrap_err();
+        assert!(
+            matches!(&err, MigrationError::Lossy(fields) if fields == &["/benchmarks/prove/latency/stddev"]),
+            "{err}"
+        );
+
+        value["metadata"]["schema_version"] = (SCHEMA_VERSION + 1).into();
+        assert!(matches!(
+            migrate_json(&value.to_string()),
+            Err(MigrationError::UnsupportedVersion(v)) if v == SCHEMA_VERSION + 1
+        ));
+        assert!(matches!(migrate_json("[]"), Err(MigrationError::Parse(_))));
+    }
+}
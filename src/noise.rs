@@ -0,0 +1,126 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Benchmark noise estimates.
+//!
+//! Some benchmarks swing by 10% from run to run no matter what changed.
+//! Their coefficient of variation (CV, standard deviation over mean),
+//! measured within a run or across a history of runs, lets
+//! [`compare`](crate::compare) widen their thresholds or mark their
+//! changes inconclusive instead of alerting on them every night.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::hash::unique_reports;
+use crate::schema::{BenchmarkReport, BenchmarkResult, MetricKind, MetricValue};
+use crate::statistics::calculate_statistics;
+use crate::units::to_base;
+
+/// Run-to-run coefficient of variation per benchmark and metric.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NoiseProfile {
+    /// CV keyed by benchmark name, then metric name.
+    pub cv: BTreeMap<String, BTreeMap<String, f64>>,
+}
+
+impl NoiseProfile {
+    /// Measures the CV of every metric across a history of reports, such
+    /// as past nightly runs of the same suite on the same machine.
+    /// Metrics recorded in fewer than `min_runs` reports (at least 2) are
+    /// left out. Duplicate reports (same
+    /// [`content_hash`](BenchmarkReport::content_hash)) count once.
+    pub fn from_history(reports: &[BenchmarkReport], min_runs: usize) -> Self {
+        let mut values: BTreeMap<&str, BTreeMap<&str, Vec<f64>>> = BTreeMap::new();
+        for report in unique_reports(reports) {
+            for (name, result) in &report.benchmarks {
+                for (metric, value) in result.metrics() {
+                    values
+                        .entry(name)
+                        .or_default()
+                        .entry(metric)
+                        .or_default()
+                        .push(to_base(value.value, &value.unit));
+                }
+            }
+        }
+        let cv = values
+            .into_iter()
+            .map(|(name, metrics)| {
+                let metrics: BTreeMap<String, f64> = metrics
+                    .into_iter()
+                    .filter(|(_, v)| v.len() >= min_runs.max(2))
+                    .filter_map(|(metric, v)| Some((metric.to_string(), coefficient(&v)?)))
+                    .collect();
+                (name.to_string(), metrics)
+            })
+            .filter(|(_, metrics)| !metrics.is_empty())
+            .collect();
+        Self { cv }
+    }
+
+    /// Returns the recorded CV of a metric of a benchmark.
+    pub fn get(&self, benchmark: &str, metric: &str) -> Option<f64> {
+        self.cv.get(benchmark)?.get(metric).copied()
+    }
+}
+
+fn coefficient(values: &[f64]) -> Option<f64> {
+    let (mean, stdev) = calculate_statistics(values);
+    (mean != 0.0).then(|| (stdev / mean).abs())
+}
+
+/// Within-run noise of a metric: the CV of the latency samples, or,
+/// without samples, the relative half-width of the confidence interval
+/// as an approximation.
+pub(crate) fn relative_spread(
+    result: &BenchmarkResult,
+    metric: &str,
+    value: &MetricValue,
+) -> Option<f64> {
+    if metric == MetricKind::Latency.as_str() && result.samples.len() >= 2 {
+        return coefficient(&result.samples);
+    }
+    let (lower, upper) = (value.lower_value?, value.upper_value?);
+    (value.value != 0.0).then(|| ((upper - lower) / (2.0 * value.value)).abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn history_cv() {
+        let history = [
+            ReportBuilder::new()
+                .latencies_ms(&[("prove", 90.0)])
+                .build(),
+            ReportBuilder::new()
+                .latencies_ms(&[("prove", 100.0)])
+                .build(),
+            ReportBuilder::new()
+                .latencies_ms(&[("prove", 110.0)])
+                .build(),
+        ];
+        let profile = NoiseProfile::from_history(&history, 3);
+        assert!((profile.get("prove", "latency").unwrap() - 0.1).abs() < 1e-12);
+        assert!(profile.get("prove", "memory").is_none());
+        assert!(NoiseProfile::from_history(&history, 4).cv.is_empty());
+    }
+
+    #[test]
+    fn spread_from_samples_or_bounds() {
+        let sampled = BenchmarkResult {
+            samples: vec![9.0, 10.0, 11.0],
+            ..Default::default()
+        };
+        let value = MetricValue::new(10.0, "ms");
+        assert!((relative_spread(&sampled, "latency", &value).unwrap() - 0.1).abs() < 1e-12);
+        let bounded = MetricValue::with_bounds(100.0, "ms", 95.0, 105.0);
+        let result = BenchmarkResult::default();
+        assert_eq!(relative_spread(&result, "latency", &bounded), Some(0.05));
+        assert_eq!(relative_spread(&result, "latency", &value), None);
+    }
+}
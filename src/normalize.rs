@@ -0,0 +1,294 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hardware normalization for comparisons across machines.
+//!
+//! Raw timings from a 64-core server and an 8-core laptop are not
+//! comparable. Normalizing scales time metrics up and rate metrics down
+//! by a hardware factor (cores or GHz), so the compared quantities become
+//! core-time or cycles. Bucketing by platform fingerprint instead refuses
+//! to judge changes between different machines at all.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{BenchmarkReport, MetricValue};
+use crate::units::base_unit;
+
+/// How to account for hardware differences in [`compare`](crate::compare).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Normalization {
+    /// Compare raw values.
+    #[default]
+    None,
+    /// Multiply times and divide rates by the core count.
+    PerCore,
+    /// Multiply times and divide rates by the clock frequency in GHz.
+    PerGhz,
+    /// Compare raw values, but only classify changes when both reports
+    /// come from the same platform fingerprint.
+    Fingerprint,
+}
+
+impl Normalization {
+    /// Hardware factor of a report's platform, if the mode scales values
+    /// and the platform records what it needs.
+    pub fn factor(&self, report: &BenchmarkReport) -> Option<f64> {
+        let platform = &report.metadata.platform;
+        match self {
+            Self::PerCore => Some(platform.cpu_count as f64).filter(|n| *n > 0.0),
+            Self::PerGhz => platform
+                .cpu_mhz
+                .filter(|mhz| *mhz > 0)
+                .map(|mhz| mhz as f64 / 1000.0),
+            Self::None | Self::Fingerprint => None,
+        }
+    }
+}
+
+impl fmt::Display for Normalization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::None => "none",
+            Self::PerCore => "per core",
+            Self::PerGhz => "per GHz",
+            Self::Fingerprint => "per platform fingerprint",
+        })
+    }
+}
+
+/// Record of the normalization applied to a comparison, so every output
+/// built from it can state that the numbers are not raw.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NormalizationNote {
+    pub mode: Normalization,
+    /// Hardware factor of the baseline (cores or GHz).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub baseline_factor: Option<f64>,
+    /// Hardware factor of the candidate (cores or GHz).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub candidate_factor: Option<f64>,
+    pub baseline_fingerprint: String,
+    pub candidate_fingerprint: String,
+    /// Whether the mode took effect. Scaling modes are skipped when a
+    /// platform lacks the required information; fingerprint bucketing
+    /// fails when the fingerprints differ, in which case no change is
+    /// classified.
+    pub applied: bool,
+}
+
+impl NormalizationNote {
+    pub(crate) fn new(
+        mode: Normalization,
+        baseline: &BenchmarkReport,
+        candidate: &BenchmarkReport,
+    ) -> Self {
+        let baseline_fingerprint = baseline.metadata.platform.fingerprint();
+        let candidate_fingerprint = candidate.metadata.platform.fingerprint();
+        let (baseline_factor, candidate_factor) = (mode.factor(baseline), mode.factor(candidate));
+        let applied = match mode {
+            Normalization::None => false,
+            Normalization::PerCore | Normalization::PerGhz => {
+                baseline_factor.is_some() && candidate_factor.is_some()
+            }
+            Normalization::Fingerprint => baseline_fingerprint == candidate_fingerprint,
+        };
+        Self {
+            mode,
+            baseline_factor,
+            candidate_factor,
+            baseline_fingerprint,
+            candidate_fingerprint,
+            applied,
+        }
+    }
+}
+
+impl fmt::Display for NormalizationNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self.mode {
+            Normalization::PerCore => "cores",
+            _ => "GHz",
+        };
+        match (self.mode, self.applied) {
+            (Normalization::None, _) => write!(f, "values are not normalized"),
+            (Normalization::Fingerprint, true) => write!(
+                f,
+                "both reports come from platform {}",
+                self.candidate_fingerprint
+            ),
+            (Normalization::Fingerprint, false) => write!(
+                f,
+                "platforms differ ({} vs {}); changes are not classified",
+                self.baseline_fingerprint, self.candidate_fingerprint
+            ),
+            (mode, true) => write!(
+                f,
+                "values normalized {} (baseline {} {unit}, candidate {} {unit})",
+                mode,
+                self.baseline_factor.unwrap_or_default(),
+                self.candidate_factor.unwrap_or_default()
+            ),
+            (mode, false) => write!(
+                f,
+                "values not normalized {}: platform information missing",
+                mode
+            ),
+        }
+    }
+}
+
+/// Returns a copy of the report with every time metric (and the latency
+/// samples) multiplied and every rate metric (unit ending in `/s`)
+/// divided by `factor`. Other metrics, such as memory, are unchanged.
+pub fn normalize_report(report: &BenchmarkReport, factor: f64) -> BenchmarkReport {
+    let mut normalized = report.clone();
+    for result in normalized.benchmarks.values_mut() {
+        let latency_scale = result.latency.as_ref().map(|m| scale_for(&m.unit, factor));
+        for metric in [
+            &mut result.latency,
+            &mut result.throughput,
+            &mut result.memory,
+        ]
+        .into_iter()
+        .flatten()
+        .chain(result.counters.values_mut())
+        {
+            scale(metric, scale_for(&metric.unit, factor));
+        }
+        if let Some(s) = latency_scale {
+            result.samples.iter_mut().for_each(|v| *v *= s);
+        }
+    }
+    normalized
+}
+
+fn scale_for(unit: &str, factor: f64) -> f64 {
+    if unit.ends_with("/s") {
+        1.0 / factor
+    } else if base_unit(unit) == "ns" {
+        factor
+    } else {
+        1.0
+    }
+}
+
+fn scale(metric: &mut MetricValue, s: f64) {
+    metric.value *= s;
+    for bound in [&mut metric.lower_value, &mut metric.upper_value]
+        .into_iter()
+        .flatten()
+    {
+        *bound *= s;
+    }
+}
+
+/// Groups reports by platform fingerprint, so trends and leaderboards can
+/// be built per machine.
+pub fn group_by_fingerprint(
+    reports: &[BenchmarkReport],
+) -> BTreeMap<String, Vec<&BenchmarkReport>> {
+    let mut groups: BTreeMap<String, Vec<&BenchmarkReport>> = BTreeMap::new();
+    for report in reports {
+        groups
+            .entry(report.metadata.platform.fingerprint())
+            .or_default()
+            .push(report);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    fn prove() -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(10.0, "ms", 9.0, 11.0)),
+            throughput: Some(MetricValue::new(100.0, "ops/s")),
+            memory: Some(MetricValue::new(64.0, "MiB")),
+            samples: vec![9.0, 11.0],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn scales_times_and_rates() {
+        let normalized = normalize_report(
+            &ReportBuilder::new()
+                .metadata(|m| (m.platform.cpu_count, m.platform.cpu_mhz) = (4, None))
+                .result("prove", prove())
+                .build(),
+            4.0,
+        );
+        let result = &normalized.benchmarks["prove"];
+        let latency = result.latency.as_ref().unwrap();
+        assert_eq!(
+            (latency.value, latency.lower_value, latency.upper_value),
+            (40.0, Some(36.0), Some(44.0))
+        );
+        assert_eq!(result.samples, [36.0, 44.0]);
+        assert_eq!(result.throughput.as_ref().unwrap().value, 25.0);
+        assert_eq!(result.memory.as_ref().unwrap().value, 64.0);
+    }
+
+    #[test]
+    fn factors_and_notes() {
+        let (small, big) = (
+            ReportBuilder::new()
+                .metadata(|m| (m.platform.cpu_count, m.platform.cpu_mhz) = (8, Some(3500)))
+                .result("prove", prove())
+                .build(),
+            ReportBuilder::new()
+                .metadata(|m| (m.platform.cpu_count, m.platform.cpu_mhz) = (32, None))
+                .result("prove", prove())
+                .build(),
+        );
+        assert_eq!(Normalization::PerCore.factor(&big), Some(32.0));
+        assert_eq!(Normalization::PerGhz.factor(&small), Some(3.5));
+        assert_eq!(Normalization::PerGhz.factor(&big), None);
+
+        let note = NormalizationNote::new(Normalization::PerCore, &small, &big);
+        assert!(note.applied);
+        assert_eq!(
+            note.to_string(),
+            "values normalized per core (baseline 8 cores, candidate 32 cores)"
+        );
+        let note = NormalizationNote::new(Normalization::PerGhz, &small, &big);
+        assert!(!note.applied);
+        assert_eq!(
+            note.to_string(),
+            "values not normalized per GHz: platform information missing"
+        );
+        let note = NormalizationNote::new(Normalization::Fingerprint, &small, &big);
+        assert!(!note.applied);
+        assert!(note.to_string().starts_with("platforms differ"));
+    }
+
+    #[test]
+    fn groups_by_fingerprint() {
+        let reports = [
+            ReportBuilder::new()
+                .metadata(|m| (m.platform.cpu_count, m.platform.cpu_mhz) = (8, None))
+                .result("prove", prove())
+                .build(),
+            ReportBuilder::new()
+                .metadata(|m| (m.platform.cpu_count, m.platform.cpu_mhz) = (32, None))
+                .result("prove", prove())
+                .build(),
+            ReportBuilder::new()
+                .metadata(|m| (m.platform.cpu_count, m.platform.cpu_mhz) = (8, Some(3000)))
+                .result("prove", prove())
+                .build(),
+        ];
+        let groups = group_by_fingerprint(&reports);
+        assert_eq!(groups.len(), 2);
+        let eight = reports[0].metadata.platform.fingerprint();
+        assert_eq!(groups[&eight].len(), 2);
+    }
+}
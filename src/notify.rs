@@ -0,0 +1,238 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Chat notifications for regression results.
+//!
+//! Formats a short summary of how a candidate report moved against a
+//! baseline (suite geomean plus the largest regressions and improvements)
+//! as a Slack or Discord webhook payload. Posting the payload requires the
+//! `notify` feature.
+
+use std::fmt::Write;
+
+use serde_json::{Value, json};
+
+use crate::compare::{CompareOptions, compare};
+use crate::schema::BenchmarkReport;
+use crate::statistics::geometric_mean;
+use crate::units::format_metric;
+
+/// Discord rejects messages longer than this many characters.
+const DISCORD_MAX_CONTENT: usize = 2000;
+
+/// Chat service a webhook URL belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Webhook {
+    Slack,
+    Discord,
+}
+
+impl Webhook {
+    /// Guesses the service from a webhook URL: Discord webhooks live under
+    /// `discord.com/api/webhooks`, anything else is treated as Slack.
+    pub fn detect(url: &str) -> Self {
+        if url.contains("discord.com/api/webhooks") || url.contains("discordapp.com/api/webhooks") {
+            Self::Discord
+        } else {
+            Self::Slack
+        }
+    }
+}
+
+/// Latency change of a single benchmark, formatted for display.
+#[derive(Debug, Clone)]
+pub struct BenchmarkChange {
+    pub benchmark: String,
+    /// Relative change, `candidate / baseline - 1` (positive is slower).
+    pub change: f64,
+    pub baseline: String,
+    pub candidate: String,
+}
+
+/// Concise summary of a candidate report against a baseline.
+#[derive(Debug, Clone)]
+pub struct NotificationSummary {
+    pub title: String,
+    /// Geometric mean of latency ratios minus one, over all benchmarks
+    /// present in both reports.
+    pub geomean_change: Option<f64>,
+    /// Largest slowdowns, worst first.
+    pub regressions: Vec<BenchmarkChange>,
+    /// Largest speedups, best first.
+    pub improvements: Vec<BenchmarkChange>,
+}
+
+impl NotificationSummary {
+    /// Summarizes latency changes, keeping at most `top` regressions and
+    /// `top` improvements.
+    pub fn new(baseline: &BenchmarkReport, candidate: &BenchmarkReport, top: usize) -> Self {
+        let comparison = compare(baseline, candidate, &CompareOptions::default());
+        let mut changes: Vec<BenchmarkChange> = comparison
+            .deltas()
+            .filter(|(_, d)| d.metric == "latency")
+            .map(|(name, d)| BenchmarkChange {
+                benchmark: name.to_string(),
+                change: d.change,
+                baseline: format_metric(&d.baseline),
+                candidate: format_metric(&d.candidate),
+            })
+            .collect();
+        let ratios: Vec<f64> = changes.iter().map(|c| 1.0 + c.change).collect();
+        changes.sort_by(|a, b| b.change.total_cmp(&a.change));
+        let regressions = changes
+            .iter()
+            .filter(|c| c.change > 0.0)
+            .take(top)
+            .cloned()
+            .collect();
+        let improvements = changes
+            .iter()
+            .rev()
+            .filter(|c| c.change < 0.0)
+            .take(top)
+            .cloned()
+            .collect();
+        let meta = &candidate.metadata;
+        Self {
+            title: format!(
+                "zkbench: {} {} ({} vs {})",
+                meta.implementation, meta.version, meta.commit_sha, baseline.metadata.commit_sha
+            ),
+            geomean_change: geometric_mean(&ratios).map(|g| g - 1.0),
+            regressions,
+            improvements,
+        }
+    }
+
+    /// Renders the summary as chat markdown; `bold` is the service's
+    /// strong-emphasis delimiter (`*` for Slack, `**` for Discord).
+    fn to_text(&self, bold: &str) -> String {
+        let mut out = format!("{bold}{}{bold}\n", self.title);
+        match self.geomean_change {
+            Some(change) => {
+                let _ = writeln!(out, "Suite geomean: {:+.1}%", change * 100.0);
+            }
+            None => out.push_str("Suite geomean: n/a (no common benchmarks)\n"),
+        }
+        for (heading, changes) in [
+            ("Top regressions", &self.regressions),
+            ("Top improvements", &self.improvements),
+        ] {
+            if changes.is_empty() {
+                continue;
+            }
+            let _ = writeln!(out, "{bold}{heading}{bold}");
+            for c in changes {
+                let _ = writeln!(
+                    out,
+                    "• {}: {:+.1}% ({} → {})",
+                    c.benchmark,
+                    c.change * 100.0,
+                    c.baseline,
+                    c.candidate
+                );
+            }
+        }
+        out
+    }
+
+    /// Builds the JSON body expected by the given webhook service.
+    pub fn payload(&self, webhook: Webhook) -> Value {
+        match webhook {
+            Webhook::Slack => json!({ "text": self.to_text("*") }),
+            Webhook::Discord => {
+                let mut content = self.to_text("**");
+                if content.chars().count() > DISCORD_MAX_CONTENT {
+                    content = content.chars().take(DISCORD_MAX_CONTENT - 1).collect();
+                    content.push('…');
+                }
+                json!({ "content": content })
+            }
+        }
+    }
+
+    /// Posts the summary to a Slack or Discord webhook, detecting the
+    /// service from the URL.
+    #[cfg(feature = "notify")]
+    pub fn post(&self, url: &str) -> Result<(), ureq::Error> {
+        let body = self.payload(Webhook::detect(url)).to_string();
+        ureq::post(url)
+            .header("Content-Type", "application/json")
+            .send(body)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    fn summary() -> NotificationSummary {
+        let baseline = ReportBuilder::new()
+            .latencies_ms(&[("a", 100.0), ("b", 100.0), ("c", 100.0), ("d", 100.0)])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies_ms(&[("a", 150.0), ("b", 110.0), ("c", 50.0), ("d", 100.0)])
+            .build();
+        NotificationSummary::new(&baseline, &candidate, 1)
+    }
+
+    #[test]
+    fn picks_top_changes() {
+        let s = summary();
+        assert_eq!(s.regressions.len(), 1);
+        assert_eq!(s.regressions[0].benchmark, "a");
+        assert_eq!(s.improvements.len(), 1);
+        assert_eq!(s.improvements[0].benchmark, "c");
+        // (1.5 * 1.1 * 0.5 * 1.0)^(1/4) - 1
+        let expected = (1.5f64 * 1.1 * 0.5).powf(0.25) - 1.0;
+        assert!((s.geomean_change.unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn slack_and_discord_payloads() {
+        let s = summary();
+        let slack = s.payload(Webhook::Slack);
+        let text = slack["text"].as_str().unwrap();
+        assert!(text.starts_with("*zkbench: impl 1.0.0"));
+        assert!(text.contains("• a: +50.0% (100 ms → 150 ms)"));
+        let discord = s.payload(Webhook::Discord);
+        assert!(
+            discord["content"]
+                .as_str()
+                .unwrap()
+                .starts_with("**zkbench")
+        );
+    }
+
+    #[test]
+    fn detect_webhook() {
+        assert_eq!(
+            Webhook::detect("https://discord.com/api/webhooks/1/abc"),
+            Webhook::Discord
+        );
+        assert_eq!(
+            Webhook::detect("https://hooks.slack.com/services/T/B/X"),
+            Webhook::Slack
+        );
+    }
+
+    #[test]
+    fn discord_content_is_truncated() {
+        let entries: Vec<(String, f64)> =
+            (0..200).map(|i| (format!("bench_{i:03}"), 1.0)).collect();
+        let names: Vec<(&str, f64)> = entries.iter().map(|(n, v)| (n.as_str(), *v)).collect();
+        let slower: Vec<(&str, f64)> = names.iter().map(|(n, _)| (*n, 2.0)).collect();
+        let s = NotificationSummary::new(
+            &ReportBuilder::new().latencies_ms(&names).build(),
+            &ReportBuilder::new().latencies_ms(&slower).build(),
+            200,
+        );
+        let content = s.payload(Webhook::Discord)["content"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        assert_eq!(content.chars().count(), DISCORD_MAX_CONTENT);
+    }
+}
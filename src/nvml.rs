@@ -0,0 +1,387 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! NVIDIA GPU power, utilization and memory sampling through NVML, behind
+//! the `nvml` feature.
+//!
+//! GPU provers are compared by proofs per joule as much as by latency.
+//! [`GpuSampler`] is used like a [`Stopwatch`](crate::Stopwatch) around
+//! the measured region: while it runs, a background thread polls every
+//! GPU, and [`GpuSampler::record`] stores the averages, peaks and energy
+//! as `gpu_*` counters of the result.
+//!
+//! NVML is loaded at run time from the driver, so binaries built with the
+//! feature still start on machines without an NVIDIA GPU;
+//! [`GpuSampler::new`] fails there instead.
+
+use std::sync::Arc;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use nvml_wrapper::error::NvmlError;
+use nvml_wrapper::{Device, Nvml};
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Default time between samples. NVML updates power readings about this
+/// often on recent GPUs.
+pub const GPU_SAMPLE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Average and peak of a sampled quantity.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SampleSummary {
+    samples: u64,
+    sum: f64,
+    peak: f64,
+}
+
+impl SampleSummary {
+    /// Number of samples taken.
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    /// Mean of the samples, or zero without samples.
+    pub fn average(&self) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            self.sum / self.samples as f64
+        }
+    }
+
+    /// Largest sample, or zero without samples.
+    pub fn peak(&self) -> f64 {
+        self.peak
+    }
+
+    fn add(&mut self, value: f64) {
+        self.peak = if self.samples == 0 {
+            value
+        } else {
+            self.peak.max(value)
+        };
+        self.samples += 1;
+        self.sum += value;
+    }
+
+    fn merge(&mut self, other: &SampleSummary) {
+        if other.samples > 0 {
+            self.peak = if self.samples == 0 {
+                other.peak
+            } else {
+                self.peak.max(other.peak)
+            };
+            self.samples += other.samples;
+            self.sum += other.sum;
+        }
+    }
+}
+
+/// What a [`GpuSampler`] measured, over all GPUs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpuStats {
+    /// Total power draw, in watts.
+    pub power: SampleSummary,
+    /// Mean share of time the GPUs were running kernels, in percent.
+    pub utilization: SampleSummary,
+    /// Total memory in use, in bytes.
+    pub memory: SampleSummary,
+    /// Energy used, in joules: from the GPUs' energy counters where all
+    /// support them (Volta and newer), otherwise integrated from the power
+    /// samples.
+    pub energy: f64,
+}
+
+impl GpuStats {
+    /// Records the stats as counters of `result`: the average and peak of
+    /// each sampled quantity as `gpu_<quantity>` and
+    /// `gpu_<quantity>_peak`, and the energy per iteration as `gpu_energy`
+    /// along with its inverse, `gpu_proofs_per_joule`. Quantities no GPU
+    /// reported are left out.
+    pub fn record(&self, result: &mut BenchmarkResult) {
+        for (name, summary, unit) in [
+            ("gpu_power", &self.power, "W"),
+            ("gpu_utilization", &self.utilization, "%"),
+            ("gpu_memory", &self.memory, "B"),
+        ] {
+            if summary.samples == 0 {
+                continue;
+            }
+            result
+                .counters
+                .insert(name.to_string(), MetricValue::new(summary.average(), unit));
+            result.counters.insert(
+                format!("{name}_peak"),
+                MetricValue::new(summary.peak(), unit),
+            );
+        }
+        if self.energy > 0.0 {
+            let iterations = result.iterations.max(1) as f64;
+            result.counters.insert(
+                "gpu_energy".to_string(),
+                MetricValue::new(self.energy / iterations, "J"),
+            );
+            result.counters.insert(
+                "gpu_proofs_per_joule".to_string(),
+                MetricValue::new(iterations / self.energy, "proofs/J"),
+            );
+        }
+    }
+
+    fn merge(&mut self, other: &GpuStats) {
+        self.power.merge(&other.power);
+        self.utilization.merge(&other.utilization);
+        self.memory.merge(&other.memory);
+        self.energy += other.energy;
+    }
+}
+
+/// Samples every NVIDIA GPU in the background while running.
+///
+/// Like a [`Stopwatch`](crate::Stopwatch), stats accumulate across
+/// `start`/`stop` pairs until `reset`. GPUs are shared, so work of other
+/// processes is included.
+///
+/// # Example
+///
+/// ```no_run
+/// use zkbench::{BenchmarkResult, GpuSampler};
+/// # fn prove_on_gpu() {}
+///
+/// let mut gpu = GpuSampler::new()?;
+/// gpu.start();
+/// for _ in 0..10 {
+///     prove_on_gpu();
+/// }
+/// gpu.stop();
+/// let mut result = BenchmarkResult {
+///     iterations: 10,
+///     ..Default::default()
+/// };
+/// gpu.record(&mut result);
+/// println!("{} proofs/J", result.counters["gpu_proofs_per_joule"].value);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct GpuSampler {
+    nvml: Arc<Nvml>,
+    devices: u32,
+    interval: Duration,
+    running: Option<(Sender<()>, JoinHandle<GpuStats>)>,
+    stats: GpuStats,
+}
+
+impl GpuSampler {
+    /// Loads NVML and finds the GPUs, stopped.
+    pub fn new() -> Result<Self, NvmlError> {
+        let nvml = Nvml::init()?;
+        let devices = nvml.device_count()?;
+        Ok(Self {
+            nvml: Arc::new(nvml),
+            devices,
+            interval: GPU_SAMPLE_INTERVAL,
+            running: None,
+            stats: GpuStats::default(),
+        })
+    }
+
+    /// Sets the time between samples, [`GPU_SAMPLE_INTERVAL`] by default.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Number of GPUs sampled.
+    pub fn devices(&self) -> u32 {
+        self.devices
+    }
+
+    /// Starts sampling. Has no effect if already running.
+    pub fn start(&mut self) {
+        if self.running.is_some() {
+            return;
+        }
+        let (stop, stopped) = mpsc::channel();
+        let nvml = Arc::clone(&self.nvml);
+        let (devices, interval) = (self.devices, self.interval);
+        let thread = thread::spawn(move || {
+            let devices: Vec<Device> = (0..devices)
+                .filter_map(|index| nvml.device_by_index(index).ok())
+                .collect();
+            sample(&devices, interval, |timeout| {
+                !matches!(
+                    stopped.recv_timeout(timeout),
+                    Err(RecvTimeoutError::Timeout)
+                )
+            })
+        });
+        self.running = Some((stop, thread));
+    }
+
+    /// Takes a last sample, stops sampling and accumulates the stats. Has
+    /// no effect if already stopped.
+    pub fn stop(&mut self) {
+        if let Some((stop, thread)) = self.running.take() {
+            let _ = stop.send(());
+            let stats = thread.join().expect("GPU sampler panicked");
+            self.stats.merge(&stats);
+        }
+    }
+
+    /// Clears the stats and stops sampling.
+    pub fn reset(&mut self) {
+        self.stop();
+        self.stats = GpuStats::default();
+    }
+
+    /// The stats accumulated so far, not including a running
+    /// `start`/`stop` pair.
+    pub fn stats(&self) -> &GpuStats {
+        &self.stats
+    }
+
+    /// Records the stats as counters of `result`; see
+    /// [`GpuStats::record`].
+    pub fn record(&self, result: &mut BenchmarkResult) {
+        self.stats.record(result);
+    }
+}
+
+impl Drop for GpuSampler {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Samples `devices` until `wait` returns true, which it does once
+/// stopped rather than after the timeout it is given, then once more.
+fn sample(
+    devices: &[Device],
+    interval: Duration,
+    mut wait: impl FnMut(Duration) -> bool,
+) -> GpuStats {
+    let energy = |devices: &[Device]| -> Option<u64> {
+        devices
+            .iter()
+            .map(|device| device.total_energy_consumption().ok())
+            .sum()
+    };
+    let started_energy = energy(devices);
+    let mut stats = GpuStats::default();
+    let mut integrated = 0.0;
+    let mut last = Instant::now();
+    let mut stopped = false;
+    loop {
+        let now = Instant::now();
+        let power: Vec<f64> = devices
+            .iter()
+            .filter_map(|device| device.power_usage().ok())
+            .map(|milliwatts| milliwatts as f64 / 1e3)
+            .collect();
+        if !power.is_empty() {
+            let power = power.iter().sum::<f64>();
+            stats.power.add(power);
+            integrated += power * (now - last).as_secs_f64();
+        }
+        let utilization: Vec<f64> = devices
+            .iter()
+            .filter_map(|device| device.utilization_rates().ok())
+            .map(|rates| rates.gpu as f64)
+            .collect();
+        if !utilization.is_empty() {
+            stats
+                .utilization
+                .add(utilization.iter().sum::<f64>() / utilization.len() as f64);
+        }
+        let memory: Vec<f64> = devices
+            .iter()
+            .filter_map(|device| device.memory_info().ok())
+            .map(|info| info.used as f64)
+            .collect();
+        if !memory.is_empty() {
+            stats.memory.add(memory.iter().sum());
+        }
+        last = now;
+        if stopped {
+            break;
+        }
+        stopped = wait(interval);
+    }
+    // Energy counters are in millijoules.
+    stats.energy = match (started_energy, energy(devices)) {
+        (Some(started), Some(stopped)) if !devices.is_empty() => {
+            stopped.saturating_sub(started) as f64 / 1e3
+        }
+        _ => integrated,
+    };
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_samples() {
+        let mut power = SampleSummary::default();
+        assert_eq!(power.average(), 0.0);
+        for watts in [100.0, 300.0, 200.0] {
+            power.add(watts);
+        }
+        let mut later = SampleSummary::default();
+        later.add(50.0);
+        power.merge(&later);
+        power.merge(&SampleSummary::default());
+        assert_eq!(power.samples(), 4);
+        assert_eq!(power.average(), 162.5);
+        assert_eq!(power.peak(), 300.0);
+    }
+
+    #[test]
+    fn records_gpu_counters() {
+        let mut stats = GpuStats {
+            energy: 40.0,
+            ..Default::default()
+        };
+        stats.power.add(250.0);
+        stats.power.add(350.0);
+        stats.memory.add(2e9);
+        let mut result = BenchmarkResult {
+            iterations: 10,
+            ..Default::default()
+        };
+        stats.record(&mut result);
+
+        let counter = |name: &str| {
+            let metric = &result.counters[name];
+            (metric.value, metric.unit.as_str())
+        };
+        assert_eq!(counter("gpu_power"), (300.0, "W"));
+        assert_eq!(counter("gpu_power_peak"), (350.0, "W"));
+        assert_eq!(counter("gpu_memory_peak"), (2e9, "B"));
+        assert_eq!(counter("gpu_energy"), (4.0, "J"));
+        assert_eq!(counter("gpu_proofs_per_joule"), (0.25, "proofs/J"));
+        assert!(!result.counters.contains_key("gpu_utilization"));
+    }
+
+    #[test]
+    fn samples_until_stopped() {
+        // Without GPUs only the sampling loop itself runs.
+        let mut waits = 0;
+        let stats = sample(&[], Duration::ZERO, |_| {
+            waits += 1;
+            waits == 3
+        });
+        assert_eq!(waits, 3);
+        assert_eq!(stats, GpuStats::default());
+
+        if let Ok(mut gpu) = GpuSampler::new() {
+            gpu.start();
+            thread::sleep(Duration::from_millis(120));
+            gpu.stop();
+            assert!(gpu.devices() == 0 || gpu.stats().power.samples() >= 2);
+        }
+    }
+}
@@ -0,0 +1,694 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Thread activity and parallel efficiency of multi-threaded benchmarks.
+//!
+//! A prover that runs 1.2× faster on 16 threads than on 8 either has
+//! little left to parallelize or spends the extra threads waiting on
+//! locks. [`ThreadTimer`] tells these apart by recording, per thread, the
+//! time spent running, runnable but waiting for a core, and blocked,
+//! from the scheduler statistics in `/proc/self/task` on Linux; other
+//! platforms record nothing. [`thread_scaling`] turns the latencies of a
+//! thread-count sweep, benchmarks named `<group>/threads/<N>`, into the
+//! speedup over the fewest threads and the parallel efficiency, the
+//! speedup per thread, which shows where scaling flattens.
+//!
+//! With the `rayon` feature, [`run_threads`] runs such a sweep on rayon
+//! pools of each size, and [`run_threads_pinned`] on pools whose threads
+//! are pinned to a [`CpuPinning`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "rayon")]
+use crate::affinity::CpuPinning;
+use crate::schema::{BenchmarkReport, BenchmarkResult, MetricValue};
+use crate::speedup::speedup;
+use crate::sweep::split_parameter;
+
+/// Path segment before the thread count in the name of a thread-count
+/// sweep, as in `prove/threads/8`.
+pub const THREADS_SEGMENT: &str = "threads";
+
+/// Time one thread spent in the measured region.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadTime {
+    /// Thread id, e.g. the Linux TID.
+    pub id: u64,
+    /// Time running on a core.
+    pub busy: Duration,
+    /// Time runnable but waiting for a core, when the scheduler reports
+    /// it. Many of these mean more threads than cores.
+    pub runnable: Option<Duration>,
+    /// The rest of the region: waiting for a lock, a condition, I/O or
+    /// other threads.
+    pub blocked: Duration,
+}
+
+/// Activity of the threads that ran in the measured region, in the order
+/// of their ids. Threads that did not run are left out, and so are
+/// threads that exited before the end of the region.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThreadUsage {
+    /// Wall-clock length of the region.
+    pub wall: Duration,
+    pub threads: Vec<ThreadTime>,
+}
+
+impl ThreadUsage {
+    /// Busy time summed over threads.
+    pub fn busy(&self) -> Duration {
+        self.threads.iter().map(|t| t.busy).sum()
+    }
+
+    /// Blocked time summed over threads.
+    pub fn blocked(&self) -> Duration {
+        self.threads.iter().map(|t| t.blocked).sum()
+    }
+
+    /// Runnable time summed over threads, if reported for all of them.
+    pub fn runnable(&self) -> Option<Duration> {
+        self.threads.iter().map(|t| t.runnable).sum()
+    }
+
+    /// Fraction of the wall time the threads were busy on average, in
+    /// percent.
+    pub fn utilization(&self) -> Option<f64> {
+        let capacity = self.wall.as_secs_f64() * self.threads.len() as f64;
+        (capacity > 0.0).then(|| 100.0 * self.busy().as_secs_f64() / capacity)
+    }
+
+    /// Records the activity as counters of `result`: the times summed
+    /// over threads and divided by its iterations, as `thread_busy`,
+    /// `thread_runnable` and `thread_blocked` in `ns`, the number of
+    /// threads as `active_threads` and their `thread_utilization` in `%`.
+    /// Records nothing without threads.
+    pub fn record(&self, result: &mut BenchmarkResult) {
+        let Some(utilization) = self.utilization() else {
+            return;
+        };
+        let iterations = result.iterations.max(1) as f64;
+        let per_iteration =
+            |time: Duration| MetricValue::new(time.as_nanos() as f64 / iterations, "ns");
+        let counters = &mut result.counters;
+        counters.insert("thread_busy".to_string(), per_iteration(self.busy()));
+        if let Some(runnable) = self.runnable() {
+            counters.insert("thread_runnable".to_string(), per_iteration(runnable));
+        }
+        counters.insert("thread_blocked".to_string(), per_iteration(self.blocked()));
+        counters.insert(
+            "active_threads".to_string(),
+            MetricValue::new(self.threads.len() as f64, "count"),
+        );
+        counters.insert(
+            "thread_utilization".to_string(),
+            MetricValue::new(utilization, "%"),
+        );
+    }
+}
+
+/// Scheduler times of one thread since it started.
+#[derive(Debug, Clone, Copy, Default)]
+struct SchedTimes {
+    busy_ns: u64,
+    runnable_ns: Option<u64>,
+}
+
+/// Measures the [`ThreadUsage`] of all threads of the process over one or
+/// more start/stop regions.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{BenchmarkResult, ThreadTimer};
+/// # fn prove() {}
+///
+/// let mut timer = ThreadTimer::new();
+/// timer.start()?;
+/// prove();
+/// timer.stop()?;
+/// let mut result = BenchmarkResult {
+///     iterations: 1,
+///     ..Default::default()
+/// };
+/// timer.usage().record(&mut result);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct ThreadTimer {
+    started: Option<(Instant, HashMap<u64, SchedTimes>)>,
+    usage: ThreadUsage,
+}
+
+impl ThreadTimer {
+    /// Creates a stopped timer with nothing recorded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a region. Has no effect if already running.
+    pub fn start(&mut self) -> io::Result<()> {
+        if self.started.is_none() {
+            let times = sched_times()?;
+            self.started = Some((Instant::now(), times));
+        }
+        Ok(())
+    }
+
+    /// Ends the region and adds its activity to the usage. Has no effect
+    /// if already stopped.
+    pub fn stop(&mut self) -> io::Result<()> {
+        let Some((started_at, before)) = self.started.take() else {
+            return Ok(());
+        };
+        let after = sched_times()?;
+        let wall = started_at.elapsed();
+        self.usage.wall += wall;
+        for (id, now) in after {
+            // Threads spawned in the region start from zero.
+            let earlier = before.get(&id).copied().unwrap_or_default();
+            let busy = Duration::from_nanos(now.busy_ns.saturating_sub(earlier.busy_ns));
+            if busy.is_zero() {
+                continue;
+            }
+            let runnable = now.runnable_ns.map(|ns| {
+                Duration::from_nanos(ns.saturating_sub(earlier.runnable_ns.unwrap_or(0)))
+            });
+            let blocked = wall.saturating_sub(busy + runnable.unwrap_or_default());
+            let index = match self.usage.threads.binary_search_by_key(&id, |t| t.id) {
+                Ok(index) => index,
+                Err(index) => {
+                    self.usage.threads.insert(
+                        index,
+                        ThreadTime {
+                            id,
+                            runnable: runnable.map(|_| Duration::ZERO),
+                            ..Default::default()
+                        },
+                    );
+                    index
+                }
+            };
+            let thread = &mut self.usage.threads[index];
+            thread.busy += busy;
+            thread.runnable = thread.runnable.zip(runnable).map(|(a, b)| a + b);
+            thread.blocked += blocked;
+        }
+        Ok(())
+    }
+
+    /// Stops the timer and clears the usage.
+    pub fn reset(&mut self) {
+        self.started = None;
+        self.usage = ThreadUsage::default();
+    }
+
+    /// The activity over all stopped regions.
+    pub fn usage(&self) -> &ThreadUsage {
+        &self.usage
+    }
+}
+
+/// Reads `/proc/self/task/<tid>/schedstat`, whose first two fields are
+/// the nanoseconds on a core and in the run queue.
+#[cfg(target_os = "linux")]
+fn sched_times() -> io::Result<HashMap<u64, SchedTimes>> {
+    let mut times = HashMap::new();
+    for entry in std::fs::read_dir("/proc/self/task")? {
+        let entry = entry?;
+        let Some(id) = entry.file_name().to_str().and_then(|s| s.parse().ok()) else {
+            continue;
+        };
+        // The thread may have exited since the directory was listed.
+        let Ok(schedstat) = std::fs::read_to_string(entry.path().join("schedstat")) else {
+            continue;
+        };
+        let mut fields = schedstat.split_whitespace().map(|f| f.parse::<u64>().ok());
+        if let Some(Some(busy_ns)) = fields.next() {
+            let runnable_ns = fields.next().flatten();
+            times.insert(
+                id,
+                SchedTimes {
+                    busy_ns,
+                    runnable_ns,
+                },
+            );
+        }
+    }
+    Ok(times)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn sched_times() -> io::Result<HashMap<u64, SchedTimes>> {
+    Ok(HashMap::new())
+}
+
+/// One thread count of a [`ThreadScaling`].
+#[derive(Debug, Clone)]
+pub struct ThreadPoint {
+    pub threads: usize,
+    /// Full benchmark name this point was taken from.
+    pub benchmark: String,
+    pub latency: MetricValue,
+    /// How many times faster than the fewest threads.
+    pub speedup: MetricValue,
+    /// Speedup per added thread: the speedup times the fewest threads
+    /// divided by these threads, 1× for perfect scaling.
+    pub efficiency: MetricValue,
+}
+
+/// A group of benchmarks that differ only in their thread count.
+#[derive(Debug, Clone)]
+pub struct ThreadScaling {
+    /// Name before the thread count, e.g. `prove` for `prove/threads/8`.
+    pub name: String,
+    /// Points ordered by ascending thread count.
+    pub points: Vec<ThreadPoint>,
+}
+
+impl ThreadScaling {
+    /// The fewest threads whose efficiency falls below `min_efficiency`,
+    /// e.g. 0.8, where adding threads stops paying off.
+    pub fn flattens_at(&self, min_efficiency: f64) -> Option<usize> {
+        self.points
+            .iter()
+            .find(|p| p.efficiency.value < min_efficiency)
+            .map(|p| p.threads)
+    }
+}
+
+/// Extracts the thread-count sweeps of a report, benchmarks named
+/// `<group>/threads/<N>` with a latency, with the speedup and efficiency
+/// of each point. Groups with fewer than two points are dropped; the
+/// rest are sorted by name.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{BenchmarkReport, BenchmarkResult, Metadata, MetricValue, thread_scaling};
+///
+/// let benchmarks = [(1, 80.0), (2, 40.0), (4, 25.0)]
+///     .into_iter()
+///     .map(|(threads, ms)| {
+///         let result = BenchmarkResult {
+///             latency: Some(MetricValue::new(ms, "ms")),
+///             ..Default::default()
+///         };
+///         (format!("prove/threads/{threads}"), result)
+///     })
+///     .collect();
+/// let report = BenchmarkReport {
+///     metadata: Metadata::create("my-impl", "0.1.0"),
+///     benchmarks,
+/// };
+/// let scaling = &thread_scaling(&report)[0];
+/// assert_eq!(scaling.points[2].efficiency.value, 0.8);
+/// assert_eq!(scaling.flattens_at(0.9), Some(4));
+/// ```
+pub fn thread_scaling(report: &BenchmarkReport) -> Vec<ThreadScaling> {
+    scaling_of(&report.benchmarks)
+}
+
+/// Like [`thread_scaling`], and also records each point's speedup and
+/// efficiency as the `parallel_speedup` and `parallel_efficiency`
+/// counters of its benchmark, in `x`.
+pub fn record_parallel_efficiency(report: &mut BenchmarkReport) -> Vec<ThreadScaling> {
+    let scaling = scaling_of(&report.benchmarks);
+    record_scaling(&scaling, &mut report.benchmarks);
+    scaling
+}
+
+fn scaling_of(benchmarks: &HashMap<String, BenchmarkResult>) -> Vec<ThreadScaling> {
+    let mut groups: BTreeMap<&str, Vec<(usize, &String, &MetricValue)>> = BTreeMap::new();
+    for (name, result) in benchmarks {
+        let Some(latency) = &result.latency else {
+            continue;
+        };
+        let Some((prefix, threads)) = split_parameter(name) else {
+            continue;
+        };
+        let Some(group) = prefix
+            .strip_suffix(THREADS_SEGMENT)
+            .and_then(|group| group.strip_suffix('/'))
+        else {
+            continue;
+        };
+        if threads >= 1.0 {
+            groups
+                .entry(group)
+                .or_default()
+                .push((threads as usize, name, latency));
+        }
+    }
+    groups
+        .into_iter()
+        .filter(|(_, points)| points.len() >= 2)
+        .map(|(name, mut points)| {
+            points.sort_by_key(|&(threads, ..)| threads);
+            let (base_threads, _, base) = points[0];
+            let points = points
+                .into_iter()
+                .map(|(threads, benchmark, latency)| {
+                    let speedup = speedup(base, latency);
+                    let scale = base_threads as f64 / threads as f64;
+                    let efficiency = MetricValue {
+                        value: speedup.value * scale,
+                        unit: speedup.unit.clone(),
+                        lower_value: speedup.lower_value.map(|v| v * scale),
+                        upper_value: speedup.upper_value.map(|v| v * scale),
+                    };
+                    ThreadPoint {
+                        threads,
+                        benchmark: benchmark.clone(),
+                        latency: latency.clone(),
+                        speedup,
+                        efficiency,
+                    }
+                })
+                .collect();
+            ThreadScaling {
+                name: name.to_string(),
+                points,
+            }
+        })
+        .collect()
+}
+
+fn record_scaling(scaling: &[ThreadScaling], benchmarks: &mut HashMap<String, BenchmarkResult>) {
+    for point in scaling.iter().flat_map(|s| &s.points) {
+        if let Some(result) = benchmarks.get_mut(&point.benchmark) {
+            result
+                .counters
+                .insert("parallel_speedup".to_string(), point.speedup.clone());
+            result
+                .counters
+                .insert("parallel_efficiency".to_string(), point.efficiency.clone());
+        }
+    }
+}
+
+/// Runs `f` `iterations` times on a rayon pool of each of `threads`
+/// threads, after one warm-up run, and returns the results named
+/// `<name>/threads/<N>`.
+///
+/// Each result has the latency in `ms` with a 95% confidence interval,
+/// the samples, the thread count in its `threads` metadata, the
+/// [`ThreadUsage`] counters where measurable, and the counters of
+/// [`record_parallel_efficiency`].
+///
+/// # Example
+///
+/// ```
+/// use rayon::prelude::*;
+/// use zkbench::run_threads;
+///
+/// let results = run_threads("sum", &[1, 2], 3, || {
+///     std::hint::black_box((0..100_000u64).into_par_iter().sum::<u64>());
+/// })?;
+/// assert!(results["sum/threads/2"].counters.contains_key("parallel_efficiency"));
+/// # Ok::<(), rayon::ThreadPoolBuildError>(())
+/// ```
+#[cfg(feature = "rayon")]
+pub fn run_threads(
+    name: &str,
+    threads: &[usize],
+    iterations: usize,
+    f: impl FnMut() + Send,
+) -> Result<HashMap<String, BenchmarkResult>, rayon::ThreadPoolBuildError> {
+    run_pools(name, threads, iterations, None, f)
+}
+
+/// Like [`run_threads`], with every pool thread pinned to the cores of
+/// `pinning`, which is also recorded in the `cpu_pinning` metadata of
+/// each result.
+///
+/// # Example
+///
+/// ```no_run
+/// use zkbench::{CpuSelection, run_threads_pinned};
+/// # fn prove() {}
+///
+/// let pinning = CpuSelection::PerformanceCores.resolve()?;
+/// let threads = [1, 2, pinning.cores.len()];
+/// let results = run_threads_pinned("prove", &threads, 10, &pinning, prove)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "rayon")]
+pub fn run_threads_pinned(
+    name: &str,
+    threads: &[usize],
+    iterations: usize,
+    pinning: &CpuPinning,
+    f: impl FnMut() + Send,
+) -> Result<HashMap<String, BenchmarkResult>, rayon::ThreadPoolBuildError> {
+    run_pools(name, threads, iterations, Some(pinning), f)
+}
+
+#[cfg(feature = "rayon")]
+fn run_pools(
+    name: &str,
+    threads: &[usize],
+    iterations: usize,
+    pinning: Option<&CpuPinning>,
+    mut f: impl FnMut() + Send,
+) -> Result<HashMap<String, BenchmarkResult>, rayon::ThreadPoolBuildError> {
+    use crate::statistics::{calculate_confidence_interval_default, calculate_statistics};
+
+    let iterations = iterations.max(1);
+    let mut results = HashMap::new();
+    for &n in threads {
+        let mut builder = rayon::ThreadPoolBuilder::new().num_threads(n);
+        if let Some(pinning) = pinning.cloned() {
+            // The cores were checked when the pinning was resolved, so
+            // this only fails if they went offline since.
+            builder = builder.start_handler(move |_| {
+                let _ = pinning.apply();
+            });
+        }
+        let pool = builder.build()?;
+        let mut timer = ThreadTimer::new();
+        let samples = pool.install(|| {
+            f();
+            // Without thread statistics, only the thread counters are lost.
+            let _ = timer.start();
+            let samples: Vec<f64> = (0..iterations)
+                .map(|_| {
+                    let started = Instant::now();
+                    f();
+                    started.elapsed().as_secs_f64() * 1_000.0
+                })
+                .collect();
+            let _ = timer.stop();
+            samples
+        });
+
+        let (mean, stdev) = calculate_statistics(&samples);
+        let latency = if iterations > 1 {
+            let (lower, upper) = calculate_confidence_interval_default(mean, stdev, iterations);
+            MetricValue::with_bounds(mean, "ms", lower, upper)
+        } else {
+            MetricValue::new(mean, "ms")
+        };
+        let mut result = BenchmarkResult {
+            latency: Some(latency),
+            iterations,
+            samples,
+            ..Default::default()
+        };
+        result
+            .metadata
+            .insert("threads".to_string(), serde_json::json!(n));
+        if let Some(pinning) = pinning {
+            result
+                .metadata
+                .insert("cpu_pinning".to_string(), serde_json::json!(pinning));
+        }
+        timer.usage().record(&mut result);
+        results.insert(format!("{name}/{THREADS_SEGMENT}/{n}"), result);
+    }
+    record_scaling(&scaling_of(&results), &mut results);
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    use std::sync::{Arc, Barrier, Mutex};
+
+    /// CPU time consumed by the calling thread.
+    #[cfg(unix)]
+    fn thread_cpu_time() -> Duration {
+        // SAFETY: `timespec` is plain data, for which all zeroes is valid.
+        let mut ts: libc::timespec = unsafe { std::mem::zeroed() };
+        // SAFETY: `ts` is a writable `timespec`.
+        let rc = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) };
+        assert_eq!(rc, 0, "{}", io::Error::last_os_error());
+        Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32)
+    }
+
+    #[cfg(not(unix))]
+    fn thread_cpu_time() -> Duration {
+        thread_local!(static STARTED: Instant = Instant::now());
+        STARTED.with(Instant::elapsed)
+    }
+
+    #[test]
+    fn splits_busy_and_blocked_threads() {
+        let lock = Arc::new(Mutex::new(()));
+        // Started, done, and exiting: threads that exited by the end of
+        // the region are not seen.
+        let barriers = Arc::new([Barrier::new(3), Barrier::new(3), Barrier::new(3)]);
+        let guard = lock.lock().unwrap();
+        let spawn = |spin: bool| {
+            let (lock, barriers) = (Arc::clone(&lock), Arc::clone(&barriers));
+            std::thread::spawn(move || {
+                barriers[0].wait();
+                if spin {
+                    // Wall time is no measure of CPU time on a loaded
+                    // machine, so spin on the thread's own clock.
+                    let started = thread_cpu_time();
+                    while thread_cpu_time() - started < Duration::from_millis(100) {
+                        std::hint::black_box(());
+                    }
+                } else {
+                    drop(lock.lock().unwrap());
+                }
+                barriers[1].wait();
+                barriers[2].wait();
+            })
+        };
+        let (spinner, waiter) = (spawn(true), spawn(false));
+
+        let mut timer = ThreadTimer::new();
+        timer.start().unwrap();
+        barriers[0].wait();
+        std::thread::sleep(Duration::from_millis(150));
+        drop(guard);
+        barriers[1].wait();
+        timer.stop().unwrap();
+        barriers[2].wait();
+        spinner.join().unwrap();
+        waiter.join().unwrap();
+
+        let usage = timer.usage();
+        if cfg!(target_os = "linux") {
+            assert!(usage.wall >= Duration::from_millis(150));
+            let spinner = usage.threads.iter().max_by_key(|t| t.busy).unwrap();
+            assert!(spinner.busy >= Duration::from_millis(100), "{usage:?}");
+            assert!(usage.blocked() >= Duration::from_millis(100), "{usage:?}");
+            let utilization = usage.utilization().unwrap();
+            assert!(utilization > 0.0 && utilization < 100.0);
+        }
+
+        let usage = ThreadUsage {
+            wall: Duration::from_micros(10),
+            threads: vec![
+                ThreadTime {
+                    id: 1,
+                    busy: Duration::from_micros(10),
+                    runnable: Some(Duration::ZERO),
+                    blocked: Duration::ZERO,
+                },
+                ThreadTime {
+                    id: 2,
+                    busy: Duration::from_micros(2),
+                    runnable: Some(Duration::from_micros(1)),
+                    blocked: Duration::from_micros(7),
+                },
+            ],
+        };
+        let mut result = BenchmarkResult {
+            iterations: 2,
+            ..Default::default()
+        };
+        usage.record(&mut result);
+        let counters = &result.counters;
+        assert_eq!(counters["thread_busy"], MetricValue::new(6_000.0, "ns"));
+        assert_eq!(counters["thread_runnable"], MetricValue::new(500.0, "ns"));
+        assert_eq!(counters["thread_blocked"], MetricValue::new(3_500.0, "ns"));
+        assert_eq!(counters["active_threads"].value, 2.0);
+        assert_eq!(counters["thread_utilization"], MetricValue::new(60.0, "%"));
+    }
+
+    #[test]
+    fn efficiency_relative_to_fewest_threads() {
+        let mut report = ReportBuilder::new()
+            .latencies_ms(&[
+                ("prove/threads/2", 100.0),
+                ("prove/threads/4", 50.0),
+                ("prove/threads/8", 40.0),
+                ("verify/threads/1", 1.0),
+                ("msm/2^16", 5.0),
+            ])
+            .build();
+        let scaling = record_parallel_efficiency(&mut report);
+        assert_eq!(scaling.len(), 1);
+        assert_eq!(scaling[0].name, "prove");
+        let efficiency: Vec<_> = scaling[0]
+            .points
+            .iter()
+            .map(|p| p.efficiency.value)
+            .collect();
+        assert_eq!(efficiency, [1.0, 1.0, 0.625]);
+        assert_eq!(scaling[0].flattens_at(0.8), Some(8));
+        assert_eq!(scaling[0].flattens_at(0.5), None);
+
+        let counters = &report.benchmarks["prove/threads/8"].counters;
+        assert_eq!(counters["parallel_speedup"], MetricValue::new(2.5, "x"));
+        assert_eq!(
+            counters["parallel_efficiency"],
+            MetricValue::new(0.625, "x")
+        );
+        assert!(report.benchmarks["verify/threads/1"].counters.is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn runs_on_pools_of_each_size() {
+        use rayon::prelude::*;
+
+        let results = run_threads("sum", &[1, 2], 2, || {
+            std::hint::black_box((0..200_000u64).into_par_iter().map(|x| x * x).sum::<u64>());
+        })
+        .unwrap();
+        assert_eq!(results.len(), 2);
+        let result = &results["sum/threads/2"];
+        assert_eq!(result.samples.len(), 2);
+        assert_eq!(result.metadata["threads"], 2);
+        assert!(result.counters.contains_key("parallel_efficiency"));
+        assert_eq!(
+            results["sum/threads/1"].counters["parallel_speedup"].value,
+            1.0
+        );
+        if cfg!(target_os = "linux") {
+            assert!(result.counters["thread_busy"].value > 0.0);
+        }
+    }
+
+    #[cfg(all(feature = "rayon", target_os = "linux"))]
+    #[test]
+    fn pins_pool_threads() {
+        let pinning = crate::affinity::CpuSelection::PerformanceCores
+            .resolve()
+            .unwrap();
+        let core = pinning.cores[0];
+        let pinning = CpuPinning {
+            cores: vec![core],
+            performance_cores_only: false,
+        };
+        let results = run_threads_pinned("pinned", &[2], 1, &pinning, || {
+            rayon::broadcast(|_| {
+                // SAFETY: sched_getcpu has no preconditions.
+                assert_eq!(unsafe { libc::sched_getcpu() }, core as i32);
+            });
+        })
+        .unwrap();
+        assert_eq!(
+            results["pinned/threads/2"].metadata["cpu_pinning"],
+            serde_json::json!({ "cores": [core] })
+        );
+    }
+}
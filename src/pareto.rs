@@ -0,0 +1,221 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pareto frontier analysis over two metrics.
+//!
+//! ZK provers trade one cost for another: proof size against prove time,
+//! memory against latency. Rather than ranking on a single metric, the
+//! frontier shows which implementations or configurations are the best
+//! available at each point of the tradeoff, and which are dominated.
+
+use serde::{Deserialize, Serialize};
+
+use crate::compare::{glob_match, higher_is_better};
+use crate::schema::{BenchmarkReport, MetricValue};
+use crate::units::to_base;
+
+/// One (implementation, benchmark) pair recording both metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParetoPoint {
+    pub implementation: String,
+    pub version: String,
+    pub benchmark: String,
+    pub x: MetricValue,
+    pub y: MetricValue,
+    /// Indices of the points that dominate this one: no worse on both
+    /// metrics and strictly better on at least one. Empty for points on
+    /// the frontier.
+    pub dominated_by: Vec<usize>,
+}
+
+impl ParetoPoint {
+    /// Whether the point lies on the Pareto frontier.
+    pub fn is_optimal(&self) -> bool {
+        self.dominated_by.is_empty()
+    }
+}
+
+/// Result of [`pareto_frontier`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParetoAnalysis {
+    pub x_metric: String,
+    pub y_metric: String,
+    /// All points, sorted by ascending `x` (in base units).
+    pub points: Vec<ParetoPoint>,
+}
+
+impl ParetoAnalysis {
+    /// Iterates over the non-dominated points, in ascending `x`.
+    pub fn frontier(&self) -> impl Iterator<Item = &ParetoPoint> {
+        self.points.iter().filter(|p| p.is_optimal())
+    }
+
+    /// Iterates over the dominated points, in ascending `x`.
+    pub fn dominated(&self) -> impl Iterator<Item = &ParetoPoint> {
+        self.points.iter().filter(|p| !p.is_optimal())
+    }
+}
+
+/// Computes the Pareto frontier over metrics `x` and `y`.
+///
+/// Metrics are named like in [`BenchmarkResult::metrics`]
+/// (`latency`, `memory`, `throughput` or a counter such as `proof_size`),
+/// and each is minimized or maximized according to its direction: rates
+/// (`/s`) and throughput are maximized, everything else minimized. Every
+/// benchmark of every report that records both metrics becomes a point;
+/// `benchmarks` optionally restricts them to names matching a glob, e.g.
+/// `prove/*` to compare prover configurations.
+///
+/// [`BenchmarkResult::metrics`]: crate::BenchmarkResult::metrics
+pub fn pareto_frontier(
+    reports: &[BenchmarkReport],
+    x: &str,
+    y: &str,
+    benchmarks: Option<&str>,
+) -> ParetoAnalysis {
+    let find = |metrics: &[(&str, &MetricValue)], name: &str| {
+        metrics
+            .iter()
+            .find(|(m, _)| *m == name)
+            .map(|(_, value)| (*value).clone())
+    };
+    let mut points: Vec<ParetoPoint> = reports
+        .iter()
+        .flat_map(|report| {
+            report
+                .benchmarks
+                .iter()
+                .map(move |(name, result)| (report, name, result))
+        })
+        .filter(|(_, name, _)| benchmarks.is_none_or(|pattern| glob_match(pattern, name)))
+        .filter_map(|(report, name, result)| {
+            let metrics = result.metrics();
+            Some(ParetoPoint {
+                implementation: report.metadata.implementation.clone(),
+                version: report.metadata.version.clone(),
+                benchmark: name.clone(),
+                x: find(&metrics, x)?,
+                y: find(&metrics, y)?,
+                dominated_by: Vec::new(),
+            })
+        })
+        .collect();
+
+    let base = |m: &MetricValue| to_base(m.value, &m.unit);
+    points.sort_by(|a, b| {
+        base(&a.x)
+            .total_cmp(&base(&b.x))
+            .then_with(|| base(&a.y).total_cmp(&base(&b.y)))
+            .then_with(|| a.implementation.cmp(&b.implementation))
+            .then_with(|| a.benchmark.cmp(&b.benchmark))
+    });
+
+    // Orient both axes so that smaller is better.
+    let cost = |m: &MetricValue, metric: &str| {
+        if higher_is_better(metric, &m.unit) {
+            -base(m)
+        } else {
+            base(m)
+        }
+    };
+    let costs: Vec<(f64, f64)> = points
+        .iter()
+        .map(|p| (cost(&p.x, x), cost(&p.y, y)))
+        .collect();
+    for (i, point) in points.iter_mut().enumerate() {
+        let (xi, yi) = costs[i];
+        point.dominated_by = costs
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(xj, yj))| xj <= xi && yj <= yi && (xj < xi || yj < yi))
+            .map(|(j, _)| j)
+            .collect();
+    }
+
+    ParetoAnalysis {
+        x_metric: x.to_string(),
+        y_metric: y.to_string(),
+        points,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+    use std::collections::HashMap;
+
+    fn sized(seconds: f64, kib: f64) -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::new(seconds, "s")),
+            counters: HashMap::from([("proof_size".to_string(), MetricValue::new(kib, "KiB"))]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn marks_dominated_points() {
+        let reports = [
+            ReportBuilder::new()
+                .implementation("stark")
+                .results([
+                    ("prove/blowup-2", sized(1.0, 400.0)),
+                    ("prove/blowup-8", sized(3.0, 120.0)),
+                ])
+                .build(),
+            ReportBuilder::new()
+                .implementation("snark")
+                .results([("prove", sized(8.0, 0.2))])
+                .build(),
+            ReportBuilder::new()
+                .implementation("slow")
+                .results([("prove", sized(9.0, 500.0))])
+                .build(),
+        ];
+        let analysis = pareto_frontier(&reports, "latency", "proof_size", None);
+        let frontier: Vec<_> = analysis
+            .frontier()
+            .map(|p| (p.implementation.as_str(), p.benchmark.as_str()))
+            .collect();
+        assert_eq!(
+            frontier,
+            [
+                ("stark", "prove/blowup-2"),
+                ("stark", "prove/blowup-8"),
+                ("snark", "prove")
+            ]
+        );
+        let slow = analysis.dominated().next().unwrap();
+        assert_eq!(slow.implementation, "slow");
+        assert_eq!(slow.dominated_by, [0, 1, 2]);
+    }
+
+    #[test]
+    fn filters_benchmarks_and_respects_direction() {
+        let mut fast = ReportBuilder::new()
+            .implementation("a")
+            .results([("prove/x", sized(1.0, 100.0)), ("verify", sized(0.1, 1.0))])
+            .build();
+        fast.benchmarks.get_mut("prove/x").unwrap().throughput =
+            Some(MetricValue::new(10.0, "ops/s"));
+        let mut slow = ReportBuilder::new()
+            .implementation("b")
+            .results([("prove/y", sized(2.0, 50.0))])
+            .build();
+        slow.benchmarks.get_mut("prove/y").unwrap().throughput =
+            Some(MetricValue::new(20.0, "ops/s"));
+        let reports = [fast, slow];
+
+        let analysis = pareto_frontier(&reports, "throughput", "proof_size", Some("prove/*"));
+        assert_eq!(analysis.points.len(), 2);
+        // b has higher throughput and a smaller proof: it dominates a.
+        let optimal: Vec<_> = analysis
+            .frontier()
+            .map(|p| p.implementation.as_str())
+            .collect();
+        assert_eq!(optimal, ["b"]);
+        let json = serde_json::to_value(&analysis).unwrap();
+        assert_eq!(json["points"][0]["dominated_by"][0], 1);
+    }
+}
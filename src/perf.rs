@@ -0,0 +1,294 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hardware performance counters through Linux `perf_event_open`, behind
+//! the `perf` feature.
+//!
+//! Instruction and cycle counts barely change between runs of the same
+//! code, so comparing them across a PR catches regressions that wall-clock
+//! noise hides. [`PerfCounters`] is used like a
+//! [`Stopwatch`](crate::Stopwatch) around the measured region and records
+//! its counts as counters of the result, in the same `count` unit and
+//! snake-case names as [`import_iai_callgrind`](crate::import_iai_callgrind).
+//!
+//! Opening counters fails where the kernel does not allow it, e.g. in
+//! containers without `CAP_PERFMON` or with `kernel.perf_event_paranoid`
+//! above 2; only user-space events are counted, so the default level of 2
+//! is enough.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read};
+use std::os::fd::{AsRawFd, FromRawFd};
+
+use perf_event_open_sys::bindings::{
+    perf_event_attr, perf_event_read_format_PERF_FORMAT_TOTAL_TIME_ENABLED,
+    perf_event_read_format_PERF_FORMAT_TOTAL_TIME_RUNNING, perf_hw_id,
+    perf_hw_id_PERF_COUNT_HW_BRANCH_MISSES, perf_hw_id_PERF_COUNT_HW_CACHE_MISSES,
+    perf_hw_id_PERF_COUNT_HW_CPU_CYCLES, perf_hw_id_PERF_COUNT_HW_INSTRUCTIONS,
+    perf_type_id_PERF_TYPE_HARDWARE,
+};
+use perf_event_open_sys::{ioctls, perf_event_open};
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// A hardware event counted by [`PerfCounters`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PerfCounter {
+    /// Retired instructions.
+    Instructions,
+    /// CPU cycles; varies with frequency scaling.
+    Cycles,
+    /// Mispredicted branches.
+    BranchMisses,
+    /// Last-level cache misses.
+    CacheMisses,
+}
+
+impl PerfCounter {
+    /// All counters, in declaration order.
+    pub const ALL: [PerfCounter; 4] = [
+        Self::Instructions,
+        Self::Cycles,
+        Self::BranchMisses,
+        Self::CacheMisses,
+    ];
+
+    /// Returns the name of the counter in reports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Instructions => "instructions",
+            Self::Cycles => "cycles",
+            Self::BranchMisses => "branch_misses",
+            Self::CacheMisses => "cache_misses",
+        }
+    }
+
+    fn event(&self) -> perf_hw_id {
+        match self {
+            Self::Instructions => perf_hw_id_PERF_COUNT_HW_INSTRUCTIONS,
+            Self::Cycles => perf_hw_id_PERF_COUNT_HW_CPU_CYCLES,
+            Self::BranchMisses => perf_hw_id_PERF_COUNT_HW_BRANCH_MISSES,
+            Self::CacheMisses => perf_hw_id_PERF_COUNT_HW_CACHE_MISSES,
+        }
+    }
+}
+
+impl std::fmt::Display for PerfCounter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Hardware counters of the calling thread and of the threads it spawns
+/// while they are open.
+///
+/// Like a [`Stopwatch`](crate::Stopwatch), counts accumulate across
+/// `start`/`stop` pairs until `reset`. When the CPU has fewer counters
+/// than requested, the kernel time-shares them and the counts are scaled
+/// up from the time each was running.
+///
+/// # Example
+///
+/// ```no_run
+/// use zkbench::{BenchmarkResult, PerfCounters};
+/// # fn prove() {}
+///
+/// let mut counters = PerfCounters::new()?;
+/// counters.start()?;
+/// for _ in 0..10 {
+///     prove();
+/// }
+/// counters.stop()?;
+/// let mut result = BenchmarkResult {
+///     iterations: 10,
+///     ..Default::default()
+/// };
+/// counters.record(&mut result)?;
+/// println!("{} instructions per proof", result.counters["instructions"].value);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct PerfCounters {
+    counters: Vec<(PerfCounter, File)>,
+}
+
+impl PerfCounters {
+    /// Opens all [`PerfCounter`]s, stopped.
+    pub fn new() -> io::Result<Self> {
+        Self::with_counters(&PerfCounter::ALL)
+    }
+
+    /// Opens the given counters, stopped.
+    pub fn with_counters(counters: &[PerfCounter]) -> io::Result<Self> {
+        let counters = counters
+            .iter()
+            .map(|&counter| {
+                let file = open(perf_type_id_PERF_TYPE_HARDWARE, counter.event().into())?;
+                Ok((counter, file))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self { counters })
+    }
+
+    /// Starts counting.
+    pub fn start(&mut self) -> io::Result<()> {
+        self.ioctl(ioctls::ENABLE)
+    }
+
+    /// Stops counting, keeping the counts.
+    pub fn stop(&mut self) -> io::Result<()> {
+        self.ioctl(ioctls::DISABLE)
+    }
+
+    /// Sets the counts to zero; does not stop counting.
+    pub fn reset(&mut self) -> io::Result<()> {
+        self.ioctl(ioctls::RESET)
+    }
+
+    /// The counts so far.
+    pub fn read(&self) -> io::Result<BTreeMap<PerfCounter, u64>> {
+        self.counters
+            .iter()
+            .map(|(counter, file)| Ok((*counter, read_scaled(file)?)))
+            .collect()
+    }
+
+    /// Records the counts as counters of `result`, divided by its
+    /// iterations so they are per iteration like its latency.
+    pub fn record(&self, result: &mut BenchmarkResult) -> io::Result<()> {
+        let iterations = result.iterations.max(1) as f64;
+        for (counter, count) in self.read()? {
+            result.counters.insert(
+                counter.as_str().to_string(),
+                MetricValue::new(count as f64 / iterations, "count"),
+            );
+        }
+        Ok(())
+    }
+
+    fn ioctl(&mut self, request: unsafe fn(i32, u32) -> i32) -> io::Result<()> {
+        for (_, file) in &self.counters {
+            // SAFETY: the descriptor is an open perf event owned by `file`.
+            if unsafe { request(file.as_raw_fd(), 0) } == -1 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Opens a disabled event of the calling thread and its future threads.
+fn open(type_: u32, config: u64) -> io::Result<File> {
+    let mut attr = perf_event_attr {
+        type_,
+        size: std::mem::size_of::<perf_event_attr>() as u32,
+        config,
+        read_format: (perf_event_read_format_PERF_FORMAT_TOTAL_TIME_ENABLED
+            | perf_event_read_format_PERF_FORMAT_TOTAL_TIME_RUNNING)
+            .into(),
+        ..Default::default()
+    };
+    attr.set_disabled(1);
+    attr.set_inherit(1);
+    attr.set_exclude_kernel(1);
+    attr.set_exclude_hv(1);
+    // SAFETY: `attr` is a valid, fully initialized attribute struct; pid 0
+    // and cpu -1 count the calling thread on any CPU.
+    let fd = unsafe { perf_event_open(&mut attr, 0, -1, -1, 0) };
+    if fd == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: the kernel returned a new descriptor that nothing else owns.
+    Ok(unsafe { File::from_raw_fd(fd) })
+}
+
+/// Reads a count, scaled up when the counter was not running all the time
+/// it was enabled. Like all perf data, it is in native byte order.
+fn read_scaled(mut file: &File) -> io::Result<u64> {
+    let mut buffer = [0; 24];
+    file.read_exact(&mut buffer)?;
+    let [value, enabled, running] =
+        [0, 8, 16].map(|at| u64::from_ne_bytes(buffer[at..at + 8].try_into().expect("8 bytes")));
+    Ok(scale(value, enabled, running))
+}
+
+fn scale(value: u64, enabled: u64, running: u64) -> u64 {
+    if running == 0 || running >= enabled {
+        value
+    } else {
+        (value as u128 * enabled as u128 / running as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use perf_event_open_sys::bindings::{
+        perf_sw_ids_PERF_COUNT_SW_TASK_CLOCK, perf_type_id_PERF_TYPE_SOFTWARE,
+    };
+
+    use super::*;
+
+    #[test]
+    fn scales_multiplexed_counts() {
+        assert_eq!(scale(100, 10, 10), 100);
+        assert_eq!(scale(100, 10, 5), 200);
+        assert_eq!(scale(100, 0, 0), 100);
+        assert_eq!(scale(u64::MAX / 2, 4, 2), u64::MAX - 1);
+    }
+
+    #[test]
+    fn starts_stops_and_resets() {
+        // Virtual machines often expose no hardware counters, so this
+        // counts a software event (nanoseconds on the CPU) instead.
+        let Ok(file) = open(
+            perf_type_id_PERF_TYPE_SOFTWARE,
+            perf_sw_ids_PERF_COUNT_SW_TASK_CLOCK.into(),
+        ) else {
+            return;
+        };
+        let mut counters = PerfCounters {
+            counters: vec![(PerfCounter::Cycles, file)],
+        };
+        let count = |counters: &PerfCounters| counters.read().unwrap()[&PerfCounter::Cycles];
+        assert_eq!(count(&counters), 0);
+        counters.start().unwrap();
+        let mut sum = 0u64;
+        for i in 0..1_000_000u64 {
+            sum = std::hint::black_box(sum.wrapping_add(i * i));
+        }
+        counters.stop().unwrap();
+        let stopped = count(&counters);
+        assert!(stopped > 0);
+        std::hint::black_box(sum);
+        assert_eq!(count(&counters), stopped);
+        counters.reset().unwrap();
+        assert_eq!(count(&counters), 0);
+    }
+
+    #[test]
+    fn counts_instructions_when_permitted() {
+        // Containers and CI runners often forbid perf events.
+        let Ok(mut counters) = PerfCounters::with_counters(&[PerfCounter::Instructions]) else {
+            return;
+        };
+        counters.start().unwrap();
+        let mut sum = 0u64;
+        for i in 0..100_000u64 {
+            sum = std::hint::black_box(sum.wrapping_add(i * i));
+        }
+        counters.stop().unwrap();
+        let mut result = BenchmarkResult {
+            iterations: 10,
+            ..Default::default()
+        };
+        counters.record(&mut result).unwrap();
+        let instructions = &result.counters["instructions"];
+        assert_eq!(instructions.unit, "count");
+        assert!(instructions.value >= 10_000.0, "{}", instructions.value);
+
+        let before = counters.read().unwrap()[&PerfCounter::Instructions];
+        std::hint::black_box(sum);
+        assert_eq!(counters.read().unwrap()[&PerfCounter::Instructions], before);
+    }
+}
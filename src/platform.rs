@@ -3,7 +3,10 @@
 
 //! Platform detection utilities.
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// Platform information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +18,9 @@ pub struct Platform {
     pub cpu_vendor: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gpu_vendor: Option<String>,
+    /// Maximum CPU clock frequency in MHz, when the OS reports it.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cpu_mhz: Option<u32>,
 }
 
 impl Platform {
@@ -28,7 +34,31 @@ impl Platform {
                 .unwrap_or(1),
             cpu_vendor: get_cpu_vendor(),
             gpu_vendor: get_gpu_vendor(),
+            cpu_mhz: get_cpu_mhz(),
+        }
+    }
+
+    /// Returns a short stable identifier for this hardware/OS combination.
+    ///
+    /// The fingerprint is the first 12 hex digits of a SHA-256 over the
+    /// OS, architecture, core count and CPU/GPU vendors, so reports from
+    /// the same machine share it while reports from different machines
+    /// almost never collide. The clock frequency is left out because it
+    /// is not detected everywhere.
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = Sha256::new();
+        for field in [
+            self.os.as_str(),
+            self.arch.as_str(),
+            &self.cpu_count.to_string(),
+            self.cpu_vendor.as_deref().unwrap_or(""),
+            self.gpu_vendor.as_deref().unwrap_or(""),
+        ] {
+            hasher.update(field.as_bytes());
+            hasher.update([0]);
         }
+        let digest = hasher.finalize();
+        digest[..6].iter().map(|b| format!("{:02x}", b)).collect()
     }
 }
 
@@ -94,6 +124,57 @@ fn get_cpu_vendor_windows() -> Option<String> {
     std::env::var("PROCESSOR_IDENTIFIER").ok()
 }
 
+/// Detects the maximum CPU clock frequency in MHz.
+///
+/// Returns the frequency from:
+/// - Linux: cpufreq's `cpuinfo_max_freq`, falling back to /proc/cpuinfo
+/// - macOS: sysctl -n hw.cpufrequency_max (Intel only)
+pub fn get_cpu_mhz() -> Option<u32> {
+    #[cfg(target_os = "linux")]
+    {
+        get_cpu_mhz_linux()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        get_cpu_mhz_macos()
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_cpu_mhz_linux() -> Option<u32> {
+    if let Ok(khz) =
+        std::fs::read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/cpuinfo_max_freq")
+        && let Ok(khz) = khz.trim().parse::<u32>()
+    {
+        return Some(khz / 1000);
+    }
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo
+        .lines()
+        .find(|line| line.starts_with("cpu MHz"))
+        .and_then(|line| line.split(':').nth(1))
+        .and_then(|mhz| mhz.trim().parse::<f64>().ok())
+        .map(|mhz| mhz.round() as u32)
+}
+
+#[cfg(target_os = "macos")]
+fn get_cpu_mhz_macos() -> Option<u32> {
+    use std::process::Command;
+
+    Command::new("sysctl")
+        .args(["-n", "hw.cpufrequency_max"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|hz| hz.trim().parse::<u64>().ok())
+        .map(|hz| (hz / 1_000_000) as u32)
+}
+
 /// Detects GPU vendor/model string.
 ///
 /// Returns GPU vendor information from:
@@ -173,6 +254,146 @@ fn get_gpu_vendor_macos() -> Option<String> {
         })
 }
 
+/// A property of the host that makes benchmark results noisy or
+/// incomparable, found by [`check_environment`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnvironmentWarning {
+    /// CPUs run a frequency governor other than `performance`, so clocks
+    /// ramp up and down during the run.
+    CpuGovernor {
+        governor: String,
+        cpus: usize,
+        total: usize,
+    },
+    /// Turbo boost is enabled, so clocks depend on temperature and on how
+    /// many cores are busy.
+    TurboBoost,
+    /// The machine runs on battery, which usually caps clocks.
+    OnBattery,
+    /// Other processes keep the CPUs busy.
+    HighLoad { load: f64, cpus: usize },
+}
+
+impl fmt::Display for EnvironmentWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CpuGovernor {
+                governor,
+                cpus,
+                total,
+            } => write!(
+                f,
+                "CPU frequency governor is '{governor}' on {cpus} of {total} CPU(s); \
+                 use 'performance' for stable clocks"
+            ),
+            Self::TurboBoost => write!(f, "turbo boost is enabled; clocks vary with load and heat"),
+            Self::OnBattery => write!(f, "running on battery power; clocks may be capped"),
+            Self::HighLoad { load, cpus } => write!(
+                f,
+                "1-minute load average is {load:.2} on {cpus} CPU(s); other processes compete \
+                 for the CPUs"
+            ),
+        }
+    }
+}
+
+/// Checks the host for conditions that make benchmarks unreliable:
+/// frequency scaling, turbo boost, battery power and a busy machine.
+/// Conditions that cannot be read are not reported.
+///
+/// Only Linux is inspected; other systems yield no warnings.
+pub fn check_environment() -> Vec<EnvironmentWarning> {
+    #[cfg(target_os = "linux")]
+    {
+        let cpus = std::thread::available_parallelism()
+            .map(|p| p.get())
+            .unwrap_or(1);
+        check_environment_linux(std::path::Path::new("/"), cpus)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Vec::new()
+    }
+}
+
+/// Reads sysfs and procfs below `root`.
+#[cfg(target_os = "linux")]
+fn check_environment_linux(root: &std::path::Path, cpus: usize) -> Vec<EnvironmentWarning> {
+    use std::collections::BTreeMap;
+
+    let read = |path: &str| {
+        std::fs::read_to_string(root.join(path))
+            .ok()
+            .map(|s| s.trim().to_string())
+    };
+    let mut warnings = Vec::new();
+
+    let cpu_dir = root.join("sys/devices/system/cpu");
+    let mut governors: BTreeMap<String, usize> = BTreeMap::new();
+    let mut total = 0;
+    for entry in std::fs::read_dir(&cpu_dir).into_iter().flatten().flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name
+            .strip_prefix("cpu")
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+        {
+            continue;
+        }
+        let Ok(governor) = std::fs::read_to_string(entry.path().join("cpufreq/scaling_governor"))
+        else {
+            continue;
+        };
+        total += 1;
+        *governors.entry(governor.trim().to_string()).or_default() += 1;
+    }
+    for (governor, count) in governors {
+        if governor != "performance" {
+            warnings.push(EnvironmentWarning::CpuGovernor {
+                governor,
+                cpus: count,
+                total,
+            });
+        }
+    }
+
+    let turbo = match read("sys/devices/system/cpu/intel_pstate/no_turbo") {
+        Some(no_turbo) => no_turbo == "0",
+        None => read("sys/devices/system/cpu/cpufreq/boost").is_some_and(|b| b == "1"),
+    };
+    if turbo {
+        warnings.push(EnvironmentWarning::TurboBoost);
+    }
+
+    let supplies = root.join("sys/class/power_supply");
+    let on_battery = std::fs::read_dir(&supplies)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .any(|entry| {
+            let path = entry.path();
+            let field = |name: &str| {
+                std::fs::read_to_string(path.join(name))
+                    .map(|s| s.trim().to_string())
+                    .unwrap_or_default()
+            };
+            field("type") == "Battery" && field("status") == "Discharging"
+        });
+    if on_battery {
+        warnings.push(EnvironmentWarning::OnBattery);
+    }
+
+    if let Some(load) = read("proc/loadavg").and_then(|l| {
+        l.split_whitespace()
+            .next()
+            .and_then(|v| v.parse::<f64>().ok())
+    }) && load > (cpus as f64 / 2.0).max(1.0)
+    {
+        warnings.push(EnvironmentWarning::HighLoad { load, cpus });
+    }
+    warnings
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,6 +417,28 @@ mod tests {
         assert!(platform.cpu_count >= 1);
     }
 
+    #[test]
+    fn test_platform_fingerprint() {
+        let platform = Platform {
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            cpu_count: 8,
+            cpu_vendor: Some("AMD EPYC".to_string()),
+            gpu_vendor: None,
+            cpu_mhz: None,
+        };
+        let fingerprint = platform.fingerprint();
+        assert_eq!(fingerprint.len(), 12);
+        assert!(fingerprint.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(fingerprint, platform.clone().fingerprint());
+
+        let other = Platform {
+            cpu_count: 16,
+            ..platform
+        };
+        assert_ne!(fingerprint, other.fingerprint());
+    }
+
     #[test]
     fn test_platform_serialization() {
         let platform = Platform::current();
@@ -234,6 +477,7 @@ mod tests {
             cpu_count: 4,
             cpu_vendor: None,
             gpu_vendor: None,
+            cpu_mhz: None,
         };
         let json = serde_json::to_string(&platform).unwrap();
 
@@ -276,6 +520,7 @@ mod tests {
             cpu_count: 4,
             cpu_vendor: None,
             gpu_vendor: None,
+            cpu_mhz: None,
         };
         let json = serde_json::to_string(&platform).unwrap();
 
@@ -302,10 +547,80 @@ mod tests {
             cpu_count: 8,
             cpu_vendor: Some("Intel Core i9".to_string()),
             gpu_vendor: Some("NVIDIA GeForce RTX 4090".to_string()),
+            cpu_mhz: Some(5800),
         };
         let json = serde_json::to_string(&platform).unwrap();
         let deserialized: Platform = serde_json::from_str(&json).unwrap();
 
         assert_eq!(platform.gpu_vendor, deserialized.gpu_vendor);
+        assert_eq!(platform.cpu_mhz, deserialized.cpu_mhz);
+    }
+
+    #[test]
+    fn test_platform_cpu_mhz_skip_none() {
+        let json = r#"{"os": "linux", "arch": "x86_64", "cpu_count": 8}"#;
+        let platform: Platform = serde_json::from_str(json).unwrap();
+        assert!(platform.cpu_mhz.is_none());
+        assert!(
+            !serde_json::to_string(&platform)
+                .unwrap()
+                .contains("cpu_mhz")
+        );
+        assert!(get_cpu_mhz().is_none_or(|mhz| mhz > 0));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_check_environment_linux() {
+        let root = std::env::temp_dir().join(format!("zkbench-host-{}", std::process::id()));
+        let write = |path: &str, contents: &str| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        };
+        for (cpu, governor) in [(0, "powersave\n"), (1, "powersave\n"), (2, "performance\n")] {
+            write(
+                &format!("sys/devices/system/cpu/cpu{cpu}/cpufreq/scaling_governor"),
+                governor,
+            );
+        }
+        write("sys/devices/system/cpu/cpufreq/boost", "1\n");
+        write("sys/class/power_supply/BAT0/type", "Battery\n");
+        write("sys/class/power_supply/BAT0/status", "Discharging\n");
+        write("proc/loadavg", "3.50 2.00 1.00 2/300 4242\n");
+
+        let warnings = check_environment_linux(&root, 4);
+        assert_eq!(
+            warnings,
+            [
+                EnvironmentWarning::CpuGovernor {
+                    governor: "powersave".to_string(),
+                    cpus: 2,
+                    total: 3,
+                },
+                EnvironmentWarning::TurboBoost,
+                EnvironmentWarning::OnBattery,
+                EnvironmentWarning::HighLoad { load: 3.5, cpus: 4 },
+            ]
+        );
+        assert!(
+            warnings[0]
+                .to_string()
+                .contains("'powersave' on 2 of 3 CPU(s)")
+        );
+
+        write("sys/class/power_supply/BAT0/status", "Charging\n");
+        write(
+            "sys/devices/system/cpu/cpu0/cpufreq/scaling_governor",
+            "performance\n",
+        );
+        write(
+            "sys/devices/system/cpu/cpu1/cpufreq/scaling_governor",
+            "performance\n",
+        );
+        write("sys/devices/system/cpu/intel_pstate/no_turbo", "1\n");
+        write("proc/loadavg", "0.10 0.20 0.30 1/300 4242\n");
+        assert!(check_environment_linux(&root, 4).is_empty());
+        std::fs::remove_dir_all(&root).unwrap();
     }
 }
@@ -0,0 +1,597 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Chart rendering with plotters (feature `plot`).
+//!
+//! Charts are written as SVG or PNG depending on the output file
+//! extension:
+//!
+//! - [`plot_distribution`]: box or violin plot of one benchmark's latency
+//!   across reports.
+//! - [`plot_sweep`]: log-log scaling curves of a parameter sweep, one line
+//!   per report.
+//! - [`plot_trend`]: one metric of one benchmark over a sequence of
+//!   reports (e.g. ordered by commit), with confidence-interval error bars.
+
+use std::fmt;
+use std::path::Path;
+
+use plotters::coord::Shift;
+use plotters::prelude::*;
+
+use crate::schema::{BenchmarkReport, BenchmarkResult, MetricKind};
+use crate::statistics::{calculate_statistics, quantile};
+use crate::sweep::extract_sweeps;
+use crate::units::{scale, to_base};
+
+const SIZE: (u32, u32) = (960, 540);
+
+/// Error returned by the plotting functions.
+#[derive(Debug)]
+pub enum PlotError {
+    /// None of the reports contain data for the requested chart.
+    NoData(String),
+    /// The output path has an extension other than `.svg` or `.png`.
+    UnsupportedFormat(String),
+    /// The drawing backend failed (I/O errors, missing fonts, ...).
+    Drawing(String),
+}
+
+impl fmt::Display for PlotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoData(what) => write!(f, "no data to plot for {}", what),
+            Self::UnsupportedFormat(path) => {
+                write!(
+                    f,
+                    "unsupported image format for '{}' (use .svg or .png)",
+                    path
+                )
+            }
+            Self::Drawing(e) => write!(f, "drawing failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PlotError {}
+
+fn drawing<E: fmt::Display>(e: E) -> PlotError {
+    PlotError::Drawing(e.to_string())
+}
+
+/// How [`plot_distribution`] draws each report's samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DistributionStyle {
+    /// Whiskers at min/max, box at the quartiles, line at the median.
+    #[default]
+    Box,
+    /// Kernel density estimate mirrored around the row, with the median.
+    Violin,
+}
+
+/// Dispatches to the SVG or PNG backend based on the file extension.
+macro_rules! with_backend {
+    ($path:expr, |$root:ident| $body:expr) => {{
+        let path: &Path = $path;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("svg") => {
+                let $root = SVGBackend::new(path, SIZE).into_drawing_area();
+                $body?;
+                $root.present().map_err(drawing)
+            }
+            Some("png") => {
+                let $root = BitMapBackend::new(path, SIZE).into_drawing_area();
+                $body?;
+                $root.present().map_err(drawing)
+            }
+            _ => Err(PlotError::UnsupportedFormat(path.display().to_string())),
+        }
+    }};
+}
+
+fn label(report: &BenchmarkReport) -> String {
+    format!(
+        "{} {}",
+        report.metadata.implementation, report.metadata.version
+    )
+}
+
+/// One report's latency distribution in the chart's display unit.
+struct Row {
+    label: String,
+    /// Sorted samples, or the point estimate (plus bounds) if there are
+    /// no samples.
+    values: Vec<f64>,
+}
+
+/// Plots the latency distribution of `benchmark` in every report that
+/// contains it, one row per report.
+pub fn plot_distribution(
+    reports: &[BenchmarkReport],
+    benchmark: &str,
+    style: DistributionStyle,
+    path: impl AsRef<Path>,
+) -> Result<(), PlotError> {
+    let results: Vec<(String, &BenchmarkResult)> = reports
+        .iter()
+        .filter_map(|r| Some((label(r), r.benchmarks.get(benchmark)?)))
+        .filter(|(_, result)| result.latency.is_some())
+        .collect();
+    let Some(unit) = results
+        .first()
+        .and_then(|(_, r)| r.latency.as_ref())
+        .map(|l| l.unit.clone())
+    else {
+        return Err(PlotError::NoData(format!("benchmark '{}'", benchmark)));
+    };
+    let rows: Vec<Row> = results
+        .into_iter()
+        .map(|(label, result)| {
+            let latency = result.latency.as_ref().expect("filtered above");
+            let factor = to_base(1.0, &latency.unit) / to_base(1.0, &unit);
+            let mut values: Vec<f64> = if result.samples.is_empty() {
+                [
+                    latency.lower_value,
+                    Some(latency.value),
+                    latency.upper_value,
+                ]
+                .into_iter()
+                .flatten()
+                .collect()
+            } else {
+                result.samples.clone()
+            };
+            values.iter_mut().for_each(|v| *v *= factor);
+            values.sort_by(f64::total_cmp);
+            Row { label, values }
+        })
+        .collect();
+
+    let max = rows
+        .iter()
+        .flat_map(|r| r.values.last())
+        .fold(f64::MIN, |a, b| a.max(*b));
+    let min = rows
+        .iter()
+        .flat_map(|r| r.values.first())
+        .fold(f64::MAX, |a, b| a.min(*b));
+    let (_, factor, display_unit) = scale(max, &unit);
+    let pad = ((max - min) * 0.05).max(max.abs() * 0.01).max(f64::EPSILON);
+    let x_range = (min - pad) * factor..(max + pad) * factor;
+
+    with_backend!(path.as_ref(), |root| {
+        draw_distribution(
+            &root,
+            benchmark,
+            &rows,
+            style,
+            x_range,
+            factor,
+            &display_unit,
+        )
+    })
+}
+
+fn draw_distribution<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    title: &str,
+    rows: &[Row],
+    style: DistributionStyle,
+    x_range: std::ops::Range<f64>,
+    factor: f64,
+    unit: &str,
+) -> Result<(), PlotError> {
+    root.fill(&WHITE).map_err(drawing)?;
+    let n = rows.len();
+    let mut chart = ChartBuilder::on(root)
+        .caption(title, ("sans-serif", 22))
+        .margin(12)
+        .x_label_area_size(40)
+        .y_label_area_size(160)
+        .build_cartesian_2d(x_range, -0.5..(n as f64 - 0.5))
+        .map_err(drawing)?;
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .y_labels(n)
+        .y_label_formatter(&|y| {
+            let i = y.round();
+            if (y - i).abs() < 1e-6 && i >= 0.0 && (i as usize) < n {
+                rows[i as usize].label.clone()
+            } else {
+                String::new()
+            }
+        })
+        .x_desc(format!("latency ({})", unit))
+        .draw()
+        .map_err(drawing)?;
+
+    for (i, row) in rows.iter().enumerate() {
+        let color = Palette99::pick(i).to_rgba();
+        let y = i as f64;
+        let v: Vec<f64> = row.values.iter().map(|v| v * factor).collect();
+        let [min, q1, median, q3, max] = [0.0, 0.25, 0.5, 0.75, 1.0].map(|q| quantile(&v, q));
+        match style {
+            DistributionStyle::Violin if v.len() >= 3 => {
+                let outline = violin_outline(&v, y, 0.4);
+                chart
+                    .draw_series(std::iter::once(Polygon::new(outline, color.mix(0.35))))
+                    .map_err(drawing)?;
+            }
+            _ => {
+                chart
+                    .draw_series([
+                        PathElement::new(vec![(min, y), (max, y)], color),
+                        PathElement::new(vec![(min, y - 0.1), (min, y + 0.1)], color),
+                        PathElement::new(vec![(max, y - 0.1), (max, y + 0.1)], color),
+                    ])
+                    .map_err(drawing)?;
+                chart
+                    .draw_series(std::iter::once(Rectangle::new(
+                        [(q1, y - 0.25), (q3, y + 0.25)],
+                        color.mix(0.35).filled(),
+                    )))
+                    .map_err(drawing)?;
+            }
+        }
+        chart
+            .draw_series(std::iter::once(PathElement::new(
+                vec![(median, y - 0.3), (median, y + 0.3)],
+                color.stroke_width(3),
+            )))
+            .map_err(drawing)?;
+    }
+    Ok(())
+}
+
+/// Outline of a violin centered on row `y`: a Gaussian kernel density
+/// estimate (Silverman's bandwidth) mirrored above and below the row and
+/// scaled so its widest point spans `half_height`.
+fn violin_outline(sorted: &[f64], y: f64, half_height: f64) -> Vec<(f64, f64)> {
+    const STEPS: usize = 64;
+    let (_, stdev) = calculate_statistics(sorted);
+    let n = sorted.len() as f64;
+    let iqr = quantile(sorted, 0.75) - quantile(sorted, 0.25);
+    let spread = if iqr > 0.0 {
+        stdev.min(iqr / 1.34)
+    } else {
+        stdev
+    };
+    let bandwidth = (0.9 * spread * n.powf(-0.2)).max(f64::EPSILON);
+    let (lo, hi) = (sorted[0], sorted[sorted.len() - 1]);
+    let xs: Vec<f64> = (0..=STEPS)
+        .map(|i| lo + (hi - lo) * i as f64 / STEPS as f64)
+        .collect();
+    let density: Vec<f64> = xs
+        .iter()
+        .map(|x| {
+            sorted
+                .iter()
+                .map(|s| (-0.5 * ((x - s) / bandwidth).powi(2)).exp())
+                .sum::<f64>()
+        })
+        .collect();
+    let peak = density.iter().cloned().fold(f64::MIN, f64::max);
+    let mut outline: Vec<(f64, f64)> = xs
+        .iter()
+        .zip(&density)
+        .map(|(x, d)| (*x, y + d / peak * half_height))
+        .collect();
+    outline.extend(
+        xs.iter()
+            .zip(&density)
+            .rev()
+            .map(|(x, d)| (*x, y - d / peak * half_height)),
+    );
+    outline
+}
+
+/// Plots the scaling curve of the sweep named `sweep` (see
+/// [`extract_sweeps`](crate::extract_sweeps)) on log-log axes, one line per
+/// report.
+pub fn plot_sweep(
+    reports: &[BenchmarkReport],
+    sweep: &str,
+    path: impl AsRef<Path>,
+) -> Result<(), PlotError> {
+    let mut series: Vec<(String, Vec<(f64, f64)>)> = Vec::new();
+    let mut unit: Option<String> = None;
+    for report in reports {
+        let Some(found) = extract_sweeps(report).into_iter().find(|s| s.name == sweep) else {
+            continue;
+        };
+        let unit = unit.get_or_insert_with(|| found.points[0].latency.unit.clone());
+        let points = found
+            .points
+            .iter()
+            .map(|p| {
+                let value = to_base(p.latency.value, &p.latency.unit) / to_base(1.0, unit);
+                (p.parameter, value)
+            })
+            .filter(|(x, y)| *x > 0.0 && *y > 0.0)
+            .collect();
+        series.push((label(report), points));
+    }
+    let Some(unit) = unit else {
+        return Err(PlotError::NoData(format!("sweep '{}'", sweep)));
+    };
+    let all = series.iter().flat_map(|(_, points)| points.iter());
+    let (x_lo, x_hi, y_lo, y_hi) = all.fold(
+        (f64::MAX, f64::MIN, f64::MAX, f64::MIN),
+        |(a, b, c, d), (x, y)| (a.min(*x), b.max(*x), c.min(*y), d.max(*y)),
+    );
+    let (_, factor, display_unit) = scale(y_hi, &unit);
+
+    with_backend!(path.as_ref(), |root| {
+        draw_sweep(
+            &root,
+            sweep,
+            &series,
+            (x_lo, x_hi),
+            (y_lo * factor, y_hi * factor),
+            factor,
+            &display_unit,
+        )
+    })
+}
+
+fn draw_sweep<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    title: &str,
+    series: &[(String, Vec<(f64, f64)>)],
+    (x_lo, x_hi): (f64, f64),
+    (y_lo, y_hi): (f64, f64),
+    factor: f64,
+    unit: &str,
+) -> Result<(), PlotError> {
+    root.fill(&WHITE).map_err(drawing)?;
+    let mut chart = ChartBuilder::on(root)
+        .caption(title, ("sans-serif", 22))
+        .margin(12)
+        .x_label_area_size(40)
+        .y_label_area_size(70)
+        .build_cartesian_2d(
+            (x_lo / 1.2..x_hi * 1.2).log_scale(),
+            (y_lo / 1.2..y_hi * 1.2).log_scale(),
+        )
+        .map_err(drawing)?;
+    chart
+        .configure_mesh()
+        .x_desc("parameter")
+        .y_desc(format!("latency ({})", unit))
+        .draw()
+        .map_err(drawing)?;
+    for (i, (label, points)) in series.iter().enumerate() {
+        let color = Palette99::pick(i).to_rgba();
+        let scaled: Vec<(f64, f64)> = points.iter().map(|(x, y)| (*x, y * factor)).collect();
+        chart
+            .draw_series(LineSeries::new(scaled.clone(), color.stroke_width(2)))
+            .map_err(drawing)?
+            .label(label.as_str())
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+        chart
+            .draw_series(
+                scaled
+                    .into_iter()
+                    .map(|p| Circle::new(p, 3, color.filled())),
+            )
+            .map_err(drawing)?;
+    }
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()
+        .map_err(drawing)?;
+    Ok(())
+}
+
+/// Plots one metric of `benchmark` over `reports` in the order given,
+/// labeling the x axis with each report's commit. Confidence bounds are
+/// drawn as error bars.
+pub fn plot_trend(
+    reports: &[BenchmarkReport],
+    benchmark: &str,
+    metric: MetricKind,
+    path: impl AsRef<Path>,
+) -> Result<(), PlotError> {
+    let mut unit: Option<String> = None;
+    let mut points: Vec<(f64, f64, f64, f64)> = Vec::new();
+    let mut commits: Vec<String> = Vec::new();
+    for report in reports {
+        let Some(value) = report.benchmarks.get(benchmark).and_then(|r| metric.get(r)) else {
+            continue;
+        };
+        let unit = unit.get_or_insert_with(|| value.unit.clone());
+        let factor = to_base(1.0, &value.unit) / to_base(1.0, unit);
+        let x = commits.len() as f64;
+        points.push((
+            x,
+            value.lower_value.unwrap_or(value.value) * factor,
+            value.value * factor,
+            value.upper_value.unwrap_or(value.value) * factor,
+        ));
+        commits.push(report.metadata.commit_sha.chars().take(7).collect());
+    }
+    let Some(unit) = unit else {
+        return Err(PlotError::NoData(format!(
+            "{} of benchmark '{}'",
+            metric, benchmark
+        )));
+    };
+    let y_hi = points.iter().map(|p| p.3).fold(f64::MIN, f64::max);
+    let y_lo = points.iter().map(|p| p.1).fold(f64::MAX, f64::min);
+    let (_, factor, display_unit) = scale(y_hi, &unit);
+    let points: Vec<(f64, f64, f64, f64)> = points
+        .into_iter()
+        .map(|(x, lo, v, hi)| (x, lo * factor, v * factor, hi * factor))
+        .collect();
+    let pad = ((y_hi - y_lo) * 0.1).max(y_hi.abs() * 0.02) * factor;
+    let y_range = (y_lo * factor - pad).max(0.0)..(y_hi * factor + pad);
+
+    with_backend!(path.as_ref(), |root| {
+        draw_trend(
+            &root,
+            &format!("{} {}", benchmark, metric),
+            &points,
+            &commits,
+            y_range,
+            &display_unit,
+        )
+    })
+}
+
+fn draw_trend<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    title: &str,
+    points: &[(f64, f64, f64, f64)],
+    commits: &[String],
+    y_range: std::ops::Range<f64>,
+    unit: &str,
+) -> Result<(), PlotError> {
+    root.fill(&WHITE).map_err(drawing)?;
+    let n = points.len();
+    let mut chart = ChartBuilder::on(root)
+        .caption(title, ("sans-serif", 22))
+        .margin(12)
+        .x_label_area_size(40)
+        .y_label_area_size(70)
+        .build_cartesian_2d(-0.5..(n as f64 - 0.5), y_range)
+        .map_err(drawing)?;
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_labels(n.min(20))
+        .x_label_formatter(&|x| {
+            let i = x.round();
+            if (x - i).abs() < 1e-6 && i >= 0.0 && (i as usize) < n {
+                commits[i as usize].clone()
+            } else {
+                String::new()
+            }
+        })
+        .x_desc("commit")
+        .y_desc(unit)
+        .draw()
+        .map_err(drawing)?;
+    let color = Palette99::pick(0).to_rgba();
+    chart
+        .draw_series(LineSeries::new(
+            points.iter().map(|(x, _, v, _)| (*x, *v)),
+            color.stroke_width(2),
+        ))
+        .map_err(drawing)?;
+    chart
+        .draw_series(
+            points
+                .iter()
+                .map(|(x, lo, v, hi)| ErrorBar::new_vertical(*x, *lo, *v, *hi, color.filled(), 8)),
+        )
+        .map_err(drawing)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::MetricValue;
+    use crate::testing::ReportBuilder;
+
+    fn spread(value: f64) -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(
+                value,
+                "ms",
+                value * 0.9,
+                value * 1.1,
+            )),
+            samples: vec![value * 0.9, value, value * 1.05, value * 1.1],
+            ..Default::default()
+        }
+    }
+
+    fn tmp(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("zkbench-plot-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn distribution_svg() {
+        let reports = [
+            ReportBuilder::new()
+                .commit("a")
+                .results([("prove", spread(10.0))])
+                .build(),
+            ReportBuilder::new()
+                .commit("b")
+                .results([("prove", spread(12.0))])
+                .build(),
+        ];
+        for style in [DistributionStyle::Box, DistributionStyle::Violin] {
+            let path = tmp(&format!("dist-{:?}.svg", style));
+            plot_distribution(&reports, "prove", style, &path).unwrap();
+            let svg = std::fs::read_to_string(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            assert!(svg.contains("<svg"));
+            assert!(svg.contains("impl 1.0.0"));
+        }
+    }
+
+    #[test]
+    fn sweep_and_trend_svg() {
+        let reports = [
+            ReportBuilder::new()
+                .commit("aaaaaaaa1")
+                .results([("msm/2^10", spread(1.0)), ("msm/2^12", spread(4.0))])
+                .build(),
+            ReportBuilder::new()
+                .commit("bbbbbbbb2")
+                .results([("msm/2^10", spread(1.1)), ("msm/2^12", spread(4.2))])
+                .build(),
+        ];
+        let png = tmp("sweep.png");
+        plot_sweep(&reports, "msm", &png).unwrap();
+        assert!(std::fs::read(&png).unwrap().starts_with(b"\x89PNG"));
+        std::fs::remove_file(&png).unwrap();
+
+        let sweep = tmp("sweep.svg");
+        plot_sweep(&reports, "msm", &sweep).unwrap();
+        assert!(std::fs::read_to_string(&sweep).unwrap().contains("<svg"));
+        std::fs::remove_file(&sweep).unwrap();
+
+        let trend = tmp("trend.svg");
+        plot_trend(&reports, "msm/2^12", MetricKind::Latency, &trend).unwrap();
+        let svg = std::fs::read_to_string(&trend).unwrap();
+        std::fs::remove_file(&trend).unwrap();
+        assert!(svg.contains("bbbbbbb"));
+    }
+
+    #[test]
+    fn errors() {
+        let reports = [ReportBuilder::new()
+            .commit("a")
+            .results([("prove", spread(10.0))])
+            .build()];
+        assert!(matches!(
+            plot_distribution(&reports, "missing", DistributionStyle::Box, tmp("x.svg")),
+            Err(PlotError::NoData(_))
+        ));
+        assert!(matches!(
+            plot_sweep(&reports, "prove", tmp("x.svg")),
+            Err(PlotError::NoData(_))
+        ));
+        assert!(matches!(
+            plot_trend(&reports, "prove", MetricKind::Latency, tmp("x.gif")),
+            Err(PlotError::UnsupportedFormat(_))
+        ));
+    }
+
+    #[test]
+    fn violin_outline_is_closed_and_symmetric() {
+        let outline = violin_outline(&[1.0, 2.0, 2.0, 3.0], 0.0, 0.4);
+        assert_eq!(outline.len(), 130);
+        let top_peak = outline.iter().map(|p| p.1).fold(f64::MIN, f64::max);
+        let bottom_peak = outline.iter().map(|p| p.1).fold(f64::MAX, f64::min);
+        assert!((top_peak - 0.4).abs() < 1e-9);
+        assert!((bottom_peak + 0.4).abs() < 1e-9);
+    }
+}
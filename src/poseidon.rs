@@ -0,0 +1,171 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Poseidon and Poseidon2 sponges over the BN254 and BLS12-381 scalar
+//! fields, behind the `poseidon` feature.
+//!
+//! The byte encoding is specified on
+//! [`HashAlgorithm::is_circuit_native`]; the permutations and their round
+//! constants come from the reference implementation by the Poseidon
+//! authors (the `zkhash` crate).
+
+use std::fmt;
+
+use ark_ff::{BigInteger, PrimeField};
+use zkhash::fields::bls12::FpBLS12;
+use zkhash::fields::bn256::FpBN256;
+use zkhash::poseidon::poseidon::Poseidon;
+use zkhash::poseidon::poseidon_instance_bls12::POSEIDON_BLS_3_PARAMS;
+use zkhash::poseidon::poseidon_instance_bn256::POSEIDON_BN_PARAMS;
+use zkhash::poseidon2::poseidon2::Poseidon2;
+use zkhash::poseidon2::poseidon2_instance_bls12::POSEIDON2_BLS_3_PARAMS;
+use zkhash::poseidon2::poseidon2_instance_bn256::POSEIDON2_BN256_PARAMS;
+
+use crate::hash::HashAlgorithm;
+
+/// Bytes packed into one field element; both fields exceed 2^248.
+const CHUNK_BYTES: usize = 31;
+
+/// Elements absorbed per permutation.
+const RATE: usize = 2;
+
+/// Streaming Poseidon or Poseidon2 digest of bytes.
+#[derive(Clone)]
+pub(crate) enum PoseidonHasher {
+    Bn254(Sponge<FpBN256>),
+    Bls12_381(Sponge<FpBLS12>),
+}
+
+impl PoseidonHasher {
+    /// Returns `None` for algorithms that are not Poseidon-based.
+    pub(crate) fn new(algorithm: HashAlgorithm) -> Option<Self> {
+        let hasher = match algorithm {
+            HashAlgorithm::PoseidonBn254 => Self::Bn254(Sponge::new(
+                algorithm,
+                Permutation::Poseidon(Poseidon::new(&POSEIDON_BN_PARAMS)),
+            )),
+            HashAlgorithm::Poseidon2Bn254 => Self::Bn254(Sponge::new(
+                algorithm,
+                Permutation::Poseidon2(Poseidon2::new(&POSEIDON2_BN256_PARAMS)),
+            )),
+            HashAlgorithm::PoseidonBls12_381 => Self::Bls12_381(Sponge::new(
+                algorithm,
+                Permutation::Poseidon(Poseidon::new(&POSEIDON_BLS_3_PARAMS)),
+            )),
+            HashAlgorithm::Poseidon2Bls12_381 => Self::Bls12_381(Sponge::new(
+                algorithm,
+                Permutation::Poseidon2(Poseidon2::new(&POSEIDON2_BLS_3_PARAMS)),
+            )),
+            _ => return None,
+        };
+        Some(hasher)
+    }
+
+    pub(crate) fn algorithm(&self) -> HashAlgorithm {
+        match self {
+            Self::Bn254(sponge) => sponge.algorithm,
+            Self::Bls12_381(sponge) => sponge.algorithm,
+        }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Bn254(sponge) => sponge.update(data),
+            Self::Bls12_381(sponge) => sponge.update(data),
+        }
+    }
+
+    pub(crate) fn finalize(self) -> [u8; 32] {
+        match self {
+            Self::Bn254(sponge) => sponge.finalize(),
+            Self::Bls12_381(sponge) => sponge.finalize(),
+        }
+    }
+}
+
+impl fmt::Debug for PoseidonHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PoseidonHasher")
+            .field(&self.algorithm())
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+enum Permutation<F: PrimeField> {
+    Poseidon(Poseidon<F>),
+    Poseidon2(Poseidon2<F>),
+}
+
+#[derive(Clone)]
+pub(crate) struct Sponge<F: PrimeField> {
+    algorithm: HashAlgorithm,
+    permutation: Permutation<F>,
+    state: Vec<F>,
+    /// Rate elements added since the last permutation.
+    absorbed: usize,
+    /// Bytes of the next, incomplete chunk.
+    pending: Vec<u8>,
+}
+
+impl<F: PrimeField> Sponge<F> {
+    fn new(algorithm: HashAlgorithm, permutation: Permutation<F>) -> Self {
+        Self {
+            algorithm,
+            permutation,
+            state: vec![F::zero(); RATE + 1],
+            absorbed: 0,
+            pending: Vec::with_capacity(CHUNK_BYTES),
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        if !self.pending.is_empty() {
+            let take = data.len().min(CHUNK_BYTES - self.pending.len());
+            self.pending.extend_from_slice(&data[..take]);
+            data = &data[take..];
+            if self.pending.len() < CHUNK_BYTES {
+                return;
+            }
+            let chunk = std::mem::take(&mut self.pending);
+            self.absorb(&chunk);
+        }
+        let mut chunks = data.chunks_exact(CHUNK_BYTES);
+        for chunk in &mut chunks {
+            self.absorb(chunk);
+        }
+        self.pending.extend_from_slice(chunks.remainder());
+    }
+
+    fn absorb(&mut self, chunk: &[u8]) {
+        self.state[1 + self.absorbed] += F::from_le_bytes_mod_order(chunk);
+        self.absorbed += 1;
+        if self.absorbed == RATE {
+            self.permute();
+        }
+    }
+
+    fn permute(&mut self) {
+        self.state = match &self.permutation {
+            Permutation::Poseidon(poseidon) => poseidon.permutation(&self.state),
+            Permutation::Poseidon2(poseidon2) => poseidon2.permutation(&self.state),
+        };
+        self.absorbed = 0;
+    }
+
+    fn finalize(mut self) -> [u8; 32] {
+        // Trailing zero bytes do not change a little-endian integer, so
+        // the last chunk needs only the 0x01 marker.
+        let mut last = std::mem::take(&mut self.pending);
+        last.push(0x01);
+        self.absorb(&last);
+        if self.absorbed > 0 {
+            self.permute();
+        }
+        self.state[1]
+            .into_bigint()
+            .to_bytes_be()
+            .try_into()
+            .expect("elements of both fields fit in 32 bytes")
+    }
+}
@@ -0,0 +1,318 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Energy measurement on macOS by sampling `powermetrics`.
+//!
+//! macOS has no unprivileged energy counters; `powermetrics` reads them
+//! as root and prints the average power of each sample interval. While an
+//! [`EnergyMeter`](crate::EnergyMeter) runs, it keeps `powermetrics`
+//! sampling in the background and integrates those averages over the
+//! reported interval lengths.
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::thread::{self, JoinHandle};
+
+use crate::energy::EnergyError;
+
+/// Where macOS installs `powermetrics`.
+pub const POWERMETRICS: &str = "/usr/bin/powermetrics";
+
+/// Sample interval in milliseconds. Energy used in the part of the last
+/// interval before `stop` that was not reported yet is missed, so the
+/// timed region should be much longer than this.
+const INTERVAL_MS: u32 = 100;
+
+/// A `powermetrics` sampler and the energy of the samples read so far, in
+/// microjoules per domain.
+#[derive(Debug)]
+pub(crate) struct Powermetrics {
+    program: PathBuf,
+    running: Option<(Child, JoinHandle<Samples>)>,
+    accumulated: BTreeMap<String, f64>,
+}
+
+impl Powermetrics {
+    /// Takes one sample with `program` to check that it runs and reports
+    /// power.
+    pub(crate) fn new(program: &Path) -> Result<Self, EnergyError> {
+        let output = match command(program).args(["-n", "1"]).output() {
+            Ok(output) => output,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(EnergyError::Unsupported);
+            }
+            Err(source) => return Err(io_error(program, source)),
+        };
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            // "powermetrics must be invoked as the superuser"
+            return Err(if stderr.contains("superuser") {
+                EnergyError::PermissionDenied(program.to_path_buf())
+            } else {
+                io_error(program, io::Error::other(stderr.trim().to_string()))
+            });
+        }
+        let mut samples = Samples::default();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            samples.line(line);
+        }
+        if samples.finish().is_empty() {
+            return Err(EnergyError::Unsupported);
+        }
+        Ok(Self {
+            program: program.to_path_buf(),
+            running: None,
+            accumulated: BTreeMap::new(),
+        })
+    }
+
+    /// Starts sampling in the background. Has no effect if already
+    /// running.
+    pub(crate) fn start(&mut self) -> Result<(), EnergyError> {
+        if self.running.is_some() {
+            return Ok(());
+        }
+        let mut child = command(&self.program)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|source| io_error(&self.program, source))?;
+        let stdout = child.stdout.take().expect("stdout is piped");
+        let reader = thread::spawn(move || {
+            let mut samples = Samples::default();
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                samples.line(&line);
+            }
+            samples
+        });
+        self.running = Some((child, reader));
+        Ok(())
+    }
+
+    /// Stops sampling and accumulates the energy of the samples it took.
+    /// Has no effect if already stopped.
+    pub(crate) fn stop(&mut self) -> Result<(), EnergyError> {
+        let Some((mut child, reader)) = self.running.take() else {
+            return Ok(());
+        };
+        child
+            .kill()
+            .and_then(|()| child.wait())
+            .map_err(|source| io_error(&self.program, source))?;
+        let samples = reader.join().expect("powermetrics reader panicked");
+        for (domain, energy) in samples.finish() {
+            *self.accumulated.entry(domain).or_default() += energy;
+        }
+        Ok(())
+    }
+
+    /// Clears the accumulated energy and stops sampling.
+    pub(crate) fn reset(&mut self) {
+        if let Some((mut child, reader)) = self.running.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+            let _ = reader.join();
+        }
+        self.accumulated.clear();
+    }
+
+    /// Accumulated energy of each domain in joules.
+    pub(crate) fn domains(&self) -> BTreeMap<String, f64> {
+        self.accumulated
+            .iter()
+            .map(|(domain, energy)| (domain.clone(), energy / 1e6))
+            .collect()
+    }
+}
+
+impl Drop for Powermetrics {
+    fn drop(&mut self) {
+        // `powermetrics` samples until it is killed.
+        self.reset();
+    }
+}
+
+fn command(program: &Path) -> Command {
+    let mut command = Command::new(program);
+    command.args([
+        "--samplers",
+        "cpu_power",
+        "-i",
+        &INTERVAL_MS.to_string(),
+        // Unbuffered, so killing it loses no finished samples.
+        "-b",
+        "0",
+    ]);
+    command
+}
+
+fn io_error(program: &Path, source: io::Error) -> EnergyError {
+    EnergyError::Io {
+        path: program.to_path_buf(),
+        source,
+    }
+}
+
+/// Integrates the power lines of `powermetrics` text output, e.g.
+///
+/// ```text
+/// *** Sampled system activity (Fri Oct 16 10:00:00 2026 +0200) (102.37ms elapsed) ***
+/// CPU Power: 4120 mW
+/// GPU Power: 35 mW
+/// ANE Power: 0 mW
+/// Combined Power (CPU + GPU + ANE): 4155 mW
+/// ```
+///
+/// on Apple Silicon, or `Intel energy model derived package power
+/// (CPUs+GT+SA): 3.12W` on Intel.
+#[derive(Debug, Default)]
+struct Samples {
+    elapsed_ms: Option<f64>,
+    /// Power of the current sample in milliwatts; a later line for the
+    /// same domain replaces an earlier one.
+    power: BTreeMap<String, f64>,
+    /// Energy of the finished samples in microjoules.
+    energy: BTreeMap<String, f64>,
+}
+
+impl Samples {
+    fn line(&mut self, line: &str) {
+        let line = line.trim();
+        if line.starts_with("*** Sampled system activity") {
+            self.end_sample();
+            self.elapsed_ms = line
+                .rsplit_once("ms elapsed")
+                .and_then(|(head, _)| head.rsplit_once('('))
+                .and_then(|(_, ms)| ms.trim().parse().ok());
+            return;
+        }
+        let Some((label, value)) = line.split_once(": ") else {
+            return;
+        };
+        let domain = match label {
+            "CPU Power" => "cpu",
+            "GPU Power" => "gpu",
+            "ANE Power" => "ane",
+            label if label.starts_with("Intel energy model derived package power") => "package",
+            _ => return,
+        };
+        let power = if let Some(mw) = value.strip_suffix("mW") {
+            mw.trim().parse().ok()
+        } else if let Some(w) = value.strip_suffix('W') {
+            w.trim().parse::<f64>().ok().map(|w| w * 1e3)
+        } else {
+            None
+        };
+        if let Some(power) = power {
+            self.power.insert(domain.to_string(), power);
+        }
+    }
+
+    fn end_sample(&mut self) {
+        let power = std::mem::take(&mut self.power);
+        if let Some(elapsed_ms) = self.elapsed_ms.take() {
+            // mW * ms = uJ
+            for (domain, power) in power {
+                *self.energy.entry(domain).or_default() += power * elapsed_ms;
+            }
+        }
+    }
+
+    fn finish(mut self) -> BTreeMap<String, f64> {
+        self.end_sample();
+        self.energy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const APPLE: &str = "\
+Machine model: Mac14,5
+*** Sampled system activity (Fri Oct 16 10:00:00 2026 +0200) (100.00ms elapsed) ***
+
+**** Processor usage ****
+
+E-Cluster HW active frequency: 1020 MHz
+CPU Power: 4000 mW
+GPU Power: 30 mW
+ANE Power: 0 mW
+Combined Power (CPU + GPU + ANE): 4030 mW
+
+*** Sampled system activity (Fri Oct 16 10:00:00 2026 +0200) (50.00ms elapsed) ***
+
+CPU Power: 2000 mW
+GPU Power: 10 mW
+";
+
+    #[test]
+    fn integrates_power_samples() {
+        let mut samples = Samples::default();
+        for line in APPLE.lines() {
+            samples.line(line);
+        }
+        assert_eq!(
+            samples.finish(),
+            BTreeMap::from([
+                ("ane".to_string(), 0.0),
+                ("cpu".to_string(), 500_000.0),
+                ("gpu".to_string(), 3_500.0),
+            ])
+        );
+
+        let mut samples = Samples::default();
+        samples.line("*** Sampled system activity (Fri Oct 16 2026) (200ms elapsed) ***");
+        samples.line("Intel energy model derived package power (CPUs+GT+SA): 3.5W");
+        assert_eq!(
+            samples.finish(),
+            BTreeMap::from([("package".to_string(), 700_000.0)])
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn samples_in_the_background() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("zkbench-powermetrics-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = |name: &str, body: &str| {
+            let path = dir.join(name);
+            std::fs::write(&path, format!("#!/bin/sh\n{body}")).unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+            path
+        };
+        let sample = "echo '*** Sampled system activity (now) (100.00ms elapsed) ***'\n\
+                      echo 'CPU Power: 1000 mW'\n";
+        let fake = script(
+            "powermetrics",
+            &format!("{sample}case \"$*\" in *\"-n 1\"*) exit 0;; esac\n{sample}exec sleep 10\n"),
+        );
+        let denied = script(
+            "denied",
+            "echo 'powermetrics must be invoked as the superuser' >&2\nexit 1\n",
+        );
+
+        assert!(matches!(
+            Powermetrics::new(&dir.join("missing")),
+            Err(EnergyError::Unsupported)
+        ));
+        assert!(matches!(
+            Powermetrics::new(&denied),
+            Err(EnergyError::PermissionDenied(path)) if path == denied
+        ));
+
+        let mut meter = Powermetrics::new(&fake).unwrap();
+        meter.start().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(300));
+        meter.stop().unwrap();
+        assert_eq!(meter.domains(), BTreeMap::from([("cpu".to_string(), 0.2)]));
+        meter.reset();
+        assert!(meter.domains().is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
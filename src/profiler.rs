@@ -0,0 +1,295 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sampling CPU profiles of the measured region through pprof-rs, behind
+//! the `pprof` feature.
+//!
+//! A regression report says that a prover got slower, not where. A
+//! [`SamplingProfiler`] is used like a [`Stopwatch`](crate::Stopwatch)
+//! around the measured region and keeps the [`Profile`] in memory; once
+//! the run is compared with its baseline,
+//! [`attach_regression_profiles`] writes a flamegraph and a pprof
+//! protobuf for each regressed benchmark and attaches them to its result
+//! as artifacts, so only the runs worth looking at leave files behind.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use pprof::protos::Message;
+use pprof::{ProfilerGuard, ProfilerGuardBuilder, Report};
+
+use crate::artifact::{Artifact, ArtifactKind};
+use crate::compare::Comparison;
+use crate::schema::{BenchmarkReport, BenchmarkResult};
+
+/// Default sampling frequency in hertz; slightly off 1 kHz so samples do
+/// not line up with periodic work.
+pub const DEFAULT_PROFILE_FREQUENCY: i32 = 997;
+
+/// Why a profile could not be taken or written.
+#[derive(Debug)]
+pub enum ProfileError {
+    /// The profiler failed, e.g. because another one is running.
+    Profiler(pprof::Error),
+    /// A profile file could not be written.
+    Io(io::Error),
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Profiler(source) => write!(f, "profiler: {}", source),
+            Self::Io(source) => write!(f, "writing profile: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Profiler(source) => Some(source),
+            Self::Io(source) => Some(source),
+        }
+    }
+}
+
+impl From<pprof::Error> for ProfileError {
+    fn from(err: pprof::Error) -> Self {
+        Self::Profiler(err)
+    }
+}
+
+impl From<io::Error> for ProfileError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Samples the call stacks of all threads of the process while running.
+///
+/// Only one profiler can run in a process at a time.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::collections::{BTreeSet, HashMap};
+///
+/// use zkbench::{
+///     BenchmarkReport, CompareOptions, SamplingProfiler, attach_regression_profiles, compare,
+/// };
+/// # fn prove() {}
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let baseline = BenchmarkReport::load("baseline.json")?;
+/// # let mut report = BenchmarkReport::load("candidate.json")?;
+///
+/// let mut profiler = SamplingProfiler::new();
+/// profiler.start()?;
+/// prove();
+/// let profiles = HashMap::from([("prove".to_string(), profiler.stop()?)]);
+/// // ... record the results in `report` ...
+///
+/// let comparison = compare(&baseline, &report, &CompareOptions::default());
+/// attach_regression_profiles(&mut report, &comparison, &profiles, "profiles")?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct SamplingProfiler {
+    frequency: i32,
+    guard: Option<ProfilerGuard<'static>>,
+}
+
+impl fmt::Debug for SamplingProfiler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SamplingProfiler")
+            .field("frequency", &self.frequency)
+            .field("running", &self.guard.is_some())
+            .finish()
+    }
+}
+
+impl Default for SamplingProfiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SamplingProfiler {
+    /// Creates a stopped profiler sampling at
+    /// [`DEFAULT_PROFILE_FREQUENCY`].
+    pub fn new() -> Self {
+        Self {
+            frequency: DEFAULT_PROFILE_FREQUENCY,
+            guard: None,
+        }
+    }
+
+    /// Sets the sampling frequency in hertz.
+    pub fn with_frequency(mut self, frequency: i32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Starts sampling. Has no effect if already running.
+    pub fn start(&mut self) -> Result<(), ProfileError> {
+        if self.guard.is_none() {
+            let guard = ProfilerGuardBuilder::default()
+                .frequency(self.frequency)
+                // Unwinding through these can deadlock or crash.
+                .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+                .build()?;
+            self.guard = Some(guard);
+        }
+        Ok(())
+    }
+
+    /// Stops sampling and returns the samples taken since
+    /// [`start`](Self::start), or an empty profile if not running.
+    pub fn stop(&mut self) -> Result<Profile, ProfileError> {
+        let report = match self.guard.take() {
+            Some(guard) => guard.report().build()?,
+            None => Report {
+                data: HashMap::new(),
+                timing: Default::default(),
+            },
+        };
+        Ok(Profile { report })
+    }
+}
+
+/// Call stacks sampled by a [`SamplingProfiler`].
+#[derive(Debug)]
+pub struct Profile {
+    report: Report,
+}
+
+impl Profile {
+    /// Number of samples taken.
+    pub fn samples(&self) -> usize {
+        self.report.data.values().map(|&count| count as usize).sum()
+    }
+
+    /// Writes an SVG flamegraph to `path` and returns it as an artifact.
+    pub fn write_flamegraph(&self, path: impl AsRef<Path>) -> Result<Artifact, ProfileError> {
+        let path = path.as_ref();
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.report.flamegraph(&mut writer)?;
+        writer.flush()?;
+        Ok(Artifact::from_file(ArtifactKind::Flamegraph, path)?)
+    }
+
+    /// Writes the profile in pprof's protobuf format to `path`, for
+    /// `go tool pprof` and similar viewers, and returns it as an artifact.
+    pub fn write_pprof(&self, path: impl AsRef<Path>) -> Result<Artifact, ProfileError> {
+        let path = path.as_ref();
+        let profile = self.report.pprof()?;
+        std::fs::write(path, profile.encode_to_vec())?;
+        Ok(Artifact::from_file(ArtifactKind::Profile, path)?)
+    }
+
+    /// Writes a flamegraph and a pprof protobuf named after `benchmark`
+    /// into `dir` and attaches them to `result`. Writes nothing for a
+    /// profile without samples.
+    pub fn attach(
+        &self,
+        result: &mut BenchmarkResult,
+        benchmark: &str,
+        dir: impl AsRef<Path>,
+    ) -> Result<(), ProfileError> {
+        if self.samples() == 0 {
+            return Ok(());
+        }
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        let stem = benchmark.replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "_");
+        let flamegraph = self.write_flamegraph(dir.join(format!("{stem}.svg")))?;
+        let pprof = self.write_pprof(dir.join(format!("{stem}.pb")))?;
+        result.artifacts.push(flamegraph);
+        result.artifacts.push(pprof);
+        Ok(())
+    }
+}
+
+/// Attaches the profiles of the benchmarks `comparison` found a
+/// regression in to their results in `report`, the candidate of the
+/// comparison, writing the files into `dir`. Returns the names of the
+/// benchmarks that got profiles, sorted.
+///
+/// Whether a benchmark regressed is up to the comparison, so the
+/// thresholds of its [`CompareOptions`](crate::CompareOptions) apply.
+pub fn attach_regression_profiles(
+    report: &mut BenchmarkReport,
+    comparison: &Comparison,
+    profiles: &HashMap<String, Profile>,
+    dir: impl AsRef<Path>,
+) -> Result<Vec<String>, ProfileError> {
+    let regressed: BTreeSet<&str> = comparison.regressions().map(|(name, _)| name).collect();
+    let mut attached = Vec::new();
+    for name in regressed {
+        if let (Some(profile), Some(result)) = (profiles.get(name), report.benchmarks.get_mut(name))
+            && profile.samples() > 0
+        {
+            profile.attach(result, name, dir.as_ref())?;
+            attached.push(name.to_string());
+        }
+    }
+    Ok(attached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compare::{CompareOptions, compare};
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn attaches_profiles_of_regressions() {
+        let mut profiler = SamplingProfiler::new();
+        assert_eq!(profiler.stop().unwrap().samples(), 0);
+        if profiler.start().is_err() {
+            return;
+        }
+        let started = std::time::Instant::now();
+        let mut x = 0u64;
+        while started.elapsed().as_millis() < 200 {
+            x = std::hint::black_box(x.wrapping_mul(6364136223846793005).wrapping_add(1));
+        }
+        let profile = profiler.stop().unwrap();
+        if profile.samples() == 0 {
+            // Some sandboxes do not deliver profiling signals.
+            return;
+        }
+
+        let report = |prove_ms: f64| {
+            ReportBuilder::new()
+                .latencies_ms(&[("prove", prove_ms), ("verify", 1.0)])
+                .build()
+        };
+        let baseline = report(10.0);
+        let mut candidate = report(20.0);
+        let comparison = compare(&baseline, &candidate, &CompareOptions::default());
+        let profiles = HashMap::from([
+            ("prove".to_string(), profile),
+            ("verify".to_string(), profiler.stop().unwrap()),
+        ]);
+        let dir = std::env::temp_dir().join(format!("zkbench-pprof-{}", std::process::id()));
+        let attached =
+            attach_regression_profiles(&mut candidate, &comparison, &profiles, &dir).unwrap();
+        assert_eq!(attached, ["prove"]);
+
+        let artifacts = &candidate.benchmarks["prove"].artifacts;
+        assert_eq!(artifacts.len(), 2);
+        assert_eq!(artifacts[0].kind, ArtifactKind::Flamegraph);
+        assert!(
+            std::fs::read_to_string(dir.join("prove.svg"))
+                .unwrap()
+                .contains("<svg")
+        );
+        assert_eq!(artifacts[1].kind, ArtifactKind::Profile);
+        assert!(artifacts[1].size_bytes.unwrap() > 0);
+        assert!(candidate.benchmarks["verify"].artifacts.is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
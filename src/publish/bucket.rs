@@ -0,0 +1,272 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! S3/GCS bucket uploads.
+
+use std::sync::Arc;
+
+use object_store::aws::AmazonS3Builder;
+use object_store::gcp::GoogleCloudStorageBuilder;
+use object_store::path::Path;
+use object_store::{ObjectStore, PutPayload};
+
+use crate::export::report_id;
+use crate::schema::BenchmarkReport;
+
+/// Key layout used unless overridden with
+/// [`BucketPublisher::with_key_template`].
+pub const DEFAULT_KEY_TEMPLATE: &str = "{implementation}/{commit}/{platform}/{timestamp}.json";
+
+/// Writes reports as JSON objects into a bucket.
+///
+/// # Example
+///
+/// ```no_run
+/// use zkbench::publish::BucketPublisher;
+/// # async fn run(report: zkbench::BenchmarkReport) -> Result<(), object_store::Error> {
+///
+/// // Credentials come from the usual AWS_* / GOOGLE_* environment variables.
+/// let publisher = BucketPublisher::from_url("s3://my-bucket/zkbench")?;
+/// let key = publisher.upload(&report).await?;
+/// println!("uploaded to {}", key);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BucketPublisher {
+    store: Arc<dyn ObjectStore>,
+    /// `scheme://bucket` when built with [`from_url`](Self::from_url).
+    bucket_url: Option<String>,
+    prefix: Path,
+    key_template: String,
+}
+
+impl BucketPublisher {
+    /// Wraps an already configured store.
+    pub fn new(store: Arc<dyn ObjectStore>) -> Self {
+        Self {
+            store,
+            bucket_url: None,
+            prefix: Path::default(),
+            key_template: DEFAULT_KEY_TEMPLATE.to_string(),
+        }
+    }
+
+    /// Builds a store from an `s3://bucket/prefix` or `gs://bucket/prefix`
+    /// URL, taking credentials and region from the environment.
+    pub fn from_url(url: &str) -> Result<Self, object_store::Error> {
+        let (scheme, rest) = url.split_once("://").unwrap_or(("", url));
+        let (bucket, prefix) = rest.split_once('/').unwrap_or((rest, ""));
+        let bucket_url = format!("{}://{}", scheme, bucket);
+        let store: Arc<dyn ObjectStore> = match scheme {
+            "s3" | "s3a" => Arc::new(AmazonS3Builder::from_env().with_url(&bucket_url).build()?),
+            "gs" => Arc::new(
+                GoogleCloudStorageBuilder::from_env()
+                    .with_url(&bucket_url)
+                    .build()?,
+            ),
+            _ => {
+                return Err(object_store::Error::Generic {
+                    store: "zkbench",
+                    source: format!("unsupported bucket URL: {}", url).into(),
+                });
+            }
+        };
+        let mut publisher = Self::new(store).with_prefix(prefix);
+        publisher.bucket_url = Some(bucket_url);
+        Ok(publisher)
+    }
+
+    /// Places every key under `prefix`.
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Path::from(prefix);
+        self
+    }
+
+    /// Overrides the key layout; see [`object_key`] for placeholders.
+    pub fn with_key_template(mut self, template: &str) -> Self {
+        self.key_template = template.to_string();
+        self
+    }
+
+    /// Returns the full object path a report would be written to.
+    pub fn key_for(&self, report: &BenchmarkReport) -> Path {
+        let key = object_key(report, &self.key_template);
+        key.split('/')
+            .filter(|part| !part.is_empty())
+            .fold(self.prefix.clone(), |path, part| path.child(part))
+    }
+
+    /// Returns the URL a report would be written to, such as
+    /// `s3://bucket/prefix/<key>`, for publishers built with
+    /// [`from_url`](Self::from_url).
+    pub fn url_for(&self, report: &BenchmarkReport) -> Option<String> {
+        let bucket_url = self.bucket_url.as_ref()?;
+        Some(format!("{}/{}", bucket_url, self.key_for(report)))
+    }
+
+    /// Uploads a report and returns the path it was written to.
+    pub async fn upload(&self, report: &BenchmarkReport) -> Result<Path, object_store::Error> {
+        let json = report
+            .to_json(true)
+            .map_err(|e| object_store::Error::Generic {
+                store: "zkbench",
+                source: Box::new(e),
+            })?;
+        let path = self.key_for(report);
+        self.store.put(&path, PutPayload::from(json)).await?;
+        Ok(path)
+    }
+
+    /// Uploads a report from synchronous code, on a single-threaded
+    /// runtime created for the call. Must not be called from within an
+    /// async runtime; use [`upload`](Self::upload) there.
+    pub fn upload_blocking(&self, report: &BenchmarkReport) -> Result<Path, object_store::Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| object_store::Error::Generic {
+                store: "zkbench",
+                source: Box::new(e),
+            })?;
+        runtime.block_on(self.upload(report))
+    }
+}
+
+/// Expands a key template for a report.
+///
+/// Supported placeholders are `{implementation}`, `{version}`, `{commit}`,
+/// `{platform}` (the [`Platform::fingerprint`](crate::Platform::fingerprint)),
+/// `{timestamp}` (compact UTC, e.g. `20260101T120000Z`) and `{id}` (the
+/// [`report_id`](crate::report_id) content hash). Substituted values have
+/// `/` and whitespace replaced so they never introduce extra path segments.
+pub fn object_key(report: &BenchmarkReport, template: &str) -> String {
+    let metadata = &report.metadata;
+    let timestamp = chrono::DateTime::parse_from_rfc3339(&metadata.timestamp)
+        .map(|t| {
+            t.with_timezone(&chrono::Utc)
+                .format("%Y%m%dT%H%M%SZ")
+                .to_string()
+        })
+        .unwrap_or_else(|_| metadata.timestamp.clone());
+    let commit = if metadata.commit_sha.is_empty() {
+        "unknown"
+    } else {
+        &metadata.commit_sha
+    };
+    [
+        ("{implementation}", metadata.implementation.as_str()),
+        ("{version}", metadata.version.as_str()),
+        ("{commit}", commit),
+        ("{platform}", &metadata.platform.fingerprint()),
+        ("{timestamp}", &timestamp),
+        ("{id}", &report_id(report)),
+    ]
+    .iter()
+    .fold(template.to_string(), |key, (placeholder, value)| {
+        key.replace(placeholder, &sanitize(value))
+    })
+}
+
+fn sanitize(segment: &str) -> String {
+    segment
+        .chars()
+        .map(|c| {
+            if c == '/' || c.is_whitespace() {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    use object_store::memory::InMemory;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    /// Drives a future that never actually waits, as `InMemory` does.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn default_key_layout() {
+        let report = ReportBuilder::new()
+            .implementation("my impl")
+            .version("0.1.0")
+            .commit("abc123")
+            .timestamp("2026-01-02T03:04:05+00:00")
+            .build();
+        assert_eq!(
+            object_key(&report, DEFAULT_KEY_TEMPLATE),
+            format!(
+                "my_impl/abc123/{}/20260102T030405Z.json",
+                report.metadata.platform.fingerprint()
+            )
+        );
+        assert_eq!(
+            object_key(&report, "{version}/{id}.json"),
+            format!("0.1.0/{}.json", report_id(&report))
+        );
+    }
+
+    #[test]
+    fn uploads_under_prefix() {
+        let store = Arc::new(InMemory::new());
+        let publisher = BucketPublisher::new(store.clone())
+            .with_prefix("archive")
+            .with_key_template("{implementation}/{commit}.json");
+        let report = ReportBuilder::new()
+            .implementation("my impl")
+            .version("0.1.0")
+            .commit("abc123")
+            .timestamp("2026-01-02T03:04:05+00:00")
+            .build();
+        let path = block_on(publisher.upload(&report)).unwrap();
+        assert_eq!(path.as_ref(), "archive/my_impl/abc123.json");
+
+        let bytes = block_on(async { store.get(&path).await?.bytes().await }).unwrap();
+        let stored = BenchmarkReport::from_json(std::str::from_utf8(&bytes).unwrap()).unwrap();
+        assert_eq!(stored.metadata.commit_sha, "abc123");
+    }
+
+    #[test]
+    fn uploads_blocking_and_resolves_urls() {
+        let report = ReportBuilder::new()
+            .implementation("my impl")
+            .version("0.1.0")
+            .commit("abc123")
+            .timestamp("2026-01-02T03:04:05+00:00")
+            .build();
+        let publisher = BucketPublisher::new(Arc::new(InMemory::new()));
+        let path = publisher.upload_blocking(&report).unwrap();
+        assert!(path.as_ref().starts_with("my_impl/abc123/"));
+        assert_eq!(publisher.url_for(&report), None);
+
+        let publisher = BucketPublisher::from_url("s3://bucket/archive")
+            .unwrap()
+            .with_key_template("{commit}.json");
+        assert_eq!(
+            publisher.url_for(&report).unwrap(),
+            "s3://bucket/archive/abc123.json"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_scheme() {
+        assert!(BucketPublisher::from_url("ftp://bucket/x").is_err());
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! HTTP ingestion client.
+
+use std::fmt;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+
+use crate::export::report_id;
+use crate::schema::BenchmarkReport;
+
+/// Error returned by [`HttpPublisher::publish`].
+#[derive(Debug)]
+pub enum PublishError {
+    /// The report could not be serialized.
+    Serialize(serde_json::Error),
+    /// Compressing the request body failed.
+    Compress(std::io::Error),
+    /// The endpoint rejected the report with a non-retryable status.
+    Rejected { status: u16 },
+    /// Every attempt failed; holds the last error.
+    RetriesExhausted { attempts: u32, last: String },
+}
+
+impl fmt::Display for PublishError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(e) => write!(f, "failed to serialize report: {}", e),
+            Self::Compress(e) => write!(f, "failed to compress report: {}", e),
+            Self::Rejected { status } => write!(f, "endpoint rejected report with HTTP {}", status),
+            Self::RetriesExhausted { attempts, last } => {
+                write!(f, "publishing failed after {} attempts: {}", attempts, last)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PublishError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Serialize(e) => Some(e),
+            Self::Compress(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of a successful publish.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishReceipt {
+    /// HTTP status returned by the endpoint.
+    pub status: u16,
+    /// Number of attempts it took, starting at 1.
+    pub attempts: u32,
+    /// Idempotency key sent with the request.
+    pub idempotency_key: String,
+}
+
+/// POSTs reports as JSON to an ingestion endpoint.
+///
+/// Requests carry an `Idempotency-Key` header derived from the report's
+/// content hash, so retried uploads of the same report can be
+/// deduplicated server-side. Transport errors, `429` and `5xx` responses
+/// are retried with exponential backoff; other `4xx` responses fail
+/// immediately.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use zkbench::publish::HttpPublisher;
+/// # let report: zkbench::BenchmarkReport = unimplemented!();
+///
+/// let receipt = HttpPublisher::new("https://bench.example.com/api/reports")
+///     .with_token("secret")
+///     .with_max_retries(5)
+///     .with_backoff(Duration::from_millis(500))
+///     .publish(&report)?;
+/// println!("published after {} attempt(s)", receipt.attempts);
+/// # Ok::<(), zkbench::publish::PublishError>(())
+/// ```
+#[derive(Clone)]
+pub struct HttpPublisher {
+    endpoint: String,
+    token: Option<String>,
+    gzip: bool,
+    max_retries: u32,
+    backoff: Duration,
+    timeout: Duration,
+}
+
+impl fmt::Debug for HttpPublisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpPublisher")
+            .field("endpoint", &self.endpoint)
+            // The token is a credential; logs should not see it.
+            .field("token", &self.token.as_ref().map(|_| "<redacted>"))
+            .field("gzip", &self.gzip)
+            .field("max_retries", &self.max_retries)
+            .field("backoff", &self.backoff)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl HttpPublisher {
+    /// Creates a publisher with gzip enabled, 3 retries, 1 s initial
+    /// backoff and a 30 s request timeout.
+    pub fn new(endpoint: &str) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            token: None,
+            gzip: true,
+            max_retries: 3,
+            backoff: Duration::from_secs(1),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Sends `Authorization: Bearer <token>` with every request.
+    pub fn with_token(mut self, token: &str) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+
+    /// Enables or disables gzip compression of the request body.
+    pub fn with_gzip(mut self, gzip: bool) -> Self {
+        self.gzip = gzip;
+        self
+    }
+
+    /// Sets how many times a failed request is retried.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry; it doubles on every retry.
+    pub fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets the per-request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the configured endpoint.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Publishes a report, retrying transient failures.
+    pub fn publish(&self, report: &BenchmarkReport) -> Result<PublishReceipt, PublishError> {
+        let json = report.to_json(false).map_err(PublishError::Serialize)?;
+        let body = if self.gzip {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder
+                .write_all(json.as_bytes())
+                .map_err(PublishError::Compress)?;
+            encoder.finish().map_err(PublishError::Compress)?
+        } else {
+            json.into_bytes()
+        };
+        let key = report_id(report);
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(self.timeout))
+            .build()
+            .into();
+
+        let mut last = String::new();
+        for attempt in 0..=self.max_retries {
+            if attempt > 0 {
+                thread::sleep(self.backoff * 2u32.saturating_pow(attempt - 1));
+            }
+            let mut request = agent
+                .post(&self.endpoint)
+                .header("Content-Type", "application/json")
+                .header("Idempotency-Key", &key);
+            if self.gzip {
+                request = request.header("Content-Encoding", "gzip");
+            }
+            if let Some(token) = &self.token {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
+            match request.send(&body[..]) {
+                Ok(response) => {
+                    return Ok(PublishReceipt {
+                        status: response.status().as_u16(),
+                        attempts: attempt + 1,
+                        idempotency_key: key,
+                    });
+                }
+                Err(ureq::Error::StatusCode(status)) if !retryable(status) => {
+                    return Err(PublishError::Rejected { status });
+                }
+                Err(e) => last = e.to_string(),
+            }
+        }
+        Err(PublishError::RetriesExhausted {
+            attempts: self.max_retries + 1,
+            last,
+        })
+    }
+}
+
+fn retryable(status: u16) -> bool {
+    status == 429 || status >= 500
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    use flate2::read::GzDecoder;
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+
+    struct Request {
+        headers: Vec<String>,
+        body: Vec<u8>,
+    }
+
+    /// Serves one canned status per connection and returns what it saw.
+    fn serve(statuses: Vec<u16>) -> (String, thread::JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/reports", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut seen = Vec::new();
+            for status in statuses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(line.trim().to_string());
+                }
+                let length: usize = headers
+                    .iter()
+                    .find_map(|h| {
+                        h.to_ascii_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|v| v.trim().parse().unwrap())
+                    })
+                    .unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                seen.push(Request { headers, body });
+                let mut stream = stream;
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                )
+                .unwrap();
+            }
+            seen
+        });
+        (url, handle)
+    }
+
+    fn has_header(request: &Request, header: &str) -> bool {
+        request
+            .headers
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case(header))
+    }
+
+    #[test]
+    fn retries_then_succeeds_with_headers() {
+        let (url, server) = serve(vec![503, 201]);
+        let report = ReportBuilder::new().build();
+        let receipt = HttpPublisher::new(&url)
+            .with_token("t0k")
+            .with_backoff(Duration::from_millis(1))
+            .publish(&report)
+            .unwrap();
+        assert_eq!(receipt.status, 201);
+        assert_eq!(receipt.attempts, 2);
+        assert_eq!(receipt.idempotency_key, report_id(&report));
+
+        let requests = server.join().unwrap();
+        let last = &requests[1];
+        assert!(has_header(last, "authorization: Bearer t0k"));
+        assert!(has_header(last, "content-encoding: gzip"));
+        assert!(has_header(
+            last,
+            &format!("idempotency-key: {}", receipt.idempotency_key)
+        ));
+        let mut json = String::new();
+        GzDecoder::new(&last.body[..])
+            .read_to_string(&mut json)
+            .unwrap();
+        assert!(BenchmarkReport::from_json(&json).is_ok());
+    }
+
+    #[test]
+    fn client_errors_are_not_retried() {
+        let (url, server) = serve(vec![400]);
+        let err = HttpPublisher::new(&url)
+            .with_gzip(false)
+            .with_backoff(Duration::from_millis(1))
+            .publish(&ReportBuilder::new().build())
+            .unwrap_err();
+        assert!(matches!(err, PublishError::Rejected { status: 400 }));
+        let requests = server.join().unwrap();
+        assert!(!has_header(&requests[0], "content-encoding: gzip"));
+    }
+
+    #[test]
+    fn retries_are_bounded() {
+        let (url, server) = serve(vec![500, 502]);
+        let err = HttpPublisher::new(&url)
+            .with_max_retries(1)
+            .with_backoff(Duration::from_millis(1))
+            .publish(&ReportBuilder::new().build())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PublishError::RetriesExhausted { attempts: 2, .. }
+        ));
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn debug_redacts_token() {
+        let publisher = HttpPublisher::new("https://bench.example.com").with_token("s3cret");
+        let debug = format!("{publisher:?}");
+        assert!(
+            debug.contains("<redacted>") && !debug.contains("s3cret"),
+            "{debug}"
+        );
+        let err = PublishError::Compress(std::io::ErrorKind::Other.into());
+        assert!(std::error::Error::source(&err).is_some());
+    }
+}
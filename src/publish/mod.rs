@@ -0,0 +1,31 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Publishing reports to remote destinations.
+//!
+//! - [`HttpPublisher`] (feature `publish`): POSTs reports to an ingestion
+//!   endpoint.
+//! - [`BucketPublisher`] (feature `object-store`): writes reports into an
+//!   S3 or GCS bucket.
+//!
+//! `zkbench upload` resolves its destination and credentials from
+//! [`UPLOAD_URL_ENV`] and [`UPLOAD_TOKEN_ENV`]; bucket credentials come
+//! from the usual `AWS_*` and `GOOGLE_*` variables.
+
+#[cfg(feature = "object-store")]
+mod bucket;
+#[cfg(feature = "publish")]
+mod http;
+
+#[cfg(feature = "object-store")]
+pub use bucket::{BucketPublisher, DEFAULT_KEY_TEMPLATE, object_key};
+#[cfg(feature = "publish")]
+pub use http::{HttpPublisher, PublishError, PublishReceipt};
+
+/// Environment variable holding the default destination of
+/// `zkbench upload`: an `http(s)://` endpoint or an `s3://`/`gs://`
+/// bucket URL.
+pub const UPLOAD_URL_ENV: &str = "ZKBENCH_UPLOAD_URL";
+
+/// Environment variable holding the bearer token sent to HTTP endpoints.
+pub const UPLOAD_TOKEN_ENV: &str = "ZKBENCH_UPLOAD_TOKEN";
@@ -0,0 +1,124 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! GitHub Actions workflow commands for gate results.
+//!
+//! Printed to a step's output, `::error` and `::warning` lines become
+//! annotations on the run's summary page and pull request, so a
+//! regression shows up without opening the log.
+
+use std::fmt::Write as _;
+
+use crate::gate::{GateResult, Violation, ViolationKind};
+
+/// Renders a gate result as GitHub Actions annotations.
+///
+/// Each violation counting against the gate is an `::error`; exempted
+/// violations, a platform mismatch and an exhausted regression budget
+/// are `::warning`s. A last line summarizes the verdict, as an `::error`
+/// when the gate failed and a `::notice` otherwise.
+pub fn render_github_annotations(result: &GateResult) -> String {
+    let mut out = String::new();
+    for violation in &result.violations {
+        command(&mut out, "error", title(violation), &violation.to_string());
+    }
+    for violation in &result.exempted {
+        let title = format!("{} (exempted)", title(violation));
+        command(&mut out, "warning", &title, &violation.to_string());
+    }
+    if let Some(mismatch) = &result.comparison.platform_mismatch {
+        command(
+            &mut out,
+            "warning",
+            "Platform mismatch",
+            &mismatch.to_string(),
+        );
+    }
+    if let Some(budget) = result.budget.as_ref().filter(|b| !b.within_budget()) {
+        command(
+            &mut out,
+            "warning",
+            "Regression budget",
+            &budget.to_string(),
+        );
+    }
+    let summary = result.to_string();
+    let summary = summary.lines().next().unwrap_or_default();
+    let level = if result.passed() { "notice" } else { "error" };
+    command(&mut out, level, "Benchmark gate", summary);
+    out
+}
+
+fn title(violation: &Violation) -> &'static str {
+    match violation.kind {
+        ViolationKind::Regression { .. } => "Benchmark regression",
+        ViolationKind::Removed => "Benchmark removed",
+    }
+}
+
+/// Writes `::<level> title=<title>::<message>`, escaped as the runner
+/// expects.
+fn command(out: &mut String, level: &str, title: &str, message: &str) {
+    let _ = writeln!(
+        out,
+        "::{level} title={}::{}",
+        escape_property(title),
+        escape_data(message)
+    );
+}
+
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compare::CompareOptions;
+    use crate::gate::{GatePolicy, gate};
+
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn violations_become_errors() {
+        let baseline = ReportBuilder::new()
+            .latencies_ms(&[("prove", 100.0), ("hash", 10.0)])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies_ms(&[("prove", 120.0), ("hash", 20.0)])
+            .tags("hash", &["flaky"])
+            .build();
+        let policy = GatePolicy {
+            exempt_tags: vec!["flaky".to_string()],
+            ..Default::default()
+        };
+        let result = gate(&baseline, &candidate, &CompareOptions::default(), &policy);
+        let out = render_github_annotations(&result);
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(
+            lines[0].starts_with(
+                "::error title=Benchmark regression::prove: latency regressed by +20.0%"
+            ),
+            "{}",
+            lines[0]
+        );
+        assert!(lines[1].starts_with("::warning title=Benchmark regression (exempted)::hash"));
+        assert_eq!(
+            lines[2],
+            "::error title=Benchmark gate::benchmark gate failed: 1 violation(s), 1 exempted"
+        );
+    }
+
+    #[test]
+    fn escapes_commands() {
+        assert_eq!(escape_data("50%\nof"), "50%25%0Aof");
+        assert_eq!(escape_property("a: b, c"), "a%3A b%2C c");
+    }
+}
@@ -0,0 +1,409 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Markdown and HTML rendering of comparisons.
+
+use std::fmt::Write;
+
+use super::html::{STYLE, escape};
+use crate::compare::{Classification, Comparison, DecisionRule, MetricDelta};
+use crate::schema::MetricValue;
+use crate::units::{format_metric, format_value};
+
+const LEGEND: &str = "▲/▼ significant increase/decrease · \
+    `*` p < 0.05, `**` p < 0.01, `***` p < 0.001 (Welch's t-test) \
+    or `*` disjoint confidence intervals · `?` too noisy to judge";
+
+/// Significance stars for a delta: three levels for t-tests, one star
+/// for disjoint confidence intervals, none for plain thresholds.
+fn stars(rule: &DecisionRule) -> &'static str {
+    match *rule {
+        DecisionRule::WelchTTest { p_value, alpha } if p_value < alpha => {
+            if p_value < 0.001 {
+                "***"
+            } else if p_value < 0.01 {
+                "**"
+            } else {
+                "*"
+            }
+        }
+        DecisionRule::ConfidenceIntervals { overlap: false } => "*",
+        _ => "",
+    }
+}
+
+/// The change cell: signed percentage, direction marker for classified
+/// changes, and significance stars.
+pub(super) fn change_cell(delta: &MetricDelta) -> String {
+    let marker = match delta.classification {
+        Classification::Regression | Classification::Improvement if delta.change > 0.0 => " ▲",
+        Classification::Regression | Classification::Improvement => " ▼",
+        Classification::Inconclusive => " ?",
+        Classification::Unchanged => "",
+    };
+    format!(
+        "{:+.1}%{}{}",
+        delta.change * 100.0,
+        marker,
+        stars(&delta.rule)
+    )
+}
+
+fn counts(comparison: &Comparison) -> (usize, usize, usize) {
+    let inconclusive = comparison
+        .deltas()
+        .filter(|(_, d)| d.classification == Classification::Inconclusive)
+        .count();
+    (
+        comparison.regressions().count(),
+        comparison.improvements().count(),
+        inconclusive,
+    )
+}
+
+/// Renders a comparison as a compact markdown diff table.
+///
+/// Each row is one metric of one benchmark with both values and the
+/// change, marked ▲/▼ when the change is a significant regression or
+/// improvement and starred by its statistical significance. A summary
+/// line, a warning when the platforms differ, the normalization note (if
+/// any), renamed, added and removed
+/// benchmarks, a legend and the fitted scaling exponents of sweeps
+/// surround the table. `metrics` restricts the
+/// rows to the named metrics; an empty slice shows all of them.
+pub fn render_comparison_markdown(comparison: &Comparison, metrics: &[&str]) -> String {
+    let cell = |text: &str| text.replace('|', "\\|");
+    let mut out = String::new();
+    let (regressions, improvements, inconclusive) = counts(comparison);
+    let _ = write!(
+        out,
+        "**{regressions}** regression(s), **{improvements}** improvement(s)"
+    );
+    if inconclusive > 0 {
+        let _ = write!(out, ", {inconclusive} inconclusive");
+    }
+    out.push_str(".\n");
+    if let Some(mismatch) = &comparison.platform_mismatch {
+        let _ = writeln!(out, "\n> [!WARNING]\n> Platform mismatch: {mismatch}.");
+    }
+    if let Some(note) = &comparison.normalization {
+        let _ = writeln!(out, "\n_Note: {note}._");
+    }
+
+    let rows: Vec<(&str, &MetricDelta)> = comparison
+        .deltas()
+        .filter(|(_, d)| metrics.is_empty() || metrics.contains(&d.metric.as_str()))
+        .collect();
+    if rows.is_empty() {
+        out.push_str("\nNo metrics to compare.\n");
+    } else {
+        out.push_str("\n| Benchmark | Metric | Baseline | Candidate | Change |\n");
+        out.push_str("|-----------|--------|---------:|----------:|-------:|\n");
+        for (name, delta) in rows {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} | {} |",
+                cell(name),
+                cell(&delta.metric),
+                format_metric(&delta.baseline),
+                format_metric(&delta.candidate),
+                change_cell(delta)
+            );
+        }
+        let _ = writeln!(out, "\n<sub>{LEGEND}</sub>");
+    }
+
+    let renamed: Vec<String> = comparison
+        .benchmarks
+        .iter()
+        .filter_map(|b| Some(format!("`{}` → `{}`", b.renamed_from.as_ref()?, b.name)))
+        .collect();
+    for (heading, names) in [
+        ("Renamed", renamed),
+        (
+            "Added",
+            comparison.added.iter().map(|n| format!("`{n}`")).collect(),
+        ),
+        (
+            "Removed",
+            comparison
+                .removed
+                .iter()
+                .map(|n| format!("`{n}`"))
+                .collect(),
+        ),
+    ] {
+        if !names.is_empty() {
+            let _ = writeln!(out, "\n**{heading}:** {}", names.join(", "));
+        }
+    }
+
+    if !comparison.scaling.is_empty() {
+        out.push_str("\n**Scaling:**\n\n| Sweep | Exponent | Level | Change |\n");
+        out.push_str("|-------|---------:|------:|--------|\n");
+        for s in &comparison.scaling {
+            let _ = writeln!(
+                out,
+                "| {} | {:.2} → {:.2} | {:+.1}% | {} |",
+                cell(&s.sweep),
+                s.baseline.exponent,
+                s.candidate.exponent,
+                s.level_change * 100.0,
+                s.change
+            );
+        }
+    }
+    out
+}
+
+const DIFF_STYLE: &str = r#"details { margin: 4px 0; }
+summary { cursor: pointer; }
+.regression { color: #c0392b; }
+.improvement { color: #27ae60; }
+.unchanged, .inconclusive { color: #777; }"#;
+
+/// Renders a comparison as a self-contained HTML page.
+///
+/// Every benchmark is a collapsible `<details>` element whose summary
+/// shows its worst classification and latency change; expanding it shows
+/// all metric deltas with confidence bounds, the decision rule and the
+/// noise level.
+pub fn render_comparison_html(comparison: &Comparison) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>zkbench comparison</title>\n");
+    let _ = writeln!(
+        out,
+        "<style>\n{}\n{}\n</style>\n</head>\n<body>",
+        STYLE, DIFF_STYLE
+    );
+    out.push_str("<h1>Benchmark comparison</h1>\n");
+    let (regressions, improvements, inconclusive) = counts(comparison);
+    let _ = writeln!(
+        out,
+        "<p>{regressions} regression(s), {improvements} improvement(s), \
+         {inconclusive} inconclusive · verdict: <strong>{:?}</strong></p>",
+        comparison.verdict
+    );
+    if let Some(mismatch) = &comparison.platform_mismatch {
+        let _ = writeln!(
+            out,
+            "<p class=\"regression\"><strong>Platform mismatch:</strong> {}.</p>",
+            escape(&mismatch.to_string())
+        );
+    }
+    if let Some(note) = &comparison.normalization {
+        let _ = writeln!(
+            out,
+            "<p class=\"meta\">Note: {}.</p>",
+            escape(&note.to_string())
+        );
+    }
+
+    for benchmark in &comparison.benchmarks {
+        let worst = benchmark
+            .deltas
+            .iter()
+            .map(|d| d.classification)
+            .min_by_key(|c| match c {
+                Classification::Regression => 0,
+                Classification::Improvement => 1,
+                Classification::Inconclusive => 2,
+                Classification::Unchanged => 3,
+            })
+            .unwrap_or(Classification::Unchanged);
+        let class = class_name(worst);
+        let _ = write!(
+            out,
+            "<details>\n<summary><span class=\"{class}\">{}</span>",
+            escape(&benchmark.name)
+        );
+        if let Some(from) = &benchmark.renamed_from {
+            let _ = write!(out, " <span class=\"meta\">(was {})</span>", escape(from));
+        }
+        if let Some(latency) = benchmark.delta("latency") {
+            let _ = write!(
+                out,
+                " <span class=\"{class}\">{}</span>",
+                change_cell(latency)
+            );
+        }
+        out.push_str("</summary>\n<table>\n<thead><tr><th>Metric</th><th>Baseline</th>");
+        out.push_str("<th>Candidate</th><th>Change</th><th>Decision</th><th>CV</th>");
+        out.push_str("</tr></thead>\n<tbody>\n");
+        for delta in &benchmark.deltas {
+            let _ = writeln!(
+                out,
+                "<tr class=\"{}\"><td>{}</td><td class=\"num\">{}</td>\
+                 <td class=\"num\">{}</td><td class=\"num\">{}</td><td>{}</td>\
+                 <td class=\"num\">{}</td></tr>",
+                class_name(delta.classification),
+                escape(&delta.metric),
+                escape(&with_bounds(&delta.baseline)),
+                escape(&with_bounds(&delta.candidate)),
+                escape(&change_cell(delta)),
+                escape(&delta.rule.to_string()),
+                delta
+                    .cv
+                    .map(|cv| format!("{:.1}%", cv * 100.0))
+                    .unwrap_or_else(|| "-".to_string())
+            );
+        }
+        out.push_str("</tbody>\n</table>\n</details>\n");
+    }
+
+    for (heading, names) in [
+        ("Added", &comparison.added),
+        ("Removed", &comparison.removed),
+    ] {
+        if names.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "<h2>{heading}</h2>\n<ul>");
+        for name in names {
+            let _ = writeln!(out, "<li>{}</li>", escape(name));
+        }
+        out.push_str("</ul>\n");
+    }
+    let _ = writeln!(
+        out,
+        "<p class=\"meta\">{}</p>\n</body>\n</html>",
+        escape(LEGEND).replace('`', "")
+    );
+    out
+}
+
+fn class_name(classification: Classification) -> &'static str {
+    match classification {
+        Classification::Regression => "regression",
+        Classification::Improvement => "improvement",
+        Classification::Unchanged => "unchanged",
+        Classification::Inconclusive => "inconclusive",
+    }
+}
+
+fn with_bounds(metric: &MetricValue) -> String {
+    match (metric.lower_value, metric.upper_value) {
+        (Some(lower), Some(upper)) => format!(
+            "{} [{} – {}]",
+            format_metric(metric),
+            format_value(lower, &metric.unit),
+            format_value(upper, &metric.unit)
+        ),
+        _ => format_metric(metric),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compare::{CompareOptions, compare};
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    fn with_memory(latency: MetricValue) -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(latency),
+            memory: Some(MetricValue::new(64.0, "MiB")),
+            ..Default::default()
+        }
+    }
+
+    fn comparison() -> Comparison {
+        let baseline = ReportBuilder::new()
+            .results([
+                (
+                    "fft",
+                    with_memory(MetricValue::with_bounds(100.0, "ms", 99.0, 101.0)),
+                ),
+                ("msm", with_memory(MetricValue::new(100.0, "ms"))),
+                ("old", with_memory(MetricValue::new(1.0, "ms"))),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .results([
+                (
+                    "fft",
+                    with_memory(MetricValue::with_bounds(90.0, "ms", 89.0, 91.0)),
+                ),
+                ("msm", with_memory(MetricValue::new(120.0, "ms"))),
+                ("<new>", with_memory(MetricValue::new(1.0, "ms"))),
+            ])
+            .build();
+        compare(&baseline, &candidate, &CompareOptions::default())
+    }
+
+    #[test]
+    fn markdown_diff_table() {
+        let md = render_comparison_markdown(&comparison(), &[]);
+        assert!(md.starts_with("**1** regression(s), **1** improvement(s).\n"));
+        assert!(md.contains("| fft | latency | 100 ms | 90 ms | -10.0% ▼* |"));
+        assert!(md.contains("| msm | latency | 100 ms | 120 ms | +20.0% ▲ |"));
+        assert!(md.contains("| msm | memory | 64 MiB | 64 MiB | +0.0% |"));
+        assert!(md.contains("**Added:** `<new>`"));
+        assert!(md.contains("**Removed:** `old`"));
+
+        let latency_only = render_comparison_markdown(&comparison(), &["latency"]);
+        assert!(!latency_only.contains("| memory |"));
+    }
+
+    #[test]
+    fn markdown_scaling_table() {
+        let sweep = |ms: [f64; 3]| {
+            ReportBuilder::new()
+                .results([
+                    ("ntt/2^10", with_memory(MetricValue::new(ms[0], "ms"))),
+                    ("ntt/2^12", with_memory(MetricValue::new(ms[1], "ms"))),
+                    ("ntt/2^14", with_memory(MetricValue::new(ms[2], "ms"))),
+                ])
+                .build()
+        };
+        let comparison = compare(
+            &sweep([1.0, 4.0, 16.0]),
+            &sweep([1.0, 8.0, 64.0]),
+            &CompareOptions::default(),
+        );
+        let md = render_comparison_markdown(&comparison, &[]);
+        assert!(md.contains("| ntt | 1.00 → 1.50 | +100.0% | exponent regression |"));
+        assert!(!render_comparison_markdown(&self::comparison(), &[]).contains("**Scaling:**"));
+    }
+
+    #[test]
+    fn warns_about_platform_mismatch() {
+        let baseline = ReportBuilder::new()
+            .results([("fft", with_memory(MetricValue::new(100.0, "ms")))])
+            .build();
+        let mut candidate = baseline.clone();
+        candidate.metadata.platform.cpu_count = baseline.metadata.platform.cpu_count + 1;
+        let comparison = compare(&baseline, &candidate, &CompareOptions::default());
+        let md = render_comparison_markdown(&comparison, &[]);
+        assert!(md.contains("> [!WARNING]\n> Platform mismatch: reports come from different"));
+        let html = render_comparison_html(&comparison);
+        assert!(html.contains("<strong>Platform mismatch:</strong>"));
+        assert!(!render_comparison_markdown(&self::comparison(), &[]).contains("[!WARNING]"));
+    }
+
+    #[test]
+    fn stars_follow_p_values() {
+        let t = |p_value| DecisionRule::WelchTTest {
+            p_value,
+            alpha: 0.05,
+        };
+        assert_eq!(stars(&t(0.0005)), "***");
+        assert_eq!(stars(&t(0.005)), "**");
+        assert_eq!(stars(&t(0.03)), "*");
+        assert_eq!(stars(&t(0.2)), "");
+        assert_eq!(stars(&DecisionRule::Threshold { threshold: 0.05 }), "");
+    }
+
+    #[test]
+    fn html_has_expandable_details() {
+        let html = render_comparison_html(&comparison());
+        assert!(html.contains("<summary><span class=\"regression\">msm</span>"));
+        assert!(html.contains("<summary><span class=\"improvement\">fft</span>"));
+        assert!(html.contains("100 ms [99 ms – 101 ms]"));
+        assert!(html.contains("confidence intervals disjoint"));
+        assert!(html.contains("<li>&lt;new&gt;</li>"));
+        assert_eq!(html.matches("<details>").count(), 2);
+    }
+}
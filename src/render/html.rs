@@ -0,0 +1,620 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Self-contained HTML report rendering.
+//!
+//! The output is a single file with inline CSS, inline SVG charts and a
+//! few lines of inline JavaScript for table sorting, so it can be uploaded
+//! as a CI artifact and opened without a web server or network access.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use super::{format_test_vectors, has_test_vectors};
+use crate::digest::DigestFormat;
+use crate::schema::{BenchmarkReport, BenchmarkResult, MetricValue};
+use crate::statistics::quantile;
+use crate::sweep::{Sweep, extract_sweeps};
+use crate::units::{format_ci, format_metric, to_base};
+
+const PALETTE: &[&str] = &[
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1",
+];
+
+pub(super) const STYLE: &str = r#"body { font-family: system-ui, sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; margin-bottom: 1.5em; }
+th, td { padding: 4px 10px; border-bottom: 1px solid #ddd; }
+th { cursor: pointer; text-align: left; background: #f5f5f5; user-select: none; }
+td.num { text-align: right; font-variant-numeric: tabular-nums; }
+.meta { color: #666; font-size: 0.9em; }
+figure { margin: 0 0 1.5em 0; }
+figcaption { font-weight: 600; margin-bottom: 4px; }
+svg text { font-size: 11px; fill: #444; }"#;
+
+pub(super) const SCRIPT: &str = r#"document.querySelectorAll('table.sortable th').forEach(function (th) {
+  th.addEventListener('click', function () {
+    var body = th.closest('table').tBodies[0], col = th.cellIndex;
+    var asc = th.dataset.order !== 'asc';
+    th.dataset.order = asc ? 'asc' : 'desc';
+    function key(row) {
+      var v = row.cells[col].dataset.value;
+      return v === undefined ? null : parseFloat(v);
+    }
+    Array.from(body.rows).sort(function (a, b) {
+      var x = key(a), y = key(b), r;
+      if (x === null && y === null) {
+        r = a.cells[col].textContent.localeCompare(b.cells[col].textContent);
+      } else if (x === null || y === null) {
+        return x === null ? 1 : -1;
+      } else {
+        r = x - y;
+      }
+      return asc ? r : -r;
+    }).forEach(function (row) { body.appendChild(row); });
+  });
+});"#;
+
+/// Renders one or more reports as a single self-contained HTML page.
+///
+/// The page contains a sortable results table per report, a latency
+/// distribution chart per benchmark (box plots when raw samples are
+/// present, confidence intervals otherwise) comparing all reports, and a
+/// log-log scaling curve for every parameter sweep found in the reports.
+/// Reports whose benchmarks recorded test vectors get a column with their
+/// [short](DigestFormat::Short) output hash.
+pub fn render_html(reports: &[BenchmarkReport]) -> String {
+    render_html_with(reports, DigestFormat::Short)
+}
+
+/// Like [`render_html`], with output hashes written in `digest_format`.
+pub fn render_html_with(reports: &[BenchmarkReport], digest_format: DigestFormat) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>zkbench report</title>\n");
+    let _ = writeln!(out, "<style>\n{}\n</style>\n</head>\n<body>", STYLE);
+    out.push_str("<h1>Benchmark report</h1>\n");
+
+    for report in reports {
+        render_report_table(&mut out, report, digest_format);
+    }
+
+    let distributions = render_distributions(reports);
+    if !distributions.is_empty() {
+        out.push_str("<h2>Latency distributions</h2>\n");
+        out.push_str(&distributions);
+    }
+
+    let sweeps = render_sweeps(reports);
+    if !sweeps.is_empty() {
+        out.push_str("<h2>Parameter sweeps</h2>\n");
+        out.push_str(&sweeps);
+    }
+
+    let _ = writeln!(out, "<script>\n{}\n</script>\n</body>\n</html>", SCRIPT);
+    out
+}
+
+fn label(report: &BenchmarkReport) -> String {
+    format!(
+        "{} {}",
+        report.metadata.implementation, report.metadata.version
+    )
+}
+
+fn render_report_table(out: &mut String, report: &BenchmarkReport, digest_format: DigestFormat) {
+    let hashes = has_test_vectors(report);
+    let meta = &report.metadata;
+    let platform = &meta.platform;
+    let _ = writeln!(out, "<h2>{}</h2>", escape(&label(report)));
+    let _ = writeln!(
+        out,
+        "<p class=\"meta\">commit {} · {} · {}/{} · {} CPUs{}</p>",
+        escape(&meta.commit_sha),
+        escape(&meta.timestamp),
+        escape(&platform.os),
+        escape(&platform.arch),
+        platform.cpu_count,
+        platform
+            .cpu_vendor
+            .as_deref()
+            .map(|cpu| format!(" · {}", escape(cpu)))
+            .unwrap_or_default(),
+    );
+    out.push_str("<table class=\"sortable\">\n<thead><tr><th>Benchmark</th><th>Latency</th>");
+    out.push_str("<th>Throughput</th><th>Memory</th><th>±CI</th><th>Iterations</th>");
+    if hashes {
+        out.push_str("<th>Output hash</th>");
+    }
+    out.push_str("</tr></thead>\n");
+    out.push_str("<tbody>\n");
+    for (name, result) in report.sorted_benchmarks() {
+        let _ = write!(out, "<tr><td>{}</td>", escape(name));
+        metric_cell(out, result.latency.as_ref());
+        metric_cell(out, result.throughput.as_ref());
+        metric_cell(out, result.memory.as_ref());
+        match result.latency.as_ref().and_then(format_ci) {
+            Some(ci) => {
+                let latency = result.latency.as_ref().expect("ci implies latency");
+                let width = latency.upper_value.unwrap_or_default()
+                    - latency.lower_value.unwrap_or_default();
+                let _ = write!(
+                    out,
+                    "<td class=\"num\" data-value=\"{}\">{}</td>",
+                    to_base(width, &latency.unit),
+                    ci
+                );
+            }
+            None => out.push_str("<td class=\"num\">-</td>"),
+        }
+        if result.iterations > 0 {
+            let _ = write!(
+                out,
+                "<td class=\"num\" data-value=\"{0}\">{0}</td>",
+                result.iterations
+            );
+        } else {
+            out.push_str("<td class=\"num\">-</td>");
+        }
+        if hashes {
+            let hash = result
+                .test_vectors
+                .as_ref()
+                .map(|vectors| format_test_vectors(vectors, digest_format));
+            let _ = write!(
+                out,
+                "<td><code>{}</code></td>",
+                escape(hash.as_deref().unwrap_or("-"))
+            );
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</tbody>\n</table>\n");
+}
+
+fn metric_cell(out: &mut String, metric: Option<&MetricValue>) {
+    match metric {
+        Some(metric) => {
+            let _ = write!(
+                out,
+                "<td class=\"num\" data-value=\"{}\">{}</td>",
+                to_base(metric.value, &metric.unit),
+                format_metric(metric)
+            );
+        }
+        None => out.push_str("<td class=\"num\">-</td>"),
+    }
+}
+
+/// Summary of one report's latency for a single benchmark, in a shared
+/// reference unit.
+struct Distribution {
+    label: String,
+    value: f64,
+    interval: Option<(f64, f64)>,
+    /// min, q1, median, q3, max of the raw samples.
+    box_plot: Option<[f64; 5]>,
+}
+
+impl Distribution {
+    fn from_result(label: String, result: &BenchmarkResult, unit: &str) -> Option<Self> {
+        let latency = result.latency.as_ref()?;
+        let factor = to_base(1.0, &latency.unit) / to_base(1.0, unit);
+        let box_plot = (!result.samples.is_empty()).then(|| {
+            let mut sorted: Vec<f64> = result.samples.iter().map(|s| s * factor).collect();
+            sorted.sort_by(f64::total_cmp);
+            [0.0, 0.25, 0.5, 0.75, 1.0].map(|q| quantile(&sorted, q))
+        });
+        Some(Self {
+            label,
+            value: latency.value * factor,
+            interval: latency
+                .lower_value
+                .zip(latency.upper_value)
+                .map(|(lo, hi)| (lo * factor, hi * factor)),
+            box_plot,
+        })
+    }
+
+    fn extent(&self) -> (f64, f64) {
+        let mut lo = self.value;
+        let mut hi = self.value;
+        for v in self
+            .interval
+            .iter()
+            .flat_map(|(a, b)| [*a, *b])
+            .chain(self.box_plot.iter().flatten().copied())
+        {
+            lo = lo.min(v);
+            hi = hi.max(v);
+        }
+        (lo, hi)
+    }
+}
+
+fn render_distributions(reports: &[BenchmarkReport]) -> String {
+    let names: BTreeSet<&String> = reports.iter().flat_map(|r| r.benchmarks.keys()).collect();
+    let mut out = String::new();
+    for name in names {
+        let Some(unit) = reports
+            .iter()
+            .find_map(|r| r.benchmarks.get(name)?.latency.as_ref())
+            .map(|latency| latency.unit.clone())
+        else {
+            continue;
+        };
+        let rows: Vec<Distribution> = reports
+            .iter()
+            .filter_map(|r| Distribution::from_result(label(r), r.benchmarks.get(name)?, &unit))
+            .collect();
+        let _ = writeln!(
+            out,
+            "<figure><figcaption>{}</figcaption>\n{}</figure>",
+            escape(name),
+            distribution_svg(&rows, &unit)
+        );
+    }
+    out
+}
+
+fn distribution_svg(rows: &[Distribution], unit: &str) -> String {
+    const WIDTH: f64 = 640.0;
+    const LEFT: f64 = 160.0;
+    const RIGHT: f64 = 20.0;
+    const ROW: f64 = 26.0;
+    const AXIS: f64 = 24.0;
+
+    let (mut lo, mut hi) = rows
+        .iter()
+        .map(Distribution::extent)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (a, b)| {
+            (lo.min(a), hi.max(b))
+        });
+    let pad = ((hi - lo) * 0.05).max(hi.abs() * 0.01).max(f64::EPSILON);
+    lo -= pad;
+    hi += pad;
+    let x = |v: f64| LEFT + (v - lo) / (hi - lo) * (WIDTH - LEFT - RIGHT);
+    let height = rows.len() as f64 * ROW + AXIS;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{height}\" viewBox=\"0 0 {WIDTH} {height}\">"
+    );
+    for (i, row) in rows.iter().enumerate() {
+        let color = PALETTE[i % PALETTE.len()];
+        let cy = i as f64 * ROW + ROW / 2.0;
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>",
+            LEFT - 8.0,
+            cy + 4.0,
+            escape(&row.label)
+        );
+        if let Some([min, q1, median, q3, max]) = row.box_plot {
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{:.1}\" y1=\"{cy}\" x2=\"{:.1}\" y2=\"{cy}\" stroke=\"{color}\"/>",
+                x(min),
+                x(max)
+            );
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{:.1}\" y=\"{}\" width=\"{:.1}\" height=\"{}\" fill=\"{color}\" fill-opacity=\"0.35\" stroke=\"{color}\"/>",
+                x(q1),
+                cy - ROW / 3.0,
+                (x(q3) - x(q1)).max(1.0),
+                ROW * 2.0 / 3.0
+            );
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{0:.1}\" y1=\"{1}\" x2=\"{0:.1}\" y2=\"{2}\" stroke=\"{color}\" stroke-width=\"2\"/>",
+                x(median),
+                cy - ROW / 3.0,
+                cy + ROW / 3.0
+            );
+        } else if let Some((lower, upper)) = row.interval {
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{:.1}\" y1=\"{cy}\" x2=\"{:.1}\" y2=\"{cy}\" stroke=\"{color}\" stroke-width=\"2\"/>",
+                x(lower),
+                x(upper)
+            );
+        }
+        let _ = writeln!(
+            svg,
+            "<circle cx=\"{:.1}\" cy=\"{cy}\" r=\"3.5\" fill=\"{color}\"><title>{}</title></circle>",
+            x(row.value),
+            format_metric(&MetricValue::new(row.value, unit))
+        );
+    }
+    let axis_y = rows.len() as f64 * ROW;
+    let _ = writeln!(
+        svg,
+        "<line x1=\"{LEFT}\" y1=\"{axis_y}\" x2=\"{}\" y2=\"{axis_y}\" stroke=\"#999\"/>",
+        WIDTH - RIGHT
+    );
+    for i in 0..=4 {
+        let v = lo + (hi - lo) * i as f64 / 4.0;
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.1}\" y=\"{}\" text-anchor=\"middle\">{}</text>",
+            x(v),
+            axis_y + 16.0,
+            format_metric(&MetricValue::new(v, unit))
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn render_sweeps(reports: &[BenchmarkReport]) -> String {
+    let mut by_name: BTreeMap<String, Vec<(String, Sweep)>> = BTreeMap::new();
+    for report in reports {
+        for sweep in extract_sweeps(report) {
+            by_name
+                .entry(sweep.name.clone())
+                .or_default()
+                .push((label(report), sweep));
+        }
+    }
+    let mut out = String::new();
+    for (name, series) in &by_name {
+        let _ = writeln!(
+            out,
+            "<figure><figcaption>{}</figcaption>\n{}</figure>",
+            escape(name),
+            sweep_svg(series)
+        );
+    }
+    out
+}
+
+fn sweep_svg(series: &[(String, Sweep)]) -> String {
+    const WIDTH: f64 = 640.0;
+    const HEIGHT: f64 = 320.0;
+    const LEFT: f64 = 80.0;
+    const RIGHT: f64 = 150.0;
+    const TOP: f64 = 10.0;
+    const BOTTOM: f64 = 30.0;
+
+    // Every series is converted to the unit of the first point and plotted
+    // on log-log axes.
+    let unit = series[0].1.points[0].latency.unit.clone();
+    let points: Vec<Vec<(f64, f64)>> = series
+        .iter()
+        .map(|(_, sweep)| {
+            sweep
+                .points
+                .iter()
+                .filter(|p| p.parameter > 0.0 && p.latency.value > 0.0)
+                .map(|p| {
+                    let value = to_base(p.latency.value, &p.latency.unit) / to_base(1.0, &unit);
+                    (p.parameter.log2(), value.log10())
+                })
+                .collect()
+        })
+        .collect();
+    let (x_lo, x_hi, y_lo, y_hi) = points.iter().flatten().fold(
+        (
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ),
+        |(a, b, c, d), (x, y)| (a.min(*x), b.max(*x), c.min(*y), d.max(*y)),
+    );
+    let x_span = (x_hi - x_lo).max(1.0);
+    let y_pad = ((y_hi - y_lo) * 0.05).max(0.05);
+    let (y_lo, y_hi) = (y_lo - y_pad, y_hi + y_pad);
+    let px = |v: f64| LEFT + (v - x_lo) / x_span * (WIDTH - LEFT - RIGHT);
+    let py = |v: f64| TOP + (y_hi - v) / (y_hi - y_lo) * (HEIGHT - TOP - BOTTOM);
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" viewBox=\"0 0 {WIDTH} {HEIGHT}\">"
+    );
+    let _ = writeln!(
+        svg,
+        "<rect x=\"{LEFT}\" y=\"{TOP}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"#999\"/>",
+        WIDTH - LEFT - RIGHT,
+        HEIGHT - TOP - BOTTOM
+    );
+
+    let params: BTreeSet<u64> = series
+        .iter()
+        .flat_map(|(_, sweep)| sweep.points.iter().map(|p| p.parameter.to_bits()))
+        .collect();
+    for bits in params {
+        let parameter = f64::from_bits(bits);
+        if parameter <= 0.0 {
+            continue;
+        }
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.1}\" y=\"{}\" text-anchor=\"middle\">{}</text>",
+            px(parameter.log2()),
+            HEIGHT - BOTTOM + 16.0,
+            parameter_label(parameter)
+        );
+    }
+    for i in 0..=4 {
+        let v = y_lo + (y_hi - y_lo) * i as f64 / 4.0;
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+            LEFT - 6.0,
+            py(v) + 4.0,
+            format_metric(&MetricValue::new(10f64.powf(v), &unit))
+        );
+    }
+
+    for (i, ((label, _), line)) in series.iter().zip(&points).enumerate() {
+        let color = PALETTE[i % PALETTE.len()];
+        let path: Vec<String> = line
+            .iter()
+            .map(|(x, y)| format!("{:.1},{:.1}", px(*x), py(*y)))
+            .collect();
+        let _ = writeln!(
+            svg,
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"{color}\" stroke-width=\"2\"/>",
+            path.join(" ")
+        );
+        for (x, y) in line {
+            let _ = writeln!(
+                svg,
+                "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"3\" fill=\"{color}\"><title>{} @ {}: {}</title></circle>",
+                px(*x),
+                py(*y),
+                escape(label),
+                parameter_label(2f64.powf(*x)),
+                format_metric(&MetricValue::new(10f64.powf(*y), &unit))
+            );
+        }
+        let ly = TOP + 14.0 + i as f64 * 16.0;
+        let lx = WIDTH - RIGHT + 12.0;
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{lx}\" y=\"{}\" width=\"10\" height=\"10\" fill=\"{color}\"/><text x=\"{}\" y=\"{ly}\">{}</text>",
+            ly - 9.0,
+            lx + 14.0,
+            escape(label)
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Labels a sweep parameter as `2^k` when it is an exact power of two.
+fn parameter_label(parameter: f64) -> String {
+    let exp = parameter.log2().round();
+    if exp >= 1.0 && (2f64.powf(exp) - parameter).abs() < 1e-6 * parameter {
+        format!("2^{}", exp as i64)
+    } else {
+        format!("{}", parameter.round())
+    }
+}
+
+pub(super) fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::HashAlgorithm;
+    use crate::schema::TestVectors;
+    use crate::testing::ReportBuilder;
+
+    fn latency(value: f64) -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::new(value, "ms")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn page_is_self_contained() {
+        let html = render_html(&[ReportBuilder::new()
+            .implementation("a")
+            .results(vec![("prove", latency(12.0))])
+            .build()]);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert!(html.contains("<script>"));
+        assert!(!html.contains("src=\"http"));
+        assert!(!html.contains("href=\"http"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+
+    #[test]
+    fn table_cells_carry_sort_keys() {
+        let html = render_html(&[ReportBuilder::new()
+            .implementation("a")
+            .results(vec![("prove", latency(12.0))])
+            .build()]);
+        assert!(html.contains("<table class=\"sortable\">"));
+        assert!(html.contains("data-value=\"12000000\">12 ms</td>"));
+    }
+
+    #[test]
+    fn output_hash_column_only_with_test_vectors() {
+        let html = render_html(&[ReportBuilder::new()
+            .implementation("a")
+            .results(vec![("prove", latency(12.0))])
+            .build()]);
+        assert!(!html.contains("Output hash"));
+
+        let mut result = latency(12.0);
+        let vectors = TestVectors::compute(HashAlgorithm::Sha256, b"in", b"out");
+        result.test_vectors = Some(vectors.clone());
+        let reports = [ReportBuilder::new()
+            .implementation("a")
+            .results(vec![("prove", result)])
+            .build()];
+        let base58 = vectors.output_hash.format(DigestFormat::Base58);
+        assert!(render_html(&reports).contains("<th>Output hash</th>"));
+        assert!(
+            render_html_with(&reports, DigestFormat::Base58)
+                .contains(&format!("<td><code>{} (unverified)</code></td>", base58))
+        );
+    }
+
+    #[test]
+    fn distributions_use_samples_when_present() {
+        let mut result = latency(10.0);
+        result.samples = vec![9.0, 10.0, 11.0, 12.0];
+        let html = render_html(&[ReportBuilder::new()
+            .implementation("a")
+            .results(vec![("prove", result)])
+            .build()]);
+        assert!(html.contains("Latency distributions"));
+        assert!(html.contains("<rect"));
+    }
+
+    #[test]
+    fn sweeps_render_one_series_per_report() {
+        let entries = || vec![("msm/2^10", latency(1.0)), ("msm/2^12", latency(4.0))];
+        let html = render_html(&[
+            ReportBuilder::new()
+                .implementation("a")
+                .results(entries())
+                .build(),
+            ReportBuilder::new()
+                .implementation("b")
+                .results(entries())
+                .build(),
+        ]);
+        assert!(html.contains("Parameter sweeps"));
+        assert_eq!(html.matches("<polyline").count(), 2);
+        assert!(html.contains(">2^10</text>"));
+    }
+
+    #[test]
+    fn names_are_escaped() {
+        let html = render_html(&[ReportBuilder::new()
+            .implementation("<impl>")
+            .results(vec![("a<b>", latency(1.0))])
+            .build()]);
+        assert!(html.contains("a&lt;b&gt;"));
+        assert!(html.contains("&lt;impl&gt;"));
+        assert!(!html.contains("<b>"));
+    }
+
+    #[test]
+    fn parameter_labels() {
+        assert_eq!(parameter_label(1024.0), "2^10");
+        assert_eq!(parameter_label(1000.0), "1000");
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Markdown and HTML rendering of leaderboards.
+
+use std::fmt::Write;
+
+use super::html::{SCRIPT, STYLE, escape};
+use crate::leaderboard::Leaderboard;
+use crate::units::format_metric;
+
+/// Renders a leaderboard as GitHub-flavored markdown: an overall table
+/// followed by one table per benchmark.
+pub fn render_leaderboard_markdown(leaderboard: &Leaderboard) -> String {
+    let cell = |text: &str| text.replace('|', "\\|");
+    let mut out = String::new();
+    let _ = writeln!(out, "## Overall ({})\n", leaderboard.metric);
+    out.push_str("| Rank | Implementation | Version | Score | Wins | Benchmarks |\n");
+    out.push_str("|-----:|----------------|---------|------:|-----:|-----------:|\n");
+    for entry in &leaderboard.overall {
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {:.2}× | {} | {} |",
+            entry.rank,
+            cell(&entry.implementation),
+            cell(&entry.version),
+            entry.score,
+            entry.wins,
+            entry.benchmarks
+        );
+    }
+    for ranking in &leaderboard.benchmarks {
+        let _ = writeln!(out, "\n### {}\n", cell(&ranking.benchmark));
+        out.push_str("| Rank | Implementation | Version | Value | vs. best |\n");
+        out.push_str("|-----:|----------------|---------|------:|---------:|\n");
+        for entry in &ranking.entries {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} | {:.2}× |",
+                entry.rank,
+                cell(&entry.implementation),
+                cell(&entry.version),
+                format_metric(&entry.value),
+                entry.relative_to_best
+            );
+        }
+    }
+    out
+}
+
+/// Renders a leaderboard as a self-contained HTML page with sortable
+/// tables, styled like [`render_html`](crate::render_html).
+pub fn render_leaderboard_html(leaderboard: &Leaderboard) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>zkbench leaderboard</title>\n");
+    let _ = writeln!(out, "<style>\n{}\n</style>\n</head>\n<body>", STYLE);
+    let _ = writeln!(out, "<h1>Leaderboard ({})</h1>", leaderboard.metric);
+    out.push_str("<p class=\"meta\">Score: geometric mean of the factor behind the best ");
+    out.push_str("implementation over the benchmarks run; 1.00× is best everywhere.</p>\n");
+
+    out.push_str("<h2>Overall</h2>\n<table class=\"sortable\">\n<thead><tr><th>Rank</th>");
+    out.push_str("<th>Implementation</th><th>Version</th><th>Score</th><th>Wins</th>");
+    out.push_str("<th>Benchmarks</th></tr></thead>\n<tbody>\n");
+    for entry in &leaderboard.overall {
+        let _ = writeln!(
+            out,
+            "<tr><td class=\"num\" data-value=\"{0}\">{0}</td><td>{1}</td><td>{2}</td>\
+             <td class=\"num\" data-value=\"{3}\">{3:.2}×</td>\
+             <td class=\"num\" data-value=\"{4}\">{4}</td>\
+             <td class=\"num\" data-value=\"{5}\">{5}</td></tr>",
+            entry.rank,
+            escape(&entry.implementation),
+            escape(&entry.version),
+            entry.score,
+            entry.wins,
+            entry.benchmarks
+        );
+    }
+    out.push_str("</tbody>\n</table>\n");
+
+    for ranking in &leaderboard.benchmarks {
+        let _ = writeln!(out, "<h2>{}</h2>", escape(&ranking.benchmark));
+        out.push_str("<table class=\"sortable\">\n<thead><tr><th>Rank</th>");
+        out.push_str("<th>Implementation</th><th>Version</th><th>Value</th>");
+        out.push_str("<th>vs. best</th></tr></thead>\n<tbody>\n");
+        for entry in &ranking.entries {
+            let _ = writeln!(
+                out,
+                "<tr><td class=\"num\" data-value=\"{0}\">{0}</td><td>{1}</td><td>{2}</td>\
+                 <td class=\"num\" data-value=\"{3}\">{4}</td>\
+                 <td class=\"num\" data-value=\"{3}\">{3:.2}×</td></tr>",
+                entry.rank,
+                escape(&entry.implementation),
+                escape(&entry.version),
+                entry.relative_to_best,
+                format_metric(&entry.value)
+            );
+        }
+        out.push_str("</tbody>\n</table>\n");
+    }
+
+    let _ = writeln!(out, "<script>\n{}\n</script>\n</body>\n</html>", SCRIPT);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::leaderboard::LeaderboardBuilder;
+    use crate::testing::ReportBuilder;
+
+    fn leaderboard() -> Leaderboard {
+        let report = |implementation: &str, ms: f64| {
+            ReportBuilder::new()
+                .implementation(implementation)
+                .latencies_ms(&[("msm/2^16", ms)])
+                .build()
+        };
+        LeaderboardBuilder::new()
+            .with_reports([report("<fast>", 100.0), report("slow", 250.0)])
+            .build()
+    }
+
+    #[test]
+    fn markdown_tables() {
+        let md = render_leaderboard_markdown(&leaderboard());
+        assert!(md.starts_with("## Overall (latency)\n"));
+        assert!(md.contains("| 1 | <fast> | 1.0.0 | 1.00× | 1 | 1 |"));
+        assert!(md.contains("### msm/2^16"));
+        assert!(md.contains("| 2 | slow | 1.0.0 | 250 ms | 2.50× |"));
+    }
+
+    #[test]
+    fn html_is_escaped_and_sortable() {
+        let html = render_leaderboard_html(&leaderboard());
+        assert!(html.contains("<td>&lt;fast&gt;</td>"));
+        assert!(!html.contains("<fast>"));
+        assert!(html.contains("<table class=\"sortable\">"));
+        assert!(html.contains("data-value=\"2.5\">2.50×</td>"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+}
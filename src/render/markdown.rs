@@ -0,0 +1,185 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! GitHub-flavored markdown rendering.
+
+use std::fmt::Write;
+
+use super::{format_test_vectors, has_test_vectors};
+use crate::digest::DigestFormat;
+use crate::schema::{BenchmarkReport, MetricValue};
+use crate::units::{format_ci, format_metric};
+
+/// Renders a report as a GitHub-flavored markdown table, suitable for
+/// pasting into PR descriptions and release notes.
+///
+/// One row per benchmark (sorted by name) with latency, throughput and
+/// memory scaled to a readable unit, plus the latency confidence
+/// interval half-width when bounds are present. When benchmarks recorded
+/// test vectors, a last column shows their
+/// [short](DigestFormat::Short) output hash.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{BenchmarkReport, BenchmarkResult, Metadata, MetricValue, render_markdown};
+/// use std::collections::HashMap;
+///
+/// let mut benchmarks = HashMap::new();
+/// benchmarks.insert(
+///     "prove".to_string(),
+///     BenchmarkResult {
+///         latency: Some(MetricValue::new(1_500_000.0, "ns")),
+///         ..Default::default()
+///     },
+/// );
+/// let report = BenchmarkReport {
+///     metadata: Metadata::create("my-impl", "0.1.0"),
+///     benchmarks,
+/// };
+/// assert!(render_markdown(&report).contains("| prove | 1.5 ms |"));
+/// ```
+pub fn render_markdown(report: &BenchmarkReport) -> String {
+    render_markdown_with(report, DigestFormat::Short)
+}
+
+/// Like [`render_markdown`], with output hashes written in
+/// `digest_format`.
+pub fn render_markdown_with(report: &BenchmarkReport, digest_format: DigestFormat) -> String {
+    let hashes = has_test_vectors(report);
+    let mut out = String::new();
+    out.push_str("| Benchmark | Latency | Throughput | Memory | ±CI |");
+    out.push_str(if hashes { " Output hash |\n" } else { "\n" });
+    out.push_str("|-----------|--------:|-----------:|-------:|----:|");
+    out.push_str(if hashes { "-------------|\n" } else { "\n" });
+    for (name, result) in report.sorted_benchmarks() {
+        let ci = result.latency.as_ref().and_then(format_ci);
+        let _ = write!(
+            out,
+            "| {} | {} | {} | {} | {} |",
+            escape(name),
+            cell(result.latency.as_ref()),
+            cell(result.throughput.as_ref()),
+            cell(result.memory.as_ref()),
+            ci.as_deref().unwrap_or("-"),
+        );
+        if hashes {
+            let hash = result
+                .test_vectors
+                .as_ref()
+                .map(|vectors| format_test_vectors(vectors, digest_format));
+            let _ = write!(out, " {} |", hash.as_deref().unwrap_or("-"));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn cell(metric: Option<&MetricValue>) -> String {
+    metric.map(format_metric).unwrap_or_else(|| "-".to_string())
+}
+
+/// Escapes characters that would otherwise break the table layout.
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::HashAlgorithm;
+    use crate::schema::{BenchmarkResult, TestVectors};
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn header_and_rows_sorted() {
+        let md = render_markdown(
+            &ReportBuilder::new()
+                .results(vec![
+                    ("b", BenchmarkResult::default()),
+                    ("a", BenchmarkResult::default()),
+                ])
+                .build(),
+        );
+        let lines: Vec<_> = md.lines().collect();
+        assert_eq!(
+            lines[0],
+            "| Benchmark | Latency | Throughput | Memory | ±CI |"
+        );
+        assert!(lines[2].starts_with("| a |"));
+        assert!(lines[3].starts_with("| b |"));
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn metrics_are_scaled() {
+        let md = render_markdown(
+            &ReportBuilder::new()
+                .results(vec![(
+                    "msm",
+                    BenchmarkResult {
+                        latency: Some(MetricValue::with_bounds(
+                            123_456_789.0,
+                            "ns",
+                            120_000_000.0,
+                            126_000_000.0,
+                        )),
+                        throughput: Some(MetricValue::new(8300.0, "ops/s")),
+                        memory: Some(MetricValue::new(2048.0, "KB")),
+                        ..Default::default()
+                    },
+                )])
+                .build(),
+        );
+        assert!(md.contains("| msm | 123.5 ms | 8.3 Kops/s | 2 MiB | ±3.46 ms |"));
+    }
+
+    #[test]
+    fn missing_metrics_render_as_dash() {
+        let md = render_markdown(
+            &ReportBuilder::new()
+                .results(vec![("empty", BenchmarkResult::default())])
+                .build(),
+        );
+        assert!(md.contains("| empty | - | - | - | - |"));
+    }
+
+    #[test]
+    fn output_hashes_follow_the_digest_format() {
+        let vectors = TestVectors::compute(HashAlgorithm::Sha256, b"in", b"out");
+        let hex = vectors.output_hash.to_string();
+        let report = ReportBuilder::new()
+            .results(vec![
+                (
+                    "a",
+                    BenchmarkResult {
+                        test_vectors: Some(vectors.verify(b"in", b"out").unwrap()),
+                        ..Default::default()
+                    },
+                ),
+                ("b", BenchmarkResult::default()),
+            ])
+            .build();
+        let md = render_markdown(&report);
+        assert!(
+            md.starts_with("| Benchmark | Latency | Throughput | Memory | ±CI | Output hash |\n")
+        );
+        assert!(md.contains(&format!(
+            "| a | - | - | - | - | {} (verified) |",
+            &hex[..12]
+        )));
+        assert!(md.contains("| b | - | - | - | - | - |\n"));
+        let md = render_markdown_with(&report, DigestFormat::Prefixed);
+        assert!(md.contains(&format!("| 0x{} (verified) |", hex)));
+    }
+
+    #[test]
+    fn pipes_in_names_are_escaped() {
+        let md = render_markdown(
+            &ReportBuilder::new()
+                .results(vec![("a|b", BenchmarkResult::default())])
+                .build(),
+        );
+        assert!(md.contains("| a\\|b |"));
+    }
+}
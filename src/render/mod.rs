@@ -0,0 +1,51 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Human-readable renderings of benchmark reports.
+//!
+//! All renderers format values like [`format_value`](crate::format_value),
+//! so a metric reads the same in every output, and digests in the
+//! [`DigestFormat`] passed to them, [`Short`](DigestFormat::Short) by
+//! default.
+
+mod annotations;
+mod comparison;
+mod html;
+mod leaderboard;
+mod markdown;
+mod pr_comment;
+mod terminal;
+
+pub use annotations::render_github_annotations;
+pub use comparison::{render_comparison_html, render_comparison_markdown};
+pub use html::{render_html, render_html_with};
+pub use leaderboard::{render_leaderboard_html, render_leaderboard_markdown};
+pub use markdown::{render_markdown, render_markdown_with};
+pub use pr_comment::{PR_COMMENT_MARKER, render_pr_comment};
+pub use terminal::{
+    print_summary, render_comparison_terminal, render_terminal, render_terminal_sorted,
+    render_trends_terminal, sparkline,
+};
+
+use crate::digest::DigestFormat;
+use crate::schema::{BenchmarkReport, TestVectors};
+
+/// Whether any benchmark of the report recorded test vectors, i.e.
+/// whether its table needs an output hash column.
+pub(crate) fn has_test_vectors(report: &BenchmarkReport) -> bool {
+    report.benchmarks.values().any(|r| r.test_vectors.is_some())
+}
+
+/// The output digest of test vectors and whether it was verified, e.g.
+/// `3c4d5e6f7a8b (verified)`.
+pub(crate) fn format_test_vectors(vectors: &TestVectors, format: DigestFormat) -> String {
+    format!(
+        "{} ({})",
+        vectors.output_hash.format(format),
+        if vectors.verified {
+            "verified"
+        } else {
+            "unverified"
+        }
+    )
+}
@@ -0,0 +1,103 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Markdown body for a pull-request benchmark comment.
+
+use std::fmt::Write;
+
+use super::{render_comparison_markdown, render_markdown};
+use crate::compare::{CompareOptions, compare};
+use crate::schema::BenchmarkReport;
+
+/// Hidden marker at the top of every comment, so CI bots can find and
+/// update their previous comment instead of posting a new one.
+pub const PR_COMMENT_MARKER: &str = "<!-- zkbench-pr-comment -->";
+
+/// Renders the markdown body of a PR comment comparing a PR report
+/// against a baseline.
+///
+/// The body contains the latency rows of
+/// [`render_comparison_markdown`](crate::render_comparison_markdown), the
+/// renamed, added and removed benchmarks, and both raw reports in a
+/// collapsible `<details>` block. Significance is decided by
+/// [`compare`](crate::compare): a t-test on samples, non-overlapping
+/// confidence intervals, or, without either, a change beyond `threshold`
+/// (a fraction: `0.05` is 5%).
+pub fn render_pr_comment(
+    baseline: &BenchmarkReport,
+    candidate: &BenchmarkReport,
+    threshold: f64,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{PR_COMMENT_MARKER}");
+    let _ = writeln!(
+        out,
+        "## Benchmark results\n\nComparing `{}` (PR) against `{}` (baseline).\n",
+        candidate.metadata.commit_sha, baseline.metadata.commit_sha
+    );
+
+    let opts = CompareOptions {
+        threshold,
+        ..Default::default()
+    };
+    let comparison = compare(baseline, candidate, &opts);
+    out.push_str(&render_comparison_markdown(&comparison, &["latency"]));
+
+    out.push_str("\n<details>\n<summary>Raw results</summary>\n\n");
+    out.push_str("#### PR\n\n");
+    out.push_str(&render_markdown(candidate));
+    out.push_str("\n#### Baseline\n\n");
+    out.push_str(&render_markdown(baseline));
+    out.push_str("\n</details>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::MetricValue;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn markers_follow_significance() {
+        let baseline = ReportBuilder::new()
+            .latencies([
+                ("slower", MetricValue::with_bounds(100.0, "ms", 98.0, 102.0)),
+                ("noisy", MetricValue::with_bounds(100.0, "ms", 80.0, 120.0)),
+                ("faster", MetricValue::new(100.0, "ms")),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies([
+                (
+                    "slower",
+                    MetricValue::with_bounds(110.0, "ms", 108.0, 112.0),
+                ),
+                ("noisy", MetricValue::with_bounds(110.0, "ms", 90.0, 130.0)),
+                ("faster", MetricValue::new(80.0, "ms")),
+            ])
+            .build();
+        let body = render_pr_comment(&baseline, &candidate, 0.05);
+        assert!(body.starts_with(PR_COMMENT_MARKER));
+        assert!(body.contains("| slower | latency | 100 ms | 110 ms | +10.0% ▲* |"));
+        assert!(body.contains("| noisy | latency | 100 ms | 110 ms | +10.0% |"));
+        assert!(body.contains("| faster | latency | 100 ms | 80 ms | -20.0% ▼ |"));
+        assert!(body.contains("**1** regression(s), **1** improvement(s)."));
+    }
+
+    #[test]
+    fn lists_added_removed_and_raw_details() {
+        let baseline = ReportBuilder::new()
+            .latencies([("old", MetricValue::new(1.0, "ms"))])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies([("new", MetricValue::new(1.0, "ms"))])
+            .build();
+        let body = render_pr_comment(&baseline, &candidate, 0.05);
+        assert!(body.contains("No metrics to compare."));
+        assert!(body.contains("**Added:** `new`"));
+        assert!(body.contains("**Removed:** `old`"));
+        assert!(body.contains("<details>\n<summary>Raw results</summary>"));
+        assert!(body.trim_end().ends_with("</details>"));
+    }
+}
@@ -0,0 +1,508 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Colored terminal summary table.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal, Write};
+
+use super::comparison::change_cell;
+use crate::compare::{Classification, CompareOptions, Comparison, MetricDelta, compare};
+use crate::schema::{BenchmarkReport, BenchmarkResult, MetricKind, MetricValue};
+use crate::trend::Trend;
+use crate::units::{format_ci, format_metric, format_value, to_base};
+
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// Renders a report as an aligned plain-text table for the terminal.
+///
+/// One row per benchmark (sorted by name) with latency, its confidence
+/// interval half-width, throughput and memory. With a baseline, a
+/// `Change` column shows the relative latency change; significant changes
+/// (see [`compare`](crate::compare) for the rule and `threshold`) are
+/// colored red for slowdowns and green for speedups when `color` is set,
+/// and a summary line counts them.
+pub fn render_terminal(
+    report: &BenchmarkReport,
+    baseline: Option<&BenchmarkReport>,
+    threshold: f64,
+    color: bool,
+) -> String {
+    render_rows(
+        report,
+        report.sorted_benchmarks(),
+        baseline,
+        threshold,
+        color,
+    )
+}
+
+/// Renders a report like [`render_terminal`], with the rows ordered by a
+/// metric instead of by name: ascending, or descending if `descending`
+/// is set. Values in different units of a family are compared in the
+/// family's base unit; benchmarks without the metric come last.
+pub fn render_terminal_sorted(
+    report: &BenchmarkReport,
+    baseline: Option<&BenchmarkReport>,
+    threshold: f64,
+    sort_by: MetricKind,
+    descending: bool,
+    color: bool,
+) -> String {
+    let mut benchmarks = report.sorted_benchmarks();
+    benchmarks.sort_by(|(_, a), (_, b)| {
+        let value = |result| {
+            sort_by
+                .get(result)
+                .map(|metric| to_base(metric.value, &metric.unit))
+        };
+        match (value(a), value(b)) {
+            (Some(a), Some(b)) if descending => b.total_cmp(&a),
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        }
+    });
+    render_rows(report, benchmarks, baseline, threshold, color)
+}
+
+fn render_rows(
+    report: &BenchmarkReport,
+    benchmarks: Vec<(&String, &BenchmarkResult)>,
+    baseline: Option<&BenchmarkReport>,
+    threshold: f64,
+    color: bool,
+) -> String {
+    let opts = CompareOptions {
+        threshold,
+        ..Default::default()
+    };
+    let comparison = baseline.map(|baseline| compare(baseline, report, &opts));
+    let deltas: HashMap<&str, &MetricDelta> = comparison
+        .iter()
+        .flat_map(|c| c.deltas())
+        .filter(|(_, delta)| delta.metric == "latency")
+        .collect();
+
+    let mut header = vec!["Benchmark", "Latency", "±CI", "Throughput", "Memory"];
+    if baseline.is_some() {
+        header.push("Change");
+    }
+    let (mut regressions, mut improvements) = (0, 0);
+    let mut rows: Vec<Vec<(String, Option<&str>)>> = Vec::new();
+    for (name, result) in benchmarks {
+        let mut row = vec![
+            (name.clone(), None),
+            (cell(result.latency.as_ref()), None),
+            (
+                result
+                    .latency
+                    .as_ref()
+                    .and_then(format_ci)
+                    .unwrap_or_else(|| "-".to_string()),
+                Some(DIM),
+            ),
+            (cell(result.throughput.as_ref()), None),
+            (cell(result.memory.as_ref()), None),
+        ];
+        if let Some(baseline) = baseline {
+            row.push(match deltas.get(name.as_str()) {
+                Some(delta) => {
+                    let style = match delta.classification {
+                        Classification::Regression => {
+                            regressions += 1;
+                            RED
+                        }
+                        Classification::Improvement => {
+                            improvements += 1;
+                            GREEN
+                        }
+                        Classification::Unchanged | Classification::Inconclusive => DIM,
+                    };
+                    (format!("{:+.1}%", delta.change * 100.0), Some(style))
+                }
+                None if !baseline.benchmarks.contains_key(name) => ("new".to_string(), Some(DIM)),
+                None => ("-".to_string(), Some(DIM)),
+            });
+        }
+        rows.push(row);
+    }
+
+    let paint = |text: String, style: Option<&str>| paint(text, style, color);
+    let mut out = table(&header, rows, 1, color);
+    if baseline.is_some() {
+        let _ = writeln!(
+            out,
+            "\n{} benchmark(s), {} {}, {} {}",
+            report.benchmarks.len(),
+            regressions,
+            paint(
+                "regression(s)".to_string(),
+                Some(RED).filter(|_| regressions > 0)
+            ),
+            improvements,
+            paint(
+                "improvement(s)".to_string(),
+                Some(GREEN).filter(|_| improvements > 0)
+            ),
+        );
+    }
+    out
+}
+
+/// Prints [`render_terminal`] to stdout, with colors when stdout is a
+/// terminal and `NO_COLOR` is not set. Call it when a run completes to get
+/// immediate feedback.
+pub fn print_summary(
+    report: &BenchmarkReport,
+    baseline: Option<&BenchmarkReport>,
+    threshold: f64,
+) -> io::Result<()> {
+    let stdout = io::stdout();
+    let color = stdout.is_terminal() && std::env::var_os("NO_COLOR").is_none();
+    stdout
+        .lock()
+        .write_all(render_terminal(report, baseline, threshold, color).as_bytes())
+}
+
+/// Renders a comparison as a colored diff table for the terminal.
+///
+/// One row per metric of each benchmark in both reports, with both
+/// values and the change (marked like
+/// [`render_comparison_markdown`](crate::render_comparison_markdown));
+/// regressions are red and improvements green when `color` is set. A
+/// summary line, a platform-mismatch warning and the added and removed
+/// benchmarks follow the table.
+pub fn render_comparison_terminal(comparison: &Comparison, color: bool) -> String {
+    let header = ["Benchmark", "Metric", "Baseline", "Candidate", "Change"];
+    let rows = comparison
+        .deltas()
+        .map(|(name, delta)| {
+            let style = match delta.classification {
+                Classification::Regression => RED,
+                Classification::Improvement => GREEN,
+                Classification::Unchanged | Classification::Inconclusive => DIM,
+            };
+            vec![
+                (name.to_string(), None),
+                (delta.metric.clone(), None),
+                (format_metric(&delta.baseline), None),
+                (format_metric(&delta.candidate), None),
+                (change_cell(delta), Some(style)),
+            ]
+        })
+        .collect();
+    let mut out = table(&header, rows, 2, color);
+    let (regressions, improvements) = (
+        comparison.regressions().count(),
+        comparison.improvements().count(),
+    );
+    let _ = writeln!(
+        out,
+        "\n{} benchmark(s), {} {}, {} {}",
+        comparison.benchmarks.len(),
+        regressions,
+        paint(
+            "regression(s)".to_string(),
+            Some(RED).filter(|_| regressions > 0),
+            color
+        ),
+        improvements,
+        paint(
+            "improvement(s)".to_string(),
+            Some(GREEN).filter(|_| improvements > 0),
+            color
+        ),
+    );
+    if let Some(mismatch) = &comparison.platform_mismatch {
+        let _ = writeln!(
+            out,
+            "{}",
+            paint(format!("platform mismatch: {mismatch}"), Some(RED), color)
+        );
+    }
+    for (label, names) in [
+        ("added", &comparison.added),
+        ("removed", &comparison.removed),
+    ] {
+        if !names.is_empty() {
+            let _ = writeln!(out, "{label}: {}", names.join(", "));
+        }
+    }
+    out
+}
+
+/// Renders a series as a sparkline of `▁▂▃▄▅▆▇█`, one character per
+/// value, scaled between the smallest and largest value. A flat series
+/// is drawn at mid height and non-finite values as spaces.
+pub fn sparkline(values: &[f64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let finite = values.iter().copied().filter(|v| v.is_finite());
+    let min = finite.clone().fold(f64::INFINITY, f64::min);
+    let max = finite.fold(f64::NEG_INFINITY, f64::max);
+    values
+        .iter()
+        .map(|&v| {
+            if !v.is_finite() {
+                ' '
+            } else if max > min {
+                BARS[(((v - min) / (max - min)) * 7.0).round() as usize]
+            } else {
+                BARS[3]
+            }
+        })
+        .collect()
+}
+
+/// Renders trends for the terminal: per series a sparkline, the first
+/// and last value and the total change, followed by its change points
+/// with the commit that introduced each. Shifts are red when they make
+/// a latency, memory or throughput worse and green when better, if
+/// `color` is set.
+pub fn render_trends_terminal(trends: &[Trend], color: bool) -> String {
+    let mut out = String::new();
+    for trend in trends {
+        let (Some(first), Some(last)) = (trend.points.first(), trend.points.last()) else {
+            continue;
+        };
+        let values: Vec<f64> = trend.points.iter().map(|p| p.value).collect();
+        let _ = writeln!(
+            out,
+            "{} {} {}  {}  {} → {} ({:+.1}%, {} runs)",
+            trend.implementation,
+            paint(trend.benchmark.clone(), Some(BOLD), color),
+            trend.metric,
+            sparkline(&values),
+            format_value(first.value, &trend.unit),
+            format_value(last.value, &trend.unit),
+            trend.stats.total_change * 100.0,
+            trend.points.len()
+        );
+        let higher_is_better = trend
+            .metric
+            .parse::<MetricKind>()
+            .ok()
+            .map(|kind| kind.higher_is_better());
+        for change in &trend.change_points {
+            let style = higher_is_better.map(|higher| {
+                if (change.change > 0.0) == higher {
+                    GREEN
+                } else {
+                    RED
+                }
+            });
+            let _ = writeln!(
+                out,
+                "  {} at {} ({}): {} → {}",
+                paint(format!("{:+.1}%", change.change * 100.0), style, color),
+                change.commit_sha.chars().take(12).collect::<String>(),
+                change.timestamp,
+                format_value(change.mean_before, &trend.unit),
+                format_value(change.mean_after, &trend.unit),
+            );
+        }
+    }
+    out
+}
+
+/// Lays out rows of styled cells under a bold header, each column as
+/// wide as its widest cell. The first `text_columns` columns are
+/// left-aligned, the rest right-aligned.
+fn table(
+    header: &[&str],
+    rows: Vec<Vec<(String, Option<&str>)>>,
+    text_columns: usize,
+    color: bool,
+) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, (text, _)) in widths.iter_mut().zip(row) {
+            *width = (*width).max(text.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    let header_cells: Vec<_> = header
+        .iter()
+        .zip(&widths)
+        .enumerate()
+        .map(|(i, (text, width))| paint(pad(text, *width, i < text_columns), Some(BOLD), color))
+        .collect();
+    let _ = writeln!(out, "{}", header_cells.join("  ").trim_end());
+    let total: usize = widths.iter().sum::<usize>() + 2 * (widths.len() - 1);
+    let _ = writeln!(out, "{}", "─".repeat(total));
+    for row in rows {
+        let cells: Vec<_> = row
+            .into_iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, ((text, style), width))| {
+                paint(pad(&text, *width, i < text_columns), style, color)
+            })
+            .collect();
+        let _ = writeln!(out, "{}", cells.join("  ").trim_end());
+    }
+    out
+}
+
+/// Wraps text in an ANSI style when coloring.
+fn paint(text: String, style: Option<&str>, color: bool) -> String {
+    match style {
+        Some(style) if color => format!("{style}{text}{RESET}"),
+        _ => text,
+    }
+}
+
+fn cell(metric: Option<&MetricValue>) -> String {
+    metric.map(format_metric).unwrap_or_else(|| "-".to_string())
+}
+
+/// Pads to `width` characters, left-aligned for names and right-aligned
+/// for numbers.
+fn pad(text: &str, width: usize, left: bool) -> String {
+    if left {
+        format!("{:<width$}", text)
+    } else {
+        format!("{:>width$}", text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn plain_table_is_aligned() {
+        let report = ReportBuilder::new()
+            .latencies([
+                ("prove", MetricValue::with_bounds(1.5, "s", 1.4, 1.6)),
+                ("verify", MetricValue::new(2_000_000.0, "ns")),
+            ])
+            .build();
+        let out = render_terminal(&report, None, 0.05, false);
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(lines[0], "Benchmark  Latency     ±CI  Throughput  Memory");
+        assert!(lines[1].chars().all(|c| c == '─'));
+        assert_eq!(lines[2], "prove        1.5 s  ±0.1 s           -       -");
+        assert_eq!(lines[3], "verify        2 ms       -           -       -");
+        assert!(!out.contains('\x1b'));
+    }
+
+    #[test]
+    fn rows_sort_by_metric_in_base_units() {
+        let mut report = ReportBuilder::new()
+            .latencies([
+                ("a", MetricValue::new(2.0, "s")),
+                ("b", MetricValue::new(30.0, "ms")),
+                ("c", MetricValue::new(900.0, "ms")),
+            ])
+            .build();
+        report.benchmarks.get_mut("b").unwrap().latency = None;
+        let names = |descending| {
+            render_terminal_sorted(&report, None, 0.0, MetricKind::Latency, descending, false)
+                .lines()
+                .skip(2)
+                .map(|line| line[..1].to_string())
+                .collect::<String>()
+        };
+        assert_eq!(names(false), "cab");
+        assert_eq!(names(true), "acb");
+    }
+
+    #[test]
+    fn sparkline_scales_between_extremes() {
+        assert_eq!(sparkline(&[1.0, 2.0, 8.0, f64::NAN]), "▁▂█ ");
+        assert_eq!(sparkline(&[5.0, 5.0]), "▄▄");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn trends_show_change_points_with_commits() {
+        let reports: Vec<_> = [10.0, 10.1, 9.9, 10.0, 20.0, 20.1, 19.9, 20.0]
+            .into_iter()
+            .enumerate()
+            .map(|(i, ms)| {
+                let mut report = ReportBuilder::new()
+                    .latencies([("prove", MetricValue::new(ms, "ms"))])
+                    .build();
+                report.metadata.commit_sha = format!("commit{i}");
+                report.metadata.timestamp = format!("2026-01-{:02}T00:00:00+00:00", i + 1);
+                report
+            })
+            .collect();
+        let trends = crate::trend::analyze_trends(&reports, &Default::default());
+        let out = render_trends_terminal(&trends, false);
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "impl prove latency  ▁▁▁▁████  10 ms → 20 ms (+100.0%, 8 runs)",
+                "  +100.0% at commit4 (2026-01-05T00:00:00+00:00): 10 ms → 20 ms",
+            ]
+        );
+        assert!(render_trends_terminal(&trends, true).contains(&format!("{RED}+100.0%")));
+    }
+
+    #[test]
+    fn baseline_deltas_are_colored() {
+        let baseline = ReportBuilder::new()
+            .latencies([
+                ("slower", MetricValue::new(100.0, "ms")),
+                ("faster", MetricValue::new(100.0, "ms")),
+                ("same", MetricValue::new(100.0, "ms")),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies([
+                ("slower", MetricValue::new(120.0, "ms")),
+                ("faster", MetricValue::new(50.0, "ms")),
+                ("same", MetricValue::new(101.0, "ms")),
+                ("added", MetricValue::new(1.0, "ms")),
+            ])
+            .build();
+        let out = render_terminal(&candidate, Some(&baseline), 0.05, true);
+        assert!(out.contains(&format!("{RED}+20.0%{RESET}")));
+        assert!(out.contains(&format!("{GREEN}-50.0%{RESET}")));
+        assert!(out.contains(&format!("{DIM} +1.0%{RESET}")));
+        assert!(out.contains(&format!("{DIM}   new{RESET}")));
+        assert!(out.contains("4 benchmark(s), 1 "));
+
+        let plain = render_terminal(&candidate, Some(&baseline), 0.05, false);
+        assert!(plain.contains("4 benchmark(s), 1 regression(s), 1 improvement(s)"));
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    fn comparison_table_lists_every_metric() {
+        let baseline = ReportBuilder::new()
+            .latencies([
+                ("prove", MetricValue::new(100.0, "ms")),
+                ("gone", MetricValue::new(1.0, "ms")),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies([("prove", MetricValue::new(130.0, "ms"))])
+            .build();
+        let comparison = compare(&baseline, &candidate, &CompareOptions::default());
+        let plain = render_comparison_terminal(&comparison, false);
+        let lines: Vec<_> = plain.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Benchmark  Metric   Baseline  Candidate    Change"
+        );
+        assert_eq!(
+            lines[2],
+            "prove      latency    100 ms     130 ms  +30.0% ▲"
+        );
+        assert!(plain.contains("1 benchmark(s), 1 regression(s), 0 improvement(s)"));
+        assert!(plain.ends_with("removed: gone\n"));
+
+        let colored = render_comparison_terminal(&comparison, true);
+        assert!(colored.contains(&format!("{RED}+30.0% ▲{RESET}")));
+    }
+}
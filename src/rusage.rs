@@ -0,0 +1,200 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Page fault and context switch accounting.
+//!
+//! Latency spikes in memory-heavy proving are often the kernel at work:
+//! major faults reading pages back from disk, bursts of minor faults as a
+//! fresh allocation is first touched, or involuntary context switches when
+//! another process takes the CPU. [`ResourceUsage`] snapshots the process
+//! counters from `getrusage` on Unix and `GetProcessMemoryInfo` on Windows
+//! before and after the measured region and records the difference as
+//! counters of the result.
+
+use std::io;
+
+use crate::schema::{BenchmarkResult, MetricValue};
+
+/// Page fault and context switch counts of the current process, all
+/// threads included. Counts a platform does not report are `None`.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{BenchmarkResult, ResourceUsage};
+/// # fn prove() {}
+///
+/// let before = ResourceUsage::now()?;
+/// for _ in 0..10 {
+///     prove();
+/// }
+/// let usage = ResourceUsage::now()?.since(&before);
+/// let mut result = BenchmarkResult {
+///     iterations: 10,
+///     ..Default::default()
+/// };
+/// usage.record(&mut result);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceUsage {
+    /// Faults served without I/O, e.g. on first touching a page. Windows
+    /// counts all faults here, since it does not tell them apart.
+    pub minor_page_faults: Option<u64>,
+    /// Faults that had to read from disk.
+    pub major_page_faults: Option<u64>,
+    /// Switches because a thread waited, e.g. for a lock or I/O.
+    pub voluntary_context_switches: Option<u64>,
+    /// Switches because a thread's time slice ended or a higher-priority
+    /// thread became runnable.
+    pub involuntary_context_switches: Option<u64>,
+}
+
+impl ResourceUsage {
+    /// The counts since the process started.
+    pub fn now() -> io::Result<Self> {
+        now()
+    }
+
+    /// The counts since `earlier`, a snapshot of the same process.
+    pub fn since(&self, earlier: &ResourceUsage) -> Self {
+        let delta = |now: Option<u64>, earlier: Option<u64>| Some(now?.saturating_sub(earlier?));
+        Self {
+            minor_page_faults: delta(self.minor_page_faults, earlier.minor_page_faults),
+            major_page_faults: delta(self.major_page_faults, earlier.major_page_faults),
+            voluntary_context_switches: delta(
+                self.voluntary_context_switches,
+                earlier.voluntary_context_switches,
+            ),
+            involuntary_context_switches: delta(
+                self.involuntary_context_switches,
+                earlier.involuntary_context_switches,
+            ),
+        }
+    }
+
+    /// Records the counts as counters of `result`, named like the fields
+    /// and divided by its iterations so they are per iteration like its
+    /// latency.
+    pub fn record(&self, result: &mut BenchmarkResult) {
+        let iterations = result.iterations.max(1) as f64;
+        for (name, count) in [
+            ("minor_page_faults", self.minor_page_faults),
+            ("major_page_faults", self.major_page_faults),
+            (
+                "voluntary_context_switches",
+                self.voluntary_context_switches,
+            ),
+            (
+                "involuntary_context_switches",
+                self.involuntary_context_switches,
+            ),
+        ] {
+            if let Some(count) = count {
+                result.counters.insert(
+                    name.to_string(),
+                    MetricValue::new(count as f64 / iterations, "count"),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+fn now() -> io::Result<ResourceUsage> {
+    // SAFETY: `rusage` is plain data, for which all zeroes is valid.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // SAFETY: `usage` is a valid, writable `rusage`.
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let count = |value: libc::c_long| Some(value as u64);
+    Ok(ResourceUsage {
+        minor_page_faults: count(usage.ru_minflt),
+        major_page_faults: count(usage.ru_majflt),
+        voluntary_context_switches: count(usage.ru_nvcsw),
+        involuntary_context_switches: count(usage.ru_nivcsw),
+    })
+}
+
+#[cfg(windows)]
+fn now() -> io::Result<ResourceUsage> {
+    use windows_sys::Win32::System::ProcessStatus::{
+        GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS,
+    };
+    use windows_sys::Win32::System::Threading::GetCurrentProcess;
+
+    let mut counters = PROCESS_MEMORY_COUNTERS::default();
+    let size = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+    // SAFETY: the pseudo handle of the current process needs no closing,
+    // and `counters` is writable and `size` bytes long.
+    if unsafe { GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, size) } == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(ResourceUsage {
+        minor_page_faults: Some(counters.PageFaultCount.into()),
+        ..Default::default()
+    })
+}
+
+#[cfg(not(any(unix, windows)))]
+fn now() -> io::Result<ResourceUsage> {
+    Ok(ResourceUsage::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_faults_on_fresh_memory() {
+        let before = ResourceUsage::now().unwrap();
+        // Above the largest mmap threshold of glibc, so always fresh pages.
+        let memory = vec![1u8; 64 << 20];
+        std::hint::black_box(&memory);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        let usage = ResourceUsage::now().unwrap().since(&before);
+        if cfg!(any(unix, windows)) {
+            assert!(usage.minor_page_faults.unwrap() > 0, "{usage:?}");
+        }
+
+        let mut result = BenchmarkResult {
+            iterations: 2,
+            ..Default::default()
+        };
+        let usage = ResourceUsage {
+            minor_page_faults: Some(10),
+            major_page_faults: Some(0),
+            ..Default::default()
+        };
+        usage.record(&mut result);
+        assert_eq!(result.counters["minor_page_faults"].value, 5.0);
+        assert_eq!(result.counters["major_page_faults"].unit, "count");
+        assert!(!result.counters.contains_key("voluntary_context_switches"));
+    }
+
+    #[test]
+    fn subtracts_snapshots() {
+        let earlier = ResourceUsage {
+            minor_page_faults: Some(5),
+            major_page_faults: Some(1),
+            voluntary_context_switches: None,
+            involuntary_context_switches: Some(7),
+        };
+        let later = ResourceUsage {
+            minor_page_faults: Some(12),
+            major_page_faults: Some(1),
+            voluntary_context_switches: Some(3),
+            involuntary_context_switches: Some(9),
+        };
+        assert_eq!(
+            later.since(&earlier),
+            ResourceUsage {
+                minor_page_faults: Some(7),
+                major_page_faults: Some(0),
+                voluntary_context_switches: None,
+                involuntary_context_switches: Some(2),
+            }
+        );
+    }
+}
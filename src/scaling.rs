@@ -0,0 +1,299 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Comparison of scaling curves across parameter sweeps.
+//!
+//! Point-by-point deltas cannot tell "slower at 2^22 but with the same
+//! asymptotics" from a complexity regression. When both reports contain
+//! a [`Sweep`](crate::Sweep), each side is fitted with a power law
+//! `latency = constant · n^exponent` by least squares on the log-log
+//! points, and the exponents and levels of the two curves are compared
+//! separately.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::compare::CompareOptions;
+use crate::schema::BenchmarkReport;
+use crate::sweep::{Sweep, extract_sweeps};
+use crate::units::to_base;
+
+/// Power law fitted to a sweep, with latency in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScalingFit {
+    /// Growth exponent: `1.0` is linear, `2.0` quadratic.
+    pub exponent: f64,
+    /// Fitted latency at `n = 1`, in ns.
+    pub constant: f64,
+    /// Standard error of the exponent; `None` with only two points.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub exponent_stderr: Option<f64>,
+    /// Coefficient of determination of the log-log fit.
+    pub r_squared: f64,
+    /// Number of points fitted.
+    pub points: usize,
+}
+
+impl ScalingFit {
+    /// Fits `y = constant · x^exponent` to `(x, y)` points. Returns `None`
+    /// with fewer than two positive points or a single distinct `x`.
+    pub fn fit(points: &[(f64, f64)]) -> Option<Self> {
+        let logs: Vec<(f64, f64)> = points
+            .iter()
+            .filter(|(x, y)| *x > 0.0 && *y > 0.0)
+            .map(|(x, y)| (x.ln(), y.ln()))
+            .collect();
+        let n = logs.len() as f64;
+        if logs.len() < 2 {
+            return None;
+        }
+        let x_mean = logs.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let y_mean = logs.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let (sxy, sxx, syy) = logs
+            .iter()
+            .fold((0.0, 0.0, 0.0), |(sxy, sxx, syy), (x, y)| {
+                let (dx, dy) = (x - x_mean, y - y_mean);
+                (sxy + dx * dy, sxx + dx * dx, syy + dy * dy)
+            });
+        if sxx == 0.0 {
+            return None;
+        }
+        let exponent = sxy / sxx;
+        let intercept = y_mean - exponent * x_mean;
+        let residual: f64 = logs
+            .iter()
+            .map(|(x, y)| (y - intercept - exponent * x).powi(2))
+            .sum();
+        Some(Self {
+            exponent,
+            constant: intercept.exp(),
+            exponent_stderr: (logs.len() > 2).then(|| (residual / (n - 2.0) / sxx).sqrt()),
+            r_squared: if syy > 0.0 { 1.0 - residual / syy } else { 1.0 },
+            points: logs.len(),
+        })
+    }
+
+    /// Fitted latency at parameter `n`, in ns.
+    pub fn predict(&self, n: f64) -> f64 {
+        self.constant * n.powf(self.exponent)
+    }
+}
+
+/// How the scaling curve of a sweep moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScalingChange {
+    /// Same exponent and level.
+    Unchanged,
+    /// Same exponent, uniformly slower.
+    ConstantRegression,
+    /// Same exponent, uniformly faster.
+    ConstantImprovement,
+    /// The latency grows faster with the parameter: a complexity
+    /// regression.
+    ExponentRegression,
+    /// The latency grows slower with the parameter.
+    ExponentImprovement,
+}
+
+impl fmt::Display for ScalingChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Unchanged => "unchanged",
+            Self::ConstantRegression => "constant-factor regression",
+            Self::ConstantImprovement => "constant-factor improvement",
+            Self::ExponentRegression => "exponent regression",
+            Self::ExponentImprovement => "exponent improvement",
+        })
+    }
+}
+
+/// Fitted scaling curves of a sweep present in both reports.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScalingComparison {
+    /// Sweep name, e.g. `msm` for `msm/2^16`.
+    pub sweep: String,
+    /// Fits over the parameters both reports measured.
+    pub baseline: ScalingFit,
+    pub candidate: ScalingFit,
+    /// `candidate.exponent - baseline.exponent`.
+    pub exponent_change: f64,
+    /// Relative change of the fitted curves at the geometric middle of
+    /// the shared parameter range, `candidate / baseline - 1`.
+    pub level_change: f64,
+    pub change: ScalingChange,
+}
+
+/// Fits and compares the scaling curves of the sweeps both reports
+/// contain, sorted by sweep name.
+///
+/// Only parameters measured on both sides are fitted, so a sweep that
+/// grew a larger size does not shift its exponent. An exponent change
+/// beyond [`CompareOptions::scaling_tolerance`] (and twice its standard
+/// error, when known) is an exponent regression or improvement;
+/// otherwise a level change beyond the sweep's latency
+/// [threshold](CompareOptions::threshold_for) is a constant-factor one.
+pub fn compare_scaling(
+    baseline: &BenchmarkReport,
+    candidate: &BenchmarkReport,
+    opts: &CompareOptions,
+) -> Vec<ScalingComparison> {
+    let baseline_sweeps: BTreeMap<String, Sweep> = extract_sweeps(baseline)
+        .into_iter()
+        .map(|s| (s.name.clone(), s))
+        .collect();
+    extract_sweeps(candidate)
+        .into_iter()
+        .filter_map(|after| {
+            let before = baseline_sweeps.get(&after.name)?;
+            compare_sweep(before, &after, opts)
+        })
+        .collect()
+}
+
+fn compare_sweep(
+    before: &Sweep,
+    after: &Sweep,
+    opts: &CompareOptions,
+) -> Option<ScalingComparison> {
+    let (mut base_points, mut cand_points) = (Vec::new(), Vec::new());
+    for point in &after.points {
+        let Some(prior) = before
+            .points
+            .iter()
+            .find(|p| p.parameter == point.parameter)
+        else {
+            continue;
+        };
+        base_points.push((
+            prior.parameter,
+            to_base(prior.latency.value, &prior.latency.unit),
+        ));
+        cand_points.push((
+            point.parameter,
+            to_base(point.latency.value, &point.latency.unit),
+        ));
+    }
+    let base_fit = ScalingFit::fit(&base_points)?;
+    let cand_fit = ScalingFit::fit(&cand_points)?;
+
+    let (lo, hi) = (cand_points[0].0, cand_points[cand_points.len() - 1].0);
+    let middle = (lo * hi).sqrt();
+    let level_change = cand_fit.predict(middle) / base_fit.predict(middle) - 1.0;
+    let exponent_change = cand_fit.exponent - base_fit.exponent;
+    let noise = base_fit
+        .exponent_stderr
+        .zip(cand_fit.exponent_stderr)
+        .map_or(0.0, |(a, b)| 2.0 * (a * a + b * b).sqrt());
+    let threshold = opts.threshold_for(&after.name, "latency");
+    let change = if exponent_change.abs() > opts.scaling_tolerance.max(noise) {
+        if exponent_change > 0.0 {
+            ScalingChange::ExponentRegression
+        } else {
+            ScalingChange::ExponentImprovement
+        }
+    } else if level_change > threshold {
+        ScalingChange::ConstantRegression
+    } else if level_change < -threshold {
+        ScalingChange::ConstantImprovement
+    } else {
+        ScalingChange::Unchanged
+    };
+    Some(ScalingComparison {
+        sweep: after.name.clone(),
+        baseline: base_fit,
+        candidate: cand_fit,
+        exponent_change,
+        level_change,
+        change,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::MetricValue;
+    use crate::testing::ReportBuilder;
+
+    /// An `ntt/2^k` sweep with latency `c · n^e` ms.
+    fn ntt(c: f64, e: f64, ks: &[u32]) -> Vec<(String, MetricValue)> {
+        ks.iter()
+            .map(|k| {
+                let n = 2f64.powi(*k as i32);
+                (format!("ntt/2^{k}"), MetricValue::new(c * n.powf(e), "ms"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fits_power_law() {
+        let fit = ScalingFit::fit(&[(10.0, 300.0), (100.0, 3000.0), (1000.0, 30000.0)]).unwrap();
+        assert!((fit.exponent - 1.0).abs() < 1e-12);
+        assert!((fit.constant - 30.0).abs() < 1e-9);
+        assert!(fit.exponent_stderr.unwrap() < 1e-9);
+        assert!((fit.r_squared - 1.0).abs() < 1e-12);
+        assert!(ScalingFit::fit(&[(10.0, 1.0)]).is_none());
+        assert!(ScalingFit::fit(&[(10.0, 1.0), (10.0, 2.0)]).is_none());
+    }
+
+    #[test]
+    fn distinguishes_constant_from_exponent_changes() {
+        let opts = CompareOptions::default();
+        let ks = [10, 12, 14, 16];
+        let base = ReportBuilder::new().latencies(ntt(1e-3, 1.0, &ks)).build();
+
+        let slower = compare_scaling(
+            &base,
+            &ReportBuilder::new()
+                .latencies(ntt(1.5e-3, 1.0, &ks))
+                .build(),
+            &opts,
+        );
+        assert_eq!(slower.len(), 1);
+        assert_eq!(slower[0].sweep, "ntt");
+        assert_eq!(slower[0].change, ScalingChange::ConstantRegression);
+        assert!((slower[0].level_change - 0.5).abs() < 1e-9);
+        assert!(slower[0].exponent_change.abs() < 1e-9);
+
+        let worse = compare_scaling(
+            &base,
+            &ReportBuilder::new().latencies(ntt(1e-3, 1.2, &ks)).build(),
+            &opts,
+        );
+        assert_eq!(worse[0].change, ScalingChange::ExponentRegression);
+        assert!((worse[0].exponent_change - 0.2).abs() < 1e-9);
+
+        let same = compare_scaling(
+            &base,
+            &ReportBuilder::new()
+                .latencies(ntt(1.01e-3, 1.0, &ks))
+                .build(),
+            &opts,
+        );
+        assert_eq!(same[0].change, ScalingChange::Unchanged);
+    }
+
+    #[test]
+    fn fits_only_shared_parameters() {
+        let base = ReportBuilder::new()
+            .latencies(ntt(1e-3, 1.0, &[10, 12]))
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies(ntt(1e-3, 1.0, &[10, 12, 14, 16]))
+            .build();
+        let scaling = compare_scaling(&base, &candidate, &CompareOptions::default());
+        assert_eq!(scaling[0].candidate.points, 2);
+        assert!(
+            compare_scaling(
+                &base,
+                &ReportBuilder::new()
+                    .latencies(ntt(1e-3, 1.0, &[14, 16]))
+                    .build(),
+                &CompareOptions::default()
+            )
+            .is_empty()
+        );
+    }
+}
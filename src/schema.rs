@@ -4,15 +4,26 @@
 //! Schema types for benchmark reporting.
 
 use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
 use std::process::Command;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::affinity::CpuPinning;
+use crate::artifact::Artifact;
+use crate::atomic::write_atomic;
+use crate::digest::Digest;
+use crate::env::RunEnvironment;
+use crate::hash::HashAlgorithm;
+use crate::memory::MemoryProfile;
+use crate::merkle::TestVectorCommitment;
 use crate::platform::Platform;
 
 /// Represents a benchmark metric with optional confidence bounds.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct MetricValue {
     pub value: f64,
     pub unit: String,
@@ -44,12 +55,73 @@ impl MetricValue {
     }
 }
 
+/// Identifies one of the standard metrics of a [`BenchmarkResult`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricKind {
+    Latency,
+    Memory,
+    Throughput,
+}
+
+impl MetricKind {
+    /// All metric kinds, in field order.
+    pub const ALL: [MetricKind; 3] = [Self::Latency, Self::Memory, Self::Throughput];
+
+    /// Returns the name used in JSON and configuration files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Latency => "latency",
+            Self::Memory => "memory",
+            Self::Throughput => "throughput",
+        }
+    }
+
+    /// Whether a larger value of this metric is an improvement.
+    pub fn higher_is_better(&self) -> bool {
+        matches!(self, Self::Throughput)
+    }
+
+    /// Returns this metric from a result, if recorded.
+    pub fn get<'a>(&self, result: &'a BenchmarkResult) -> Option<&'a MetricValue> {
+        match self {
+            Self::Latency => result.latency.as_ref(),
+            Self::Memory => result.memory.as_ref(),
+            Self::Throughput => result.throughput.as_ref(),
+        }
+    }
+}
+
+impl std::fmt::Display for MetricKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for MetricKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == s)
+            .ok_or_else(|| format!("unknown metric '{}'", s))
+    }
+}
+
 /// Test vector verification information.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TestVectors {
-    pub input_hash: String,
-    pub output_hash: String,
+    pub input_hash: Digest,
+    pub output_hash: Digest,
     pub verified: bool,
+    /// Hash function of both digests; SHA-256 when absent.
+    #[serde(default, skip_serializing_if = "HashAlgorithm::is_default")]
+    pub algorithm: HashAlgorithm,
+    /// HMAC-SHA256 of the digests under a secret key, set by
+    /// [`authenticate`](Self::authenticate).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hmac: Option<Digest>,
 }
 
 /// Represents results from a single benchmark.
@@ -61,38 +133,126 @@ pub struct BenchmarkResult {
     pub memory: Option<MetricValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub throughput: Option<MetricValue>,
+    /// Resident, heap and virtual memory, beside the single `memory`
+    /// metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_profile: Option<MemoryProfile>,
     #[serde(skip_serializing_if = "is_zero", default)]
     pub iterations: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub test_vectors: Option<TestVectors>,
+    /// Raw per-iteration latency samples, in the unit of `latency`.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub samples: Vec<f64>,
+    /// Profiles, traces and other diagnostics collected during the run.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub artifacts: Vec<Artifact>,
+    /// Additional named metrics beyond the standard three, such as
+    /// instruction counts or per-counter throughput.
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub counters: HashMap<String, MetricValue>,
+    /// Free-form labels such as `gpu` or `flaky`, used to filter
+    /// benchmarks and to exempt them from CI gates.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub tags: Vec<String>,
     #[serde(skip_serializing_if = "HashMap::is_empty", default)]
     pub metadata: HashMap<String, Value>,
 }
 
+impl BenchmarkResult {
+    /// Returns every metric with its name: the standard metrics in
+    /// [`MetricKind::ALL`] order, followed by counters sorted by name.
+    pub fn metrics(&self) -> Vec<(&str, &MetricValue)> {
+        let mut counters: Vec<_> = self
+            .counters
+            .iter()
+            .map(|(name, metric)| (name.as_str(), metric))
+            .collect();
+        counters.sort_by_key(|(name, _)| *name);
+        MetricKind::ALL
+            .iter()
+            .filter_map(|kind| Some((kind.as_str(), kind.get(self)?)))
+            .chain(counters)
+            .collect()
+    }
+}
+
 fn is_zero(val: &usize) -> bool {
     *val == 0
 }
 
+fn is_false(val: &bool) -> bool {
+    !*val
+}
+
+/// Version of the report schema written by this crate. Reports record
+/// the version they were written with in
+/// [`Metadata::schema_version`]; [`migrate_json`](crate::migrate_json)
+/// upgrades older ones.
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Schema version of reports written before the version was recorded.
+fn legacy_schema_version() -> u32 {
+    1
+}
+
 /// Benchmark metadata.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Metadata {
+    /// [`SCHEMA_VERSION`] the report was written with; 1 for reports
+    /// that predate the field.
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
     pub implementation: String,
     pub version: String,
     pub commit_sha: String,
     pub timestamp: String,
     pub platform: Platform,
+    /// Branch the commit was checked out on, when not detached.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub branch: Option<String>,
+    /// Tag pointing at the commit, such as a release tag.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub git_tag: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub dirty: bool,
+    /// Id of the run that produced the report, such as a CI run id.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub run_id: Option<String>,
+    /// Link to the CI run or job that produced the report.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub run_url: Option<String>,
+    /// Merkle root over the test vectors the run was checked against,
+    /// see [`MerkleTree`](crate::MerkleTree).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub test_vector_commitment: Option<TestVectorCommitment>,
+    /// Cores the benchmark threads were pinned to; unpinned when absent.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cpu_pinning: Option<CpuPinning>,
 }
 
 impl Metadata {
-    /// Creates metadata with auto-detected platform and git info.
+    /// Creates metadata with auto-detected platform and git info,
+    /// completed from the CI environment (see [`RunEnvironment`]).
     pub fn create(implementation: &str, version: &str) -> Self {
-        Self {
+        let mut metadata = Self {
+            schema_version: SCHEMA_VERSION,
             implementation: implementation.to_string(),
             version: version.to_string(),
             commit_sha: get_git_commit_sha(),
             timestamp: chrono::Utc::now().to_rfc3339(),
             platform: Platform::current(),
-        }
+            branch: git(&["symbolic-ref", "--short", "-q", "HEAD"]),
+            git_tag: git(&["describe", "--tags", "--exact-match", "HEAD"]),
+            dirty: git(&["status", "--porcelain", "--untracked-files=no"]).is_some(),
+            run_id: None,
+            run_url: None,
+            test_vector_commitment: None,
+            cpu_pinning: None,
+        };
+        RunEnvironment::from_env().apply(&mut metadata);
+        metadata
     }
 }
 
@@ -124,6 +284,36 @@ impl BenchmarkReport {
     pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(s)
     }
+
+    /// Reads a report from a JSON file. Malformed JSON is reported as
+    /// [`io::ErrorKind::InvalidData`] naming the file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)?;
+        Self::from_json(&json).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Writes the report to a pretty-printed JSON file.
+    ///
+    /// The write is atomic and durable (see [`write_atomic`]): a crash
+    /// leaves either the previous file or the complete report, never a
+    /// truncated one.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        write_atomic(path, self.to_json(true)?)
+    }
+
+    /// Returns the benchmarks sorted by name, so output built from them
+    /// is stable despite the `HashMap` backing store.
+    pub(crate) fn sorted_benchmarks(&self) -> Vec<(&String, &BenchmarkResult)> {
+        let mut entries: Vec<_> = self.benchmarks.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+    }
 }
 
 /// Gets the current git commit SHA (first 12 characters).
@@ -144,6 +334,15 @@ fn get_git_commit_sha() -> String {
         .unwrap_or_else(|| "unknown".to_string())
 }
 
+/// Runs a git command and returns its trimmed output, `None` when it
+/// fails or prints nothing.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let stdout = stdout.trim();
+    (output.status.success() && !stdout.is_empty()).then(|| stdout.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,18 +401,58 @@ mod tests {
         assert_eq!(metric.unit, "MB");
     }
 
+    #[test]
+    fn metric_kind_get_and_parse() {
+        let result = BenchmarkResult {
+            memory: Some(MetricValue::new(1.0, "MB")),
+            ..Default::default()
+        };
+        assert!(MetricKind::Memory.get(&result).is_some());
+        assert!(MetricKind::Latency.get(&result).is_none());
+        assert_eq!(
+            "throughput".parse::<MetricKind>(),
+            Ok(MetricKind::Throughput)
+        );
+        assert!("speed".parse::<MetricKind>().is_err());
+        assert_eq!(
+            serde_json::to_string(&MetricKind::Latency).unwrap(),
+            "\"latency\""
+        );
+    }
+
     #[test]
     fn test_test_vectors() {
+        let (input, output) = ("abc123".repeat(10) + "abcd", "DEF456".repeat(10) + "DEFA");
         let tv = TestVectors {
-            input_hash: "abc123".to_string(),
-            output_hash: "def456".to_string(),
+            input_hash: input.parse().unwrap(),
+            output_hash: format!("0x{output}").parse().unwrap(),
             verified: true,
+            algorithm: HashAlgorithm::Sha256,
+            hmac: None,
         };
         let json = serde_json::to_string(&tv).unwrap();
+        assert!(!json.contains("algorithm"));
+        assert!(!json.contains("hmac"));
+        assert!(json.contains(&format!(r#""output_hash":"{}""#, output.to_lowercase())));
         let deserialized: TestVectors = serde_json::from_str(&json).unwrap();
-        assert_eq!(deserialized.input_hash, "abc123");
-        assert_eq!(deserialized.output_hash, "def456");
+        assert_eq!(deserialized.input_hash.to_string(), input);
+        assert_eq!(deserialized.output_hash, tv.output_hash);
         assert!(deserialized.verified);
+        assert_eq!(deserialized.algorithm, HashAlgorithm::Sha256);
+
+        let tv = TestVectors {
+            algorithm: HashAlgorithm::Blake3,
+            ..tv
+        };
+        let json = serde_json::to_string(&tv).unwrap();
+        assert!(json.contains(r#""algorithm":"blake3""#));
+        let deserialized: TestVectors = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.algorithm, HashAlgorithm::Blake3);
+
+        // Malformed digests fail when the report is read.
+        let truncated = json.replace(&input, "abc123");
+        let err = serde_json::from_str::<TestVectors>(&truncated).unwrap_err();
+        assert!(err.to_string().contains("digest has 6 hex digits"), "{err}");
     }
 
     #[test]
@@ -224,6 +463,8 @@ mod tests {
         assert!(result.throughput.is_none());
         assert_eq!(result.iterations, 0);
         assert!(result.test_vectors.is_none());
+        assert!(result.samples.is_empty());
+        assert!(result.artifacts.is_empty());
         assert!(result.metadata.is_empty());
     }
 
@@ -239,6 +480,7 @@ mod tests {
         assert!(!json.contains("throughput"));
         assert!(!json.contains("iterations"));
         assert!(!json.contains("test_vectors"));
+        assert!(!json.contains("samples"));
     }
 
     #[test]
@@ -247,12 +489,17 @@ mod tests {
             latency: Some(MetricValue::new(100.0, "ns")),
             memory: Some(MetricValue::new(1024.0, "KB")),
             throughput: Some(MetricValue::new(1000.0, "ops/s")),
+            memory_profile: None,
             iterations: 100,
-            test_vectors: Some(TestVectors {
-                input_hash: "input".to_string(),
-                output_hash: "output".to_string(),
-                verified: true,
-            }),
+            test_vectors: Some(TestVectors::compute(
+                HashAlgorithm::Sha256,
+                b"input",
+                b"output",
+            )),
+            samples: vec![99.0, 100.0, 101.0],
+            artifacts: Vec::new(),
+            counters: HashMap::from([("instructions".to_string(), MetricValue::new(5e6, "count"))]),
+            tags: vec!["gpu".to_string()],
             metadata: HashMap::new(),
         };
         let json = serde_json::to_string(&result).unwrap();
@@ -262,6 +509,24 @@ mod tests {
         assert!(deserialized.throughput.is_some());
         assert_eq!(deserialized.iterations, 100);
         assert!(deserialized.test_vectors.is_some());
+        assert_eq!(deserialized.samples, vec![99.0, 100.0, 101.0]);
+        assert_eq!(deserialized.counters["instructions"].value, 5e6);
+        assert_eq!(deserialized.tags, ["gpu"]);
+    }
+
+    #[test]
+    fn test_metrics_order() {
+        let result = BenchmarkResult {
+            latency: Some(MetricValue::new(1.0, "ms")),
+            throughput: Some(MetricValue::new(10.0, "ops/s")),
+            counters: HashMap::from([
+                ("l1_hits".to_string(), MetricValue::new(3.0, "count")),
+                ("instructions".to_string(), MetricValue::new(9.0, "count")),
+            ]),
+            ..Default::default()
+        };
+        let names: Vec<_> = result.metrics().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["latency", "throughput", "instructions", "l1_hits"]);
     }
 
     #[test]
@@ -282,6 +547,18 @@ mod tests {
         assert!(metadata.timestamp.contains('T'));
     }
 
+    #[test]
+    fn test_metadata_git_fields_are_optional() {
+        let mut metadata = Metadata::create("t", "0.0.0");
+        metadata.branch = None;
+        metadata.git_tag = None;
+        metadata.dirty = false;
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert!(!json.contains("branch") && !json.contains("dirty"));
+        let parsed: Metadata = serde_json::from_str(&json).unwrap();
+        assert!(parsed.branch.is_none() && !parsed.dirty);
+    }
+
     #[test]
     fn test_benchmark_report() {
         let mut benchmarks = HashMap::new();
@@ -371,4 +648,26 @@ mod tests {
         let parsed = BenchmarkReport::from_json(&json).unwrap();
         assert!(parsed.benchmarks.contains_key("y"));
     }
+
+    #[test]
+    fn save_and_load_file() {
+        let path = std::env::temp_dir().join(format!("zkbench-schema-{}.json", std::process::id()));
+        let report = BenchmarkReport {
+            metadata: Metadata::create("t", "0.0.0"),
+            benchmarks: HashMap::new(),
+        };
+        report.save(&path).unwrap();
+        assert_eq!(
+            BenchmarkReport::load(&path)
+                .unwrap()
+                .metadata
+                .implementation,
+            "t"
+        );
+
+        fs::write(&path, "{").unwrap();
+        let err = BenchmarkReport::load(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,419 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Suite-level composite score.
+//!
+//! The score of a report against a reference report is the geometric
+//! mean, over the benchmarks both ran, of the per-benchmark
+//! [`speedup`](crate::speedup) of the report over the reference. `1.0`
+//! means parity, `1.25` means 25% faster on the (geometric) average. The
+//! geometric mean keeps the score independent of the benchmarks' units
+//! and magnitudes, and makes it consistent under a change of reference:
+//! score(a, c) = score(a, b) × score(b, c) on a common benchmark set.
+//!
+//! A [`ScoreProfile`] weights the benchmarks for a particular workload,
+//! e.g. a recursion-heavy profile that ignores everything but the
+//! recursion benchmarks. Profiles are named tables of `zkbench.toml`:
+//!
+//! ```toml
+//! [scores.recursion]
+//! default_weight = 0.0
+//!
+//! [[scores.recursion.weights]]
+//! pattern = "recursion/*"
+//! weight = 3.0
+//!
+//! [[scores.recursion.weights]]
+//! pattern = "poseidon/*"
+//! weight = 1.0
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::compare::glob_match;
+use crate::schema::{BenchmarkReport, BenchmarkResult, MetricKind, MetricValue};
+use crate::speedup::{SPEEDUP_UNIT, speedup};
+
+/// Name of the synthetic benchmark entry holding the suite score.
+pub const SUITE_SCORE_BENCHMARK: &str = "suite/score";
+
+/// Tag marking synthetic entries, which are excluded from scoring.
+pub const SYNTHETIC_TAG: &str = "synthetic";
+
+/// Counter of the synthetic entry holding the score.
+const SCORE_COUNTER: &str = "score";
+
+/// Benchmark weights of a workload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreProfile {
+    /// Weight of the benchmarks no pattern matches; `0.0` leaves them out.
+    pub default_weight: f64,
+    /// Weights by glob over benchmark names; later entries take
+    /// precedence over earlier ones.
+    pub weights: Vec<BenchmarkWeight>,
+}
+
+impl Default for ScoreProfile {
+    fn default() -> Self {
+        Self {
+            default_weight: 1.0,
+            weights: Vec::new(),
+        }
+    }
+}
+
+impl ScoreProfile {
+    /// Creates a profile where every benchmark has weight 1.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the weight of the benchmarks no pattern matches.
+    pub fn with_default_weight(mut self, weight: f64) -> Self {
+        self.default_weight = weight;
+        self
+    }
+
+    /// Weights the benchmarks matching `pattern`, overriding earlier
+    /// patterns.
+    pub fn with_weight(mut self, pattern: &str, weight: f64) -> Self {
+        self.weights.push(BenchmarkWeight {
+            pattern: pattern.to_string(),
+            weight,
+        });
+        self
+    }
+
+    /// Weight of a benchmark: that of the last matching pattern, else the
+    /// default.
+    pub fn weight_for(&self, benchmark: &str) -> f64 {
+        self.weights
+            .iter()
+            .rev()
+            .find(|w| glob_match(&w.pattern, benchmark))
+            .map_or(self.default_weight, |w| w.weight)
+    }
+}
+
+/// Weight of the benchmarks whose names match a glob pattern.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkWeight {
+    /// Glob over benchmark names, as in
+    /// [`BenchmarkThreshold`](crate::BenchmarkThreshold).
+    pub pattern: String,
+    pub weight: f64,
+}
+
+/// Composite score of a report against a reference.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuiteScore {
+    pub metric: MetricKind,
+    /// Name of the [`ScoreProfile`], `None` for the unweighted score.
+    pub profile: Option<String>,
+    /// Geometric mean of the speedups, with bounds propagated from the
+    /// per-benchmark confidence intervals when they are present.
+    pub score: MetricValue,
+    /// Per-benchmark speedups over the reference.
+    pub speedups: BTreeMap<String, f64>,
+    /// Weights of the benchmarks in `speedups`.
+    pub weights: BTreeMap<String, f64>,
+    pub reference_implementation: String,
+    pub reference_version: String,
+    pub reference_commit: String,
+}
+
+impl SuiteScore {
+    /// Name of the synthetic entry: [`SUITE_SCORE_BENCHMARK`], suffixed
+    /// with `/<profile>` for a weighted score.
+    pub fn benchmark_name(&self) -> String {
+        match &self.profile {
+            Some(profile) => format!("{SUITE_SCORE_BENCHMARK}/{profile}"),
+            None => SUITE_SCORE_BENCHMARK.to_string(),
+        }
+    }
+
+    /// Builds the synthetic benchmark entry recording this score: a
+    /// `score` counter in `x`, tagged [`SYNTHETIC_TAG`], with the
+    /// definition in its metadata.
+    pub fn to_result(&self) -> BenchmarkResult {
+        let mut metadata = HashMap::from([
+            (
+                "definition".to_string(),
+                json!(format!(
+                    "{}geometric mean of {} speedups over the reference",
+                    if self.profile.is_some() {
+                        "weighted "
+                    } else {
+                        ""
+                    },
+                    self.metric
+                )),
+            ),
+            ("benchmarks".to_string(), json!(self.speedups.len())),
+            (
+                "reference".to_string(),
+                json!({
+                    "implementation": self.reference_implementation,
+                    "version": self.reference_version,
+                    "commit_sha": self.reference_commit,
+                }),
+            ),
+        ]);
+        if let Some(profile) = &self.profile {
+            metadata.insert("profile".to_string(), json!(profile));
+            metadata.insert("weights".to_string(), json!(self.weights));
+        }
+        BenchmarkResult {
+            counters: HashMap::from([(SCORE_COUNTER.to_string(), self.score.clone())]),
+            tags: vec![SYNTHETIC_TAG.to_string()],
+            metadata,
+            ..Default::default()
+        }
+    }
+}
+
+/// Computes the score of `report` against `reference` on `metric`.
+///
+/// Benchmarks present in both reports with a positive value of the
+/// metric take part; synthetic entries are skipped. The bounds are the
+/// geometric mean's interval, combining each speedup's log-scale
+/// half-widths in quadrature. Returns `None` if no benchmark is shared.
+pub fn suite_score(
+    report: &BenchmarkReport,
+    reference: &BenchmarkReport,
+    metric: MetricKind,
+) -> Option<SuiteScore> {
+    score(report, reference, metric, None, &ScoreProfile::default())
+}
+
+/// Computes the [`suite_score`] with the benchmarks weighted by the
+/// profile named `name`: the weighted geometric mean
+/// `exp(Σ wᵢ ln sᵢ / Σ wᵢ)`. Benchmarks of zero weight are left out;
+/// returns `None` if no shared benchmark has a positive weight.
+pub fn weighted_suite_score(
+    report: &BenchmarkReport,
+    reference: &BenchmarkReport,
+    metric: MetricKind,
+    name: &str,
+    profile: &ScoreProfile,
+) -> Option<SuiteScore> {
+    score(report, reference, metric, Some(name), profile)
+}
+
+fn score(
+    report: &BenchmarkReport,
+    reference: &BenchmarkReport,
+    metric: MetricKind,
+    name: Option<&str>,
+    profile: &ScoreProfile,
+) -> Option<SuiteScore> {
+    let (mut speedups, mut weights) = (BTreeMap::new(), BTreeMap::new());
+    let (mut log_sum, mut lower_var, mut upper_var) = (0.0, 0.0, 0.0);
+    let mut bounded = false;
+    for (name, result) in &report.benchmarks {
+        if result.tags.iter().any(|t| t == SYNTHETIC_TAG) {
+            continue;
+        }
+        let weight = profile.weight_for(name);
+        if weight.is_nan() || weight <= 0.0 {
+            continue;
+        }
+        let Some(before) = reference.benchmarks.get(name).and_then(|r| metric.get(r)) else {
+            continue;
+        };
+        let Some(after) = metric.get(result) else {
+            continue;
+        };
+        if !(before.value > 0.0 && after.value > 0.0) {
+            continue;
+        }
+        let ratio = speedup(before, after);
+        let ln = ratio.value.ln();
+        log_sum += weight * ln;
+        if let Some(lower) = ratio.lower_value {
+            lower_var += (weight * (ln - lower.ln())).powi(2);
+            bounded = true;
+        }
+        if let Some(upper) = ratio.upper_value {
+            upper_var += (weight * (upper.ln() - ln)).powi(2);
+            bounded = true;
+        }
+        speedups.insert(name.clone(), ratio.value);
+        weights.insert(name.clone(), weight);
+    }
+    if speedups.is_empty() {
+        return None;
+    }
+    let n: f64 = weights.values().sum();
+    let mean = log_sum / n;
+    let score = MetricValue {
+        value: mean.exp(),
+        unit: SPEEDUP_UNIT.to_string(),
+        lower_value: bounded.then(|| (mean - lower_var.sqrt() / n).exp()),
+        upper_value: bounded.then(|| (mean + upper_var.sqrt() / n).exp()),
+    };
+    let meta = &reference.metadata;
+    Some(SuiteScore {
+        metric,
+        profile: name.map(str::to_string),
+        score,
+        speedups,
+        weights,
+        reference_implementation: meta.implementation.clone(),
+        reference_version: meta.version.clone(),
+        reference_commit: meta.commit_sha.clone(),
+    })
+}
+
+impl BenchmarkReport {
+    /// Computes the [`suite_score`] against `reference` and records it as
+    /// the [`SUITE_SCORE_BENCHMARK`] entry, replacing any previous one.
+    pub fn record_suite_score(
+        &mut self,
+        reference: &BenchmarkReport,
+        metric: MetricKind,
+    ) -> Option<SuiteScore> {
+        let score = suite_score(self, reference, metric)?;
+        self.benchmarks
+            .insert(score.benchmark_name(), score.to_result());
+        Some(score)
+    }
+
+    /// Computes the [`weighted_suite_score`] for the profile `name` and
+    /// records it as the `suite/score/<name>` entry.
+    pub fn record_weighted_suite_score(
+        &mut self,
+        reference: &BenchmarkReport,
+        metric: MetricKind,
+        name: &str,
+        profile: &ScoreProfile,
+    ) -> Option<SuiteScore> {
+        let score = weighted_suite_score(self, reference, metric, name, profile)?;
+        self.benchmarks
+            .insert(score.benchmark_name(), score.to_result());
+        Some(score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn geometric_mean_of_speedups() {
+        let reference = ReportBuilder::new()
+            .latencies([
+                ("fft", MetricValue::new(100.0, "ms")),
+                ("msm", MetricValue::new(1.0, "s")),
+                ("only_ref", MetricValue::new(1.0, "ms")),
+            ])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies([
+                ("fft", MetricValue::new(50.0, "ms")),
+                ("msm", MetricValue::new(2.0, "s")),
+                ("only_new", MetricValue::new(1.0, "ms")),
+            ])
+            .build();
+        let score = suite_score(&candidate, &reference, MetricKind::Latency).unwrap();
+        assert!((score.score.value - 1.0).abs() < 1e-12);
+        assert_eq!(score.speedups.len(), 2);
+        assert_eq!(score.speedups["fft"], 2.0);
+        assert!(score.score.lower_value.is_none());
+        assert!(
+            suite_score(
+                &candidate,
+                &ReportBuilder::new().build(),
+                MetricKind::Latency
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn bounds_shrink_with_more_benchmarks() {
+        let e = 0.1f64.exp();
+        let bounded = |ms: f64| MetricValue::with_bounds(ms, "ms", ms / e, ms * e);
+        let reference = ReportBuilder::new()
+            .latencies([("a", bounded(100.0)), ("b", bounded(100.0))])
+            .build();
+        let candidate = ReportBuilder::new()
+            .latencies([("a", bounded(50.0)), ("b", bounded(50.0))])
+            .build();
+        let score = suite_score(&candidate, &reference, MetricKind::Latency)
+            .unwrap()
+            .score;
+        // Each speedup has a log half-width of 0.1 * sqrt(2); the mean of
+        // two halves that by sqrt(2).
+        assert!((score.upper_value.unwrap() - 2.0 * 0.1f64.exp()).abs() < 1e-12);
+        assert!((score.lower_value.unwrap() - 2.0 * (-0.1f64).exp()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn records_synthetic_entry() {
+        let reference = ReportBuilder::new()
+            .latencies([("fft", MetricValue::new(100.0, "ms"))])
+            .build();
+        let mut candidate = ReportBuilder::new()
+            .latencies([("fft", MetricValue::new(80.0, "ms"))])
+            .build();
+        candidate
+            .record_suite_score(&reference, MetricKind::Latency)
+            .unwrap();
+        let entry = &candidate.benchmarks[SUITE_SCORE_BENCHMARK];
+        assert_eq!(entry.counters["score"].value, 1.25);
+        assert_eq!(entry.counters["score"].unit, "x");
+        assert_eq!(entry.tags, [SYNTHETIC_TAG]);
+        assert_eq!(entry.metadata["benchmarks"], 1);
+        // Re-scoring ignores the synthetic entry.
+        let again = candidate
+            .record_suite_score(&reference, MetricKind::Latency)
+            .unwrap();
+        assert_eq!(again.speedups.len(), 1);
+    }
+
+    #[test]
+    fn profile_weights() {
+        let profile = ScoreProfile::new()
+            .with_default_weight(0.0)
+            .with_weight("recursion/*", 3.0)
+            .with_weight("recursion/wrap", 1.0);
+        assert_eq!(profile.weight_for("recursion/verify"), 3.0);
+        assert_eq!(profile.weight_for("recursion/wrap"), 1.0);
+        assert_eq!(profile.weight_for("da/encode"), 0.0);
+
+        let reference = ReportBuilder::new()
+            .latencies([
+                ("recursion/verify", MetricValue::new(100.0, "ms")),
+                ("recursion/wrap", MetricValue::new(100.0, "ms")),
+                ("da/encode", MetricValue::new(100.0, "ms")),
+            ])
+            .build();
+        let mut candidate = ReportBuilder::new()
+            .latencies([
+                ("recursion/verify", MetricValue::new(50.0, "ms")),
+                ("recursion/wrap", MetricValue::new(100.0, "ms")),
+                ("da/encode", MetricValue::new(400.0, "ms")),
+            ])
+            .build();
+        let score = candidate
+            .record_weighted_suite_score(&reference, MetricKind::Latency, "recursion", &profile)
+            .unwrap();
+        // (2^3 * 1^1)^(1/4)
+        assert!((score.score.value - 2f64.powf(0.75)).abs() < 1e-12);
+        assert_eq!(score.weights.len(), 2);
+        let entry = &candidate.benchmarks["suite/score/recursion"];
+        assert_eq!(entry.metadata["profile"], "recursion");
+        assert_eq!(entry.metadata["weights"]["recursion/verify"], 3.0);
+
+        let none = ScoreProfile::new().with_default_weight(0.0);
+        assert!(
+            weighted_suite_score(&candidate, &reference, MetricKind::Latency, "x", &none).is_none()
+        );
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Speedup ratios with propagated uncertainty.
+
+use crate::schema::MetricValue;
+use crate::units::{format_number, to_base};
+
+/// Unit of the [`MetricValue`] returned by [`speedup`].
+pub const SPEEDUP_UNIT: &str = "x";
+
+/// How many times faster `b` is than `a`.
+///
+/// For times (and any other lower-is-better unit) this is `a / b`; for
+/// rates (units ending in `/s`) it is `b / a`. Both values are converted
+/// to their base unit first, so `1 s` against `500 ms` is a 2× speedup.
+///
+/// The bounds are propagated on the log scale, treating the two
+/// measurements as independent: each side's confidence interval
+/// contributes its relative half-widths, which add in quadrature. The
+/// result is therefore narrower than naive interval arithmetic
+/// (`a_lo / b_hi` to `a_hi / b_lo`) and asymmetric, like a ratio should
+/// be. When only one side has bounds, they alone determine the interval;
+/// when neither does, the result has none.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{MetricValue, format_speedup, speedup};
+///
+/// let before = MetricValue::with_bounds(2.3, "s", 2.2, 2.4);
+/// let after = MetricValue::with_bounds(1000.0, "ms", 960.0, 1040.0);
+/// let s = speedup(&before, &after);
+/// assert!((s.value - 2.3).abs() < 1e-12);
+/// assert_eq!(format_speedup(&s), "2.3× (2.17–2.44×)");
+/// ```
+pub fn speedup(a: &MetricValue, b: &MetricValue) -> MetricValue {
+    let rate = a.unit.ends_with("/s");
+    let (num, den) = if rate { (b, a) } else { (a, b) };
+    let value = to_base(num.value, &num.unit) / to_base(den.value, &den.unit);
+
+    // Log-scale distances from each estimate to its lower/upper bound.
+    let spread = |m: &MetricValue| {
+        let ln = |v: f64| to_base(v, &m.unit).ln();
+        let center = ln(m.value);
+        (
+            m.lower_value.map(|lo| center - ln(lo)),
+            m.upper_value.map(|hi| ln(hi) - center),
+        )
+    };
+    let (num_down, num_up) = spread(num);
+    let (den_down, den_up) = spread(den);
+    // The ratio is low when the numerator is low and the denominator high.
+    let combine = |x: Option<f64>, y: Option<f64>| match (x, y) {
+        (None, None) => None,
+        (x, y) => Some(x.unwrap_or(0.0).hypot(y.unwrap_or(0.0))),
+    };
+    let lower = combine(num_down, den_up).map(|d| value * (-d).exp());
+    let upper = combine(num_up, den_down).map(|d| value * d.exp());
+
+    MetricValue {
+        value,
+        unit: SPEEDUP_UNIT.to_string(),
+        lower_value: lower.filter(|v| v.is_finite()),
+        upper_value: upper.filter(|v| v.is_finite()),
+    }
+}
+
+/// Formats a speedup as `2.3×`, or `2.3× (2.1–2.5×)` when it has bounds.
+pub fn format_speedup(speedup: &MetricValue) -> String {
+    match (speedup.lower_value, speedup.upper_value) {
+        (Some(lower), Some(upper)) => format!(
+            "{}× ({}–{}×)",
+            format_number(speedup.value),
+            format_number(lower),
+            format_number(upper)
+        ),
+        _ => format!("{}×", format_number(speedup.value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_estimates() {
+        let s = speedup(&MetricValue::new(10.0, "ms"), &MetricValue::new(5.0, "ms"));
+        assert_eq!((s.value, s.unit.as_str()), (2.0, "x"));
+        assert!(s.lower_value.is_none() && s.upper_value.is_none());
+        assert_eq!(format_speedup(&s), "2×");
+
+        let rates = speedup(
+            &MetricValue::new(100.0, "ops/s"),
+            &MetricValue::new(50.0, "ops/s"),
+        );
+        assert_eq!(rates.value, 0.5);
+    }
+
+    #[test]
+    fn bounds_add_in_quadrature_on_log_scale() {
+        let e = 0.1f64.exp();
+        let a = MetricValue::with_bounds(100.0, "ms", 100.0 / e, 100.0 * e);
+        let b = MetricValue::with_bounds(50.0, "ms", 50.0 / e, 50.0 * e);
+        let s = speedup(&a, &b);
+        let d = (2.0f64 * 0.01).sqrt();
+        assert!((s.lower_value.unwrap() - 2.0 * (-d).exp()).abs() < 1e-12);
+        assert!((s.upper_value.unwrap() - 2.0 * d.exp()).abs() < 1e-12);
+        // Narrower than interval arithmetic.
+        assert!(s.lower_value.unwrap() > a.lower_value.unwrap() / b.upper_value.unwrap());
+    }
+
+    #[test]
+    fn one_sided_bounds_are_kept() {
+        let a = MetricValue::with_bounds(100.0, "ms", 90.0, 120.0);
+        let s = speedup(&a, &MetricValue::new(100.0, "ms"));
+        assert!((s.lower_value.unwrap() - 0.9).abs() < 1e-12);
+        assert!((s.upper_value.unwrap() - 1.2).abs() < 1e-12);
+        assert_eq!(format_speedup(&s), "1× (0.9–1.2×)");
+    }
+}
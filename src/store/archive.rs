@@ -0,0 +1,177 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Single-file archives of a [`DirStore`] (feature `archive`).
+//!
+//! [`DirStore::export`] packs the indexed reports, the index and the
+//! artifact blobs into one gzip-compressed tarball with the store's own
+//! layout, so a history can be moved between CI systems or attached to a
+//! release for public reproduction. [`DirStore::import`] unpacks one
+//! into a store, merging it with the reports already there.
+//!
+//! ```no_run
+//! use zkbench::store::DirStore;
+//!
+//! let store = DirStore::new("bench-history");
+//! store.export("history.tar.gz")?;
+//! let copy = DirStore::import("history.tar.gz", "restored-history")?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use super::dir::{BLOB_DIR, DIR_STORE_INDEX, DirStore, walk};
+
+impl DirStore {
+    /// Writes the store to a `.tar.gz` archive and returns the number of
+    /// reports in it. The store is locked while it is read, so the
+    /// archive is a consistent snapshot; the archive file itself appears
+    /// only once complete.
+    pub fn export(&self, path: impl AsRef<Path>) -> io::Result<usize> {
+        let path = path.as_ref();
+        let _lock = self.lock()?;
+        let index = self.list()?;
+        let name = path.file_name().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{}: not a file path", path.display()),
+            )
+        })?;
+        let tmp = path.with_file_name(format!(
+            ".{}.tmp-{}",
+            name.to_string_lossy(),
+            std::process::id()
+        ));
+        let written = (|| {
+            let encoder = GzEncoder::new(File::create(&tmp)?, Compression::default());
+            let mut tar = tar::Builder::new(encoder);
+            tar.append_path_with_name(self.root().join(DIR_STORE_INDEX), DIR_STORE_INDEX)?;
+            for entry in &index {
+                tar.append_path_with_name(self.root().join(&entry.path), &entry.path)?;
+            }
+            for (relative, file) in walk(&self.root().join(BLOB_DIR))? {
+                tar.append_path_with_name(file, format!("{BLOB_DIR}/{relative}"))?;
+            }
+            tar.into_inner()?.finish()?.sync_all()?;
+            fs::rename(&tmp, path)
+        })();
+        if written.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+        written.map(|()| index.len())
+    }
+
+    /// Unpacks an archive written by [`export`](Self::export) into the
+    /// store at `root` and rebuilds its index, so imported reports join
+    /// any already stored there (duplicates are indexed once). Entries
+    /// that would land outside `root` are rejected.
+    pub fn import(archive: impl AsRef<Path>, root: impl Into<PathBuf>) -> io::Result<Self> {
+        let store = Self::new(root);
+        fs::create_dir_all(store.root())?;
+        let mut tar = tar::Archive::new(GzDecoder::new(File::open(archive)?));
+        for entry in tar.entries()? {
+            let mut entry = entry?;
+            if entry.path()?.as_os_str() == DIR_STORE_INDEX {
+                continue;
+            }
+            if !entry.unpack_in(store.root())? {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "archive entry escapes the store: {}",
+                        entry.path()?.display()
+                    ),
+                ));
+            }
+        }
+        store.reindex()?;
+        Ok(store)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::ArtifactKind;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    fn temp(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("zkbench-archive-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn round_trips_and_merges() {
+        let source = DirStore::new(temp("source"));
+        let mut first = ReportBuilder::new()
+            .commit("c1")
+            .day(1)
+            .result("prove", BenchmarkResult::default())
+            .build();
+        let proof = source
+            .blobs()
+            .put_artifact(ArtifactKind::Proof, b"proof")
+            .unwrap();
+        first
+            .benchmarks
+            .get_mut("prove")
+            .unwrap()
+            .attach_artifact(proof.clone());
+        source.add(&first).unwrap();
+        source
+            .add(
+                &ReportBuilder::new()
+                    .commit("c2")
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        let archive = temp("history.tar.gz");
+        assert_eq!(source.export(&archive).unwrap(), 2);
+
+        let target = DirStore::new(temp("target"));
+        target
+            .add(
+                &ReportBuilder::new()
+                    .commit("c3")
+                    .day(3)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        target
+            .add(
+                &ReportBuilder::new()
+                    .commit("c2")
+                    .day(5)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        let target = DirStore::import(&archive, target.root()).unwrap();
+        let commits: Vec<_> = target
+            .list()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.commit_sha)
+            .collect();
+        assert_eq!(commits, ["c1", "c2", "c3"]);
+        assert!(target.blobs().contains(proof.blob_hash().unwrap()));
+
+        for path in [source.root(), target.root()] {
+            fs::remove_dir_all(path).unwrap();
+        }
+        fs::remove_file(&archive).unwrap();
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`ReportStore`] trait shared by the storage backends.
+//!
+//! Comparison, trend and baseline tooling only needs to put reports
+//! somewhere and read them back. [`ReportStore`] captures that much, so
+//! code written against it runs on any backend: the built-in
+//! [`DirStore`], [`GitNotesStore`] and `SqliteStore`, or one of your own
+//! on top of Redis, DynamoDB or an internal service.
+//!
+//! ```
+//! use std::io;
+//! use zkbench::store::{Query, ReportStore};
+//! use zkbench::{TrendOptions, analyze_trends};
+//!
+//! fn trends(store: &impl ReportStore) -> io::Result<Vec<zkbench::Trend>> {
+//!     let reports = store.query(&Query::new().with_implementation("my-impl"))?;
+//!     Ok(analyze_trends(&reports, &TrendOptions::default()))
+//! }
+//! ```
+//!
+//! A backend implements [`put`](ReportStore::put),
+//! [`get`](ReportStore::get) and [`list`](ReportStore::list); queries
+//! and the other reads have default implementations on top of those,
+//! which a backend can override with something faster.
+
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use super::dir::DirStore;
+use super::git_notes::GitNotesStore;
+use super::query::Query;
+use crate::schema::BenchmarkReport;
+use crate::trend::sort_chronologically;
+
+/// Identifying columns of a stored report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredReport {
+    /// The store's id for the report, as passed to
+    /// [`ReportStore::get`].
+    pub id: String,
+    pub implementation: String,
+    pub version: String,
+    pub commit_sha: String,
+    pub timestamp: String,
+}
+
+impl StoredReport {
+    pub(crate) fn new(id: String, report: &BenchmarkReport) -> Self {
+        let meta = &report.metadata;
+        Self {
+            id,
+            implementation: meta.implementation.clone(),
+            version: meta.version.clone(),
+            commit_sha: meta.commit_sha.clone(),
+            timestamp: meta.timestamp.clone(),
+        }
+    }
+}
+
+/// A place reports are stored in and read back from.
+///
+/// Ids are opaque strings chosen by the backend. Storing a report that
+/// is already stored, up to its timestamp (the same
+/// [`content_hash`](BenchmarkReport::content_hash)), should not store
+/// it twice but return the id of the stored copy, as the built-in
+/// backends do. Backend errors that are not I/O errors are wrapped with
+/// [`io::Error::other`].
+pub trait ReportStore {
+    /// Stores a report and returns its id.
+    fn put(&mut self, report: &BenchmarkReport) -> io::Result<String>;
+
+    /// Loads the report stored under `id`, if any.
+    fn get(&self, id: &str) -> io::Result<Option<BenchmarkReport>>;
+
+    /// Lists the stored reports, oldest first.
+    fn list(&self) -> io::Result<Vec<StoredReport>>;
+
+    /// Loads every stored report, oldest first.
+    fn reports(&self) -> io::Result<Vec<BenchmarkReport>> {
+        let mut reports = Vec::new();
+        for stored in self.list()? {
+            reports.extend(self.get(&stored.id)?);
+        }
+        sort_chronologically(&mut reports);
+        Ok(reports)
+    }
+
+    /// Runs a query over the stored reports. The default loads the
+    /// reports of the queried implementation and applies the query to
+    /// them.
+    fn query(&self, query: &Query) -> io::Result<Vec<BenchmarkReport>> {
+        let mut reports = Vec::new();
+        for stored in self.list()? {
+            if query
+                .implementation
+                .as_ref()
+                .is_none_or(|i| *i == stored.implementation)
+            {
+                reports.extend(self.get(&stored.id)?);
+            }
+        }
+        Ok(query.apply(&reports))
+    }
+
+    /// Loads the newest stored report of an implementation, e.g. as the
+    /// baseline of a comparison.
+    fn latest(&self, implementation: &str) -> io::Result<Option<BenchmarkReport>> {
+        let mut reports = self.query(&Query::new().with_implementation(implementation))?;
+        Ok(reports.pop())
+    }
+}
+
+/// Ids are report paths relative to the store root.
+impl ReportStore for DirStore {
+    fn put(&mut self, report: &BenchmarkReport) -> io::Result<String> {
+        Ok(self.add(report)?.path)
+    }
+
+    fn get(&self, id: &str) -> io::Result<Option<BenchmarkReport>> {
+        match DirStore::find(self, |e| e.path == id)?.first() {
+            Some(entry) => self.load(entry).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn list(&self) -> io::Result<Vec<StoredReport>> {
+        Ok(DirStore::list(self)?
+            .into_iter()
+            .map(|e| StoredReport {
+                id: e.path,
+                implementation: e.implementation,
+                version: e.version,
+                commit_sha: e.commit_sha,
+                timestamp: e.timestamp,
+            })
+            .collect())
+    }
+
+    fn reports(&self) -> io::Result<Vec<BenchmarkReport>> {
+        self.load_where(|_| true)
+    }
+
+    fn query(&self, query: &Query) -> io::Result<Vec<BenchmarkReport>> {
+        DirStore::query(self, query)
+    }
+}
+
+/// Ids are `<commit>:<content hash>`, with the full SHA of the commit
+/// the report is attached to.
+impl ReportStore for GitNotesStore {
+    fn put(&mut self, report: &BenchmarkReport) -> io::Result<String> {
+        let commit = GitNotesStore::put(self, report)?;
+        Ok(format!("{commit}:{}", report.content_hash()))
+    }
+
+    fn get(&self, id: &str) -> io::Result<Option<BenchmarkReport>> {
+        let Some((commit, hash)) = id.split_once(':') else {
+            return Ok(None);
+        };
+        let reports = match GitNotesStore::get(self, commit) {
+            Ok(reports) => reports,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        Ok(reports.into_iter().find(|r| r.content_hash() == hash))
+    }
+
+    fn list(&self) -> io::Result<Vec<StoredReport>> {
+        let mut stored = Vec::new();
+        for commit in self.commits()? {
+            for report in GitNotesStore::get(self, &commit)? {
+                let id = format!("{commit}:{}", report.content_hash());
+                stored.push(StoredReport::new(id, &report));
+            }
+        }
+        stored.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.id.cmp(&b.id)));
+        Ok(stored)
+    }
+
+    fn reports(&self) -> io::Result<Vec<BenchmarkReport>> {
+        GitNotesStore::reports(self)
+    }
+}
+
+/// Ids are [`report_id`](crate::report_id)s.
+#[cfg(feature = "sqlite")]
+impl ReportStore for super::sqlite::SqliteStore {
+    fn put(&mut self, report: &BenchmarkReport) -> io::Result<String> {
+        self.insert(report).map_err(io::Error::other)
+    }
+
+    fn get(&self, id: &str) -> io::Result<Option<BenchmarkReport>> {
+        self.load(id).map_err(io::Error::other)
+    }
+
+    fn list(&self) -> io::Result<Vec<StoredReport>> {
+        Self::list(self, None).map_err(io::Error::other)
+    }
+
+    fn reports(&self) -> io::Result<Vec<BenchmarkReport>> {
+        Self::reports(self, None).map_err(io::Error::other)
+    }
+
+    fn query(&self, query: &Query) -> io::Result<Vec<BenchmarkReport>> {
+        Self::query(self, query).map_err(io::Error::other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+    use std::collections::BTreeMap;
+
+    /// A minimal custom backend, relying on the default methods.
+    #[derive(Default)]
+    struct MemoryStore(BTreeMap<String, BenchmarkReport>);
+
+    impl ReportStore for MemoryStore {
+        fn put(&mut self, report: &BenchmarkReport) -> io::Result<String> {
+            let id = report.content_hash();
+            self.0.entry(id.clone()).or_insert_with(|| report.clone());
+            Ok(id)
+        }
+
+        fn get(&self, id: &str) -> io::Result<Option<BenchmarkReport>> {
+            Ok(self.0.get(id).cloned())
+        }
+
+        fn list(&self) -> io::Result<Vec<StoredReport>> {
+            let mut stored: Vec<_> = self
+                .0
+                .iter()
+                .map(|(id, report)| StoredReport::new(id.clone(), report))
+                .collect();
+            stored.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+            Ok(stored)
+        }
+    }
+
+    /// Exercises a backend through the trait only.
+    fn check(store: &mut impl ReportStore) {
+        let first = store
+            .put(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(1)
+                    .latencies_ms(&[("prove", f64::from(1))])
+                    .build(),
+            )
+            .unwrap();
+        store
+            .put(
+                &ReportBuilder::new()
+                    .implementation("b")
+                    .commit("c1")
+                    .day(2)
+                    .latencies_ms(&[("prove", f64::from(2))])
+                    .build(),
+            )
+            .unwrap();
+        store
+            .put(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c2")
+                    .day(3)
+                    .latencies_ms(&[("prove", f64::from(3))])
+                    .build(),
+            )
+            .unwrap();
+        let mut retried = ReportBuilder::new()
+            .implementation("a")
+            .commit("c1")
+            .day(1)
+            .latencies_ms(&[("prove", f64::from(1))])
+            .build();
+        retried.metadata.timestamp = "2026-01-09T00:00:00+00:00".to_string();
+        assert_eq!(store.put(&retried).unwrap(), first);
+
+        let listed = store.list().unwrap();
+        assert_eq!(listed.len(), 3);
+        assert_eq!(listed[0].id, first);
+        assert_eq!(
+            store.get(&first).unwrap().unwrap().metadata.commit_sha,
+            "c1"
+        );
+        assert!(store.get("missing").unwrap().is_none());
+        assert_eq!(store.reports().unwrap().len(), 3);
+        assert_eq!(
+            store
+                .query(&Query::new().with_implementation("a"))
+                .unwrap()
+                .len(),
+            2
+        );
+        let latest = store.latest("a").unwrap().unwrap();
+        assert_eq!(latest.metadata.commit_sha, "c2");
+        assert!(store.latest("c").unwrap().is_none());
+    }
+
+    #[test]
+    fn custom_backend_gets_default_reads() {
+        check(&mut MemoryStore::default());
+    }
+
+    #[test]
+    fn dir_store_is_a_report_store() {
+        let root = std::env::temp_dir().join(format!("zkbench-backend-dir-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        check(&mut DirStore::new(&root));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn sqlite_store_is_a_report_store() {
+        check(&mut super::super::sqlite::SqliteStore::open_in_memory().unwrap());
+    }
+}
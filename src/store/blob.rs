@@ -0,0 +1,290 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Content-addressed blob store for large artifacts.
+//!
+//! Proof bytes, witness files and flamegraphs are too large to inline in
+//! a report and too valuable to leave as paths into a CI runner's
+//! scratch space. A [`BlobStore`] keeps them under their SHA-256, at
+//! `<root>/sha256/<first two hex digits>/<rest>`, and hands back an
+//! [`Artifact`] whose location is `blob:sha256:<hex>`, so the result
+//! references the bytes by hash. Identical artifacts are stored once;
+//! [`BlobStore::gc`] removes blobs no report references any more.
+//!
+//! ```no_run
+//! use zkbench::ArtifactKind;
+//! use zkbench::store::BlobStore;
+//! # let mut result = zkbench::BenchmarkResult::default();
+//! # let proof: Vec<u8> = Vec::new();
+//!
+//! let blobs = BlobStore::new("bench-history/blobs");
+//! result.attach_artifact(blobs.put_artifact(ArtifactKind::Proof, &proof)?);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use sha2::{Digest, Sha256};
+
+use crate::artifact::{Artifact, ArtifactKind};
+use crate::schema::BenchmarkReport;
+
+/// Location prefix of artifacts stored in a [`BlobStore`].
+pub const BLOB_SCHEME: &str = "blob:";
+
+const HASH_PREFIX: &str = "sha256:";
+
+/// Outcome of [`BlobStore::gc`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlobGc {
+    /// Hashes of the removed blobs, sorted.
+    pub removed: Vec<String>,
+    pub freed_bytes: u64,
+}
+
+/// Blobs stored under their SHA-256.
+#[derive(Debug, Clone)]
+pub struct BlobStore {
+    root: PathBuf,
+}
+
+impl BlobStore {
+    /// Stores blobs under `root`, which is created on the first put.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the root directory of the store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Stores bytes and returns their hash in `sha256:<hex>` form.
+    pub fn put(&self, bytes: &[u8]) -> io::Result<String> {
+        self.put_reader(&mut &bytes[..]).map(|(hash, _)| hash)
+    }
+
+    /// Stores a file, streaming it through the hasher, and returns its
+    /// hash and size.
+    pub fn put_file(&self, path: impl AsRef<Path>) -> io::Result<(String, u64)> {
+        self.put_reader(&mut File::open(path)?)
+    }
+
+    /// Stores bytes and returns an artifact referencing them.
+    pub fn put_artifact(&self, kind: ArtifactKind, bytes: &[u8]) -> io::Result<Artifact> {
+        let hash = self.put(bytes)?;
+        Ok(Artifact::from_blob(kind, &hash, bytes.len() as u64))
+    }
+
+    /// Stores a file and returns an artifact referencing it.
+    pub fn put_file_artifact(
+        &self,
+        kind: ArtifactKind,
+        path: impl AsRef<Path>,
+    ) -> io::Result<Artifact> {
+        let (hash, size) = self.put_file(path)?;
+        Ok(Artifact::from_blob(kind, &hash, size))
+    }
+
+    /// Whether a blob is stored.
+    pub fn contains(&self, hash: &str) -> bool {
+        self.path(hash).is_ok_and(|path| path.is_file())
+    }
+
+    /// Reads a blob. A malformed hash is
+    /// [`io::ErrorKind::InvalidInput`], a missing blob
+    /// [`io::ErrorKind::NotFound`].
+    pub fn get(&self, hash: &str) -> io::Result<Vec<u8>> {
+        fs::read(self.path(hash)?)
+    }
+
+    /// Path at which a blob is (or would be) stored.
+    pub fn path(&self, hash: &str) -> io::Result<PathBuf> {
+        let hex = hash
+            .strip_prefix(HASH_PREFIX)
+            .filter(|hex| hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("not a sha256:<hex> digest: {hash}"),
+                )
+            })?
+            .to_ascii_lowercase();
+        Ok(self.root.join("sha256").join(&hex[..2]).join(&hex[2..]))
+    }
+
+    /// Lists the stored blobs' hashes, sorted.
+    pub fn list(&self) -> io::Result<Vec<String>> {
+        let mut hashes = Vec::new();
+        let shards = match fs::read_dir(self.root.join("sha256")) {
+            Ok(shards) => shards,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(hashes),
+            Err(e) => return Err(e),
+        };
+        for shard in shards {
+            let shard = shard?;
+            if !shard.file_type()?.is_dir() {
+                continue;
+            }
+            for blob in fs::read_dir(shard.path())? {
+                let blob = blob?;
+                let hash = format!(
+                    "{HASH_PREFIX}{}{}",
+                    shard.file_name().to_string_lossy(),
+                    blob.file_name().to_string_lossy()
+                );
+                if self.path(&hash).is_ok() {
+                    hashes.push(hash);
+                }
+            }
+        }
+        hashes.sort();
+        Ok(hashes)
+    }
+
+    /// Removes every blob that no artifact of the reports references by
+    /// content hash. With `dry_run`, only reports what would be removed.
+    pub fn gc(&self, reports: &[BenchmarkReport], dry_run: bool) -> io::Result<BlobGc> {
+        let referenced: HashSet<&str> = reports
+            .iter()
+            .flat_map(|report| report.benchmarks.values())
+            .flat_map(|result| &result.artifacts)
+            .filter_map(|artifact| artifact.content_hash.as_deref())
+            .collect();
+        let mut gc = BlobGc::default();
+        for hash in self.list()? {
+            if referenced.contains(hash.as_str()) {
+                continue;
+            }
+            let path = self.path(&hash)?;
+            gc.freed_bytes += fs::metadata(&path)?.len();
+            if !dry_run {
+                fs::remove_file(&path)?;
+                if let Some(shard) = path.parent() {
+                    let _ = fs::remove_dir(shard);
+                }
+            }
+            gc.removed.push(hash);
+        }
+        Ok(gc)
+    }
+
+    /// Copies a reader into a temporary file while hashing it, then
+    /// moves the file to its content address. A blob already stored is
+    /// left untouched.
+    fn put_reader(&self, reader: &mut impl io::Read) -> io::Result<(String, u64)> {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let tmp_dir = self.root.join("tmp");
+        fs::create_dir_all(&tmp_dir)?;
+        let tmp = tmp_dir.join(format!(
+            "{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = (|| {
+            let mut file = File::create(&tmp)?;
+            let mut hasher = HashingWriter {
+                inner: &mut file,
+                hasher: Sha256::new(),
+            };
+            let size = io::copy(reader, &mut hasher)?;
+            let hash = format!("{HASH_PREFIX}{:x}", hasher.hasher.finalize());
+            file.sync_all()?;
+            let path = self.path(&hash)?;
+            if path.is_file() {
+                fs::remove_file(&tmp)?;
+            } else {
+                fs::create_dir_all(path.parent().expect("blob paths have a shard"))?;
+                fs::rename(&tmp, &path)?;
+            }
+            Ok((hash, size))
+        })();
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+        result
+    }
+}
+
+/// Writes through to a file while feeding the hasher.
+struct HashingWriter<'a> {
+    inner: &'a mut File,
+    hasher: Sha256,
+}
+
+impl Write for HashingWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    fn store(name: &str) -> BlobStore {
+        let root =
+            std::env::temp_dir().join(format!("zkbench-blobs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        BlobStore::new(root)
+    }
+
+    #[test]
+    fn stores_by_content_hash() {
+        let store = store("put");
+        let hash = store.put(b"abc").unwrap();
+        assert_eq!(
+            hash,
+            "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(store.put(b"abc").unwrap(), hash);
+        assert_eq!(store.get(&hash).unwrap(), b"abc");
+        assert!(
+            store
+                .root()
+                .join("sha256/ba/7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+                .is_file()
+        );
+        assert_eq!(store.list().unwrap(), [hash]);
+
+        let artifact = store.put_artifact(ArtifactKind::Proof, b"proof").unwrap();
+        assert!(artifact.location.starts_with(BLOB_SCHEME));
+        assert_eq!(artifact.blob_hash(), artifact.content_hash.as_deref());
+        assert_eq!(artifact.size_bytes, Some(5));
+
+        let err = store.get("sha256:../../etc/passwd").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn collects_unreferenced_blobs() {
+        let store = store("gc");
+        let mut result = BenchmarkResult::default();
+        result.attach_artifact(store.put_artifact(ArtifactKind::Witness, b"kept").unwrap());
+        let dropped = store.put(b"dropped").unwrap();
+        let report = ReportBuilder::new().result("prove", result).build();
+
+        let plan = store.gc(std::slice::from_ref(&report), true).unwrap();
+        assert_eq!(plan.removed, [dropped.as_str()]);
+        assert_eq!(plan.freed_bytes, 7);
+        assert!(store.contains(&dropped));
+
+        store.gc(&[report], false).unwrap();
+        assert!(!store.contains(&dropped));
+        assert_eq!(store.list().unwrap().len(), 1);
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+}
@@ -0,0 +1,923 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Directory-based report store.
+//!
+//! Reports are written under a canonical layout,
+//! `<root>/<implementation>/<commit>/<platform fingerprint>/<timestamp>.json`,
+//! and summarized in `<root>/index.json`, so listing and filtering
+//! thousands of reports reads one file instead of parsing every report.
+//! Updates of the index hold an exclusive lock on `<root>/.lock`, so
+//! several benchmark processes, such as parallel CI shards on one
+//! machine, can add to the same store without losing each other's
+//! entries. Reports and the index are written atomically; a report that
+//! is nevertheless corrupt when loaded, e.g. one copied in truncated, and
+//! that does not migrate from an older schema version either, is moved
+//! to `<root>/quarantine/` and dropped from the index rather than failing
+//! the whole load.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use zkbench::store::DirStore;
+//! # let report: zkbench::BenchmarkReport = unimplemented!();
+//!
+//! let store = DirStore::new("bench-history");
+//! store.add(&report)?;
+//! let main = store.load_where(|e| e.implementation == "my-impl")?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::blob::{BlobGc, BlobStore};
+use super::migrate::{MigratedReport, MigrationFailure, MigrationReport};
+use super::query::Query;
+use super::retention::{Candidate, PruneReport, RetentionPolicy};
+use crate::atomic::write_atomic;
+use crate::migrate::{load_migrated, migrate_json};
+use crate::schema::BenchmarkReport;
+use crate::trend::parse_timestamp;
+
+/// Name of the index file at the root of a [`DirStore`].
+pub const DIR_STORE_INDEX: &str = "index.json";
+
+/// Name of the lock file that serializes index updates of a
+/// [`DirStore`].
+pub const DIR_STORE_LOCK: &str = ".lock";
+
+/// Directory of a [`DirStore`] that corrupt reports are moved to.
+pub const DIR_STORE_QUARANTINE: &str = "quarantine";
+
+/// Directory of a [`DirStore`] holding its [`BlobStore`].
+pub(super) const BLOB_DIR: &str = "blobs";
+
+/// Summary of a stored report, as kept in the index.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// Path of the report relative to the store root, with `/`
+    /// separators.
+    pub path: String,
+    pub implementation: String,
+    pub version: String,
+    pub commit_sha: String,
+    pub timestamp: String,
+    /// [`Platform::fingerprint`](crate::Platform::fingerprint) of the
+    /// machine that produced the report.
+    pub fingerprint: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub git_tag: Option<String>,
+    #[serde(default)]
+    pub dirty: bool,
+    /// Number of benchmarks in the report.
+    pub benchmarks: usize,
+    /// [`BenchmarkReport::content_hash`] of the report. Empty in indexes
+    /// written before it was recorded, until the next
+    /// [`reindex`](DirStore::reindex).
+    #[serde(default)]
+    pub content_hash: String,
+}
+
+impl IndexEntry {
+    fn new(path: String, report: &BenchmarkReport) -> Self {
+        let meta = &report.metadata;
+        Self {
+            path,
+            implementation: meta.implementation.clone(),
+            version: meta.version.clone(),
+            commit_sha: meta.commit_sha.clone(),
+            timestamp: meta.timestamp.clone(),
+            fingerprint: meta.platform.fingerprint(),
+            branch: meta.branch.clone(),
+            git_tag: meta.git_tag.clone(),
+            dirty: meta.dirty,
+            benchmarks: report.benchmarks.len(),
+            content_hash: report.content_hash(),
+        }
+    }
+
+    fn candidate(&self) -> Candidate {
+        Candidate {
+            id: self.path.clone(),
+            implementation: self.implementation.clone(),
+            commit_sha: self.commit_sha.clone(),
+            timestamp: self.timestamp.clone(),
+            branch: self.branch.clone(),
+            git_tag: self.git_tag.clone(),
+            dirty: self.dirty,
+        }
+    }
+}
+
+/// Reports stored as JSON files under a canonical directory layout.
+#[derive(Debug, Clone)]
+pub struct DirStore {
+    root: PathBuf,
+}
+
+impl DirStore {
+    /// Stores reports under `root`, which is created on the first add.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the root directory of the store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Blob store for the artifacts of the stored reports, under
+    /// `<root>/blobs`.
+    pub fn blobs(&self) -> BlobStore {
+        BlobStore::new(self.root.join(BLOB_DIR))
+    }
+
+    /// Removes the blobs no stored report references. With `dry_run`,
+    /// only reports what would be removed.
+    pub fn gc_blobs(&self, dry_run: bool) -> io::Result<BlobGc> {
+        self.blobs().gc(&self.load_where(|_| true)?, dry_run)
+    }
+
+    /// Canonical path of a report relative to the root. Path components
+    /// are sanitized so that no metadata value can escape the root.
+    pub fn relative_path(report: &BenchmarkReport) -> String {
+        let meta = &report.metadata;
+        format!(
+            "{}/{}/{}/{}.json",
+            component(&meta.implementation),
+            component(&meta.commit_sha),
+            meta.platform.fingerprint(),
+            component(&meta.timestamp)
+        )
+    }
+
+    /// Writes a report to its canonical path, replacing a report stored
+    /// there before, and records it in the index.
+    ///
+    /// A report with the same content hash as an indexed one, typically
+    /// the re-upload of a retried CI job, is not written again: the entry
+    /// of the stored copy is returned instead.
+    pub fn add(&self, report: &BenchmarkReport) -> io::Result<IndexEntry> {
+        let _lock = self.lock()?;
+        let relative = Self::relative_path(report);
+        let entry = IndexEntry::new(relative, report);
+        let mut index = self.list()?;
+        if let Some(existing) = index
+            .iter()
+            .find(|e| e.content_hash == entry.content_hash && e.path != entry.path)
+        {
+            return Ok(existing.clone());
+        }
+        let path = self.root.join(&entry.path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        report.save(&path)?;
+        index.retain(|e| e.path != entry.path);
+        index.push(entry.clone());
+        self.write_index(index)?;
+        Ok(entry)
+    }
+
+    /// Lists the indexed reports, oldest first. A store without an index
+    /// is empty.
+    pub fn list(&self) -> io::Result<Vec<IndexEntry>> {
+        let path = self.root.join(DIR_STORE_INDEX);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        serde_json::from_str(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Lists the indexed reports matching a predicate, oldest first.
+    pub fn find(&self, predicate: impl Fn(&IndexEntry) -> bool) -> io::Result<Vec<IndexEntry>> {
+        Ok(self.list()?.into_iter().filter(|e| predicate(e)).collect())
+    }
+
+    /// Loads the report an index entry points to, migrating it in memory
+    /// if it does not parse as the current schema.
+    pub fn load(&self, entry: &IndexEntry) -> io::Result<BenchmarkReport> {
+        load_migrated(&self.root.join(&entry.path))
+    }
+
+    /// Loads the reports whose index entries match a predicate, oldest
+    /// first. Reports that are not valid JSON, or that fail to migrate,
+    /// are quarantined and left out; reports written by a newer version
+    /// of the crate are left out but kept in place.
+    pub fn load_where(
+        &self,
+        predicate: impl Fn(&IndexEntry) -> bool,
+    ) -> io::Result<Vec<BenchmarkReport>> {
+        let mut reports = Vec::new();
+        let mut corrupt = Vec::new();
+        for entry in self.find(predicate)? {
+            match self.load(&entry) {
+                Ok(report) => reports.push(report),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData => corrupt.push(entry.path),
+                Err(e) if e.kind() == io::ErrorKind::Unsupported => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if !corrupt.is_empty() {
+            self.quarantine(&corrupt)?;
+        }
+        Ok(reports)
+    }
+
+    /// Lists the quarantined reports, as paths relative to the
+    /// quarantine directory, sorted.
+    pub fn quarantined(&self) -> io::Result<Vec<String>> {
+        let mut paths: Vec<String> = walk(&self.root.join(DIR_STORE_QUARANTINE))?
+            .into_iter()
+            .map(|(relative, _)| relative)
+            .collect();
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Moves reports to the quarantine directory, keeping their relative
+    /// paths, and drops them from the index.
+    fn quarantine(&self, paths: &[String]) -> io::Result<()> {
+        let _lock = self.lock()?;
+        for path in paths {
+            let target = self.root.join(DIR_STORE_QUARANTINE).join(path);
+            fs::create_dir_all(target.parent().expect("report paths have a parent"))?;
+            match fs::rename(self.root.join(path), &target) {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let mut index = self.list()?;
+        index.retain(|e| !paths.contains(&e.path));
+        self.write_index(index)
+    }
+
+    /// Runs a query over the stored reports, loading only those whose
+    /// index entries pass its implementation, fingerprint and date
+    /// filters.
+    pub fn query(&self, query: &Query) -> io::Result<Vec<BenchmarkReport>> {
+        let candidates = self.load_where(|e| {
+            query
+                .implementation
+                .as_ref()
+                .is_none_or(|i| *i == e.implementation)
+                && query
+                    .fingerprint
+                    .as_ref()
+                    .is_none_or(|f| *f == e.fingerprint)
+                && (query.since.is_none() && query.until.is_none()
+                    || parse_timestamp(&e.timestamp).is_some_and(|t| {
+                        query.since.is_none_or(|s| t >= s) && query.until.is_none_or(|u| t <= u)
+                    }))
+        })?;
+        Ok(query.apply(&candidates))
+    }
+
+    /// Rebuilds the index from the report files under the root, e.g.
+    /// after reports were copied in by hand, and returns the number of
+    /// reports indexed. Files that are not reports are skipped, and of
+    /// several files holding the same report only the oldest is indexed.
+    pub fn reindex(&self) -> io::Result<usize> {
+        let _lock = self.lock()?;
+        let mut index: Vec<IndexEntry> = Vec::new();
+        for (relative, path) in walk(&self.root)? {
+            let top = relative.split('/').next().unwrap_or_default();
+            if relative.ends_with(".json")
+                && relative != DIR_STORE_INDEX
+                && top != DIR_STORE_QUARANTINE
+                && top != BLOB_DIR
+                && let Ok(report) = load_migrated(&path)
+            {
+                index.push(IndexEntry::new(relative, &report));
+            }
+        }
+        index.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.path.cmp(&b.path)));
+        let mut seen = HashSet::new();
+        index.retain(|e| seen.insert(e.content_hash.clone()));
+        let count = index.len();
+        self.write_index(index)?;
+        Ok(count)
+    }
+
+    /// Deletes the reports the retention policy drops, along with
+    /// directories left empty, and removes them from the index. With
+    /// `dry_run`, only reports what would be deleted. Pruned ids are
+    /// index paths.
+    pub fn prune(&self, policy: &RetentionPolicy, dry_run: bool) -> io::Result<PruneReport> {
+        let _lock = self.lock()?;
+        let index = self.list()?;
+        let mut report = policy.plan(
+            index.iter().map(IndexEntry::candidate).collect(),
+            chrono::Utc::now(),
+        );
+        report.dry_run = dry_run;
+        if dry_run || report.pruned.is_empty() {
+            return Ok(report);
+        }
+        for pruned in &report.pruned {
+            let path = self.root.join(&pruned.id);
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+            let mut dir = path.parent();
+            while let Some(d) = dir.filter(|d| *d != self.root) {
+                if fs::remove_dir(d).is_err() {
+                    break;
+                }
+                dir = d.parent();
+            }
+        }
+        let pruned: HashSet<&str> = report.pruned.iter().map(|p| p.id.as_str()).collect();
+        self.write_index(
+            index
+                .into_iter()
+                .filter(|e| !pruned.contains(e.path.as_str()))
+                .collect(),
+        )?;
+        Ok(report)
+    }
+
+    /// Upgrades every indexed report written with an older schema
+    /// version in place (see [`migrate_json`]) and updates its index
+    /// entry. Reports that cannot be upgraded without loss are left
+    /// untouched and listed as failed. With `dry_run`, only reports what
+    /// would be migrated.
+    pub fn migrate(&self, dry_run: bool) -> io::Result<MigrationReport> {
+        let _lock = self.lock()?;
+        let mut index = self.list()?;
+        let mut report = MigrationReport::new(dry_run);
+        for entry in &mut index {
+            let json = fs::read_to_string(self.root.join(&entry.path))?;
+            let migrated = match migrate_json(&json) {
+                Ok(migrated) if !migrated.changed() => {
+                    report.current += 1;
+                    continue;
+                }
+                Ok(migrated) => migrated,
+                Err(e) => {
+                    report.failed.push(MigrationFailure {
+                        id: entry.path.clone(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+            if !dry_run {
+                migrated.report.save(self.root.join(&entry.path))?;
+                *entry = IndexEntry::new(entry.path.clone(), &migrated.report);
+            }
+            report.migrated.push(MigratedReport {
+                id: entry.path.clone(),
+                new_id: entry.path.clone(),
+                from_version: migrated.from_version,
+            });
+        }
+        if !dry_run && !report.migrated.is_empty() {
+            self.write_index(index)?;
+        }
+        Ok(report)
+    }
+
+    /// Takes the store's exclusive lock, blocking until other writers
+    /// release it. The lock is released when the returned file is
+    /// dropped.
+    pub(super) fn lock(&self) -> io::Result<File> {
+        fs::create_dir_all(&self.root)?;
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(self.root.join(DIR_STORE_LOCK))?;
+        file.lock()?;
+        Ok(file)
+    }
+
+    fn write_index(&self, mut index: Vec<IndexEntry>) -> io::Result<()> {
+        index.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.path.cmp(&b.path)));
+        fs::create_dir_all(&self.root)?;
+        write_atomic(
+            self.root.join(DIR_STORE_INDEX),
+            serde_json::to_string_pretty(&index)?,
+        )
+    }
+}
+
+/// Lists the files below `root`, with their paths relative to it
+/// (`/`-separated). A missing root has none.
+pub(super) fn walk(root: &Path) -> io::Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                let relative = path
+                    .strip_prefix(root)
+                    .expect("walked from the root")
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                files.push((relative, path));
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Makes a metadata value safe as a single path component.
+pub(crate) fn component(value: &str) -> String {
+    let sanitized: String = value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '+') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    match sanitized.trim_start_matches('.') {
+        "" => "_".to_string(),
+        rest => rest.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::ArtifactKind;
+    use crate::schema::{BenchmarkResult, MetricValue, SCHEMA_VERSION};
+    use crate::testing::ReportBuilder;
+
+    fn store(name: &str) -> DirStore {
+        let root =
+            std::env::temp_dir().join(format!("zkbench-dirstore-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        DirStore::new(root)
+    }
+
+    #[test]
+    fn canonical_layout() {
+        let report = ReportBuilder::new()
+            .implementation("../evil")
+            .commit("abc123")
+            .day(1)
+            .result("prove", BenchmarkResult::default())
+            .build();
+        let path = DirStore::relative_path(&report);
+        let fingerprint = report.metadata.platform.fingerprint();
+        assert_eq!(
+            path,
+            format!("_evil/abc123/{fingerprint}/2026-01-01T00_00_00+00_00.json")
+        );
+    }
+
+    #[test]
+    fn adds_lists_and_filters() {
+        let store = store("filter");
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c2")
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(1)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("b")
+                    .commit("c1")
+                    .day(3)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("b")
+                    .commit("c1")
+                    .day(3)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+
+        let all = store.list().unwrap();
+        assert_eq!(all.len(), 3);
+        let commits: Vec<_> = all.iter().map(|e| e.commit_sha.as_str()).collect();
+        assert_eq!(commits, ["c1", "c2", "c1"]);
+        assert_eq!(all[0].benchmarks, 1);
+
+        let a = store.load_where(|e| e.implementation == "a").unwrap();
+        assert_eq!(a.len(), 2);
+        assert_eq!(a[1].metadata.commit_sha, "c2");
+        assert!(store.find(|e| e.commit_sha == "c9").unwrap().is_empty());
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn reindexes_from_files() {
+        let store = store("reindex");
+        assert!(store.list().unwrap().is_empty());
+        let entry = store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(1)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c2")
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        fs::write(store.root().join("a/notes.json"), "not a report").unwrap();
+        fs::remove_file(store.root().join(DIR_STORE_INDEX)).unwrap();
+
+        assert_eq!(store.reindex().unwrap(), 2);
+        let index = store.list().unwrap();
+        assert_eq!(index[0], entry);
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn skips_reuploaded_reports() {
+        let store = store("dedup");
+        let first = store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(1)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        let retried = store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        assert_eq!(retried, first);
+        assert_eq!(store.list().unwrap().len(), 1);
+        assert!(
+            !store
+                .root()
+                .join(DirStore::relative_path(
+                    &ReportBuilder::new()
+                        .implementation("a")
+                        .commit("c1")
+                        .day(2)
+                        .result("prove", BenchmarkResult::default())
+                        .build()
+                ))
+                .exists()
+        );
+
+        let copy = store.root().join("a/copy.json");
+        ReportBuilder::new()
+            .implementation("a")
+            .commit("c1")
+            .day(3)
+            .result("prove", BenchmarkResult::default())
+            .build()
+            .save(&copy)
+            .unwrap();
+        assert_eq!(store.reindex().unwrap(), 1);
+        assert_eq!(store.list().unwrap()[0].path, first.path);
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn quarantines_corrupt_reports() {
+        let store = store("quarantine");
+        let good = store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(1)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        let bad = store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c2")
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        fs::write(store.root().join(&bad.path), "{\"metadata\": {").unwrap();
+        let invalid = store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c3")
+                    .day(3)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        let mut value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(store.root().join(&invalid.path)).unwrap())
+                .unwrap();
+        value["metadata"]["schema_version"] = 2.into();
+        value["benchmarks"]["prove"]["test_vectors"] = serde_json::json!({
+            "input_hash": "abcdef",
+            "output_hash": "cd".repeat(32),
+            "verified": true,
+        });
+        fs::write(store.root().join(&invalid.path), value.to_string()).unwrap();
+        let newer = store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c4")
+                    .day(4)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        fs::write(
+            store.root().join(&newer.path),
+            r#"{"metadata": {"schema_version": 99}, "benchmarks": {}}"#,
+        )
+        .unwrap();
+
+        let reports = store.load_where(|_| true).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(store.list().unwrap(), [good, newer]);
+        assert_eq!(
+            store.quarantined().unwrap(),
+            [bad.path.as_str(), invalid.path.as_str()]
+        );
+        assert!(
+            store
+                .root()
+                .join(DIR_STORE_QUARANTINE)
+                .join(&bad.path)
+                .is_file()
+        );
+        assert_eq!(store.reindex().unwrap(), 1);
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn migrates_reports_in_place() {
+        let store = store("migrate");
+        let mut legacy = ReportBuilder::new()
+            .implementation("a")
+            .commit("c1")
+            .day(1)
+            .result("prove", BenchmarkResult::default())
+            .build();
+        legacy.metadata.schema_version = 1;
+        legacy.benchmarks.get_mut("prove").unwrap().memory = Some(MetricValue::new(2.0, "MB"));
+        let legacy = store.add(&legacy).unwrap();
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c2")
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        let broken = store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c3")
+                    .day(3)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        fs::write(
+            store.root().join(&broken.path),
+            r#"{"metadata": {"schema_version": 99}, "benchmarks": {}}"#,
+        )
+        .unwrap();
+
+        let plan = store.migrate(true).unwrap();
+        assert_eq!(plan.migrated.len(), 1);
+        assert_eq!(plan.migrated[0].id, legacy.path);
+        assert_eq!(store.load(&legacy).unwrap().metadata.schema_version, 1);
+
+        let done = store.migrate(false).unwrap();
+        assert_eq!((done.current, done.failed.len()), (1, 1));
+        assert_eq!(done.failed[0].id, broken.path);
+        let migrated = store.load(&legacy).unwrap();
+        assert_eq!(migrated.metadata.schema_version, SCHEMA_VERSION);
+        assert_eq!(
+            migrated.benchmarks["prove"].memory.as_ref().unwrap().unit,
+            "MiB"
+        );
+        let entry = &store.find(|e| e.path == legacy.path).unwrap()[0];
+        assert_eq!(entry.content_hash, migrated.content_hash());
+        assert!(store.migrate(false).unwrap().migrated.is_empty());
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn concurrent_writers_keep_every_entry() {
+        let store = store("concurrent");
+        std::thread::scope(|scope| {
+            for shard in 0..8 {
+                let store = store.clone();
+                scope.spawn(move || {
+                    for day in 1..=5 {
+                        store
+                            .add(
+                                &ReportBuilder::new()
+                                    .implementation("a")
+                                    .commit(&format!("shard{shard}-{day}"))
+                                    .day(day)
+                                    .result("prove", BenchmarkResult::default())
+                                    .build(),
+                            )
+                            .unwrap();
+                    }
+                });
+            }
+        });
+        assert_eq!(store.list().unwrap().len(), 40);
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn collects_blobs_of_pruned_reports() {
+        let store = store("blobs");
+        let mut kept = ReportBuilder::new()
+            .implementation("a")
+            .commit("c1")
+            .day(1)
+            .result("prove", BenchmarkResult::default())
+            .build();
+        let proof = store
+            .blobs()
+            .put_artifact(ArtifactKind::Proof, b"proof")
+            .unwrap();
+        kept.benchmarks
+            .get_mut("prove")
+            .unwrap()
+            .attach_artifact(proof.clone());
+        store.add(&kept).unwrap();
+        let orphan = store.blobs().put(b"orphan").unwrap();
+        store.reindex().unwrap();
+        assert_eq!(store.list().unwrap().len(), 1);
+
+        let gc = store.gc_blobs(false).unwrap();
+        assert_eq!(gc.removed, [orphan]);
+        assert!(store.blobs().contains(proof.blob_hash().unwrap()));
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn queries_through_the_index() {
+        let store = store("query");
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(1)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c2")
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("b")
+                    .commit("c2")
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        let query = Query::new()
+            .with_implementation("a")
+            .with_since(parse_timestamp("2026-01-02T00:00:00+00:00").unwrap());
+        let reports = store.query(&query).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].metadata.commit_sha, "c2");
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn prunes_by_retention_policy() {
+        let store = store("prune");
+        let mut dirty = ReportBuilder::new()
+            .implementation("a")
+            .commit("c0")
+            .day(1)
+            .result("prove", BenchmarkResult::default())
+            .build();
+        dirty.metadata.dirty = true;
+        dirty.metadata.timestamp = "2020-01-01T00:00:00+00:00".to_string();
+        let dirty = store.add(&dirty).unwrap();
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        store
+            .add(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c2")
+                    .day(3)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        let policy = RetentionPolicy::new().with_keep_last(1);
+
+        let plan = store.prune(&policy, true).unwrap();
+        assert!(plan.dry_run);
+        assert_eq!(plan.pruned.len(), 2);
+        assert_eq!(plan.pruned[0].id, dirty.path);
+        assert_eq!(store.list().unwrap().len(), 3);
+
+        let done = store.prune(&policy, false).unwrap();
+        assert_eq!(done.pruned, plan.pruned);
+        let left = store.list().unwrap();
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].commit_sha, "c2");
+        assert!(!store.root().join("a/c0").exists());
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Report storage in `git notes`.
+//!
+//! A [`GitNotesStore`] attaches each report to the commit it measured,
+//! as a note under a dedicated ref (`refs/notes/zkbench` by default), so
+//! the performance history travels with the repository: pushing and
+//! fetching the ref shares it, and trend and bisect tooling read it back
+//! for any revision range.
+//!
+//! ```text
+//! git push origin refs/notes/zkbench
+//! git fetch origin refs/notes/zkbench:refs/notes/zkbench
+//! ```
+//!
+//! A note holds one report per line (JSON Lines), so several
+//! implementations or machines can benchmark the same commit.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::hash::dedup_reports;
+use crate::schema::BenchmarkReport;
+use crate::trend::sort_chronologically;
+
+/// Notes ref used unless [`GitNotesStore::with_ref`] picks another.
+pub const DEFAULT_NOTES_REF: &str = "refs/notes/zkbench";
+
+/// Reports stored as notes on the commits they measured.
+#[derive(Debug, Clone)]
+pub struct GitNotesStore {
+    repo: PathBuf,
+    notes_ref: String,
+}
+
+impl GitNotesStore {
+    /// Uses the repository containing `repo`, with the default notes ref.
+    pub fn new(repo: impl Into<PathBuf>) -> Self {
+        Self {
+            repo: repo.into(),
+            notes_ref: DEFAULT_NOTES_REF.to_string(),
+        }
+    }
+
+    /// Uses another notes ref.
+    pub fn with_ref(mut self, notes_ref: impl Into<String>) -> Self {
+        self.notes_ref = notes_ref.into();
+        self
+    }
+
+    /// Returns the repository directory.
+    pub fn repo(&self) -> &Path {
+        &self.repo
+    }
+
+    /// Returns the notes ref.
+    pub fn notes_ref(&self) -> &str {
+        &self.notes_ref
+    }
+
+    /// Attaches a report to the commit in its metadata and returns the
+    /// full SHA of that commit. A report already attached there, up to
+    /// its timestamp, is not added twice.
+    pub fn put(&self, report: &BenchmarkReport) -> io::Result<String> {
+        let commit = self.resolve(&report.metadata.commit_sha)?;
+        let mut reports = self.get(&commit)?;
+        reports.push(report.clone());
+        let reports = dedup_reports(reports);
+        let mut note = String::new();
+        for report in &reports {
+            note.push_str(&report.to_json(false)?);
+            note.push('\n');
+        }
+        let mut child = self
+            .git(&[
+                "notes",
+                "--ref",
+                &self.notes_ref,
+                "add",
+                "-f",
+                "-F",
+                "-",
+                &commit,
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(note.as_bytes())?;
+        let output = child.wait_with_output()?;
+        check(&output, "git notes add")?;
+        Ok(commit)
+    }
+
+    /// Reads the reports attached to a commit (any revision git can
+    /// resolve). A commit without a note has none.
+    pub fn get(&self, commit: &str) -> io::Result<Vec<BenchmarkReport>> {
+        let commit = self.resolve(commit)?;
+        let output = self
+            .git(&["notes", "--ref", &self.notes_ref, "show", &commit])
+            .output()?;
+        if !output.status.success() {
+            // `git notes show` fails when the commit has no note.
+            return Ok(Vec::new());
+        }
+        parse_note(&String::from_utf8_lossy(&output.stdout), &commit)
+    }
+
+    /// Lists the full SHAs of the commits that have reports attached.
+    pub fn commits(&self) -> io::Result<Vec<String>> {
+        let output = self
+            .git(&["notes", "--ref", &self.notes_ref, "list"])
+            .output()?;
+        if !output.status.success() {
+            // A ref that does not exist yet has no notes.
+            return Ok(Vec::new());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.split_whitespace().nth(1))
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Reads the reports attached to the commits of a revision range
+    /// (`v0.3.0..HEAD`, or a single revision for its whole history), in
+    /// commit order, oldest first; e.g. for
+    /// [`reports_by_commit`](crate::reports_by_commit).
+    pub fn range(&self, revisions: &str) -> io::Result<Vec<BenchmarkReport>> {
+        let output = self
+            .git(&["rev-list", "--reverse", "--topo-order", revisions])
+            .output()?;
+        check(&output, "git rev-list")?;
+        let mut reports = Vec::new();
+        for commit in String::from_utf8_lossy(&output.stdout).lines() {
+            reports.extend(self.get(commit)?);
+        }
+        Ok(reports)
+    }
+
+    /// Reads every stored report, in timestamp order, e.g. for
+    /// [`analyze_trends`](crate::analyze_trends).
+    pub fn reports(&self) -> io::Result<Vec<BenchmarkReport>> {
+        let mut reports = Vec::new();
+        for commit in self.commits()? {
+            reports.extend(self.get(&commit)?);
+        }
+        sort_chronologically(&mut reports);
+        Ok(reports)
+    }
+
+    /// Removes the note of a commit, returning whether there was one.
+    pub fn remove(&self, commit: &str) -> io::Result<bool> {
+        let commit = self.resolve(commit)?;
+        let listed = self
+            .git(&["notes", "--ref", &self.notes_ref, "list", &commit])
+            .output()?;
+        if !listed.status.success() {
+            return Ok(false);
+        }
+        let output = self
+            .git(&["notes", "--ref", &self.notes_ref, "remove", &commit])
+            .output()?;
+        check(&output, "git notes remove")?;
+        Ok(true)
+    }
+
+    /// Resolves a revision to a full commit SHA.
+    fn resolve(&self, revision: &str) -> io::Result<String> {
+        let output = self
+            .git(&[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("{revision}^{{commit}}"),
+            ])
+            .output()?;
+        if !output.status.success() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("unknown commit: {revision}"),
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn git(&self, args: &[&str]) -> Command {
+        let mut command = Command::new("git");
+        command.arg("-C").arg(&self.repo).args(args);
+        command
+    }
+}
+
+/// Parses the JSON Lines of a note.
+fn parse_note(note: &str, commit: &str) -> io::Result<Vec<BenchmarkReport>> {
+    note.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            BenchmarkReport::from_json(line).map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("note on {commit}: {e}"))
+            })
+        })
+        .collect()
+}
+
+/// Turns a failed git command into an error carrying its stderr.
+fn check(output: &std::process::Output, what: &str) -> io::Result<()> {
+    if output.status.success() {
+        return Ok(());
+    }
+    Err(io::Error::other(format!(
+        "{what} failed: {}",
+        String::from_utf8_lossy(&output.stderr).trim()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    /// Creates a repository with `commits` empty commits and returns its
+    /// directory and the commits' SHAs, oldest first.
+    fn repo(name: &str, commits: usize) -> (PathBuf, Vec<String>) {
+        let dir =
+            std::env::temp_dir().join(format!("zkbench-notes-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let git = |args: &[&str]| {
+            let output = Command::new("git")
+                .arg("-C")
+                .arg(&dir)
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "{:?}", output);
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        };
+        git(&["init", "-q"]);
+        git(&["config", "user.name", "zkbench"]);
+        git(&["config", "user.email", "zkbench@example.com"]);
+        let shas = (0..commits)
+            .map(|i| {
+                git(&["commit", "-q", "--allow-empty", "-m", &format!("c{i}")]);
+                git(&["rev-parse", "HEAD"])
+            })
+            .collect();
+        (dir, shas)
+    }
+
+    #[test]
+    fn attaches_and_reads_back_reports() {
+        let (dir, shas) = repo("put", 3);
+        let store = GitNotesStore::new(&dir);
+        assert!(store.commits().unwrap().is_empty());
+        assert_eq!(
+            store
+                .put(
+                    &ReportBuilder::new()
+                        .implementation("a")
+                        .commit(&shas[0][..12])
+                        .day(1)
+                        .result("prove", BenchmarkResult::default())
+                        .build()
+                )
+                .unwrap(),
+            shas[0]
+        );
+        store
+            .put(
+                &ReportBuilder::new()
+                    .implementation("b")
+                    .commit(&shas[0][..12])
+                    .day(1)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        store
+            .put(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit(&shas[0][..12])
+                    .day(2)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        store
+            .put(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit(&shas[2][..12])
+                    .day(3)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+
+        let first = store.get(&shas[0]).unwrap();
+        let implementations: Vec<_> = first
+            .iter()
+            .map(|r| r.metadata.implementation.as_str())
+            .collect();
+        assert_eq!(implementations, ["a", "b"]);
+        assert!(store.get(&shas[1]).unwrap().is_empty());
+        assert_eq!(store.commits().unwrap().len(), 2);
+        assert_eq!(store.reports().unwrap().len(), 3);
+
+        let range = store.range(&format!("{}..HEAD", shas[0])).unwrap();
+        assert_eq!(range.len(), 1);
+        assert_eq!(range[0].metadata.commit_sha, shas[2][..12]);
+
+        assert!(store.remove(&shas[2]).unwrap());
+        assert!(!store.remove(&shas[2]).unwrap());
+        assert!(
+            store
+                .put(
+                    &ReportBuilder::new()
+                        .implementation("a")
+                        .commit(&"f".repeat(40)[..12])
+                        .day(1)
+                        .result("prove", BenchmarkResult::default())
+                        .build()
+                )
+                .is_err()
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn implements_report_store() {
+        use crate::store::ReportStore;
+
+        let (dir, shas) = repo("trait", 2);
+        let mut store = GitNotesStore::new(&dir);
+        let report = ReportBuilder::new()
+            .implementation("a")
+            .commit(&shas[1][..12])
+            .day(1)
+            .result("prove", BenchmarkResult::default())
+            .build();
+        let id = ReportStore::put(&mut store, &report).unwrap();
+        assert_eq!(id, format!("{}:{}", shas[1], report.content_hash()));
+        assert_eq!(ReportStore::list(&store).unwrap()[0].id, id);
+        assert!(ReportStore::get(&store, &id).unwrap().is_some());
+        assert!(
+            ReportStore::get(&store, &format!("{}:x", shas[0]))
+                .unwrap()
+                .is_none()
+        );
+        assert!(store.latest("a").unwrap().is_some());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn separate_refs_do_not_mix() {
+        let (dir, shas) = repo("refs", 1);
+        let store = GitNotesStore::new(&dir);
+        let other = GitNotesStore::new(&dir).with_ref("refs/notes/zkbench-gpu");
+        store
+            .put(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit(&shas[0][..12])
+                    .day(1)
+                    .result("prove", BenchmarkResult::default())
+                    .build(),
+            )
+            .unwrap();
+        assert!(other.get(&shas[0]).unwrap().is_empty());
+        assert_eq!(other.notes_ref(), "refs/notes/zkbench-gpu");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
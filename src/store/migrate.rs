@@ -0,0 +1,109 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Outcome of migrating a store to the current schema version.
+//!
+//! `DirStore::migrate` and `SqliteStore::migrate` upgrade every stored
+//! report written with an older schema version through
+//! [`migrate_json`](crate::migrate_json). A report that cannot be
+//! upgraded without loss is left as it is and listed as failed.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::SCHEMA_VERSION;
+
+/// A report a migration upgraded, or would upgrade in a dry run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigratedReport {
+    /// The store's id for the report before the migration: its path in
+    /// a [`DirStore`](super::DirStore), its report id in a SQLite store.
+    pub id: String,
+    /// The id afterwards. SQLite report ids hash the report's contents,
+    /// so they change; directory store paths do not.
+    pub new_id: String,
+    /// Schema version the report was written with.
+    pub from_version: u32,
+}
+
+/// A stored report a migration could not upgrade.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigrationFailure {
+    pub id: String,
+    pub error: String,
+}
+
+/// Outcome of a store migration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigrationReport {
+    pub migrated: Vec<MigratedReport>,
+    /// Reports left untouched because they could not be upgraded.
+    pub failed: Vec<MigrationFailure>,
+    /// Number of reports already at the current schema version.
+    pub current: usize,
+    /// Whether nothing was actually rewritten.
+    pub dry_run: bool,
+}
+
+impl MigrationReport {
+    pub(crate) fn new(dry_run: bool) -> Self {
+        Self {
+            migrated: Vec::new(),
+            failed: Vec::new(),
+            current: 0,
+            dry_run,
+        }
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} {} of {} reports to schema version {}",
+            if self.dry_run {
+                "would migrate"
+            } else {
+                "migrated"
+            },
+            self.migrated.len(),
+            self.migrated.len() + self.failed.len() + self.current,
+            SCHEMA_VERSION
+        )?;
+        for report in &self.migrated {
+            writeln!(f, "  {} (version {})", report.id, report.from_version)?;
+        }
+        for failure in &self.failed {
+            writeln!(f, "  {}: failed: {}", failure.id, failure.error)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_migration() {
+        let mut report = MigrationReport::new(true);
+        report.current = 2;
+        report.migrated.push(MigratedReport {
+            id: "a.json".to_string(),
+            new_id: "a.json".to_string(),
+            from_version: 1,
+        });
+        report.failed.push(MigrationFailure {
+            id: "b.json".to_string(),
+            error: "not a report".to_string(),
+        });
+        assert_eq!(
+            report.to_string(),
+            format!(
+                "would migrate 1 of 4 reports to schema version {SCHEMA_VERSION}\n  \
+                 a.json (version 1)\n  b.json: failed: not a report\n"
+            )
+        );
+    }
+}
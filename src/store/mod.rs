@@ -0,0 +1,51 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Persistent storage of report histories.
+//!
+//! - [`DirStore`]: report files under a canonical directory layout, with
+//!   an index for fast listing and filtering, exportable to a single
+//!   archive (feature `archive`).
+//! - [`GitNotesStore`]: reports attached to the commits they measured
+//!   via `git notes`, so the history travels with the repository.
+//! - [`RunLog`]: an append-only JSONL log of completed benchmarks, from
+//!   which crashed runs can be recovered.
+//! - [`SqliteStore`] (feature `sqlite`): reports, results and metrics in a
+//!   SQLite database with the relational [`SQL_SCHEMA`](crate::SQL_SCHEMA).
+//!
+//! The report stores, [`DirStore`], [`GitNotesStore`] and
+//! [`SqliteStore`], implement [`ReportStore`], the interface comparison
+//! and trend tooling can be written against, and which custom backends
+//! implement to plug in. [`RunLog`] records runs in progress rather than
+//! reports, so it does not.
+//!
+//! Large artifacts live in a content-addressed [`BlobStore`]. A
+//! [`Query`] selects series out of a stored history, a
+//! [`RetentionPolicy`] decides which stored reports `prune` deletes, and
+//! `migrate` upgrades stored reports to the current schema version
+//! ([`MigrationReport`]).
+
+#[cfg(feature = "archive")]
+mod archive;
+mod backend;
+mod blob;
+mod dir;
+mod git_notes;
+mod migrate;
+mod query;
+mod retention;
+mod run_log;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+pub use backend::{ReportStore, StoredReport};
+pub use blob::{BLOB_SCHEME, BlobGc, BlobStore};
+pub(crate) use dir::component;
+pub use dir::{DIR_STORE_INDEX, DIR_STORE_LOCK, DIR_STORE_QUARANTINE, DirStore, IndexEntry};
+pub use git_notes::{DEFAULT_NOTES_REF, GitNotesStore};
+pub use migrate::{MigratedReport, MigrationFailure, MigrationReport};
+pub use query::{Query, ResultSeries};
+pub use retention::{PruneReason, PruneReport, PrunedReport, RetentionPolicy};
+pub use run_log::{LoggedRun, RunLog, RunLogRecord, RunWriter};
+#[cfg(feature = "sqlite")]
+pub use sqlite::{MetricRow, SQLITE_SCHEMA, SqliteStore};
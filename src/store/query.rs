@@ -0,0 +1,327 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Queries over stored report histories.
+//!
+//! A [`Query`] selects reports by implementation, date range, commit
+//! range and platform fingerprint, and benchmarks by name glob and tags.
+//! [`Query::apply`] returns the matching reports, trimmed to the
+//! matching benchmarks, for [`analyze_trends`](crate::analyze_trends);
+//! [`Query::series`] returns one [`ResultSeries`] per benchmark, whose
+//! values feed straight into the [`statistics`](crate::calculate_statistics)
+//! functions. The stores' `query` methods run a query against their
+//! contents, narrowing it through their indexes where they can.
+//!
+//! ```no_run
+//! use zkbench::store::{DirStore, Query};
+//!
+//! let store = DirStore::new("bench-history");
+//! let query = Query::new()
+//!     .with_implementation("my-impl")
+//!     .with_benchmark("msm/*")
+//!     .with_commit_range("4f1c2a", "HEAD-sha");
+//! for series in query.series(&store.query(&query)?, "latency") {
+//!     println!("{}: {} runs", series.benchmark, series.points.len());
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::compare::glob_match;
+use crate::hash::dedup_reports;
+use crate::schema::BenchmarkReport;
+use crate::trend::{TrendPoint, parse_timestamp, sort_chronologically};
+use crate::units::{base_unit, to_base};
+
+/// Filters over a report history. Every filter left unset matches
+/// everything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    pub implementation: Option<String>,
+    /// Benchmark name globs; a benchmark matching any of them is
+    /// selected.
+    pub benchmarks: Vec<String>,
+    /// Earliest report timestamp, inclusive.
+    pub since: Option<DateTime<Utc>>,
+    /// Latest report timestamp, inclusive.
+    pub until: Option<DateTime<Utc>>,
+    /// First and last commit (SHAs or SHA prefixes) of the range.
+    pub commits: Option<(String, String)>,
+    /// [`Platform::fingerprint`](crate::Platform::fingerprint) the reports
+    /// must come from.
+    pub fingerprint: Option<String>,
+    /// Tags a benchmark must all carry to be selected.
+    pub tags: Vec<String>,
+}
+
+impl Query {
+    /// Creates a query matching everything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects reports of one implementation.
+    pub fn with_implementation(mut self, implementation: impl Into<String>) -> Self {
+        self.implementation = Some(implementation.into());
+        self
+    }
+
+    /// Adds a benchmark name glob.
+    pub fn with_benchmark(mut self, pattern: impl Into<String>) -> Self {
+        self.benchmarks.push(pattern.into());
+        self
+    }
+
+    /// Selects reports from `since` on.
+    pub fn with_since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Selects reports up to `until`.
+    pub fn with_until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Selects the reports from the first report of commit `from` through
+    /// the last report of commit `to`, in timestamp order. Either commit
+    /// may be a SHA prefix.
+    pub fn with_commit_range(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.commits = Some((from.into(), to.into()));
+        self
+    }
+
+    /// Selects reports from one platform.
+    pub fn with_fingerprint(mut self, fingerprint: impl Into<String>) -> Self {
+        self.fingerprint = Some(fingerprint.into());
+        self
+    }
+
+    /// Requires benchmarks to carry a tag.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Whether a report passes the report-level filters other than the
+    /// commit range. Reports with unparseable timestamps fail a date
+    /// filter.
+    pub fn matches_report(&self, report: &BenchmarkReport) -> bool {
+        let meta = &report.metadata;
+        if self
+            .implementation
+            .as_ref()
+            .is_some_and(|i| *i != meta.implementation)
+        {
+            return false;
+        }
+        if self
+            .fingerprint
+            .as_ref()
+            .is_some_and(|f| *f != meta.platform.fingerprint())
+        {
+            return false;
+        }
+        if self.since.is_some() || self.until.is_some() {
+            let Some(time) = parse_timestamp(&meta.timestamp) else {
+                return false;
+            };
+            if self.since.is_some_and(|since| time < since)
+                || self.until.is_some_and(|until| time > until)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Whether a benchmark passes the name and tag filters.
+    pub fn matches_benchmark(&self, name: &str, tags: &[String]) -> bool {
+        (self.benchmarks.is_empty() || self.benchmarks.iter().any(|p| glob_match(p, name)))
+            && self.tags.iter().all(|tag| tags.contains(tag))
+    }
+
+    /// Runs the query over a history: returns the matching reports in
+    /// chronological order, without duplicates, each trimmed to its
+    /// matching benchmarks. Reports left without benchmarks are dropped.
+    /// A commit range whose end commit is not in the history matches
+    /// nothing.
+    pub fn apply(&self, reports: &[BenchmarkReport]) -> Vec<BenchmarkReport> {
+        let mut reports = dedup_reports(reports.to_vec());
+        sort_chronologically(&mut reports);
+        let mut reports: Vec<BenchmarkReport> = reports
+            .into_iter()
+            .filter(|report| self.matches_report(report))
+            .collect();
+        if let Some((from, to)) = &self.commits {
+            let first = reports
+                .iter()
+                .position(|r| r.metadata.commit_sha.starts_with(from.as_str()));
+            let last = reports
+                .iter()
+                .rposition(|r| r.metadata.commit_sha.starts_with(to.as_str()));
+            reports = match (first, last) {
+                (Some(first), Some(last)) if first <= last => reports.drain(first..=last).collect(),
+                _ => Vec::new(),
+            };
+        }
+        for report in &mut reports {
+            report
+                .benchmarks
+                .retain(|name, result| self.matches_benchmark(name, &result.tags));
+        }
+        reports.retain(|report| !report.benchmarks.is_empty());
+        reports
+    }
+
+    /// Runs the query and extracts one metric as a series per
+    /// implementation and benchmark, sorted by those keys. Values are in
+    /// the base unit of their family, as in [`Trend`](crate::Trend).
+    pub fn series(&self, reports: &[BenchmarkReport], metric: &str) -> Vec<ResultSeries> {
+        let mut series: BTreeMap<(String, String), ResultSeries> = BTreeMap::new();
+        for report in self.apply(reports) {
+            let meta = &report.metadata;
+            for (name, result) in &report.benchmarks {
+                let Some((_, value)) = result.metrics().into_iter().find(|(m, _)| *m == metric)
+                else {
+                    continue;
+                };
+                if !value.value.is_finite() {
+                    continue;
+                }
+                let entry = series
+                    .entry((meta.implementation.clone(), name.clone()))
+                    .or_insert_with(|| ResultSeries {
+                        implementation: meta.implementation.clone(),
+                        benchmark: name.clone(),
+                        metric: metric.to_string(),
+                        unit: base_unit(&value.unit),
+                        points: Vec::new(),
+                    });
+                entry.points.push(TrendPoint {
+                    timestamp: meta.timestamp.clone(),
+                    commit_sha: meta.commit_sha.clone(),
+                    version: meta.version.clone(),
+                    value: to_base(value.value, &value.unit),
+                    lower_value: value.lower_value.map(|v| to_base(v, &value.unit)),
+                    upper_value: value.upper_value.map(|v| to_base(v, &value.unit)),
+                });
+            }
+        }
+        series.into_values().collect()
+    }
+}
+
+/// One metric of one benchmark of one implementation over the queried
+/// history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultSeries {
+    pub implementation: String,
+    pub benchmark: String,
+    pub metric: String,
+    /// Base unit of all values.
+    pub unit: String,
+    /// Points in chronological order.
+    pub points: Vec<TrendPoint>,
+}
+
+impl ResultSeries {
+    /// The values of the series, e.g. for
+    /// [`calculate_statistics`](crate::calculate_statistics).
+    pub fn values(&self) -> Vec<f64> {
+        self.points.iter().map(|p| p.value).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{BenchmarkResult, MetricValue};
+    use crate::testing::ReportBuilder;
+
+    fn history() -> Vec<BenchmarkReport> {
+        let result = |ms: f64, tags: &[&str]| BenchmarkResult {
+            latency: Some(MetricValue::new(ms, "ms")),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        };
+        [
+            ("a", "c3", 3),
+            ("a", "c1", 1),
+            ("b", "c2", 2),
+            ("a", "c2", 2),
+            ("a", "c4", 4),
+        ]
+        .into_iter()
+        .map(|(implementation, commit, day)| {
+            ReportBuilder::new()
+                .implementation(implementation)
+                .commit(commit)
+                .day(day)
+                .result("msm/2^16", result(f64::from(day), &["cpu"]))
+                .result("msm/2^20", result(10.0, &["cpu", "slow"]))
+                .result("fft/2^16", result(1.0, &[]))
+                .build()
+        })
+        .collect()
+    }
+
+    #[test]
+    fn filters_reports_and_benchmarks() {
+        let query = Query::new()
+            .with_implementation("a")
+            .with_benchmark("msm/*")
+            .with_tag("cpu")
+            .with_commit_range("c2", "c3");
+        let reports = query.apply(&history());
+        let commits: Vec<_> = reports
+            .iter()
+            .map(|r| r.metadata.commit_sha.as_str())
+            .collect();
+        assert_eq!(commits, ["c2", "c3"]);
+        assert_eq!(reports[0].benchmarks.len(), 2);
+
+        let slow = Query::new().with_tag("slow").apply(&history());
+        assert!(slow.iter().all(|r| r.benchmarks.len() == 1));
+        assert!(
+            Query::new()
+                .with_commit_range("c9", "c3")
+                .apply(&history())
+                .is_empty()
+        );
+        let fingerprint = history()[0].metadata.platform.fingerprint();
+        assert_eq!(
+            Query::new()
+                .with_fingerprint(fingerprint)
+                .apply(&history())
+                .len(),
+            5
+        );
+        assert!(
+            Query::new()
+                .with_fingerprint("other")
+                .apply(&history())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn date_range_and_series() {
+        let query = Query::new()
+            .with_implementation("a")
+            .with_benchmark("msm/2^16")
+            .with_since(parse_timestamp("2026-01-02T00:00:00+00:00").unwrap())
+            .with_until(parse_timestamp("2026-01-03T12:00:00+00:00").unwrap());
+        let series = query.series(&history(), "latency");
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].unit, "ns");
+        assert_eq!(series[0].values(), [2e6, 3e6]);
+        assert_eq!(series[0].points[1].commit_sha, "c3");
+        assert!(query.series(&history(), "memory").is_empty());
+    }
+}
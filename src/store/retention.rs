@@ -0,0 +1,305 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Retention policies for report stores.
+//!
+//! Nightly runs and per-PR runs add up. A [`RetentionPolicy`] decides
+//! which stored reports are worth keeping: the newest N per
+//! implementation and branch, every report of a tagged commit, and
+//! dirty-tree runs only while they are recent. The stores'
+//! `prune` methods apply it, or with `dry_run` only report what they
+//! would delete.
+//!
+//! ```toml
+//! [retention]
+//! keep_last = 50
+//! keep_tagged = true
+//! dirty_max_age_days = 30
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::trend::parse_timestamp;
+
+/// Which stored reports to keep, the `[retention]` table of
+/// `zkbench.toml`. The default only drops dirty-tree runs older than 30
+/// days.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    /// Newest reports to keep per implementation and branch; `None`
+    /// keeps all. Reports without a branch form one group per
+    /// implementation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_last: Option<usize>,
+    /// Keeps every report of a tagged commit, whatever the other rules
+    /// say.
+    pub keep_tagged: bool,
+    /// Age after which reports of a dirty working tree are dropped;
+    /// `None` keeps them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dirty_max_age_days: Option<u32>,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            keep_last: None,
+            keep_tagged: true,
+            dirty_max_age_days: Some(30),
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// Creates the default policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps only the newest `n` reports per implementation and branch.
+    pub fn with_keep_last(mut self, n: usize) -> Self {
+        self.keep_last = Some(n);
+        self
+    }
+
+    /// Sets whether reports of tagged commits are always kept.
+    pub fn with_keep_tagged(mut self, keep: bool) -> Self {
+        self.keep_tagged = keep;
+        self
+    }
+
+    /// Sets the age after which dirty-tree reports are dropped.
+    pub fn with_dirty_max_age_days(mut self, days: Option<u32>) -> Self {
+        self.dirty_max_age_days = days;
+        self
+    }
+
+    /// Decides which of the candidates to prune as of `now`. Reports
+    /// whose timestamp does not parse never count as expired and sort
+    /// as the oldest of their group.
+    pub(crate) fn plan(&self, candidates: Vec<Candidate>, now: DateTime<Utc>) -> PruneReport {
+        let total = candidates.len();
+        let dirty_cutoff = self
+            .dirty_max_age_days
+            .map(|days| now - Duration::days(i64::from(days)));
+        let mut pruned = Vec::new();
+        let mut groups: HashMap<(String, Option<String>), Vec<Candidate>> = HashMap::new();
+        for candidate in candidates {
+            let time = parse_timestamp(&candidate.timestamp);
+            let protected = self.keep_tagged && candidate.git_tag.is_some();
+            if !protected
+                && candidate.dirty
+                && let (Some(cutoff), Some(time)) = (dirty_cutoff, time)
+                && time < cutoff
+            {
+                pruned.push(candidate.prune(PruneReason::DirtyTree));
+                continue;
+            }
+            let key = (candidate.implementation.clone(), candidate.branch.clone());
+            groups.entry(key).or_default().push(candidate);
+        }
+        if let Some(keep_last) = self.keep_last {
+            for mut group in groups.into_values() {
+                group.sort_by(|a, b| {
+                    parse_timestamp(&b.timestamp)
+                        .cmp(&parse_timestamp(&a.timestamp))
+                        .then_with(|| b.timestamp.cmp(&a.timestamp))
+                });
+                for candidate in group.into_iter().skip(keep_last) {
+                    if !(self.keep_tagged && candidate.git_tag.is_some()) {
+                        pruned.push(candidate.prune(PruneReason::Superseded));
+                    }
+                }
+            }
+        }
+        pruned.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.id.cmp(&b.id)));
+        PruneReport {
+            kept: total - pruned.len(),
+            pruned,
+            dry_run: false,
+        }
+    }
+}
+
+/// A stored report as seen by a [`RetentionPolicy`].
+#[derive(Debug, Clone)]
+pub(crate) struct Candidate {
+    pub(crate) id: String,
+    pub(crate) implementation: String,
+    pub(crate) commit_sha: String,
+    pub(crate) timestamp: String,
+    pub(crate) branch: Option<String>,
+    pub(crate) git_tag: Option<String>,
+    pub(crate) dirty: bool,
+}
+
+impl Candidate {
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn new(id: String, metadata: &crate::schema::Metadata) -> Self {
+        Self {
+            id,
+            implementation: metadata.implementation.clone(),
+            commit_sha: metadata.commit_sha.clone(),
+            timestamp: metadata.timestamp.clone(),
+            branch: metadata.branch.clone(),
+            git_tag: metadata.git_tag.clone(),
+            dirty: metadata.dirty,
+        }
+    }
+
+    fn prune(self, reason: PruneReason) -> PrunedReport {
+        PrunedReport {
+            id: self.id,
+            implementation: self.implementation,
+            branch: self.branch,
+            commit_sha: self.commit_sha,
+            timestamp: self.timestamp,
+            reason,
+        }
+    }
+}
+
+/// Why a report was pruned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PruneReason {
+    /// A dirty-tree run older than `dirty_max_age_days`.
+    DirtyTree,
+    /// Older than the newest `keep_last` reports of its branch.
+    Superseded,
+}
+
+impl fmt::Display for PruneReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::DirtyTree => "expired dirty-tree run",
+            Self::Superseded => "superseded on its branch",
+        })
+    }
+}
+
+/// A report a prune deleted, or would delete in a dry run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrunedReport {
+    /// The store's id for the report: its path in a
+    /// [`DirStore`](super::DirStore), its report id in a SQLite store.
+    pub id: String,
+    pub implementation: String,
+    pub branch: Option<String>,
+    pub commit_sha: String,
+    pub timestamp: String,
+    pub reason: PruneReason,
+}
+
+/// Outcome of a prune, oldest pruned report first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PruneReport {
+    pub pruned: Vec<PrunedReport>,
+    /// Number of reports left in the store.
+    pub kept: usize,
+    /// Whether nothing was actually deleted.
+    pub dry_run: bool,
+}
+
+impl fmt::Display for PruneReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} {} of {} reports",
+            if self.dry_run {
+                "would delete"
+            } else {
+                "deleted"
+            },
+            self.pruned.len(),
+            self.pruned.len() + self.kept
+        )?;
+        for report in &self.pruned {
+            writeln!(
+                f,
+                "  {} ({} @ {}, {}): {}",
+                report.id,
+                report.implementation,
+                report.commit_sha,
+                report.timestamp,
+                report.reason
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: &str, branch: &str, day: u32) -> Candidate {
+        Candidate {
+            id: id.to_string(),
+            implementation: "impl".to_string(),
+            commit_sha: id.to_string(),
+            timestamp: format!("2026-01-{day:02}T00:00:00+00:00"),
+            branch: Some(branch.to_string()),
+            git_tag: None,
+            dirty: false,
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        parse_timestamp("2026-02-15T00:00:00+00:00").unwrap()
+    }
+
+    #[test]
+    fn keeps_last_n_per_branch_and_tagged_releases() {
+        let mut tagged = candidate("v1", "main", 1);
+        tagged.git_tag = Some("v1.0.0".to_string());
+        let candidates = vec![
+            tagged,
+            candidate("m2", "main", 2),
+            candidate("m3", "main", 3),
+            candidate("m4", "main", 4),
+            candidate("f1", "feature", 1),
+        ];
+        let report = RetentionPolicy::new()
+            .with_keep_last(2)
+            .plan(candidates.clone(), now());
+        let ids: Vec<_> = report.pruned.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["m2"]);
+        assert_eq!(report.pruned[0].reason, PruneReason::Superseded);
+        assert_eq!(report.kept, 4);
+
+        let report = RetentionPolicy::new()
+            .with_keep_last(2)
+            .with_keep_tagged(false)
+            .plan(candidates, now());
+        assert_eq!(report.pruned.len(), 2);
+    }
+
+    #[test]
+    fn drops_old_dirty_runs() {
+        let mut old = candidate("old", "main", 1);
+        old.dirty = true;
+        let mut recent = old.clone();
+        recent.id = "recent".to_string();
+        recent.timestamp = "2026-02-10T00:00:00+00:00".to_string();
+        let report = RetentionPolicy::new().plan(vec![old.clone(), recent.clone()], now());
+        assert_eq!(report.pruned.len(), 1);
+        assert_eq!(report.pruned[0].id, "old");
+        assert_eq!(report.pruned[0].reason, PruneReason::DirtyTree);
+        assert!(
+            report
+                .to_string()
+                .starts_with("deleted 1 of 2 reports\n  old (impl @ old,")
+        );
+
+        let report = RetentionPolicy::new()
+            .with_dirty_max_age_days(None)
+            .plan(vec![old, recent], now());
+        assert!(report.pruned.is_empty());
+    }
+}
@@ -0,0 +1,358 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Append-only JSONL run log.
+//!
+//! A long benchmark run that crashes after three hours should not lose
+//! three hours of results. A [`RunLog`] appends one JSON line per event
+//! and flushes it to disk immediately: a `start` line with the run's
+//! metadata, one `result` line per completed benchmark and a `finish`
+//! line. [`RunLog::read`] rebuilds a [`BenchmarkReport`] per run from
+//! whatever made it to disk, marking runs without a `finish` line as
+//! incomplete. Several runs, even interleaved ones, can share a log.
+//!
+//! ```text
+//! {"type":"start","run_id":"3f2a…","timestamp":"…","metadata":{…}}
+//! {"type":"result","run_id":"3f2a…","timestamp":"…","benchmark":"msm/2^16","result":{…}}
+//! {"type":"finish","run_id":"3f2a…","timestamp":"…"}
+//! ```
+
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::hash::compute_hash;
+use crate::schema::{BenchmarkReport, BenchmarkResult, Metadata};
+
+/// One line of a run log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RunLogRecord {
+    Start {
+        run_id: String,
+        timestamp: String,
+        metadata: Box<Metadata>,
+    },
+    Result {
+        run_id: String,
+        timestamp: String,
+        benchmark: String,
+        result: Box<BenchmarkResult>,
+    },
+    Finish {
+        run_id: String,
+        timestamp: String,
+    },
+}
+
+impl RunLogRecord {
+    /// Id of the run the record belongs to.
+    pub fn run_id(&self) -> &str {
+        match self {
+            Self::Start { run_id, .. }
+            | Self::Result { run_id, .. }
+            | Self::Finish { run_id, .. } => run_id,
+        }
+    }
+}
+
+/// A run reconstructed from a log.
+#[derive(Debug, Clone)]
+pub struct LoggedRun {
+    pub run_id: String,
+    /// The run's metadata and every result logged for it; a benchmark
+    /// logged twice keeps its last result.
+    pub report: BenchmarkReport,
+    /// Whether the run logged its `finish` line.
+    pub complete: bool,
+}
+
+/// An append-only JSONL log of benchmark runs.
+#[derive(Debug, Clone)]
+pub struct RunLog {
+    path: PathBuf,
+}
+
+impl RunLog {
+    /// Uses the log at `path`, which is created on the first write.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the file the log is stored in.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Starts a new run, logging its metadata, and returns the writer for
+    /// its results. The run id is derived from the metadata, the time and
+    /// the process, so concurrent runs get distinct ids.
+    pub fn start(&self, metadata: &Metadata) -> io::Result<RunWriter> {
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&self.path)?;
+        let timestamp = now();
+        let seed = format!(
+            "{}\0{}\0{}\0{}",
+            metadata.implementation,
+            metadata.commit_sha,
+            timestamp,
+            std::process::id()
+        );
+        let mut writer = RunWriter {
+            file,
+            run_id: compute_hash(seed.as_bytes())[..16].to_string(),
+        };
+        writer.append(&RunLogRecord::Start {
+            run_id: writer.run_id.clone(),
+            timestamp,
+            metadata: Box::new(metadata.clone()),
+        })?;
+        Ok(writer)
+    }
+
+    /// Reads every record of the log. A missing log has none. Lines cut
+    /// short by a crash mid-write are skipped, whether they are the last
+    /// line or a later run appended after them; other malformed lines are
+    /// [`io::ErrorKind::InvalidData`].
+    pub fn records(&self) -> io::Result<Vec<RunLogRecord>> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+        let mut records = Vec::with_capacity(lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            match serde_json::from_str(line) {
+                Ok(record) => records.push(record),
+                // A torn write is a prefix of a valid record.
+                Err(e) if e.is_eof() => continue,
+                Err(e) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}:{}: {}", self.path.display(), i + 1, e),
+                    ));
+                }
+            }
+        }
+        Ok(records)
+    }
+
+    /// Reconstructs the runs in the log, in the order they started.
+    /// Results of runs whose `start` line is missing are ignored.
+    pub fn read(&self) -> io::Result<Vec<LoggedRun>> {
+        let mut runs: Vec<LoggedRun> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for record in self.records()? {
+            match record {
+                RunLogRecord::Start {
+                    run_id, metadata, ..
+                } => {
+                    index.insert(run_id.clone(), runs.len());
+                    runs.push(LoggedRun {
+                        run_id,
+                        report: BenchmarkReport {
+                            metadata: *metadata,
+                            benchmarks: HashMap::new(),
+                        },
+                        complete: false,
+                    });
+                }
+                RunLogRecord::Result {
+                    run_id,
+                    benchmark,
+                    result,
+                    ..
+                } => {
+                    if let Some(&i) = index.get(&run_id) {
+                        runs[i].report.benchmarks.insert(benchmark, *result);
+                    }
+                }
+                RunLogRecord::Finish { run_id, .. } => {
+                    if let Some(&i) = index.get(&run_id) {
+                        runs[i].complete = true;
+                    }
+                }
+            }
+        }
+        Ok(runs)
+    }
+
+    /// Reconstructs the run with the given id.
+    pub fn run(&self, run_id: &str) -> io::Result<Option<LoggedRun>> {
+        Ok(self.read()?.into_iter().find(|r| r.run_id == run_id))
+    }
+}
+
+/// Appends the records of one run to a [`RunLog`].
+#[derive(Debug)]
+pub struct RunWriter {
+    file: File,
+    run_id: String,
+}
+
+impl RunWriter {
+    /// Id of the run being written.
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    /// Logs a completed benchmark and syncs it to disk.
+    pub fn record(&mut self, benchmark: &str, result: &BenchmarkResult) -> io::Result<()> {
+        self.append(&RunLogRecord::Result {
+            run_id: self.run_id.clone(),
+            timestamp: now(),
+            benchmark: benchmark.to_string(),
+            result: Box::new(result.clone()),
+        })
+    }
+
+    /// Logs the end of the run.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.append(&RunLogRecord::Finish {
+            run_id: self.run_id.clone(),
+            timestamp: now(),
+        })
+    }
+
+    /// Writes a record as a single line, so a crash can only truncate
+    /// the last one. The line is written under an exclusive lock, so
+    /// lines of processes sharing the log never interleave; a line left
+    /// unterminated by a crash is terminated first, so the record does
+    /// not merge with it.
+    fn append(&mut self, record: &RunLogRecord) -> io::Result<()> {
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+        self.file.lock()?;
+        let written = self
+            .terminate_torn_line()
+            .and_then(|()| self.file.write_all(line.as_bytes()))
+            .and_then(|()| self.file.sync_data());
+        self.file.unlock()?;
+        written
+    }
+
+    /// Writes a newline if the log does not end with one.
+    fn terminate_torn_line(&mut self) -> io::Result<()> {
+        if self.file.metadata()?.len() == 0 {
+            return Ok(());
+        }
+        let mut last = [0u8];
+        self.file.seek(SeekFrom::End(-1))?;
+        self.file.read_exact(&mut last)?;
+        if last[0] != b'\n' {
+            self.file.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+}
+
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::MetricValue;
+
+    fn log(name: &str) -> RunLog {
+        let path = std::env::temp_dir().join(format!(
+            "zkbench-runlog-{}-{}.jsonl",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        RunLog::new(path)
+    }
+
+    fn result(ms: f64) -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::new(ms, "ms")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reconstructs_complete_and_partial_runs() {
+        let log = log("runs");
+        let metadata = Metadata::create("impl", "1.0.0");
+        let mut first = log.start(&metadata).unwrap();
+        let mut second = log.start(&metadata).unwrap();
+        assert_ne!(first.run_id(), second.run_id());
+        first.record("fft", &result(1.0)).unwrap();
+        second.record("fft", &result(2.0)).unwrap();
+        first.record("msm", &result(3.0)).unwrap();
+        first.record("msm", &result(4.0)).unwrap();
+        let first_id = first.run_id().to_string();
+        first.finish().unwrap();
+
+        let runs = log.read().unwrap();
+        assert_eq!(runs.len(), 2);
+        assert!(runs[0].complete);
+        assert_eq!(runs[0].report.benchmarks.len(), 2);
+        let msm = runs[0].report.benchmarks["msm"].latency.as_ref().unwrap();
+        assert_eq!(msm.value, 4.0);
+        assert!(!runs[1].complete);
+        assert_eq!(runs[1].report.benchmarks.len(), 1);
+        assert_eq!(runs[1].report.metadata.implementation, "impl");
+        assert!(log.run(&first_id).unwrap().unwrap().complete);
+        assert_eq!(log.records().unwrap().len(), 7);
+        fs::remove_file(log.path()).unwrap();
+    }
+
+    #[test]
+    fn tolerates_truncated_last_line() {
+        let log = log("truncated");
+        let mut writer = log.start(&Metadata::create("impl", "1.0.0")).unwrap();
+        writer.record("fft", &result(1.0)).unwrap();
+        let mut file = OpenOptions::new().append(true).open(log.path()).unwrap();
+        file.write_all(br#"{"type":"result","run_id":"#).unwrap();
+        let runs = log.read().unwrap();
+        assert_eq!(runs[0].report.benchmarks.len(), 1);
+
+        file.write_all(b"\n{}\n").unwrap();
+        let err = log.read().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(log.path()).unwrap();
+    }
+
+    #[test]
+    fn appends_after_truncated_line() {
+        let log = log("crash");
+        let metadata = Metadata::create("impl", "1.0.0");
+        let mut crashed = log.start(&metadata).unwrap();
+        crashed.record("fft", &result(1.0)).unwrap();
+        crashed.record("msm", &result(2.0)).unwrap();
+        let len = fs::metadata(log.path()).unwrap().len();
+        let file = OpenOptions::new().write(true).open(log.path()).unwrap();
+        file.set_len(len - 10).unwrap();
+
+        let mut next = log.start(&metadata).unwrap();
+        next.record("fft", &result(3.0)).unwrap();
+        next.finish().unwrap();
+
+        let runs = log.read().unwrap();
+        assert_eq!(runs.len(), 2);
+        assert!(!runs[0].complete);
+        assert_eq!(runs[0].report.benchmarks.len(), 1);
+        assert!(runs[1].complete);
+        let fft = runs[1].report.benchmarks["fft"].latency.as_ref().unwrap();
+        assert_eq!(fft.value, 3.0);
+        fs::remove_file(log.path()).unwrap();
+    }
+
+    #[test]
+    fn missing_log_is_empty() {
+        assert!(log("missing").read().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,678 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! SQLite report store.
+//!
+//! The database holds the relational [`SQL_SCHEMA`], which makes metric
+//! histories a plain `SELECT` away, plus [`SQLITE_SCHEMA`]: the original
+//! JSON of every report, so reports load back without loss (samples,
+//! tags and platform details are not in the relational tables), and
+//! indexes for the history queries.
+//!
+//! Several processes can write to one database file: it is opened in WAL
+//! mode with a busy timeout, and every write is an immediate
+//! transaction, so concurrent writers queue instead of failing with
+//! `SQLITE_BUSY`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use zkbench::MetricKind;
+//! use zkbench::store::SqliteStore;
+//! # let report: zkbench::BenchmarkReport = unimplemented!();
+//!
+//! let mut store = SqliteStore::open("bench.db")?;
+//! store.insert(&report)?;
+//! for row in store.metric_history("msm/2^16", MetricKind::Latency.as_str(), None)? {
+//!     println!("{} {}", row.commit_sha, row.value.value);
+//! }
+//! # Ok::<(), rusqlite::Error>(())
+//! ```
+
+use std::path::Path;
+use std::time::Duration;
+
+use rusqlite::types::Type;
+use rusqlite::{Connection, OptionalExtension, Row, Transaction, TransactionBehavior, params};
+
+use super::backend::StoredReport;
+use super::migrate::{MigratedReport, MigrationFailure, MigrationReport};
+use super::query::Query;
+use super::retention::{Candidate, PruneReport, RetentionPolicy};
+use crate::export::{SQL_SCHEMA, report_id, sql_inserts};
+use crate::migrate::migrate_json;
+use crate::schema::{BenchmarkReport, MetricValue};
+
+/// DDL the store adds on top of [`SQL_SCHEMA`].
+pub const SQLITE_SCHEMA: &str = "\
+CREATE TABLE IF NOT EXISTS zkbench_documents (
+    report_id TEXT PRIMARY KEY REFERENCES zkbench_reports (id),
+    content_hash TEXT NOT NULL,
+    document TEXT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS zkbench_documents_by_content_hash
+    ON zkbench_documents (content_hash);
+
+CREATE INDEX IF NOT EXISTS zkbench_reports_by_implementation
+    ON zkbench_reports (implementation, timestamp);
+
+CREATE INDEX IF NOT EXISTS zkbench_metrics_by_benchmark
+    ON zkbench_metrics (benchmark, metric);
+";
+
+/// How long a write waits for other writers before failing.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// One metric of one benchmark in one stored report.
+#[derive(Debug, Clone)]
+pub struct MetricRow {
+    pub report_id: String,
+    pub implementation: String,
+    pub commit_sha: String,
+    pub timestamp: String,
+    pub value: MetricValue,
+}
+
+/// Reports persisted in a SQLite database.
+pub struct SqliteStore {
+    conn: Connection,
+}
+
+impl SqliteStore {
+    /// Opens (or creates) a database file in WAL mode and applies the
+    /// schema.
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.busy_timeout(BUSY_TIMEOUT)?;
+        conn.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))?;
+        Self::with_connection(conn)
+    }
+
+    /// Opens a private in-memory database.
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+        conn.execute_batch(SQL_SCHEMA)?;
+        conn.execute_batch(SQLITE_SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// The underlying connection, for queries the store does not offer.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Inserts a report in a single transaction and returns its id.
+    ///
+    /// Inserting a report that is already stored is a no-op, and so is
+    /// inserting one that differs only in its timestamp (the same
+    /// [`content_hash`](BenchmarkReport::content_hash)), as a retried CI
+    /// job would upload: the id of the stored report is returned, so
+    /// the duplicate never reaches the metric histories.
+    pub fn insert(&mut self, report: &BenchmarkReport) -> rusqlite::Result<String> {
+        let tx = self.write()?;
+        let id = insert_report(&tx, report)?;
+        tx.commit()?;
+        Ok(id)
+    }
+
+    /// Whether a report with this id is stored.
+    pub fn contains(&self, id: &str) -> rusqlite::Result<bool> {
+        self.conn
+            .query_row("SELECT 1 FROM zkbench_reports WHERE id = ?1", [id], |_| {
+                Ok(())
+            })
+            .optional()
+            .map(|row| row.is_some())
+    }
+
+    /// Loads the report stored under `id`.
+    pub fn load(&self, id: &str) -> rusqlite::Result<Option<BenchmarkReport>> {
+        self.conn
+            .query_row(
+                "SELECT document FROM zkbench_documents WHERE report_id = ?1",
+                [id],
+                document,
+            )
+            .optional()
+    }
+
+    /// Lists the stored reports, optionally of one implementation, oldest
+    /// first. Timestamps are compared as RFC 3339 strings, which orders
+    /// them chronologically as long as they share an offset (reports
+    /// record UTC).
+    pub fn list(&self, implementation: Option<&str>) -> rusqlite::Result<Vec<StoredReport>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, implementation, version, commit_sha, timestamp FROM zkbench_reports \
+             WHERE ?1 IS NULL OR implementation = ?1 ORDER BY timestamp, id",
+        )?;
+        stmt.query_map([implementation], |row| {
+            Ok(StoredReport {
+                id: row.get(0)?,
+                implementation: row.get(1)?,
+                version: row.get(2)?,
+                commit_sha: row.get(3)?,
+                timestamp: row.get(4)?,
+            })
+        })?
+        .collect()
+    }
+
+    /// Loads the stored reports, optionally of one implementation, oldest
+    /// first, e.g. for [`analyze_trends`](crate::analyze_trends).
+    pub fn reports(&self, implementation: Option<&str>) -> rusqlite::Result<Vec<BenchmarkReport>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT d.document FROM zkbench_documents d \
+             JOIN zkbench_reports r ON r.id = d.report_id \
+             WHERE ?1 IS NULL OR r.implementation = ?1 ORDER BY r.timestamp, r.id",
+        )?;
+        stmt.query_map([implementation], document)?.collect()
+    }
+
+    /// Runs a query over the stored reports, loading only those of the
+    /// queried implementation.
+    pub fn query(&self, query: &Query) -> rusqlite::Result<Vec<BenchmarkReport>> {
+        Ok(query.apply(&self.reports(query.implementation.as_deref())?))
+    }
+
+    /// History of one metric of one benchmark across the stored reports,
+    /// optionally of one implementation, oldest first.
+    pub fn metric_history(
+        &self,
+        benchmark: &str,
+        metric: &str,
+        implementation: Option<&str>,
+    ) -> rusqlite::Result<Vec<MetricRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT r.id, r.implementation, r.commit_sha, r.timestamp, \
+                    m.value, m.unit, m.lower_value, m.upper_value \
+             FROM zkbench_metrics m JOIN zkbench_reports r ON r.id = m.report_id \
+             WHERE m.benchmark = ?1 AND m.metric = ?2 AND (?3 IS NULL OR r.implementation = ?3) \
+             ORDER BY r.timestamp, r.id",
+        )?;
+        stmt.query_map(params![benchmark, metric, implementation], |row| {
+            Ok(MetricRow {
+                report_id: row.get(0)?,
+                implementation: row.get(1)?,
+                commit_sha: row.get(2)?,
+                timestamp: row.get(3)?,
+                value: MetricValue {
+                    value: row.get(4)?,
+                    unit: row.get(5)?,
+                    lower_value: row.get(6)?,
+                    upper_value: row.get(7)?,
+                },
+            })
+        })?
+        .collect()
+    }
+
+    /// Deletes the report stored under `id`, returning whether it existed.
+    pub fn delete(&mut self, id: &str) -> rusqlite::Result<bool> {
+        let tx = self.write()?;
+        let deleted = delete_report(&tx, id)?;
+        tx.commit()?;
+        Ok(deleted)
+    }
+
+    /// Deletes the reports the retention policy drops, in a single
+    /// transaction. With `dry_run`, only reports what would be deleted.
+    /// Pruned ids are report ids.
+    pub fn prune(
+        &mut self,
+        policy: &RetentionPolicy,
+        dry_run: bool,
+    ) -> rusqlite::Result<PruneReport> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT report_id, document FROM zkbench_documents")?;
+        let candidates = stmt
+            .query_map([], |row| {
+                let id: String = row.get(0)?;
+                let json: String = row.get(1)?;
+                let report = BenchmarkReport::from_json(&json).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(1, Type::Text, Box::new(e))
+                })?;
+                Ok(Candidate::new(id, &report.metadata))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        drop(stmt);
+        let mut report = policy.plan(candidates, chrono::Utc::now());
+        report.dry_run = dry_run;
+        if !dry_run {
+            let tx = self.write()?;
+            for pruned in &report.pruned {
+                delete_report(&tx, &pruned.id)?;
+            }
+            tx.commit()?;
+        }
+        Ok(report)
+    }
+
+    /// Upgrades every stored report written with an older schema version
+    /// (see [`migrate_json`]), in a single transaction. An upgraded
+    /// report is re-inserted, which gives it a new report id; one that
+    /// becomes a duplicate of a stored report is merged into it. Reports
+    /// that cannot be upgraded without loss are left untouched and listed
+    /// as failed. With `dry_run`, only reports what would be migrated.
+    pub fn migrate(&mut self, dry_run: bool) -> rusqlite::Result<MigrationReport> {
+        // A dry run only reads, so it needs no write lock; a migration
+        // reads inside its write transaction so no report is missed.
+        let tx = if dry_run {
+            self.conn
+                .transaction_with_behavior(TransactionBehavior::Deferred)?
+        } else {
+            self.write()?
+        };
+        let documents = tx
+            .prepare("SELECT report_id, document FROM zkbench_documents ORDER BY report_id")?
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        let mut report = MigrationReport::new(dry_run);
+        for (id, json) in documents {
+            match migrate_json(&json) {
+                Ok(migrated) if !migrated.changed() => report.current += 1,
+                Ok(migrated) => {
+                    let new_id = if dry_run {
+                        report_id(&migrated.report)
+                    } else {
+                        delete_report(&tx, &id)?;
+                        insert_report(&tx, &migrated.report)?
+                    };
+                    report.migrated.push(MigratedReport {
+                        id,
+                        new_id,
+                        from_version: migrated.from_version,
+                    });
+                }
+                Err(e) => report.failed.push(MigrationFailure {
+                    id,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        tx.commit()?;
+        Ok(report)
+    }
+}
+
+impl SqliteStore {
+    /// Starts a write transaction, taking the database's write lock
+    /// up front so that checks made inside it stay valid.
+    fn write(&mut self) -> rusqlite::Result<Transaction<'_>> {
+        self.conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+    }
+}
+
+/// Inserts a report into every table and returns its id, or the id of
+/// the stored report with the same content hash.
+fn insert_report(conn: &Connection, report: &BenchmarkReport) -> rusqlite::Result<String> {
+    let content_hash = report.content_hash();
+    let document = report
+        .to_json(false)
+        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+    let existing: Option<String> = conn
+        .query_row(
+            "SELECT report_id FROM zkbench_documents WHERE content_hash = ?1",
+            [&content_hash],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if let Some(id) = existing {
+        return Ok(id);
+    }
+    let id = report_id(report);
+    conn.execute_batch(&sql_inserts(report))?;
+    conn.execute(
+        "INSERT INTO zkbench_documents (report_id, content_hash, document) \
+         VALUES (?1, ?2, ?3) ON CONFLICT DO NOTHING",
+        params![id, content_hash, document],
+    )?;
+    Ok(id)
+}
+
+/// Deletes a report from every table, returning whether it existed.
+fn delete_report(conn: &Connection, id: &str) -> rusqlite::Result<bool> {
+    for table in ["zkbench_documents", "zkbench_metrics", "zkbench_results"] {
+        conn.execute(&format!("DELETE FROM {table} WHERE report_id = ?1"), [id])?;
+    }
+    Ok(conn.execute("DELETE FROM zkbench_reports WHERE id = ?1", [id])? > 0)
+}
+
+/// Parses the JSON document in the first column of a row.
+fn document(row: &Row<'_>) -> rusqlite::Result<BenchmarkReport> {
+    let json: String = row.get(0)?;
+    BenchmarkReport::from_json(&json)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(0, Type::Text, Box::new(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    fn prove(ms: f64) -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(ms, "ms", ms - 1.0, ms + 1.0)),
+            samples: vec![ms - 1.0, ms, ms + 1.0],
+            tags: vec!["slow".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn inserts_and_loads_losslessly() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        let original = ReportBuilder::new()
+            .implementation("a")
+            .commit("c1")
+            .day(1)
+            .result("prove", prove(100.0))
+            .build();
+        let id = store.insert(&original).unwrap();
+        assert_eq!(store.insert(&original).unwrap(), id);
+        assert!(store.contains(&id).unwrap());
+        let loaded = store.load(&id).unwrap().unwrap();
+        assert_eq!(report_id(&loaded), id);
+        assert_eq!(loaded.benchmarks["prove"].samples.len(), 3);
+        assert_eq!(loaded.benchmarks["prove"].tags, ["slow"]);
+        assert!(store.load("missing").unwrap().is_none());
+        assert_eq!(store.list(None).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn skips_reuploaded_reports() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        let id = store
+            .insert(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(1)
+                    .result("prove", prove(100.0))
+                    .build(),
+            )
+            .unwrap();
+        assert_eq!(
+            store
+                .insert(
+                    &ReportBuilder::new()
+                        .implementation("a")
+                        .commit("c1")
+                        .day(2)
+                        .result("prove", prove(100.0))
+                        .build()
+                )
+                .unwrap(),
+            id
+        );
+        assert_ne!(
+            store
+                .insert(
+                    &ReportBuilder::new()
+                        .implementation("a")
+                        .commit("c1")
+                        .day(2)
+                        .result("prove", prove(101.0))
+                        .build()
+                )
+                .unwrap(),
+            id
+        );
+        assert_eq!(store.list(None).unwrap().len(), 2);
+        assert_eq!(
+            store
+                .metric_history("prove", "latency", None)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn queries_history_in_time_order() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        store
+            .insert(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c2")
+                    .day(2)
+                    .result("prove", prove(110.0))
+                    .build(),
+            )
+            .unwrap();
+        store
+            .insert(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(1)
+                    .result("prove", prove(100.0))
+                    .build(),
+            )
+            .unwrap();
+        store
+            .insert(
+                &ReportBuilder::new()
+                    .implementation("b")
+                    .commit("c1")
+                    .day(1)
+                    .result("prove", prove(50.0))
+                    .build(),
+            )
+            .unwrap();
+
+        let history = store.metric_history("prove", "latency", Some("a")).unwrap();
+        let commits: Vec<_> = history.iter().map(|r| r.commit_sha.as_str()).collect();
+        assert_eq!(commits, ["c1", "c2"]);
+        let latest = &history[1].value;
+        assert_eq!(
+            (latest.value, latest.unit.as_str(), latest.upper_value),
+            (110.0, "ms", Some(111.0))
+        );
+        assert_eq!(
+            store
+                .metric_history("prove", "latency", None)
+                .unwrap()
+                .len(),
+            3
+        );
+
+        let reports = store.reports(Some("a")).unwrap();
+        assert_eq!(reports[0].metadata.commit_sha, "c1");
+        let series = Query::new()
+            .with_implementation("a")
+            .with_commit_range("c2", "c2")
+            .series(&store.query(&Query::new()).unwrap(), "latency");
+        assert_eq!(series[0].values(), [110e6]);
+        let listed = store.list(Some("b")).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].implementation, "b");
+    }
+
+    #[test]
+    fn deletes_and_persists_to_file() {
+        let path = std::env::temp_dir().join(format!("zkbench-sqlite-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let id = {
+            let mut store = SqliteStore::open(&path).unwrap();
+            store
+                .insert(
+                    &ReportBuilder::new()
+                        .implementation("a")
+                        .commit("c1")
+                        .day(1)
+                        .result("prove", prove(100.0))
+                        .build(),
+                )
+                .unwrap()
+        };
+        let mut store = SqliteStore::open(&path).unwrap();
+        assert!(store.contains(&id).unwrap());
+        assert!(store.delete(&id).unwrap());
+        assert!(!store.delete(&id).unwrap());
+        assert!(
+            store
+                .metric_history("prove", "latency", None)
+                .unwrap()
+                .is_empty()
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn concurrent_writers_share_a_file() {
+        let path = std::env::temp_dir().join(format!(
+            "zkbench-sqlite-concurrent-{}.db",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        std::thread::scope(|scope| {
+            for shard in 0..4 {
+                let path = &path;
+                scope.spawn(move || {
+                    let mut store = SqliteStore::open(path).unwrap();
+                    for day in 1..=10 {
+                        store
+                            .insert(
+                                &ReportBuilder::new()
+                                    .implementation("a")
+                                    .commit(&format!("shard{shard}-{day}"))
+                                    .day(day)
+                                    .result("prove", prove(100.0))
+                                    .build(),
+                            )
+                            .unwrap();
+                    }
+                });
+            }
+        });
+        let store = SqliteStore::open(&path).unwrap();
+        assert_eq!(store.list(None).unwrap().len(), 40);
+        drop(store);
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+
+    #[test]
+    fn prunes_by_retention_policy() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        let mut tagged = ReportBuilder::new()
+            .implementation("a")
+            .commit("c1")
+            .day(1)
+            .result("prove", prove(100.0))
+            .build();
+        tagged.metadata.git_tag = Some("v1.0.0".to_string());
+        let tagged = store.insert(&tagged).unwrap();
+        let old = store
+            .insert(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c2")
+                    .day(2)
+                    .result("prove", prove(100.0))
+                    .build(),
+            )
+            .unwrap();
+        let new = store
+            .insert(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c3")
+                    .day(3)
+                    .result("prove", prove(100.0))
+                    .build(),
+            )
+            .unwrap();
+        let policy = RetentionPolicy::new().with_keep_last(1);
+
+        let plan = store.prune(&policy, true).unwrap();
+        assert_eq!(plan.pruned.len(), 1);
+        assert_eq!(plan.pruned[0].id, old);
+        assert!(store.contains(&old).unwrap());
+
+        store.prune(&policy, false).unwrap();
+        assert!(!store.contains(&old).unwrap());
+        assert!(store.contains(&tagged).unwrap() && store.contains(&new).unwrap());
+        assert_eq!(
+            store
+                .metric_history("prove", "latency", None)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn migrates_stored_reports() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        let current = ReportBuilder::new()
+            .implementation("a")
+            .commit("c1")
+            .day(1)
+            .result("prove", prove(100.0))
+            .build();
+        store.insert(&current).unwrap();
+        let mut legacy = ReportBuilder::new()
+            .implementation("a")
+            .commit("c2")
+            .day(2)
+            .result("prove", prove(100.0))
+            .build();
+        legacy.metadata.schema_version = 1;
+        legacy.benchmarks.get_mut("prove").unwrap().latency = Some(MetricValue::new(100.0, "µs"));
+        let legacy = store.insert(&legacy).unwrap();
+
+        let plan = store.migrate(true).unwrap();
+        assert_eq!((plan.migrated.len(), plan.current), (1, 1));
+        assert!(store.contains(&legacy).unwrap());
+
+        let done = store.migrate(false).unwrap();
+        let migrated = &done.migrated[0];
+        assert_eq!(migrated.id, legacy);
+        assert_eq!(migrated.new_id, plan.migrated[0].new_id);
+        assert!(!store.contains(&legacy).unwrap());
+        let report = store.load(&migrated.new_id).unwrap().unwrap();
+        assert_eq!(report.metadata.schema_version, crate::SCHEMA_VERSION);
+        let history = store.metric_history("prove", "latency", None).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].value.unit, "us");
+    }
+
+    #[test]
+    fn dry_run_migration_does_not_lock_writers() {
+        let path =
+            std::env::temp_dir().join(format!("zkbench-sqlite-migrate-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut store = SqliteStore::open(&path).unwrap();
+        store
+            .insert(
+                &ReportBuilder::new()
+                    .implementation("a")
+                    .commit("c1")
+                    .day(1)
+                    .result("prove", prove(100.0))
+                    .build(),
+            )
+            .unwrap();
+        store.conn.busy_timeout(Duration::ZERO).unwrap();
+        let mut writer = SqliteStore::open(&path).unwrap();
+        let tx = writer.write().unwrap();
+        assert_eq!(store.migrate(true).unwrap().current, 1);
+        assert!(store.migrate(false).is_err());
+        drop(tx);
+        drop((writer, store));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parameter-sweep detection.
+//!
+//! Benchmarks that vary a single size parameter are conventionally named
+//! `<group>/<parameter>`, where the parameter is either a plain integer
+//! (`msm/65536`) or a power of two (`msm/2^16`). Grouping them recovers
+//! the scaling curve of each group.
+
+use std::collections::BTreeMap;
+
+use crate::schema::{BenchmarkReport, MetricValue};
+
+/// A single point on a sweep curve.
+#[derive(Debug, Clone)]
+pub struct SweepPoint {
+    /// Numeric value of the swept parameter.
+    pub parameter: f64,
+    /// Full benchmark name this point was taken from.
+    pub benchmark: String,
+    pub latency: MetricValue,
+}
+
+/// A group of benchmarks that differ only in their size parameter.
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    /// Common name prefix, e.g. `msm` for `msm/2^16`.
+    pub name: String,
+    /// Points ordered by ascending parameter.
+    pub points: Vec<SweepPoint>,
+}
+
+/// Extracts all parameter sweeps from a report.
+///
+/// Only benchmarks with a latency metric participate, and groups with
+/// fewer than two points are dropped. Sweeps are returned sorted by name.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::{BenchmarkReport, BenchmarkResult, Metadata, MetricValue, extract_sweeps};
+/// use std::collections::HashMap;
+///
+/// let mut benchmarks = HashMap::new();
+/// for k in [10, 12, 14] {
+///     benchmarks.insert(
+///         format!("ntt/2^{k}"),
+///         BenchmarkResult {
+///             latency: Some(MetricValue::new(k as f64, "ms")),
+///             ..Default::default()
+///         },
+///     );
+/// }
+/// let report = BenchmarkReport {
+///     metadata: Metadata::create("my-impl", "0.1.0"),
+///     benchmarks,
+/// };
+/// let sweeps = extract_sweeps(&report);
+/// assert_eq!(sweeps[0].name, "ntt");
+/// assert_eq!(sweeps[0].points[0].parameter, 1024.0);
+/// ```
+pub fn extract_sweeps(report: &BenchmarkReport) -> Vec<Sweep> {
+    let mut groups: BTreeMap<&str, Vec<SweepPoint>> = BTreeMap::new();
+    for (name, result) in &report.benchmarks {
+        let Some(latency) = &result.latency else {
+            continue;
+        };
+        let Some((group, parameter)) = split_parameter(name) else {
+            continue;
+        };
+        groups.entry(group).or_default().push(SweepPoint {
+            parameter,
+            benchmark: name.clone(),
+            latency: latency.clone(),
+        });
+    }
+    groups
+        .into_iter()
+        .filter(|(_, points)| points.len() >= 2)
+        .map(|(name, mut points)| {
+            points.sort_by(|a, b| a.parameter.total_cmp(&b.parameter));
+            Sweep {
+                name: name.to_string(),
+                points,
+            }
+        })
+        .collect()
+}
+
+/// Splits `group/parameter` into its parts, parsing `N` or `2^N`.
+pub(crate) fn split_parameter(name: &str) -> Option<(&str, f64)> {
+    let (group, last) = name.rsplit_once('/')?;
+    if group.is_empty() {
+        return None;
+    }
+    let parameter = match last.split_once('^') {
+        Some((base, exp)) => base.parse::<f64>().ok()?.powi(exp.parse::<i32>().ok()?),
+        None => last.parse::<u64>().ok()? as f64,
+    };
+    Some((group, parameter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn split_parameter_forms() {
+        assert_eq!(split_parameter("msm/1024"), Some(("msm", 1024.0)));
+        assert_eq!(split_parameter("msm/g1/2^16"), Some(("msm/g1", 65536.0)));
+        assert_eq!(split_parameter("msm/large"), None);
+        assert_eq!(split_parameter("msm"), None);
+        assert_eq!(split_parameter("/12"), None);
+    }
+
+    #[test]
+    fn groups_and_orders_points() {
+        let sweeps = extract_sweeps(
+            &ReportBuilder::new()
+                .latencies_ms(&[
+                    ("fft/2^12", 1.0),
+                    ("fft/2^10", 1.0),
+                    ("fft/2^11", 1.0),
+                    ("other", 1.0),
+                ])
+                .build(),
+        );
+        assert_eq!(sweeps.len(), 1);
+        let params: Vec<_> = sweeps[0].points.iter().map(|p| p.parameter).collect();
+        assert_eq!(params, vec![1024.0, 2048.0, 4096.0]);
+        assert_eq!(sweeps[0].points[0].benchmark, "fft/2^10");
+    }
+
+    #[test]
+    fn single_point_groups_are_dropped() {
+        assert!(
+            extract_sweeps(
+                &ReportBuilder::new()
+                    .latencies_ms(&[("a/1", 1.0), ("b/2", 1.0)])
+                    .build()
+            )
+            .is_empty()
+        );
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Report builder shared by the unit tests.
+
+use crate::schema::{BenchmarkReport, BenchmarkResult, Metadata, MetricValue};
+
+/// Builds a [`BenchmarkReport`] of implementation `impl` 1.0.0, with the
+/// platform and commit of the test run but no branch, tag or local
+/// changes, so reports do not depend on the state of the checkout.
+#[derive(Debug, Clone)]
+pub(crate) struct ReportBuilder {
+    report: BenchmarkReport,
+}
+
+impl ReportBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            report: BenchmarkReport {
+                metadata: Metadata {
+                    branch: None,
+                    git_tag: None,
+                    dirty: false,
+                    run_id: None,
+                    run_url: None,
+                    ..Metadata::create("impl", "1.0.0")
+                },
+                benchmarks: Default::default(),
+            },
+        }
+    }
+
+    pub(crate) fn implementation(mut self, implementation: &str) -> Self {
+        self.report.metadata.implementation = implementation.to_string();
+        self
+    }
+
+    pub(crate) fn version(mut self, version: &str) -> Self {
+        self.report.metadata.version = version.to_string();
+        self
+    }
+
+    pub(crate) fn commit(mut self, commit_sha: &str) -> Self {
+        self.report.metadata.commit_sha = commit_sha.to_string();
+        self
+    }
+
+    pub(crate) fn timestamp(mut self, timestamp: &str) -> Self {
+        self.report.metadata.timestamp = timestamp.to_string();
+        self
+    }
+
+    /// Timestamps the report at midnight UTC of January `day`, 2026.
+    pub(crate) fn day(self, day: u32) -> Self {
+        self.timestamp(&format!("2026-01-{day:02}T00:00:00+00:00"))
+    }
+
+    /// Edits the metadata for what the other methods do not cover.
+    pub(crate) fn metadata(mut self, edit: impl FnOnce(&mut Metadata)) -> Self {
+        edit(&mut self.report.metadata);
+        self
+    }
+
+    /// Adds a benchmark, replacing one of the same name.
+    pub(crate) fn result(mut self, name: &str, result: BenchmarkResult) -> Self {
+        self.report.benchmarks.insert(name.to_string(), result);
+        self
+    }
+
+    pub(crate) fn results<'a>(
+        self,
+        results: impl IntoIterator<Item = (&'a str, BenchmarkResult)>,
+    ) -> Self {
+        results
+            .into_iter()
+            .fold(self, |builder, (name, result)| builder.result(name, result))
+    }
+
+    /// Adds a benchmark with only a latency.
+    pub(crate) fn latency(self, name: &str, latency: MetricValue) -> Self {
+        self.result(
+            name,
+            BenchmarkResult {
+                latency: Some(latency),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Adds benchmarks with only a latency.
+    pub(crate) fn latencies<S: AsRef<str>>(
+        self,
+        entries: impl IntoIterator<Item = (S, MetricValue)>,
+    ) -> Self {
+        entries.into_iter().fold(self, |builder, (name, latency)| {
+            builder.latency(name.as_ref(), latency)
+        })
+    }
+
+    /// Adds benchmarks with only a latency in milliseconds.
+    pub(crate) fn latencies_ms(self, entries: &[(&str, f64)]) -> Self {
+        entries.iter().fold(self, |builder, &(name, ms)| {
+            builder.latency(name, MetricValue::new(ms, "ms"))
+        })
+    }
+
+    /// Tags a benchmark added before.
+    pub(crate) fn tags(mut self, name: &str, tags: &[&str]) -> Self {
+        let result = self
+            .report
+            .benchmarks
+            .get_mut(name)
+            .expect("tagged benchmarks are added first");
+        result.tags = tags.iter().map(|t| t.to_string()).collect();
+        self
+    }
+
+    pub(crate) fn build(self) -> BenchmarkReport {
+        self.report
+    }
+}
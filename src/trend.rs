@@ -0,0 +1,418 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Historical trend analysis.
+//!
+//! Turns a history of reports (e.g. one per nightly run) into one time
+//! series per implementation, benchmark and metric, with summary
+//! statistics and the runs at which the level of a series shifted.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::hash::unique_reports;
+use crate::migrate::load_migrated;
+use crate::schema::BenchmarkReport;
+use crate::statistics::{calculate_statistics, welch_t_test};
+use crate::units::{base_unit, to_base};
+
+/// Options for [`analyze_trends`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendOptions {
+    /// Minimum number of runs on each side of a change point.
+    pub min_segment: usize,
+    /// Significance level the level shift must reach (Welch's t-test on
+    /// the runs before and after).
+    pub alpha: f64,
+    /// Minimum relative shift of the mean (a fraction: `0.05` is 5%) for
+    /// a change point to be reported.
+    pub min_change: f64,
+}
+
+impl Default for TrendOptions {
+    fn default() -> Self {
+        Self {
+            min_segment: 3,
+            alpha: 0.01,
+            min_change: 0.05,
+        }
+    }
+}
+
+/// One run of a series.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrendPoint {
+    pub timestamp: String,
+    pub commit_sha: String,
+    pub version: String,
+    /// Value in the series' base unit.
+    pub value: f64,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub lower_value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub upper_value: Option<f64>,
+}
+
+/// Summary statistics of a series.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrendStats {
+    pub mean: f64,
+    pub stdev: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Least-squares slope in base units per run.
+    pub slope: f64,
+    /// `slope / mean`: the relative drift per run.
+    pub relative_slope: f64,
+    /// `last / first - 1`.
+    pub total_change: f64,
+}
+
+/// A run at which the level of a series shifted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangePoint {
+    /// Index of the first point of the new level.
+    pub index: usize,
+    pub commit_sha: String,
+    pub timestamp: String,
+    pub mean_before: f64,
+    pub mean_after: f64,
+    /// `mean_after / mean_before - 1`.
+    pub change: f64,
+    pub p_value: f64,
+}
+
+/// Time series of one metric of one benchmark of one implementation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trend {
+    pub implementation: String,
+    pub benchmark: String,
+    pub metric: String,
+    /// Base unit of all values (`ns`, `B`, or the recorded unit).
+    pub unit: String,
+    /// Points in chronological order.
+    pub points: Vec<TrendPoint>,
+    pub stats: TrendStats,
+    pub change_points: Vec<ChangePoint>,
+}
+
+/// Reports loaded by [`load_reports`].
+#[derive(Debug, Default)]
+pub struct LoadedReports {
+    /// Reports in chronological order.
+    pub reports: Vec<BenchmarkReport>,
+    /// Files that could not be loaded, with the reason, in path order.
+    pub skipped: Vec<(PathBuf, io::Error)>,
+}
+
+/// Loads every `*.json` report below `dir` (recursively), sorted
+/// chronologically by timestamp. Reports with unparseable timestamps sort
+/// first, in path order. Reports written with an older schema version are
+/// migrated; files that are not reports, or that fail to migrate, are
+/// skipped and listed in [`LoadedReports::skipped`] rather than failing
+/// the whole load.
+pub fn load_reports(dir: impl AsRef<Path>) -> io::Result<LoadedReports> {
+    let mut loaded = LoadedReports::default();
+    collect(dir.as_ref(), &mut loaded)?;
+    sort_chronologically(&mut loaded.reports);
+    Ok(loaded)
+}
+
+fn collect(dir: &Path, loaded: &mut LoadedReports) -> io::Result<()> {
+    let mut entries: Vec<_> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<_>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            collect(&path, loaded)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            match load_migrated(&path) {
+                Ok(report) => loaded.reports.push(report),
+                Err(e) => loaded.skipped.push((path, e)),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sorts reports by timestamp; the sort is stable, so reports with equal
+/// or unparseable timestamps keep their relative order.
+pub(crate) fn sort_chronologically(reports: &mut [BenchmarkReport]) {
+    reports.sort_by_key(|report| parse_timestamp(&report.metadata.timestamp));
+}
+
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Builds a trend for every (implementation, benchmark, metric) recorded
+/// in the reports, sorted by those keys.
+///
+/// Reports are ordered by timestamp first, so they may be passed in any
+/// order, and reports with the same
+/// [`content_hash`](BenchmarkReport::content_hash), such as re-uploads
+/// of a retried CI job, count once, at the earliest timestamp. Values are
+/// converted to the base unit of their family. Change points are found by
+/// binary segmentation: a series is split where the means before and
+/// after differ most significantly, provided the shift passes
+/// `opts.alpha` and `opts.min_change`, and both halves are searched
+/// again.
+pub fn analyze_trends(reports: &[BenchmarkReport], opts: &TrendOptions) -> Vec<Trend> {
+    let mut ordered: Vec<&BenchmarkReport> = reports.iter().collect();
+    ordered.sort_by_key(|report| parse_timestamp(&report.metadata.timestamp));
+    let ordered = unique_reports(ordered);
+
+    let mut series: BTreeMap<(&str, &str, &str), (String, Vec<TrendPoint>)> = BTreeMap::new();
+    for report in ordered {
+        let meta = &report.metadata;
+        for (name, result) in &report.benchmarks {
+            for (metric, value) in result.metrics() {
+                if !value.value.is_finite() {
+                    continue;
+                }
+                let (_, points) = series
+                    .entry((meta.implementation.as_str(), name.as_str(), metric))
+                    .or_insert_with(|| (base_unit(&value.unit), Vec::new()));
+                points.push(TrendPoint {
+                    timestamp: meta.timestamp.clone(),
+                    commit_sha: meta.commit_sha.clone(),
+                    version: meta.version.clone(),
+                    value: to_base(value.value, &value.unit),
+                    lower_value: value.lower_value.map(|v| to_base(v, &value.unit)),
+                    upper_value: value.upper_value.map(|v| to_base(v, &value.unit)),
+                });
+            }
+        }
+    }
+
+    series
+        .into_iter()
+        .map(|((implementation, benchmark, metric), (unit, points))| {
+            let values: Vec<f64> = points.iter().map(|p| p.value).collect();
+            let mut splits = Vec::new();
+            find_change_points(&values, 0, opts, &mut splits);
+            splits.sort_by_key(|(index, _)| *index);
+            let bounds: Vec<usize> = std::iter::once(0)
+                .chain(splits.iter().map(|(index, _)| *index))
+                .chain(std::iter::once(values.len()))
+                .collect();
+            let change_points = splits
+                .iter()
+                .enumerate()
+                .map(|(i, &(index, p_value))| {
+                    // Means of the segments on either side of this split.
+                    let mean_before = mean(&values[bounds[i]..index]);
+                    let mean_after = mean(&values[index..bounds[i + 2]]);
+                    ChangePoint {
+                        index,
+                        commit_sha: points[index].commit_sha.clone(),
+                        timestamp: points[index].timestamp.clone(),
+                        mean_before,
+                        mean_after,
+                        change: mean_after / mean_before - 1.0,
+                        p_value,
+                    }
+                })
+                .collect();
+            Trend {
+                implementation: implementation.to_string(),
+                benchmark: benchmark.to_string(),
+                metric: metric.to_string(),
+                unit,
+                stats: stats(&values),
+                points,
+                change_points,
+            }
+        })
+        .collect()
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn find_change_points(
+    values: &[f64],
+    offset: usize,
+    opts: &TrendOptions,
+    splits: &mut Vec<(usize, f64)>,
+) {
+    let min = opts.min_segment.max(2);
+    if values.len() < 2 * min {
+        return;
+    }
+    let best = (min..=values.len() - min)
+        .filter_map(|split| {
+            let (before, after) = values.split_at(split);
+            let (m_before, m_after) = (mean(before), mean(after));
+            if m_before == 0.0 || (m_after / m_before - 1.0).abs() < opts.min_change {
+                return None;
+            }
+            Some((split, welch_t_test(before, after)?))
+        })
+        .filter(|(_, p_value)| *p_value < opts.alpha)
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((split, p_value)) = best {
+        splits.push((offset + split, p_value));
+        find_change_points(&values[..split], offset, opts, splits);
+        find_change_points(&values[split..], offset + split, opts, splits);
+    }
+}
+
+fn stats(values: &[f64]) -> TrendStats {
+    let (mean, stdev) = calculate_statistics(values);
+    let n = values.len() as f64;
+    let x_mean = (n - 1.0) / 2.0;
+    let (covariance, variance) =
+        values
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(cov, var), (i, y)| {
+                let dx = i as f64 - x_mean;
+                (cov + dx * (y - mean), var + dx * dx)
+            });
+    let slope = if variance > 0.0 {
+        covariance / variance
+    } else {
+        0.0
+    };
+    let first = values[0];
+    let last = values[values.len() - 1];
+    TrendStats {
+        mean,
+        stdev,
+        min: values.iter().copied().fold(f64::INFINITY, f64::min),
+        max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        slope,
+        relative_slope: if mean != 0.0 { slope / mean } else { 0.0 },
+        total_change: if first != 0.0 {
+            last / first - 1.0
+        } else {
+            0.0
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::ReportBuilder;
+
+    #[test]
+    fn orders_points_and_computes_stats() {
+        let reports = vec![
+            ReportBuilder::new()
+                .day(3)
+                .commit(&format!("c{}", 3))
+                .latencies_ms(&[("prove", 3.0)])
+                .build(),
+            ReportBuilder::new()
+                .day(1)
+                .commit(&format!("c{}", 1))
+                .latencies_ms(&[("prove", 1.0)])
+                .build(),
+            ReportBuilder::new()
+                .day(2)
+                .commit(&format!("c{}", 2))
+                .latencies_ms(&[("prove", 2.0)])
+                .build(),
+        ];
+        let trends = analyze_trends(&reports, &TrendOptions::default());
+        assert_eq!(trends.len(), 1);
+        let trend = &trends[0];
+        assert_eq!(trend.unit, "ns");
+        let commits: Vec<_> = trend.points.iter().map(|p| p.commit_sha.as_str()).collect();
+        assert_eq!(commits, ["c1", "c2", "c3"]);
+        assert!((trend.stats.slope - 1e6).abs() < 1e-6);
+        assert!((trend.stats.relative_slope - 0.5).abs() < 1e-9);
+        assert!((trend.stats.total_change - 2.0).abs() < 1e-9);
+        assert!(trend.change_points.is_empty());
+    }
+
+    #[test]
+    fn detects_level_shifts() {
+        let values = [
+            10.0, 10.1, 9.9, 10.0, 10.1, 12.0, 12.1, 11.9, 12.0, 12.1, 9.0, 9.1, 8.9, 9.0,
+        ];
+        let reports: Vec<_> = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                ReportBuilder::new()
+                    .day(i as u32 + 1)
+                    .commit(&format!("c{}", i as u32 + 1))
+                    .latencies_ms(&[("prove", *v)])
+                    .build()
+            })
+            .collect();
+        let trend = &analyze_trends(&reports, &TrendOptions::default())[0];
+        let indices: Vec<_> = trend.change_points.iter().map(|c| c.index).collect();
+        assert_eq!(indices, [5, 10]);
+        let first = &trend.change_points[0];
+        assert_eq!(first.commit_sha, "c6");
+        assert!((first.change - 0.2).abs() < 0.01);
+        assert!((trend.change_points[1].mean_before - 12.02e6).abs() < 1e3);
+
+        let json = serde_json::to_value(trend).unwrap();
+        assert_eq!(json["change_points"][0]["index"], 5);
+    }
+
+    #[test]
+    fn noise_is_not_a_change_point() {
+        let values = [10.0, 10.4, 9.7, 10.2, 9.9, 10.3, 9.8, 10.1];
+        let reports: Vec<_> = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                ReportBuilder::new()
+                    .day(i as u32 + 1)
+                    .commit(&format!("c{}", i as u32 + 1))
+                    .latencies_ms(&[("prove", *v)])
+                    .build()
+            })
+            .collect();
+        let trend = &analyze_trends(&reports, &TrendOptions::default())[0];
+        assert!(trend.change_points.is_empty());
+    }
+
+    #[test]
+    fn loads_directory_recursively() {
+        let dir = std::env::temp_dir().join(format!("zkbench-trend-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        ReportBuilder::new()
+            .day(2)
+            .commit(&format!("c{}", 2))
+            .latencies_ms(&[("prove", 2.0)])
+            .build()
+            .save(dir.join("b.json"))
+            .unwrap();
+        ReportBuilder::new()
+            .day(1)
+            .commit(&format!("c{}", 1))
+            .latencies_ms(&[("prove", 1.0)])
+            .build()
+            .save(dir.join("nested/a.json"))
+            .unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+        fs::write(dir.join("nested/truncated.json"), "{\"metadata\": {").unwrap();
+        let loaded = load_reports(&dir).unwrap();
+        let skipped: Vec<_> = loaded.skipped.iter().map(|(path, _)| path).collect();
+        assert_eq!(skipped, [&dir.join("nested/truncated.json")]);
+        let commits: Vec<_> = loaded
+            .reports
+            .iter()
+            .map(|r| r.metadata.commit_sha.as_str())
+            .collect();
+        assert_eq!(commits, ["c1", "c2"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
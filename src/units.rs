@@ -0,0 +1,261 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Unit conversion and human-readable formatting.
+//!
+//! Values are rescaled within their unit family before display: times to
+//! `ns`/`us`/`ms`/`s`, memory to binary IEC units (`B`/`KiB`/`MiB`/...,
+//! with the legacy `KB`/`MB`/... accepted as their 1024-based aliases),
+//! energy to `uJ`/`mJ`/`J`/`kJ` and `<x>/s` rates to `K`/`M`/`G` decimal
+//! prefixes. Output never depends on the process locale: the decimal
+//! separator is always `.` and no digit grouping is applied.
+
+use crate::schema::MetricValue;
+
+/// Time units, with their size in nanoseconds.
+const TIME_UNITS: &[(&str, f64)] = &[("ns", 1.0), ("us", 1e3), ("ms", 1e6), ("s", 1e9)];
+
+/// Memory units, with their size in bytes.
+const MEMORY_UNITS: &[(&str, f64)] = &[
+    ("B", 1.0),
+    ("KiB", 1024.0),
+    ("MiB", 1024.0 * 1024.0),
+    ("GiB", 1024.0 * 1024.0 * 1024.0),
+    ("TiB", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+];
+
+/// Energy units, with their size in microjoules.
+const ENERGY_UNITS: &[(&str, f64)] = &[("uJ", 1.0), ("mJ", 1e3), ("J", 1e6), ("kJ", 1e9)];
+
+/// Rate prefixes applied to `<unit>/s` throughput values.
+const RATE_PREFIXES: &[(&str, f64)] = &[("", 1.0), ("K", 1e3), ("M", 1e6), ("G", 1e9)];
+
+/// Maps alternative spellings onto the names used in the tables above.
+pub(crate) fn canonical(unit: &str) -> &str {
+    match unit {
+        "µs" | "μs" => "us",
+        "µJ" | "μJ" => "uJ",
+        "KB" | "kB" => "KiB",
+        "MB" => "MiB",
+        "GB" => "GiB",
+        "TB" => "TiB",
+        _ => unit,
+    }
+}
+
+/// Whether `unit` is a time unit, in any accepted spelling.
+pub(crate) fn is_time_unit(unit: &str) -> bool {
+    let unit = canonical(unit);
+    TIME_UNITS.iter().any(|(name, _)| *name == unit)
+}
+
+/// Whether `unit` is a memory unit, in any accepted spelling.
+pub(crate) fn is_memory_unit(unit: &str) -> bool {
+    let unit = canonical(unit);
+    MEMORY_UNITS.iter().any(|(name, _)| *name == unit)
+}
+
+/// Rescales a value to the largest unit of its family in which it is at
+/// least 1, returning the scaled value, the multiplier that was applied
+/// and the new unit. Unknown units are returned unchanged.
+pub(crate) fn scale(value: f64, unit: &str) -> (f64, f64, String) {
+    let unit = canonical(unit);
+    if let Some(scaled) = scale_in(value, unit, TIME_UNITS)
+        .or_else(|| scale_in(value, unit, MEMORY_UNITS))
+        .or_else(|| scale_in(value, unit, ENERGY_UNITS))
+    {
+        return scaled;
+    }
+    if let Some(base) = unit.strip_suffix("/s") {
+        let scaled = scale_in(value, "", RATE_PREFIXES).expect("empty prefix is known");
+        return (scaled.0, scaled.1, format!("{}{}/s", scaled.2, base));
+    }
+    (value, 1.0, unit.to_string())
+}
+
+/// Converts a value to the smallest unit of its family (ns, B, uJ,
+/// `<unit>/s`), so values recorded in different units can be ordered.
+pub(crate) fn to_base(value: f64, unit: &str) -> f64 {
+    let unit = canonical(unit);
+    TIME_UNITS
+        .iter()
+        .chain(MEMORY_UNITS)
+        .chain(ENERGY_UNITS)
+        .find(|(name, _)| *name == unit)
+        .map_or(value, |(_, size)| value * size)
+}
+
+/// Name of the unit [`to_base`] converts `unit` into.
+pub(crate) fn base_unit(unit: &str) -> String {
+    let unit = canonical(unit);
+    if TIME_UNITS.iter().any(|(name, _)| *name == unit) {
+        "ns".to_string()
+    } else if MEMORY_UNITS.iter().any(|(name, _)| *name == unit) {
+        "B".to_string()
+    } else if ENERGY_UNITS.iter().any(|(name, _)| *name == unit) {
+        "uJ".to_string()
+    } else {
+        unit.to_string()
+    }
+}
+
+fn scale_in(value: f64, unit: &str, table: &[(&str, f64)]) -> Option<(f64, f64, String)> {
+    let (_, size) = table.iter().find(|(name, _)| *name == unit)?;
+    let base = value * size;
+    let (name, target) = table
+        .iter()
+        .rev()
+        .find(|(_, target)| base.abs() >= *target)
+        .unwrap_or(&table[0]);
+    let factor = size / target;
+    Some((value * factor, factor, name.to_string()))
+}
+
+/// Formats a number with precision that shrinks as magnitude grows:
+/// one decimal from 100 up, two below, without trailing zeros.
+pub(crate) fn format_number(value: f64) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let formatted = if value.abs() >= 100.0 {
+        format!("{:.1}", value)
+    } else {
+        format!("{:.2}", value)
+    };
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" {
+        "0".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Rescales a value for display, returning the scaled value and its unit.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::scale_value;
+///
+/// assert_eq!(scale_value(2_500_000.0, "ns"), (2.5, "ms".to_string()));
+/// ```
+pub fn scale_value(value: f64, unit: &str) -> (f64, String) {
+    let (value, _, unit) = scale(value, unit);
+    (value, unit)
+}
+
+/// Formats a value as `<value> <unit>` after auto-scaling its unit.
+///
+/// # Example
+///
+/// ```
+/// use zkbench::format_value;
+///
+/// assert_eq!(format_value(123_456_789.0, "ns"), "123.5 ms");
+/// assert_eq!(format_value(1536.0, "MB"), "1.5 GiB");
+/// assert_eq!(format_value(8300.0, "ops/s"), "8.3 Kops/s");
+/// ```
+pub fn format_value(value: f64, unit: &str) -> String {
+    let (value, _, unit) = scale(value, unit);
+    format!("{} {}", format_number(value), unit)
+}
+
+/// Formats a metric's value as `<value> <unit>` after auto-scaling.
+pub fn format_metric(metric: &MetricValue) -> String {
+    format_value(metric.value, &metric.unit)
+}
+
+/// Formats the half-width of a metric's confidence interval as `±<x> <unit>`,
+/// using the same unit the value itself is scaled to. Returns `None` when the
+/// metric carries no bounds.
+pub(crate) fn format_ci(metric: &MetricValue) -> Option<String> {
+    let (lower, upper) = (metric.lower_value?, metric.upper_value?);
+    let half_width = (metric.value - lower)
+        .abs()
+        .max((upper - metric.value).abs());
+    let (_, factor, unit) = scale(metric.value, &metric.unit);
+    Some(format!("±{} {}", format_number(half_width * factor), unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_time_up() {
+        let (value, factor, unit) = scale(123_456_789.0, "ns");
+        assert!((value - 123.456789).abs() < 1e-9);
+        assert!((factor - 1e-6).abs() < 1e-18);
+        assert_eq!(unit, "ms");
+    }
+
+    #[test]
+    fn scale_time_down() {
+        let (value, _, unit) = scale(0.0025, "s");
+        assert!((value - 2.5).abs() < 1e-9);
+        assert_eq!(unit, "ms");
+    }
+
+    #[test]
+    fn scale_memory() {
+        let (value, _, unit) = scale(1536.0, "KB");
+        assert!((value - 1.5).abs() < 1e-9);
+        assert_eq!(unit, "MiB");
+        assert_eq!(scale(2048.0, "MiB").2, "GiB");
+    }
+
+    #[test]
+    fn scale_rate() {
+        let (value, _, unit) = scale(8300.0, "ops/s");
+        assert!((value - 8.3).abs() < 1e-9);
+        assert_eq!(unit, "Kops/s");
+    }
+
+    #[test]
+    fn scale_unknown_unit_is_identity() {
+        assert_eq!(scale(42.0, "widgets"), (42.0, 1.0, "widgets".to_string()));
+    }
+
+    #[test]
+    fn to_base_normalizes_family() {
+        assert_eq!(to_base(2.0, "ms"), 2e6);
+        assert_eq!(to_base(3.0, "µs"), 3e3);
+        assert_eq!(to_base(1.0, "KB"), 1024.0);
+        assert_eq!(to_base(1.0, "KiB"), 1024.0);
+        assert_eq!(to_base(5.0, "ops/s"), 5.0);
+        assert_eq!(base_unit("ms"), "ns");
+        assert_eq!(base_unit("GB"), "B");
+        assert_eq!(base_unit("GiB"), "B");
+        assert_eq!(base_unit("ops/s"), "ops/s");
+        assert_eq!(to_base(1.5, "J"), 1.5e6);
+        assert_eq!(base_unit("kJ"), "uJ");
+    }
+
+    #[test]
+    fn number_formatting() {
+        assert_eq!(format_number(123.456), "123.5");
+        assert_eq!(format_number(1.5), "1.5");
+        assert_eq!(format_number(2.0), "2");
+        assert_eq!(format_number(0.004), "0");
+        assert_eq!(format_number(-0.001), "0");
+        assert_eq!(format_number(12345.67), "12345.7");
+        assert_eq!(format_number(f64::NAN), "NaN");
+    }
+
+    #[test]
+    fn formats_values() {
+        assert_eq!(format_value(123_456_789.0, "ns"), "123.5 ms");
+        assert_eq!(format_value(1536.0, "MB"), "1.5 GiB");
+        assert_eq!(format_value(512.0, "B"), "512 B");
+        assert_eq!(format_value(3.0, "count"), "3 count");
+        assert_eq!(format_value(0.0123, "J"), "12.3 mJ");
+        assert_eq!(format_value(4500.0, "J"), "4.5 kJ");
+    }
+
+    #[test]
+    fn ci_uses_value_unit() {
+        let metric = MetricValue::with_bounds(2_000_000.0, "ns", 1_900_000.0, 2_050_000.0);
+        assert_eq!(format_ci(&metric).unwrap(), "±0.1 ms");
+        assert!(format_ci(&MetricValue::new(1.0, "ns")).is_none());
+    }
+}
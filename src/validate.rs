@@ -0,0 +1,456 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validation of reports beyond what parsing checks.
+//!
+//! A report can parse and still be wrong: a lower bound above the value,
+//! a latency in bytes, a timestamp trend tooling cannot order, or a JSON
+//! object with the same benchmark twice, of which the parser silently
+//! keeps the last. [`validate_json`] checks a report file against the
+//! current schema and [`validate_report`]'s semantic rules, so ingestion
+//! services can reject bad reports before storing them.
+//!
+//! Issues are errors when the report is unusable or misleading, and
+//! warnings when it is usable but probably not what was meant.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::matching::normalize_benchmark_name;
+use crate::migrate::{dropped_fields, pointer_escape};
+use crate::schema::{BenchmarkReport, MetricKind, MetricValue, SCHEMA_VERSION};
+use crate::trend::parse_timestamp;
+use crate::units::{is_memory_unit, is_time_unit};
+
+/// How serious a [`ValidationIssue`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// A problem found in a report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    /// JSON pointer to the offending value, e.g.
+    /// `/benchmarks/prove/latency`; empty for the whole report.
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn error(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    fn warning(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}: {}", self.severity, self.message)
+        } else {
+            write!(f, "{} at {}: {}", self.severity, self.path, self.message)
+        }
+    }
+}
+
+/// Checks a report's JSON: that it parses, has no duplicate keys and no
+/// fields the current schema does not know (which parsing would drop),
+/// then applies [`validate_report`]. Issues are sorted by path.
+pub fn validate_json(json: &str) -> Vec<ValidationIssue> {
+    let mut duplicates = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let walked = Walk {
+        path: String::new(),
+        duplicates: &mut duplicates,
+    }
+    .deserialize(&mut deserializer)
+    .and_then(|()| deserializer.end());
+    if let Err(e) = walked {
+        return vec![ValidationIssue::error("", format!("not valid JSON: {e}"))];
+    }
+    let mut issues: Vec<_> = duplicates
+        .into_iter()
+        .map(|path| {
+            ValidationIssue::error(path, "duplicate key; all but the last value are ignored")
+        })
+        .collect();
+
+    let value: Value = serde_json::from_str(json).expect("the JSON was walked");
+    let report: BenchmarkReport = match serde_json::from_value(value.clone()) {
+        Ok(report) => report,
+        Err(e) => {
+            issues.push(ValidationIssue::error(
+                "",
+                format!("does not match the report schema: {e}"),
+            ));
+            return issues;
+        }
+    };
+    let written = serde_json::to_value(&report).expect("reports always serialize");
+    let mut unknown = Vec::new();
+    dropped_fields(&value, &written, "", &mut unknown);
+    issues.extend(
+        unknown
+            .into_iter()
+            .map(|path| ValidationIssue::warning(path, "unknown field; it is ignored")),
+    );
+    issues.extend(validate_report(&report));
+    issues.sort_by(|a, b| a.path.cmp(&b.path));
+    issues
+}
+
+/// Checks a parsed report against the semantic rules: metadata a store
+/// can index, finite values with ordered bounds, units of the right
+/// family, and benchmark names that stay distinct when normalized.
+pub fn validate_report(report: &BenchmarkReport) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let meta = &report.metadata;
+    if meta.schema_version > SCHEMA_VERSION {
+        issues.push(ValidationIssue::error(
+            "/metadata/schema_version",
+            format!(
+                "schema version {} is newer than the supported version {}",
+                meta.schema_version, SCHEMA_VERSION
+            ),
+        ));
+    } else if meta.schema_version < SCHEMA_VERSION {
+        issues.push(ValidationIssue::warning(
+            "/metadata/schema_version",
+            format!(
+                "written with schema version {}; `zkbench migrate` upgrades it to {}",
+                meta.schema_version, SCHEMA_VERSION
+            ),
+        ));
+    }
+    if meta.implementation.trim().is_empty() {
+        issues.push(ValidationIssue::error(
+            "/metadata/implementation",
+            "implementation is empty",
+        ));
+    }
+    if parse_timestamp(&meta.timestamp).is_none() {
+        issues.push(ValidationIssue::error(
+            "/metadata/timestamp",
+            format!(
+                "`{}` is not an RFC 3339 timestamp, so the report cannot be ordered",
+                meta.timestamp
+            ),
+        ));
+    }
+    if meta.commit_sha.trim().is_empty() || meta.commit_sha == "unknown" {
+        issues.push(ValidationIssue::warning(
+            "/metadata/commit_sha",
+            "no commit recorded; trends and bisection cannot place the report",
+        ));
+    }
+    if report.benchmarks.is_empty() {
+        issues.push(ValidationIssue::warning("/benchmarks", "no benchmarks"));
+    }
+
+    let mut names: Vec<&String> = report.benchmarks.keys().collect();
+    names.sort();
+    let mut normalized: HashMap<String, &str> = HashMap::new();
+    for name in names {
+        let result = &report.benchmarks[name];
+        let path = format!("/benchmarks/{}", pointer_escape(name));
+        if name.trim().is_empty() {
+            issues.push(ValidationIssue::error(&path, "benchmark name is empty"));
+        } else if let Some(other) = normalized.insert(normalize_benchmark_name(name), name) {
+            issues.push(ValidationIssue::warning(
+                &path,
+                format!("`{name}` and `{other}` normalize to the same name and may be confused"),
+            ));
+        }
+        for kind in MetricKind::ALL {
+            if let Some(metric) = kind.get(result) {
+                check_metric(
+                    &format!("{path}/{}", kind.as_str()),
+                    Some(kind),
+                    metric,
+                    &mut issues,
+                );
+            }
+        }
+        let mut counters: Vec<_> = result.counters.iter().collect();
+        counters.sort_by_key(|(name, _)| *name);
+        for (counter, metric) in counters {
+            let counter_path = format!("{path}/counters/{}", pointer_escape(counter));
+            check_metric(&counter_path, None, metric, &mut issues);
+        }
+        if let Some(i) = result.samples.iter().position(|s| !s.is_finite()) {
+            issues.push(ValidationIssue::error(
+                format!("{path}/samples/{i}"),
+                "sample is not a finite number",
+            ));
+        }
+        if !result.samples.is_empty() && result.latency.is_none() {
+            issues.push(ValidationIssue::warning(
+                format!("{path}/samples"),
+                "samples without a latency, so their unit is unknown",
+            ));
+        }
+    }
+    issues
+}
+
+/// Checks one metric: finite, ordered bounds, a unit of the metric's
+/// family, and no negative times or sizes.
+fn check_metric(
+    path: &str,
+    kind: Option<MetricKind>,
+    metric: &MetricValue,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let MetricValue {
+        value,
+        unit,
+        lower_value,
+        upper_value,
+    } = metric;
+    if !value.is_finite() {
+        issues.push(ValidationIssue::error(path, "value is not a finite number"));
+        return;
+    }
+    match kind {
+        Some(MetricKind::Latency) if !is_time_unit(unit) => issues.push(ValidationIssue::error(
+            path,
+            format!("latency unit `{unit}` is not a time unit (ns, us, ms, s)"),
+        )),
+        Some(MetricKind::Memory) if !is_memory_unit(unit) => issues.push(ValidationIssue::error(
+            path,
+            format!("memory unit `{unit}` is not a size unit (B, KiB, MiB, GiB, TiB)"),
+        )),
+        Some(MetricKind::Throughput) if !unit.ends_with("/s") => {
+            issues.push(ValidationIssue::warning(
+                path,
+                format!("throughput unit `{unit}` is not a rate (`<unit>/s`)"),
+            ))
+        }
+        None if unit.trim().is_empty() => {
+            issues.push(ValidationIssue::error(path, "unit is empty"))
+        }
+        _ => {}
+    }
+    if matches!(kind, Some(MetricKind::Latency | MetricKind::Memory)) && *value < 0.0 {
+        issues.push(ValidationIssue::error(path, format!("{value} is negative")));
+    }
+    for (name, bound) in [("lower", lower_value), ("upper", upper_value)] {
+        match bound {
+            Some(bound) if !bound.is_finite() => issues.push(ValidationIssue::error(
+                path,
+                format!("{name} bound is not a finite number"),
+            )),
+            Some(bound) if name == "lower" && bound > value => issues.push(ValidationIssue::error(
+                path,
+                format!("lower bound {bound} is above the value {value}"),
+            )),
+            Some(bound) if name == "upper" && bound < value => issues.push(ValidationIssue::error(
+                path,
+                format!("upper bound {bound} is below the value {value}"),
+            )),
+            _ => {}
+        }
+    }
+}
+
+/// Walks a JSON document, recording the pointers of duplicate object
+/// keys, which `serde_json` otherwise resolves silently.
+struct Walk<'a> {
+    path: String,
+    duplicates: &'a mut Vec<String>,
+}
+
+impl<'de> DeserializeSeed<'de> for Walk<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Walk<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut i = 0;
+        while seq
+            .next_element_seed(Walk {
+                path: format!("{}/{i}", self.path),
+                duplicates: &mut *self.duplicates,
+            })?
+            .is_some()
+        {
+            i += 1;
+        }
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut seen = HashSet::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let path = format!("{}/{}", self.path, pointer_escape(&key));
+            if !seen.insert(key) {
+                self.duplicates.push(path.clone());
+            }
+            map.next_value_seed(Walk {
+                path,
+                duplicates: &mut *self.duplicates,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::BenchmarkResult;
+    use crate::testing::ReportBuilder;
+
+    fn prove() -> BenchmarkResult {
+        BenchmarkResult {
+            latency: Some(MetricValue::with_bounds(10.0, "ms", 9.0, 11.0)),
+            memory: Some(MetricValue::new(2.0, "MiB")),
+            throughput: Some(MetricValue::new(5.0, "proofs/s")),
+            ..Default::default()
+        }
+    }
+
+    fn paths(issues: &[ValidationIssue]) -> Vec<(Severity, &str)> {
+        issues
+            .iter()
+            .map(|i| (i.severity, i.path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn valid_report_has_no_issues() {
+        let json = ReportBuilder::new()
+            .commit("abc123")
+            .result("prove", prove())
+            .build()
+            .to_json(true)
+            .unwrap();
+        assert_eq!(validate_json(&json), []);
+    }
+
+    #[test]
+    fn flags_semantic_problems() {
+        let mut report = ReportBuilder::new()
+            .commit("abc123")
+            .result("prove", prove())
+            .build();
+        report.metadata.timestamp = "yesterday".to_string();
+        let prove = report.benchmarks.get_mut("prove").unwrap();
+        prove.latency = Some(MetricValue::with_bounds(10.0, "MB", 12.0, 9.0));
+        prove.memory = Some(MetricValue::new(-1.0, "B"));
+        prove
+            .counters
+            .insert("hashes".to_string(), MetricValue::new(3.0, ""));
+        report
+            .benchmarks
+            .insert("Prove".to_string(), BenchmarkResult::default());
+
+        let issues = validate_report(&report);
+        assert_eq!(
+            paths(&issues),
+            [
+                (Severity::Error, "/metadata/timestamp"),
+                (Severity::Warning, "/benchmarks/prove"),
+                (Severity::Error, "/benchmarks/prove/latency"),
+                (Severity::Error, "/benchmarks/prove/latency"),
+                (Severity::Error, "/benchmarks/prove/latency"),
+                (Severity::Error, "/benchmarks/prove/memory"),
+                (Severity::Error, "/benchmarks/prove/counters/hashes"),
+            ]
+        );
+        assert_eq!(
+            issues[3].to_string(),
+            "error at /benchmarks/prove/latency: lower bound 12 is above the value 10"
+        );
+    }
+
+    #[test]
+    fn flags_duplicate_and_unknown_keys() {
+        let json = ReportBuilder::new()
+            .commit("abc123")
+            .result("prove", prove())
+            .build()
+            .to_json(false)
+            .unwrap();
+        let json = json.replacen(
+            "\"benchmarks\":{",
+            "\"benchmarks\":{\"prove\":{},\"a/b\":{\"latncy\":{\"value\":1,\"unit\":\"ms\"}},",
+            1,
+        );
+        let issues = validate_json(&json);
+        assert_eq!(
+            paths(&issues),
+            [
+                (Severity::Warning, "/benchmarks/a~1b/latncy"),
+                (Severity::Error, "/benchmarks/prove"),
+            ]
+        );
+        assert_eq!(
+            paths(&validate_json("{\"metadata\": 1")),
+            [(Severity::Error, "")]
+        );
+        assert!(validate_json("{}")[0].message.contains("report schema"));
+    }
+}
@@ -0,0 +1,542 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Golden test vectors shared across implementations.
+//!
+//! A [`VectorStore`] is a directory of named vectors, each the input of a
+//! computation and the hash of its expected output, so that every
+//! implementation benchmarked on the same fixture is checked against the
+//! same answer. A vector `name` is stored as two files:
+//!
+//! - `<name>.bin`, the raw input;
+//! - `<name>.json`, its description, the hash of the input and of the
+//!   expected output, free-form metadata and the history of updates.
+//!
+//! Expected outputs change only with an explicit [`Approval`], such as one
+//! given by setting [`UPDATE_VECTORS_ENV`], and every update is recorded
+//! with its reason so it shows up in review.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use zkbench::vectors::{Approval, VectorStore};
+//! # fn prove(_: &[u8]) -> Vec<u8> { unimplemented!() }
+//!
+//! let store = VectorStore::new("tests/vectors");
+//! let vector = store.load("keccak-1k")?;
+//! let proof = prove(&vector.input);
+//! let test_vectors = store
+//!     .verify_or_update("keccak-1k", &proof, Approval::from_env().as_ref())?
+//!     .into_test_vectors();
+//! # Ok::<(), zkbench::vectors::VectorError>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::atomic::write_atomic;
+use crate::digest::Digest;
+use crate::hash::{HashAlgorithm, TestVectorError, TestVectorMismatch, digest_with};
+use crate::schema::TestVectors;
+
+/// Environment variable approving updates of golden vectors by
+/// [`Approval::from_env`]; its value is the reason for the update.
+pub const UPDATE_VECTORS_ENV: &str = "ZKBENCH_UPDATE_VECTORS";
+
+/// Error returned by [`VectorStore`].
+#[derive(Debug)]
+pub enum VectorError {
+    /// A file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// A manifest is not valid JSON or does not match the format.
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    /// The name cannot be used as a file name.
+    InvalidName(String),
+    /// No vector has this name.
+    NotFound(String),
+    /// [`VectorStore::add`] was given the name of an existing vector.
+    AlreadyExists(String),
+    /// The vector is hashed with an algorithm this build does not
+    /// support.
+    UnsupportedAlgorithm(HashAlgorithm),
+    /// The input file no longer hashes to the digest in the manifest.
+    CorruptInput {
+        name: String,
+        expected: Digest,
+        actual: Digest,
+    },
+    /// The output does not match the expected one.
+    Mismatch {
+        name: String,
+        source: Box<TestVectorMismatch>,
+    },
+}
+
+impl fmt::Display for VectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Self::Parse { path, source } => write!(f, "invalid {}: {}", path.display(), source),
+            Self::InvalidName(name) => write!(f, "invalid vector name '{}'", name),
+            Self::NotFound(name) => write!(f, "no golden vector named '{}'", name),
+            Self::AlreadyExists(name) => write!(f, "golden vector '{}' already exists", name),
+            Self::UnsupportedAlgorithm(algorithm) => {
+                write!(f, "{} support is not enabled", algorithm)
+            }
+            Self::CorruptInput {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "input of golden vector '{}' hashes to {} instead of {}",
+                name, actual, expected
+            ),
+            Self::Mismatch { name, source } => write!(f, "golden vector '{}': {}", name, source),
+        }
+    }
+}
+
+impl std::error::Error for VectorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Parse { source, .. } => Some(source),
+            Self::Mismatch { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Permission to change the expected output of a golden vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Approval {
+    /// Why the expected output changes, recorded with the update.
+    pub reason: String,
+}
+
+impl Approval {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+
+    /// Approval given by a non-empty [`UPDATE_VECTORS_ENV`], if any.
+    pub fn from_env() -> Option<Self> {
+        std::env::var(UPDATE_VECTORS_ENV)
+            .ok()
+            .filter(|reason| !reason.trim().is_empty())
+            .map(Self::new)
+    }
+}
+
+/// A recorded change of the expected output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorUpdate {
+    /// The expected output hash before the update.
+    pub previous_output_hash: Digest,
+    pub reason: String,
+    /// When the update was made, in RFC 3339 format.
+    pub timestamp: String,
+}
+
+/// A named input and the hash of its expected output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoldenVector {
+    pub name: String,
+    pub input: Vec<u8>,
+    /// Hashes of the input and of the expected output, not verified.
+    pub expected: TestVectors,
+    pub description: Option<String>,
+    /// Free-form data such as the curve or circuit size.
+    pub metadata: BTreeMap<String, Value>,
+    /// Changes of the expected output, oldest first.
+    pub updates: Vec<VectorUpdate>,
+}
+
+impl GoldenVector {
+    /// Creates a vector of `input` expecting `output`, hashed with SHA-256.
+    pub fn new(name: impl Into<String>, input: impl Into<Vec<u8>>, output: &[u8]) -> Self {
+        Self::with_algorithm(HashAlgorithm::Sha256, name, input, output)
+    }
+
+    /// Creates a vector whose hashes use `algorithm`.
+    ///
+    /// # Panics
+    ///
+    /// If the algorithm is not [supported](HashAlgorithm::is_supported)
+    /// by this build.
+    pub fn with_algorithm(
+        algorithm: HashAlgorithm,
+        name: impl Into<String>,
+        input: impl Into<Vec<u8>>,
+        output: &[u8],
+    ) -> Self {
+        let input = input.into();
+        let expected = TestVectors::compute(algorithm, &input, output);
+        Self {
+            name: name.into(),
+            input,
+            expected,
+            description: None,
+            metadata: BTreeMap::new(),
+            updates: Vec::new(),
+        }
+    }
+
+    /// Checks an output computed from [`input`](Self::input), returning
+    /// the test vectors to record in the report.
+    pub fn check(&self, output: &[u8]) -> Result<TestVectors, TestVectorError> {
+        self.expected.check(&self.input, output)
+    }
+}
+
+/// Result of [`VectorStore::verify_or_update`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verification {
+    /// The output matched the expected one.
+    Passed(TestVectors),
+    /// The output differed and became the expected one.
+    Updated(TestVectors),
+}
+
+impl Verification {
+    /// The verified test vectors to record in the report.
+    pub fn into_test_vectors(self) -> TestVectors {
+        match self {
+            Self::Passed(vectors) | Self::Updated(vectors) => vectors,
+        }
+    }
+}
+
+/// The `<name>.json` file of a vector.
+#[derive(Serialize, Deserialize)]
+struct Manifest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default)]
+    algorithm: HashAlgorithm,
+    input_hash: Digest,
+    output_hash: Digest,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<String, Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    updates: Vec<VectorUpdate>,
+}
+
+/// A directory of golden vectors.
+#[derive(Debug, Clone)]
+pub struct VectorStore {
+    dir: PathBuf,
+}
+
+impl VectorStore {
+    /// Stores vectors in `dir`, which is created on first add.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the directory vectors are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Lists the vectors, sorted by name. A missing directory has none.
+    pub fn list(&self) -> Result<Vec<String>, VectorError> {
+        let io_error = |source| VectorError::Io {
+            path: self.dir.clone(),
+            source,
+        };
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error(e)),
+        };
+        let mut names = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(io_error)?.path();
+            if path.extension().is_some_and(|ext| ext == "json")
+                && let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+            {
+                names.push(name.to_string());
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    /// Whether a vector named `name` exists.
+    pub fn exists(&self, name: &str) -> bool {
+        self.manifest_path(name).is_ok_and(|path| path.is_file())
+    }
+
+    /// Loads a vector, checking that its input still matches the hash
+    /// recorded when it was added.
+    pub fn load(&self, name: &str) -> Result<GoldenVector, VectorError> {
+        let path = self.manifest_path(name)?;
+        let text = fs::read_to_string(&path).map_err(|source| match source.kind() {
+            io::ErrorKind::NotFound => VectorError::NotFound(name.to_string()),
+            _ => VectorError::Io {
+                path: path.clone(),
+                source,
+            },
+        })?;
+        let manifest: Manifest =
+            serde_json::from_str(&text).map_err(|source| VectorError::Parse { path, source })?;
+        let input_path = self.dir.join(format!("{}.bin", name));
+        let input = fs::read(&input_path).map_err(|source| VectorError::Io {
+            path: input_path,
+            source,
+        })?;
+        if !manifest.algorithm.is_supported() {
+            return Err(VectorError::UnsupportedAlgorithm(manifest.algorithm));
+        }
+        let input_hash = digest_with(manifest.algorithm, &input);
+        if input_hash != manifest.input_hash {
+            return Err(VectorError::CorruptInput {
+                name: name.to_string(),
+                expected: manifest.input_hash,
+                actual: input_hash,
+            });
+        }
+        Ok(GoldenVector {
+            name: name.to_string(),
+            input,
+            expected: TestVectors {
+                input_hash: manifest.input_hash,
+                output_hash: manifest.output_hash,
+                verified: false,
+                algorithm: manifest.algorithm,
+                hmac: None,
+            },
+            description: manifest.description,
+            metadata: manifest.metadata,
+            updates: manifest.updates,
+        })
+    }
+
+    /// Adds a new vector. Existing vectors change only through
+    /// [`update`](Self::update).
+    pub fn add(&self, vector: &GoldenVector) -> Result<(), VectorError> {
+        if self.exists(&vector.name) {
+            return Err(VectorError::AlreadyExists(vector.name.clone()));
+        }
+        self.write(vector)
+    }
+
+    /// Checks an output computed from the input of vector `name`.
+    pub fn verify(&self, name: &str, output: &[u8]) -> Result<TestVectors, VectorError> {
+        self.load(name)?.check(output).map_err(|e| match e {
+            TestVectorError::UnsupportedAlgorithm(algorithm) => {
+                VectorError::UnsupportedAlgorithm(algorithm)
+            }
+            TestVectorError::Mismatch(source) => VectorError::Mismatch {
+                name: name.to_string(),
+                source,
+            },
+        })
+    }
+
+    /// Makes `output` the expected output of vector `name`, recording the
+    /// reason of the approval. The input cannot change; add a new vector
+    /// instead.
+    pub fn update(
+        &self,
+        name: &str,
+        output: &[u8],
+        approval: &Approval,
+    ) -> Result<GoldenVector, VectorError> {
+        let mut vector = self.load(name)?;
+        let output_hash = digest_with(vector.expected.algorithm, output);
+        if output_hash == vector.expected.output_hash {
+            return Ok(vector);
+        }
+        let previous = std::mem::replace(&mut vector.expected.output_hash, output_hash);
+        vector.updates.push(VectorUpdate {
+            previous_output_hash: previous,
+            reason: approval.reason.clone(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        });
+        self.write(&vector)?;
+        Ok(vector)
+    }
+
+    /// Like [`verify`](Self::verify), but with an approval a mismatching
+    /// output is [updated](Self::update) instead of failing.
+    pub fn verify_or_update(
+        &self,
+        name: &str,
+        output: &[u8],
+        approval: Option<&Approval>,
+    ) -> Result<Verification, VectorError> {
+        match (self.verify(name, output), approval) {
+            (Ok(vectors), _) => Ok(Verification::Passed(vectors)),
+            (Err(VectorError::Mismatch { source, .. }), Some(approval)) => {
+                self.update(name, output, approval)?;
+                let mut vectors = source.actual;
+                vectors.verified = true;
+                Ok(Verification::Updated(vectors))
+            }
+            (Err(e), _) => Err(e),
+        }
+    }
+
+    /// Deletes vector `name`.
+    pub fn remove(&self, name: &str) -> Result<(), VectorError> {
+        let manifest = self.manifest_path(name)?;
+        if !manifest.is_file() {
+            return Err(VectorError::NotFound(name.to_string()));
+        }
+        for path in [manifest, self.dir.join(format!("{}.bin", name))] {
+            match fs::remove_file(&path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => {
+                    return Err(VectorError::Io { path, source: e });
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Vector names become file names, so they must not be able to escape
+    /// the vector directory.
+    fn manifest_path(&self, name: &str) -> Result<PathBuf, VectorError> {
+        if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\', '\0']) {
+            return Err(VectorError::InvalidName(name.to_string()));
+        }
+        Ok(self.dir.join(format!("{}.json", name)))
+    }
+
+    fn write(&self, vector: &GoldenVector) -> Result<(), VectorError> {
+        let path = self.manifest_path(&vector.name)?;
+        let manifest = Manifest {
+            description: vector.description.clone(),
+            algorithm: vector.expected.algorithm,
+            input_hash: vector.expected.input_hash,
+            output_hash: vector.expected.output_hash,
+            metadata: vector.metadata.clone(),
+            updates: vector.updates.clone(),
+        };
+        let mut json = serde_json::to_string_pretty(&manifest).expect("manifest serializes");
+        json.push('\n');
+        let input_path = self.dir.join(format!("{}.bin", vector.name));
+        fs::create_dir_all(&self.dir)
+            .and_then(|()| write_atomic(&input_path, &vector.input))
+            .map_err(|source| VectorError::Io {
+                path: input_path,
+                source,
+            })?;
+        write_atomic(&path, json).map_err(|source| VectorError::Io { path, source })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::compute_hash;
+
+    fn store(name: &str) -> VectorStore {
+        let dir =
+            std::env::temp_dir().join(format!("zkbench-vectors-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        VectorStore::new(dir)
+    }
+
+    #[test]
+    fn add_load_verify() {
+        let store = store("roundtrip");
+        assert!(store.list().unwrap().is_empty());
+
+        let mut vector = GoldenVector::new("keccak-1k", vec![1, 2, 3], b"proof");
+        vector.description = Some("1 KiB Keccak preimage".to_string());
+        vector
+            .metadata
+            .insert("curve".to_string(), Value::from("bn254"));
+        store.add(&vector).unwrap();
+        assert!(matches!(
+            store.add(&vector),
+            Err(VectorError::AlreadyExists(_))
+        ));
+        assert_eq!(store.list().unwrap(), ["keccak-1k"]);
+        assert_eq!(store.load("keccak-1k").unwrap(), vector);
+
+        let verified = store.verify("keccak-1k", b"proof").unwrap();
+        assert!(verified.verified);
+        assert_eq!(verified.output_hash.to_string(), compute_hash(b"proof"));
+        let err = store.verify("keccak-1k", b"forged").unwrap_err();
+        assert!(matches!(&err, VectorError::Mismatch { source, .. } if source.output_differs()));
+        assert!(err.to_string().starts_with("golden vector 'keccak-1k': "));
+        assert!(matches!(
+            store.verify("missing", b""),
+            Err(VectorError::NotFound(_))
+        ));
+
+        store.remove("keccak-1k").unwrap();
+        assert!(!store.exists("keccak-1k"));
+        assert!(fs::read_dir(store.dir()).unwrap().next().is_none());
+        fs::remove_dir_all(store.dir()).unwrap();
+    }
+
+    #[test]
+    fn updates_only_with_approval() {
+        let store = store("update");
+        store
+            .add(&GoldenVector::new("msm", b"points".to_vec(), b"old"))
+            .unwrap();
+
+        assert!(store.verify_or_update("msm", b"new", None).is_err());
+        assert!(matches!(
+            store.verify_or_update("msm", b"old", None),
+            Ok(Verification::Passed(_))
+        ));
+        let approval = Approval::new("new transcript domain separator");
+        let updated = store
+            .verify_or_update("msm", b"new", Some(&approval))
+            .unwrap();
+        assert!(matches!(&updated, Verification::Updated(v) if v.verified));
+
+        let vector = store.load("msm").unwrap();
+        assert_eq!(
+            vector.expected.output_hash.to_string(),
+            compute_hash(b"new")
+        );
+        assert_eq!(vector.updates.len(), 1);
+        assert_eq!(
+            vector.updates[0].previous_output_hash.to_string(),
+            compute_hash(b"old")
+        );
+        assert_eq!(vector.updates[0].reason, approval.reason);
+        assert!(store.verify("msm", b"new").is_ok());
+        fs::remove_dir_all(store.dir()).unwrap();
+    }
+
+    #[test]
+    fn detects_corrupt_input() {
+        let store = store("corrupt");
+        store
+            .add(&GoldenVector::new("fft", b"input".to_vec(), b"output"))
+            .unwrap();
+        fs::write(store.dir().join("fft.bin"), b"changed").unwrap();
+        assert!(matches!(
+            store.load("fft"),
+            Err(VectorError::CorruptInput { .. })
+        ));
+        for name in ["", "../fft", "a/b", ".hidden"] {
+            assert!(
+                matches!(store.load(name), Err(VectorError::InvalidName(_))),
+                "{name}"
+            );
+        }
+        fs::remove_dir_all(store.dir()).unwrap();
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2026 zkbench-rust Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Settings and change detection for `zkbench watch`.
+//!
+//! `zkbench watch` re-runs a bench command whenever the sources change
+//! and compares each run with the previous one, for a quick local loop
+//! while optimizing a prover. The `[watch]` table of zkbench.toml
+//! configures it:
+//!
+//! ```toml
+//! [watch]
+//! command = "cargo bench --bench prover"
+//! paths = ["src", "benches"]
+//! ignore = ["target", ".*", "*.json"]
+//! ```
+//!
+//! Changes are found by polling modification times with
+//! [`ChangeDetector`], which needs no platform-specific notification API
+//! and copes with editors that replace files instead of writing them.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::compare::glob_match;
+
+/// The `[watch]` table: what `zkbench watch` runs and watches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchConfig {
+    /// Shell command running the benchmarks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Report the command writes. When unset, the reports the command
+    /// writes into [`OUTPUT_DIR_ENV`](crate::OUTPUT_DIR_ENV), which is
+    /// set for it, are merged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<PathBuf>,
+    /// Files and directories watched for changes.
+    pub paths: Vec<PathBuf>,
+    /// Globs matched against file and directory names to ignore.
+    pub ignore: Vec<String>,
+    /// How often to look for changes, in milliseconds.
+    pub poll_interval_ms: u64,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            command: None,
+            report: None,
+            paths: vec![PathBuf::from(".")],
+            ignore: vec!["target".to_string(), ".*".to_string()],
+            poll_interval_ms: 500,
+        }
+    }
+}
+
+/// Finds files that were created, modified or removed below a set of
+/// paths since the last poll.
+#[derive(Debug)]
+pub struct ChangeDetector {
+    paths: Vec<PathBuf>,
+    ignore: Vec<String>,
+    files: HashMap<PathBuf, (SystemTime, u64)>,
+}
+
+impl ChangeDetector {
+    /// Records the current state of `paths`, skipping entries whose name
+    /// matches one of the `ignore` globs.
+    pub fn new(paths: Vec<PathBuf>, ignore: Vec<String>) -> Self {
+        let mut detector = Self {
+            paths,
+            ignore,
+            files: HashMap::new(),
+        };
+        detector.files = detector.scan();
+        detector
+    }
+
+    /// Returns the files changed since the previous poll, sorted.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let files = self.scan();
+        let mut changed: Vec<PathBuf> = files
+            .iter()
+            .filter(|(path, state)| self.files.get(*path) != Some(state))
+            .map(|(path, _)| path.clone())
+            .chain(
+                self.files
+                    .keys()
+                    .filter(|path| !files.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+        changed.sort();
+        self.files = files;
+        changed
+    }
+
+    fn scan(&self) -> HashMap<PathBuf, (SystemTime, u64)> {
+        let mut files = HashMap::new();
+        for path in &self.paths {
+            self.scan_path(path, &mut files);
+        }
+        files
+    }
+
+    fn scan_path(&self, path: &Path, files: &mut HashMap<PathBuf, (SystemTime, u64)>) {
+        let Ok(metadata) = fs::metadata(path) else {
+            return;
+        };
+        if metadata.is_dir() {
+            let Ok(entries) = fs::read_dir(path) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                if !self.ignore.iter().any(|pattern| glob_match(pattern, &name)) {
+                    self.scan_path(&entry.path(), files);
+                }
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(path.to_path_buf(), (modified, metadata.len()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_changes() {
+        let dir = std::env::temp_dir().join(format!("zkbench-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "fn a() {}").unwrap();
+
+        let mut detector = ChangeDetector::new(vec![dir.clone()], WatchConfig::default().ignore);
+        assert!(detector.poll().is_empty());
+
+        fs::write(dir.join("src/lib.rs"), "fn a() { b() }").unwrap();
+        fs::write(dir.join("src/new.rs"), "").unwrap();
+        fs::write(dir.join("target/out.o"), "").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+        assert_eq!(
+            detector.poll(),
+            [dir.join("src/lib.rs"), dir.join("src/new.rs")]
+        );
+        assert!(detector.poll().is_empty());
+
+        fs::remove_file(dir.join("src/new.rs")).unwrap();
+        assert_eq!(detector.poll(), [dir.join("src/new.rs")]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn parses_the_watch_table() {
+        let config: WatchConfig = toml::from_str(
+            r#"
+command = "cargo bench"
+paths = ["src"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.command.as_deref(), Some("cargo bench"));
+        assert_eq!(config.paths, [PathBuf::from("src")]);
+        assert_eq!(config.ignore, WatchConfig::default().ignore);
+        assert_eq!(config.poll_interval_ms, 500);
+    }
+}